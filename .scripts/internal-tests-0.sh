@@ -34,14 +34,18 @@ $rt test-with-features wtx hashbrown
 $rt test-with-features wtx hmac
 $rt test-with-features wtx http-client-pool
 $rt test-with-features wtx http-server-framework
+$rt test-with-features wtx http1
 $rt test-with-features wtx http2
 $rt test-with-features wtx httparse
 $rt test-with-features wtx matchit
 $rt test-with-features wtx memchr
+$rt test-with-features wtx mssql
+$rt test-with-features wtx mysql
 $rt test-with-features wtx pool
 $rt test-with-features wtx postgres
 $rt test-with-features wtx quick-protobuf
 $rt test-with-features wtx rand_chacha
+$rt test-with-features wtx redis
 $rt test-with-features wtx ring
 $rt test-with-features wtx rust_decimal
 $rt test-with-features wtx rustls
@@ -52,6 +56,7 @@ $rt test-with-features wtx serde_json
 $rt test-with-features wtx sha1
 $rt test-with-features wtx sha2
 $rt test-with-features wtx simdutf8
+$rt test-with-features wtx sqlite
 $rt test-with-features wtx std
 $rt test-with-features wtx tokio
 $rt test-with-features wtx tokio-rustls
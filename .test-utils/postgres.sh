@@ -75,12 +75,22 @@ ssl_cert_file = 'cert.pem'
 ssl_key_file = 'key.pem'
 EOF
 cat > "$PGDATA/pg_hba.conf" <<-EOF
-host    all wtx_scram   0.0.0.0/0   scram-sha-256
-host    all wtx_scram       ::0/0   scram-sha-256
+host    all wtx_md5         0.0.0.0/0   md5
+host    all wtx_md5             ::0/0   md5
+host    all wtx_password    0.0.0.0/0   password
+host    all wtx_password        ::0/0   password
+host    all wtx_scram       0.0.0.0/0   scram-sha-256
+host    all wtx_scram           ::0/0   scram-sha-256
 EOF
 
 psql -v ON_ERROR_STOP=1 --username $POSTGRES_USER <<-EOF
+    SET password_encryption TO 'md5';
+    CREATE ROLE wtx_md5 PASSWORD 'wtx' LOGIN;
+    GRANT ALL ON DATABASE wtx TO wtx_md5;
+
     SET password_encryption TO 'scram-sha-256';
+    CREATE ROLE wtx_password PASSWORD 'wtx' LOGIN;
+    GRANT ALL ON DATABASE wtx TO wtx_password;
     CREATE ROLE wtx_scram PASSWORD 'wtx' LOGIN;
     GRANT ALL ON DATABASE wtx TO wtx_scram;
     ALTER DATABASE wtx OWNER TO wtx_scram;
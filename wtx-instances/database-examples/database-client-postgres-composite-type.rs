@@ -6,11 +6,9 @@
 extern crate tokio;
 extern crate wtx;
 extern crate wtx_instances;
+extern crate wtx_macros;
 
-use wtx::database::{
-  client::postgres::{DecodeValue, EncodeValue, Postgres, StructDecoder, StructEncoder},
-  Decode, Encode, Executor as _, Record,
-};
+use wtx::database::{Executor as _, Record};
 
 #[tokio::main]
 async fn main() -> wtx::Result<()> {
@@ -19,28 +17,17 @@ async fn main() -> wtx::Result<()> {
   let _ = executor
     .execute_with_stmt(
       "INSERT INTO custom_composite_table VALUES ($1, $2::custom_composite_type)",
-      (1, CustomCompositeType(2, 9)),
+      (1, CustomCompositeType(2, String::from("34"))),
     )
     .await?;
   let record = executor.fetch_with_stmt("SELECT * FROM custom_composite_table", ()).await?;
   assert_eq!(record.decode::<_, i32>(0)?, 1);
-  assert_eq!(record.decode::<_, CustomCompositeType>(1)?, CustomCompositeType(2, 9));
+  assert_eq!(
+    record.decode::<_, CustomCompositeType>(1)?,
+    CustomCompositeType(2, String::from("34"))
+  );
   Ok(())
 }
 
-#[derive(Debug, PartialEq)]
-struct CustomCompositeType(u32, u64);
-
-impl Decode<'_, Postgres<wtx::Error>> for CustomCompositeType {
-  fn decode(input: &DecodeValue<'_>) -> Result<Self, wtx::Error> {
-    let mut sd = StructDecoder::<wtx::Error>::new(input);
-    Ok(Self(sd.decode()?, sd.decode()?))
-  }
-}
-
-impl Encode<Postgres<wtx::Error>> for CustomCompositeType {
-  fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), wtx::Error> {
-    let _ev = StructEncoder::<wtx::Error>::new(ev)?.encode(self.0)?.encode(self.1)?;
-    Ok(())
-  }
-}
+#[derive(Debug, PartialEq, wtx_macros::Decode, wtx_macros::Encode)]
+struct CustomCompositeType(i32, #[postgres(ty = "Varchar")] String);
@@ -6,11 +6,9 @@
 extern crate tokio;
 extern crate wtx;
 extern crate wtx_instances;
+extern crate wtx_macros;
 
-use wtx::database::{
-  client::postgres::{DecodeValue, EncodeValue, Postgres},
-  Decode, Encode, Executor as _, Record,
-};
+use wtx::database::{Executor as _, Record};
 
 #[tokio::main]
 async fn main() -> wtx::Result<()> {
@@ -25,33 +23,10 @@ async fn main() -> wtx::Result<()> {
   Ok(())
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, wtx_macros::Decode, wtx_macros::Encode)]
+#[postgres(rename_all = "lowercase")]
 enum Enum {
   Foo,
   Bar,
   Baz,
 }
-
-impl Decode<'_, Postgres<wtx::Error>> for Enum {
-  fn decode(input: &DecodeValue<'_>) -> Result<Self, wtx::Error> {
-    let s = <&str as Decode<Postgres<wtx::Error>>>::decode(input)?;
-    Ok(match s {
-      "foo" => Self::Foo,
-      "bar" => Self::Bar,
-      "baz" => Self::Baz,
-      _ => return Err(wtx::Error::UnexpectedString { length: 3 }),
-    })
-  }
-}
-
-impl Encode<Postgres<wtx::Error>> for Enum {
-  fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), wtx::Error> {
-    let s = match self {
-      Self::Foo => "foo",
-      Self::Bar => "bar",
-      Self::Baz => "baz",
-    };
-    <_ as Encode<Postgres<wtx::Error>>>::encode(&s, ev)?;
-    Ok(())
-  }
-}
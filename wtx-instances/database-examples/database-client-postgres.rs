@@ -14,11 +14,18 @@ async fn main() -> wtx::Result<()> {
   let mut executor = wtx_instances::executor_postgres(&uri).await?;
   executor
     .transaction(|this| async {
-      this.execute("CREATE TABLE IF NOT EXISTS example(id INT, name VARCHAR)", |_| {}).await?;
-      this
-        .execute_with_stmt("INSERT INTO foo VALUES ($1, $2), ($3, $4)", (1u32, "one", 2u32, "two"))
-        .await?;
-      Ok(((), this))
+      let rslt = async {
+        this.execute("CREATE TABLE IF NOT EXISTS example(id INT, name VARCHAR)", |_| {}).await?;
+        this
+          .execute_with_stmt(
+            "INSERT INTO foo VALUES ($1, $2), ($3, $4)",
+            (1u32, "one", 2u32, "two"),
+          )
+          .await?;
+        Ok(())
+      }
+      .await;
+      (rslt, this)
     })
     .await?;
   let records = executor
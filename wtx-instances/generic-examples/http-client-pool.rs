@@ -2,7 +2,7 @@
 //!
 //! This snippet requires ~25 dependencies and has an optimized binary size of ~700K.
 //!
-//! Currently, only HTTP/2 is supported.
+//! The pool is composed by HTTP/1.1 connections, see `ClientPoolBuilder::tokio` for HTTP/2.
 
 extern crate tokio;
 extern crate wtx;
@@ -17,7 +17,7 @@ use wtx::{
 async fn main() -> wtx::Result<()> {
   let uri = Uri::new("http://www.example.com");
   let res = ReqBuilder::get(ReqResBuffer::empty())
-    .send(&mut ClientPoolBuilder::tokio(1).build().lock(&uri).await?.client, &uri)
+    .send_http1(&mut ClientPoolBuilder::tokio_http1(1).build().lock(&uri).await?.client, &uri)
     .await?;
   println!("{}", from_utf8_basic(&res.rrd.body)?);
   Ok(())
@@ -6,10 +6,27 @@ pub(crate) enum Error {
   AbsentApiError,
   UnknownApiMode(Span),
 
-  // FromRecords
+  // FromRecord / FromRecords
   MissingDatabase(Span),
   MissingId(Span),
   UnknownFieldTy(Span),
+  UnknownFromRecordAttr(Span),
+
+  // Postgres
+  UnknownPostgresAttr(Span),
+  UnknownRenameRule(Span),
+
+  // Query
+  #[cfg(feature = "postgres")]
+  InvalidColumnName(Span, String),
+  #[cfg(feature = "postgres")]
+  QueryDescription(Span, String),
+  #[cfg(feature = "postgres")]
+  UnknownQueryField(Span),
+  #[cfg(feature = "postgres")]
+  UnsupportedQueryColumn(Span, String),
+  #[cfg(feature = "postgres")]
+  UnsupportedQueryParam(Span, usize),
 
   // Pkg
   AbsentFieldInUnnamedStruct(Span),
@@ -59,6 +76,53 @@ impl From<Error> for syn::Error {
         span,
         "Unknown field ty. Possible values are `decode`, `id`, `many` and `ony`",
       ),
+      Error::UnknownFromRecordAttr(span) => syn::Error::new(
+        span,
+        "Unknown attribute. Fields of named structures accept `#[from_record(rename = \"column\")]`",
+      ),
+      Error::UnknownPostgresAttr(span) => syn::Error::new(
+        span,
+        "Unknown attribute. Enums accept `#[postgres(oid = 123, rename_all = \"snake_case\")]`, \
+          variants accept `#[postgres(rename = \"label\")]` and structures accept \
+          `#[postgres(oid = 123)]`",
+      ),
+      Error::UnknownRenameRule(span) => syn::Error::new(
+        span,
+        "Unknown rule. Possible values are `camelCase`, `kebab-case`, `lowercase`, `PascalCase`, \
+          `SCREAMING-KEBAB-CASE`, `SCREAMING_SNAKE_CASE`, `snake_case` and `UPPERCASE`",
+      ),
+      #[cfg(feature = "postgres")]
+      Error::InvalidColumnName(span, name) => syn::Error::new(
+        span,
+        format!(
+          "Column `{name}` isn't a valid Rust identifier. Rename it with an alias, for example, \
+            `AS some_name`"
+        ),
+      ),
+      #[cfg(feature = "postgres")]
+      Error::QueryDescription(span, err) => {
+        syn::Error::new(span, format!("Unable to describe the query: {err}"))
+      }
+      #[cfg(feature = "postgres")]
+      Error::UnknownQueryField(span) => {
+        syn::Error::new(span, "This field doesn't match any column returned by the query")
+      }
+      #[cfg(feature = "postgres")]
+      Error::UnsupportedQueryColumn(span, name) => syn::Error::new(
+        span,
+        format!(
+          "Column `{name}` doesn't have a known Rust counterpart. Declare a field named `{name}` \
+            with the desired type"
+        ),
+      ),
+      #[cfg(feature = "postgres")]
+      Error::UnsupportedQueryParam(span, idx) => syn::Error::new(
+        span,
+        format!(
+          "Parameter `${idx}` doesn't have a known Rust counterpart. Cast it to a known type, for \
+            example, `${idx}::text`"
+        ),
+      ),
       Error::AbsentFieldInUnnamedStruct(span) => syn::Error::new(
         span,
         "Unnamed structures must have a `#[pkg::field]` attribute on each field.",
@@ -0,0 +1,115 @@
+use crate::misc::{is_option, single_elem};
+use proc_macro2::TokenStream;
+use syn::{
+  parse::{Parse, ParseStream},
+  parse_macro_input,
+  spanned::Spanned as _,
+  Attribute, Data, DeriveInput, Fields, GenericParam, Ident, Lit, Meta, NestedMeta, Path, Type,
+};
+
+pub(crate) fn from_record(item: proc_macro::TokenStream) -> crate::Result<proc_macro::TokenStream> {
+  let input = parse_macro_input::parse::<DeriveInput>(item)?;
+  let name = &input.ident;
+
+  let mut database_opt = None;
+  for input_attr in &input.attrs {
+    if input_attr.path.is_ident("from_record") {
+      database_opt = Some(syn::parse2::<ContainerAttrs>(input_attr.tokens.clone())?.database);
+    }
+  }
+  let database = database_opt.ok_or_else(|| crate::Error::MissingDatabase(name.span()))?;
+
+  let mut generics = input.generics.clone();
+  for param in &mut generics.params {
+    if let GenericParam::Type(type_param) = param {
+      type_param.bounds.push(syn::parse_quote!(for<'__de> wtx::database::Decode<'__de, #database>));
+    }
+  }
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+  let mut field_values = Vec::new();
+  let body = match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => {
+        let mut field_names = Vec::new();
+        for elem in &fields.named {
+          let Some(field_name) = &elem.ident else {
+            return Err(crate::Error::UnsupportedStructure);
+          };
+          let column = match field_rename(&elem.attrs)? {
+            Some(elem) => elem,
+            None => field_name.to_string().trim_start_matches("r#").to_owned(),
+          };
+          field_names.push(field_name);
+          field_values.push(decode_call(&column, &elem.ty));
+        }
+        quote::quote! { Ok(Self { #(#field_names: #field_values,)* }) }
+      }
+      Fields::Unnamed(fields) => {
+        for (idx, elem) in fields.unnamed.iter().enumerate() {
+          if let Some(attr) = elem.attrs.iter().find(|el| el.path.is_ident("from_record")) {
+            return Err(crate::Error::UnknownFromRecordAttr(attr.span()));
+          }
+          field_values.push(decode_call(&idx, &elem.ty));
+        }
+        quote::quote! { Ok(Self(#(#field_values,)*)) }
+      }
+      Fields::Unit => quote::quote! { Ok(Self) },
+    },
+    _ => return Err(crate::Error::UnsupportedStructure),
+  };
+
+  let expanded = quote::quote! {
+    impl #impl_generics wtx::database::FromRecord<#database> for #name #ty_generics
+    #where_clause
+    {
+      #[inline]
+      fn from_record(
+        _record: &<#database as wtx::database::Database>::Record<'_>,
+      ) -> Result<Self, <#database as wtx::database::Database>::Error> {
+        use wtx::database::Record as _;
+        #body
+      }
+    }
+  };
+  Ok(proc_macro::TokenStream::from(expanded))
+}
+
+#[derive(Debug)]
+struct ContainerAttrs {
+  database: Path,
+}
+
+impl Parse for ContainerAttrs {
+  fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+    let content_paren;
+    syn::parenthesized!(content_paren in input);
+    let database = content_paren.parse::<Path>()?;
+    Ok(Self { database })
+  }
+}
+
+pub(crate) fn decode_call(ci: &impl quote::ToTokens, ty: &Type) -> TokenStream {
+  let method = if is_option(ty) {
+    Ident::new("decode_opt", ty.span())
+  } else {
+    Ident::new("decode", ty.span())
+  };
+  quote::quote! { _record.#method(#ci)? }
+}
+
+fn field_rename(attrs: &[Attribute]) -> crate::Result<Option<String>> {
+  let Some(attr) = attrs.iter().find(|el| el.path.is_ident("from_record")) else {
+    return Ok(None);
+  };
+  if let Meta::List(meta_list) = attr.parse_meta()? {
+    if let Some(NestedMeta::Meta(Meta::NameValue(name_value))) =
+      single_elem(meta_list.nested.iter())
+    {
+      if let (true, Lit::Str(lit)) = (name_value.path.is_ident("rename"), &name_value.lit) {
+        return Ok(Some(lit.value()));
+      }
+    }
+  }
+  Err(crate::Error::UnknownFromRecordAttr(attr.span()))
+}
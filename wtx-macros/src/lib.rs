@@ -4,9 +4,13 @@
 
 mod client_api_framework;
 mod error;
+mod from_record;
 mod from_records;
 mod http;
 mod misc;
+mod postgres;
+#[cfg(feature = "postgres")]
+mod query;
 
 use error::Error;
 
@@ -36,6 +40,92 @@ pub fn conn_aux(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
   }
 }
 
+/// Decode
+///
+/// Implements `wtx::database::Decode` for PostgreSQL. Structures are decoded as composite types
+/// and enums with unit variants are decoded as textual enums.
+///
+/// Variants can be renamed with `#[postgres(rename = "label")]` or with a container-level
+/// `#[postgres(rename_all = "snake_case")]`.
+///
+/// ```rust
+/// #[derive(wtx_macros::Decode)]
+/// struct CustomComposite(i32, Option<String>);
+///
+/// #[derive(wtx_macros::Decode)]
+/// #[postgres(rename_all = "lowercase")]
+/// enum CustomEnum {
+///   Foo,
+///   #[postgres(rename = "other_bar")]
+///   Bar,
+/// }
+/// ```
+#[proc_macro_derive(Decode, attributes(postgres))]
+pub fn decode(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  match postgres::decode(item) {
+    Err(err) => syn::Error::from(err).to_compile_error().into(),
+    Ok(elem) => elem,
+  }
+}
+
+/// Encode
+///
+/// Implements `wtx::database::Encode` for PostgreSQL. Accepts the same structures and attributes
+/// of [`Decode`]. Fields of composite types must implement `wtx::database::Typed` unless their
+/// type is specified with `#[postgres(ty = "Varchar")]`, which must be a variant of
+/// `wtx::database::client::postgres::Ty`.
+///
+/// ```rust
+/// #[derive(wtx_macros::Encode)]
+/// struct CustomComposite(i32, #[postgres(ty = "Varchar")] Option<String>);
+///
+/// #[derive(wtx_macros::Encode)]
+/// #[postgres(rename_all = "lowercase")]
+/// enum CustomEnum {
+///   Foo,
+///   #[postgres(rename = "other_bar")]
+///   Bar,
+/// }
+/// ```
+#[proc_macro_derive(Encode, attributes(postgres))]
+pub fn encode(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  match postgres::encode(item) {
+    Err(err) => syn::Error::from(err).to_compile_error().into(),
+    Ok(elem) => elem,
+  }
+}
+
+/// From record
+///
+/// Implements `wtx::database::FromRecord` for the database specified in
+/// `#[from_record(SomeDatabase)]`. Named fields are decoded by column name, which can be
+/// overwritten with `#[from_record(rename = "column")]`, and unnamed fields are decoded by column
+/// index.
+///
+/// ```rust
+/// type Postgres = wtx::database::client::postgres::Postgres<wtx::Error>;
+///
+/// #[derive(wtx_macros::FromRecord)]
+/// #[from_record(Postgres)]
+/// struct User {
+///   id: i32,
+///   #[from_record(rename = "user_name")]
+///   name: String,
+///   nickname: Option<String>,
+/// }
+///
+/// #[derive(wtx_macros::FromRecord)]
+/// #[from_record(Postgres)]
+/// struct Pair(i32, String);
+/// ```
+#[proc_macro_derive(FromRecord, attributes(from_record))]
+pub fn from_record(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  match from_record::from_record(item) {
+    Err(err) => syn::Error::from(err).to_compile_error().into(),
+    Ok(elem) => elem,
+  }
+}
+
 /// Package
 ///
 /// A framework-like attribute placed in inline modules that creates all the mandatory elements
@@ -68,6 +158,43 @@ pub fn pkg(
   }
 }
 
+/// Query
+///
+/// Checks a PostgreSQL command at compile time and turns the annotated structure into a row
+/// whose fields are the returned columns. Also creates a `{Name}Params` tuple with the types of
+/// the `$N` parameters and a `{Name}::SQL` constant.
+///
+/// The command is described by the database pointed by the `DATABASE_URI` environment variable
+/// through a connection without TLS. Descriptions are written to the `.wtx/queries` directory of
+/// the current crate when `WTX_REFRESH_QUERIES=true` and these cached descriptions are used when
+/// the variable isn't set or when `WTX_OFFLINE=true`.
+///
+/// Columns are decoded into owned types and columns that aren't `NOT NULL` table columns are
+/// wrapped in `Option`, which is also the case of all columns of commands with outer joins.
+/// Declared fields overwrite the inferred types of the columns with the same names, which is
+/// necessary for user-defined types.
+///
+/// ```ignore
+/// #[wtx_macros::query("SELECT id, name, mood FROM person WHERE id = $1")]
+/// pub struct Person {
+///   mood: Mood,
+/// }
+///
+/// let params: PersonParams = (1,);
+/// let person: Person = executor.simple_entity(Person::SQL, params).await?;
+/// ```
+#[cfg(feature = "postgres")]
+#[proc_macro_attribute]
+pub fn query(
+  attr: proc_macro::TokenStream,
+  item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+  match query::query(attr, item) {
+    Err(err) => syn::Error::from(err).to_compile_error().into(),
+    Ok(elem) => elem,
+  }
+}
+
 /// From records
 #[proc_macro_derive(FromRecords, attributes(from_records))]
 pub fn from_records(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -76,3 +203,25 @@ pub fn from_records(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     Ok(elem) => elem,
   }
 }
+
+/// Typed
+///
+/// Implements `wtx::database::Typed` for PostgreSQL. Structures are mapped to `Ty::Record` and
+/// enums are mapped to `Ty::Text` unless an explicit OID is provided through
+/// `#[postgres(oid = 123)]`, which is necessary when the type is an element of an array.
+///
+/// ```rust
+/// #[derive(wtx_macros::Typed)]
+/// #[postgres(oid = 16_385)]
+/// enum CustomEnum {
+///   Foo,
+///   Bar,
+/// }
+/// ```
+#[proc_macro_derive(Typed, attributes(postgres))]
+pub fn typed(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  match postgres::typed(item) {
+    Err(err) => syn::Error::from(err).to_compile_error().into(),
+    Ok(elem) => elem,
+  }
+}
@@ -2,7 +2,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use syn::{
   punctuated::Punctuated,
   token::{Bracket, Pound},
-  AttrStyle, Attribute, GenericParam, Generics, Path, PathArguments, PathSegment, Token,
+  AttrStyle, Attribute, GenericParam, Generics, Path, PathArguments, PathSegment, Token, Type,
   WherePredicate,
 };
 
@@ -33,6 +33,15 @@ pub(crate) fn has_at_least_one_doc(attrs: &[Attribute]) -> bool {
   attrs_by_names(attrs, ["doc"])[0].is_some()
 }
 
+pub(crate) fn is_option(ty: &Type) -> bool {
+  if let Type::Path(path) = ty {
+    if let Some(last) = path.path.segments.last() {
+      return last.ident == "Option";
+    }
+  }
+  false
+}
+
 pub(crate) fn parts_from_generics(
   generics: &Generics,
 ) -> (&Punctuated<GenericParam, Token![,]>, &Punctuated<WherePredicate, Token![,]>) {
@@ -0,0 +1,224 @@
+mod attrs;
+mod rename_rule;
+
+use crate::misc::is_option;
+use attrs::{ContainerAttrs, FieldAttrs, VariantAttrs};
+use proc_macro2::{Ident, TokenStream};
+use syn::{
+  parse_macro_input, spanned::Spanned as _, Data, DeriveInput, Fields, GenericParam, Generics,
+};
+
+pub(crate) fn decode(item: proc_macro::TokenStream) -> crate::Result<proc_macro::TokenStream> {
+  let input = parse_macro_input::parse::<DeriveInput>(item)?;
+  let container_attrs = ContainerAttrs::try_from(&*input.attrs)?;
+  let name = &input.ident;
+  let generics = generics_with_bounds(
+    &input.generics,
+    Some(syn::parse_quote!('__de)),
+    &quote::quote! { wtx::database::Decode<'__de, wtx::database::client::postgres::Postgres<__E>> },
+  );
+  let (impl_generics, _, where_clause) = generics.split_for_impl();
+  let (_, ty_generics, _) = input.generics.split_for_impl();
+  let body = match &input.data {
+    Data::Enum(data) => {
+      let (labels, variants) = enum_labels(&container_attrs, data)?;
+      quote::quote! {
+        let s = <&str as wtx::database::Decode<
+          '__de, wtx::database::client::postgres::Postgres<__E>
+        >>::decode(dv)?;
+        Ok(match s {
+          #(#labels => Self::#variants,)*
+          _ => {
+            return Err(__E::from(
+              wtx::database::client::postgres::PostgresError::UnexpectedValueFromBytes {
+                expected: core::any::type_name::<Self>(),
+              }
+              .into(),
+            ));
+          }
+        })
+      }
+    }
+    Data::Struct(data) => {
+      container_attrs.check_struct()?;
+      for field in &data.fields {
+        let _ = FieldAttrs::try_from(&*field.attrs)?;
+      }
+      let decodes = data.fields.iter().map(|field| {
+        let method = if is_option(&field.ty) {
+          Ident::new("decode_opt", field.ty.span())
+        } else {
+          Ident::new("decode", field.ty.span())
+        };
+        quote::quote! { _sd.#method()? }
+      });
+      let value = match &data.fields {
+        Fields::Named(fields) => {
+          let field_names = fields.named.iter().map(|el| &el.ident);
+          quote::quote! { Self { #(#field_names: #decodes,)* } }
+        }
+        Fields::Unnamed(_) => quote::quote! { Self(#(#decodes,)*) },
+        Fields::Unit => quote::quote! { Self },
+      };
+      quote::quote! {
+        let mut _sd = wtx::database::client::postgres::StructDecoder::<__E>::new(dv);
+        Ok(#value)
+      }
+    }
+    Data::Union(_) => return Err(crate::Error::UnsupportedStructure),
+  };
+  let expanded = quote::quote! {
+    impl #impl_generics wtx::database::Decode<'__de, wtx::database::client::postgres::Postgres<__E>>
+      for #name #ty_generics
+    #where_clause
+    {
+      #[inline]
+      fn decode(
+        dv: &wtx::database::client::postgres::DecodeValue<'__de>,
+      ) -> Result<Self, __E> {
+        #body
+      }
+    }
+  };
+  Ok(proc_macro::TokenStream::from(expanded))
+}
+
+pub(crate) fn encode(item: proc_macro::TokenStream) -> crate::Result<proc_macro::TokenStream> {
+  let input = parse_macro_input::parse::<DeriveInput>(item)?;
+  let container_attrs = ContainerAttrs::try_from(&*input.attrs)?;
+  let name = &input.ident;
+  let generics = generics_with_bounds(
+    &input.generics,
+    None,
+    &quote::quote! {
+      wtx::database::Encode<wtx::database::client::postgres::Postgres<__E>>
+        + wtx::database::Typed<wtx::database::client::postgres::Postgres<__E>>
+    },
+  );
+  let (impl_generics, _, where_clause) = generics.split_for_impl();
+  let (_, ty_generics, _) = input.generics.split_for_impl();
+  let body = match &input.data {
+    Data::Enum(data) => {
+      let (labels, variants) = enum_labels(&container_attrs, data)?;
+      quote::quote! {
+        let s = match self {
+          #(Self::#variants => #labels,)*
+        };
+        <&str as wtx::database::Encode<wtx::database::client::postgres::Postgres<__E>>>::encode(
+          &s, ev,
+        )
+      }
+    }
+    Data::Struct(data) => {
+      container_attrs.check_struct()?;
+      let mut encodes = Vec::new();
+      for (idx, field) in data.fields.iter().enumerate() {
+        let field_attrs = FieldAttrs::try_from(&*field.attrs)?;
+        let field_name = match &field.ident {
+          Some(elem) => quote::quote! { #elem },
+          None => {
+            let index = syn::Index::from(idx);
+            quote::quote! { #index }
+          }
+        };
+        encodes.push(match field_attrs.ty {
+          Some(elem) => quote::quote! {
+            _ev = _ev.encode_with_ty(&self.#field_name, wtx::database::client::postgres::Ty::#elem)?;
+          },
+          None => quote::quote! { _ev = _ev.encode(&self.#field_name)?; },
+        });
+      }
+      quote::quote! {
+        let mut _ev = wtx::database::client::postgres::StructEncoder::<__E>::new(ev)?;
+        #(#encodes)*
+        Ok(())
+      }
+    }
+    Data::Union(_) => return Err(crate::Error::UnsupportedStructure),
+  };
+  let expanded = quote::quote! {
+    impl #impl_generics wtx::database::Encode<wtx::database::client::postgres::Postgres<__E>>
+      for #name #ty_generics
+    #where_clause
+    {
+      #[inline]
+      fn encode(
+        &self,
+        ev: &mut wtx::database::client::postgres::EncodeValue<'_, '_>,
+      ) -> Result<(), __E> {
+        #body
+      }
+    }
+  };
+  Ok(proc_macro::TokenStream::from(expanded))
+}
+
+pub(crate) fn typed(item: proc_macro::TokenStream) -> crate::Result<proc_macro::TokenStream> {
+  let input = parse_macro_input::parse::<DeriveInput>(item)?;
+  let container_attrs = ContainerAttrs::try_from(&*input.attrs)?;
+  let name = &input.ident;
+  let generics = generics_with_bounds(&input.generics, None, &TokenStream::new());
+  let (impl_generics, _, where_clause) = generics.split_for_impl();
+  let (_, ty_generics, _) = input.generics.split_for_impl();
+  let ty = match (container_attrs.oid, &input.data) {
+    (Some(elem), _) => quote::quote! { Custom(#elem) },
+    (None, Data::Enum(_)) => quote::quote! { Text },
+    (None, Data::Struct(_)) => quote::quote! { Record },
+    (None, Data::Union(_)) => return Err(crate::Error::UnsupportedStructure),
+  };
+  let expanded = quote::quote! {
+    impl #impl_generics wtx::database::Typed<wtx::database::client::postgres::Postgres<__E>>
+      for #name #ty_generics
+    #where_clause
+    {
+      const TY: wtx::database::client::postgres::Ty = wtx::database::client::postgres::Ty::#ty;
+    }
+  };
+  Ok(proc_macro::TokenStream::from(expanded))
+}
+
+fn enum_labels<'data>(
+  container_attrs: &ContainerAttrs,
+  data: &'data syn::DataEnum,
+) -> crate::Result<(Vec<String>, Vec<&'data Ident>)> {
+  let mut labels = Vec::new();
+  let mut variants = Vec::new();
+  for variant in &data.variants {
+    if !matches!(variant.fields, Fields::Unit) {
+      return Err(crate::Error::UnsupportedStructure);
+    }
+    let variant_attrs = VariantAttrs::try_from(&*variant.attrs)?;
+    let label = match (variant_attrs.rename, container_attrs.rename_all) {
+      (Some(elem), _) => elem,
+      (None, Some(elem)) => elem.apply(&variant.ident.to_string()),
+      (None, None) => variant.ident.to_string(),
+    };
+    labels.push(label);
+    variants.push(&variant.ident);
+  }
+  Ok((labels, variants))
+}
+
+// Adds the `__E` error parameter, an optional lifetime and `bounds` to every type parameter.
+fn generics_with_bounds(
+  generics: &Generics,
+  lifetime: Option<GenericParam>,
+  bounds: &TokenStream,
+) -> Generics {
+  let mut rslt = generics.clone();
+  let where_clause = rslt.make_where_clause();
+  if !bounds.is_empty() {
+    for param in &generics.params {
+      if let GenericParam::Type(type_param) = param {
+        let ident = &type_param.ident;
+        where_clause.predicates.push(syn::parse_quote!(#ident: #bounds));
+      }
+    }
+  }
+  where_clause.predicates.push(syn::parse_quote!(__E: From<wtx::Error>));
+  if let Some(elem) = lifetime {
+    rslt.params.insert(0, elem);
+  }
+  rslt.params.push(syn::parse_quote!(__E));
+  rslt
+}
@@ -0,0 +1,107 @@
+use crate::postgres::rename_rule::RenameRule;
+use proc_macro2::Ident;
+use syn::{spanned::Spanned as _, Attribute, Lit, LitInt, Meta, MetaNameValue, NestedMeta};
+
+#[derive(Debug)]
+pub(crate) struct ContainerAttrs {
+  pub(crate) oid: Option<LitInt>,
+  pub(crate) rename_all: Option<RenameRule>,
+  rename_all_span: Option<proc_macro2::Span>,
+}
+
+impl ContainerAttrs {
+  /// Composite types are encoded by position, as such, renaming is meaningless.
+  pub(crate) fn check_struct(&self) -> crate::Result<()> {
+    if let Some(elem) = self.rename_all_span {
+      return Err(crate::Error::UnknownPostgresAttr(elem));
+    }
+    Ok(())
+  }
+}
+
+impl TryFrom<&[Attribute]> for ContainerAttrs {
+  type Error = crate::Error;
+
+  fn try_from(from: &[Attribute]) -> Result<Self, Self::Error> {
+    let mut oid = None;
+    let mut rename_all = None;
+    let mut rename_all_span = None;
+    for name_value in name_values(from)? {
+      match (name_value.path.get_ident().map(ToString::to_string).as_deref(), &name_value.lit) {
+        (Some("oid"), Lit::Int(lit)) => {
+          oid = Some(lit.clone());
+        }
+        (Some("rename_all"), Lit::Str(lit)) => {
+          rename_all = Some(RenameRule::try_from(lit)?);
+          rename_all_span = Some(lit.span());
+        }
+        _ => return Err(crate::Error::UnknownPostgresAttr(name_value.span())),
+      }
+    }
+    Ok(Self { oid, rename_all, rename_all_span })
+  }
+}
+
+#[derive(Debug)]
+pub(crate) struct FieldAttrs {
+  pub(crate) ty: Option<Ident>,
+}
+
+impl TryFrom<&[Attribute]> for FieldAttrs {
+  type Error = crate::Error;
+
+  fn try_from(from: &[Attribute]) -> Result<Self, Self::Error> {
+    let mut ty = None;
+    for name_value in name_values(from)? {
+      match (name_value.path.get_ident().map(ToString::to_string).as_deref(), &name_value.lit) {
+        (Some("ty"), Lit::Str(lit)) => {
+          ty = Some(lit.parse::<Ident>()?);
+        }
+        _ => return Err(crate::Error::UnknownPostgresAttr(name_value.span())),
+      }
+    }
+    Ok(Self { ty })
+  }
+}
+
+#[derive(Debug)]
+pub(crate) struct VariantAttrs {
+  pub(crate) rename: Option<String>,
+}
+
+impl TryFrom<&[Attribute]> for VariantAttrs {
+  type Error = crate::Error;
+
+  fn try_from(from: &[Attribute]) -> Result<Self, Self::Error> {
+    let mut rename = None;
+    for name_value in name_values(from)? {
+      match (name_value.path.get_ident().map(ToString::to_string).as_deref(), &name_value.lit) {
+        (Some("rename"), Lit::Str(lit)) => {
+          rename = Some(lit.value());
+        }
+        _ => return Err(crate::Error::UnknownPostgresAttr(name_value.span())),
+      }
+    }
+    Ok(Self { rename })
+  }
+}
+
+// Collects all `key = value` pairs of `#[postgres(...)]` attributes.
+fn name_values(attrs: &[Attribute]) -> crate::Result<Vec<MetaNameValue>> {
+  let mut rslt = Vec::new();
+  for attr in attrs {
+    if !attr.path.is_ident("postgres") {
+      continue;
+    }
+    let Meta::List(meta_list) = attr.parse_meta()? else {
+      return Err(crate::Error::UnknownPostgresAttr(attr.span()));
+    };
+    for nested_meta in meta_list.nested {
+      let NestedMeta::Meta(Meta::NameValue(name_value)) = nested_meta else {
+        return Err(crate::Error::UnknownPostgresAttr(nested_meta.span()));
+      };
+      rslt.push(name_value);
+    }
+  }
+  Ok(rslt)
+}
@@ -0,0 +1,78 @@
+use syn::LitStr;
+
+/// Case conversion applied to the variants of an enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RenameRule {
+  CamelCase,
+  KebabCase,
+  Lowercase,
+  PascalCase,
+  ScreamingKebabCase,
+  ScreamingSnakeCase,
+  SnakeCase,
+  Uppercase,
+}
+
+impl RenameRule {
+  /// Converts a `PascalCase` identifier.
+  pub(crate) fn apply(self, ident: &str) -> String {
+    match self {
+      Self::CamelCase => {
+        let mut chars = ident.chars();
+        chars.next().map(|el| el.to_ascii_lowercase()).into_iter().chain(chars).collect()
+      }
+      Self::KebabCase => Self::SnakeCase.apply(ident).replace('_', "-"),
+      Self::Lowercase => ident.to_ascii_lowercase(),
+      Self::PascalCase => ident.to_owned(),
+      Self::ScreamingKebabCase => Self::ScreamingSnakeCase.apply(ident).replace('_', "-"),
+      Self::ScreamingSnakeCase => Self::SnakeCase.apply(ident).to_ascii_uppercase(),
+      Self::SnakeCase => {
+        let mut rslt = String::new();
+        for (idx, char) in ident.char_indices() {
+          if char.is_uppercase() && idx > 0 {
+            rslt.push('_');
+          }
+          rslt.push(char.to_ascii_lowercase());
+        }
+        rslt
+      }
+      Self::Uppercase => ident.to_ascii_uppercase(),
+    }
+  }
+}
+
+impl TryFrom<&LitStr> for RenameRule {
+  type Error = crate::Error;
+
+  fn try_from(from: &LitStr) -> Result<Self, Self::Error> {
+    Ok(match from.value().as_str() {
+      "camelCase" => Self::CamelCase,
+      "kebab-case" => Self::KebabCase,
+      "lowercase" => Self::Lowercase,
+      "PascalCase" => Self::PascalCase,
+      "SCREAMING-KEBAB-CASE" => Self::ScreamingKebabCase,
+      "SCREAMING_SNAKE_CASE" => Self::ScreamingSnakeCase,
+      "snake_case" => Self::SnakeCase,
+      "UPPERCASE" => Self::Uppercase,
+      _ => return Err(crate::Error::UnknownRenameRule(from.span())),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::postgres::rename_rule::RenameRule;
+
+  #[test]
+  fn apply_has_correct_output() {
+    let ident = "FooBar";
+    assert_eq!(RenameRule::CamelCase.apply(ident), "fooBar");
+    assert_eq!(RenameRule::KebabCase.apply(ident), "foo-bar");
+    assert_eq!(RenameRule::Lowercase.apply(ident), "foobar");
+    assert_eq!(RenameRule::PascalCase.apply(ident), "FooBar");
+    assert_eq!(RenameRule::ScreamingKebabCase.apply(ident), "FOO-BAR");
+    assert_eq!(RenameRule::ScreamingSnakeCase.apply(ident), "FOO_BAR");
+    assert_eq!(RenameRule::SnakeCase.apply(ident), "foo_bar");
+    assert_eq!(RenameRule::Uppercase.apply(ident), "FOOBAR");
+  }
+}
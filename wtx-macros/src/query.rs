@@ -0,0 +1,152 @@
+mod description;
+mod rust_ty;
+
+use crate::from_record::decode_call;
+use proc_macro2::{Ident, Span};
+use syn::{
+  parse_macro_input, punctuated::Punctuated, spanned::Spanned as _, Field, Fields, ItemStruct,
+  LitStr, Type,
+};
+
+pub(crate) fn query(
+  attr: proc_macro::TokenStream,
+  item: proc_macro::TokenStream,
+) -> crate::Result<proc_macro::TokenStream> {
+  let sql = parse_macro_input::parse::<LitStr>(attr)?;
+  let item_struct = parse_macro_input::parse::<ItemStruct>(item)?;
+  if !item_struct.generics.params.is_empty() {
+    return Err(crate::Error::UnsupportedStructure);
+  }
+  let ItemStruct { attrs, fields: struct_fields, ident, vis, .. } = item_struct;
+  let mut declared_fields: Vec<Field> = match struct_fields {
+    Fields::Named(fields) => fields.named.into_iter().collect(),
+    Fields::Unit => Vec::new(),
+    Fields::Unnamed(_) => return Err(crate::Error::UnsupportedStructure),
+  };
+  let sql_value = sql.value();
+  let desc = description::description(&sql_value)
+    .map_err(|err| crate::Error::QueryDescription(sql.span(), err))?;
+  // `NOT NULL` table columns can still return `NULL` values when they come from outer joins.
+  let is_nullable = has_outer_join(&sql_value);
+
+  let mut decodes = Vec::new();
+  let mut fields = Vec::new();
+  for (idx, column) in desc.columns().iter().enumerate() {
+    let declared_idx = declared_fields.iter().position(|el| {
+      el.ident.as_ref().is_some_and(|local| unraw(&local.to_string()) == column.name())
+    });
+    let field = if let Some(elem) = declared_idx {
+      declared_fields.remove(elem)
+    } else {
+      let Some(rust_ty) = rust_ty::column(column.ty()) else {
+        return Err(crate::Error::UnsupportedQueryColumn(sql.span(), column.name().into()));
+      };
+      Field {
+        attrs: Vec::new(),
+        colon_token: Some(<_>::default()),
+        ident: Some(column_ident(column.name(), sql.span())?),
+        ty: if column.not_null() && !is_nullable {
+          rust_ty
+        } else {
+          syn::parse_quote!(Option<#rust_ty>)
+        },
+        vis: vis.clone(),
+      }
+    };
+    decodes.push(decode_call(&idx, &field.ty));
+    fields.push(field);
+  }
+  if let Some(field) = declared_fields.first() {
+    return Err(crate::Error::UnknownQueryField(field.span()));
+  }
+  let field_names = fields.iter().map(|el| &el.ident);
+
+  let mut has_borrowed_params = false;
+  let mut params: Punctuated<Type, syn::Token![,]> = Punctuated::new();
+  for (idx, ty) in desc.params().iter().enumerate() {
+    let Some((rust_ty, is_borrowed)) = rust_ty::param(*ty) else {
+      return Err(crate::Error::UnsupportedQueryParam(sql.span(), idx.wrapping_add(1)));
+    };
+    has_borrowed_params |= is_borrowed;
+    params.push(rust_ty);
+  }
+  if !params.empty_or_trailing() {
+    params.push_punct(<_>::default());
+  }
+  let params_ident = Ident::new(&format!("{ident}Params"), ident.span());
+  let params_doc = format!("Parameters of [`{ident}::SQL`].");
+  let params_generics = has_borrowed_params.then(|| quote::quote!(<'any>));
+
+  let expanded = quote::quote! {
+    #(#attrs)*
+    #vis struct #ident {
+      #(#fields,)*
+    }
+
+    #[doc = #params_doc]
+    #vis type #params_ident #params_generics = (#params);
+
+    impl #ident {
+      /// Command checked at compile time.
+      #vis const SQL: &'static str = #sql;
+    }
+
+    impl<__E> wtx::database::FromRecord<wtx::database::client::postgres::Postgres<__E>> for #ident
+    where
+      __E: From<wtx::Error>,
+    {
+      #[inline]
+      fn from_record(
+        _record: &wtx::database::client::postgres::Record<'_, __E>,
+      ) -> Result<Self, __E> {
+        use wtx::database::Record as _;
+        Ok(Self { #(#field_names: #decodes,)* })
+      }
+    }
+  };
+  Ok(proc_macro::TokenStream::from(expanded))
+}
+
+fn column_ident(name: &str, span: Span) -> crate::Result<Ident> {
+  let mut chars = name.chars();
+  let is_valid = chars.next().is_some_and(|el| el.is_ascii_alphabetic() || el == '_')
+    && chars.all(|el| el.is_ascii_alphanumeric() || el == '_');
+  if !is_valid || matches!(name, "_" | "crate" | "self" | "Self" | "super") {
+    return Err(crate::Error::InvalidColumnName(span, name.into()));
+  }
+  Ok(syn::parse_str::<Ident>(name).unwrap_or_else(|_err| Ident::new_raw(name, span)))
+}
+
+// Conservative check that also matches keywords inside literals or comments.
+fn has_outer_join(sql: &str) -> bool {
+  let mut prev = "";
+  for word in sql.split(|el: char| !el.is_ascii_alphanumeric() && el != '_') {
+    if word.is_empty() {
+      continue;
+    }
+    let is_outer = word.eq_ignore_ascii_case("JOIN") || word.eq_ignore_ascii_case("OUTER");
+    if is_outer && ["FULL", "LEFT", "RIGHT"].iter().any(|el| prev.eq_ignore_ascii_case(el)) {
+      return true;
+    }
+    prev = word;
+  }
+  false
+}
+
+fn unraw(name: &str) -> &str {
+  name.strip_prefix("r#").unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::query::has_outer_join;
+
+  #[test]
+  fn has_outer_join_has_correct_outputs() {
+    assert!(has_outer_join("SELECT * FROM a LEFT JOIN b ON a.id = b.id"));
+    assert!(has_outer_join("SELECT * FROM a\nfull outer join b ON true"));
+    assert!(has_outer_join("SELECT * FROM a RIGHT\tJOIN b USING (id)"));
+    assert!(!has_outer_join("SELECT * FROM a JOIN b ON a.id = b.id"));
+    assert!(!has_outer_join("SELECT left_value FROM a INNER JOIN b USING (id)"));
+  }
+}
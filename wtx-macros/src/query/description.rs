@@ -0,0 +1,206 @@
+use core::{
+  fmt::Write as _,
+  future::Future,
+  pin::pin,
+  task::{Context, Poll, Waker},
+  time::Duration,
+};
+use std::{
+  io,
+  net::{TcpStream, ToSocketAddrs as _},
+  path::PathBuf,
+  sync::Arc,
+  task::Wake,
+  thread::{self, Thread},
+};
+use wtx::{
+  database::{
+    client::postgres::{ColumnDescription, Config, Executor, ExecutorBuffer, StmtDescription, Ty},
+    DEFAULT_URI_VAR,
+  },
+  misc::{simple_seed, UriRef, Vector, Xorshift64},
+};
+
+/// If set to `true`, queries are only checked against cached descriptions.
+const OFFLINE_VAR: &str = "WTX_OFFLINE";
+/// If set to `true`, descriptions retrieved from the database are written to the cache.
+const REFRESH_VAR: &str = "WTX_REFRESH_QUERIES";
+/// Maximum duration of the connection and of each read or write.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Describes `sql` using the database pointed by `DATABASE_URI` or using the cached description
+/// located in the `.wtx/queries` directory of the current crate when the database isn't
+/// available. The cached description is only refreshed when `WTX_REFRESH_QUERIES` is `true`.
+pub(crate) fn description(sql: &str) -> Result<StmtDescription, String> {
+  let path = cache_path(sql)?;
+  let is_offline = std::env::var(OFFLINE_VAR).is_ok_and(|el| el == "true");
+  let is_refresh = std::env::var(REFRESH_VAR).is_ok_and(|el| el == "true");
+  if let (false, Ok(uri)) = (is_offline, std::env::var(DEFAULT_URI_VAR)) {
+    let desc = live_description(&uri, sql).map_err(|err| err.to_string())?;
+    if is_refresh {
+      if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+      }
+      std::fs::write(&path, serialize(sql, &desc)).map_err(|err| err.to_string())?;
+    }
+    return Ok(desc);
+  }
+  if is_refresh {
+    return Err(format!("`{REFRESH_VAR}` requires an online `{DEFAULT_URI_VAR}`"));
+  }
+  let Ok(content) = std::fs::read_to_string(&path) else {
+    return Err(format!(
+      "`{DEFAULT_URI_VAR}` isn't available and there is no cached description at `{}`",
+      path.display()
+    ));
+  };
+  deserialize(&content).ok_or_else(|| format!("`{}` is corrupted", path.display()))
+}
+
+// Futures of blocking streams are ready after the first poll but the current thread is still
+// parked in case of a pending state.
+fn block_on<F>(fut: F) -> F::Output
+where
+  F: Future,
+{
+  let mut pinned = pin!(fut);
+  let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+  let mut cx = Context::from_waker(&waker);
+  loop {
+    if let Poll::Ready(elem) = pinned.as_mut().poll(&mut cx) {
+      return elem;
+    }
+    thread::park();
+  }
+}
+
+fn cache_path(sql: &str) -> Result<PathBuf, String> {
+  let dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|err| err.to_string())?;
+  let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+  for byte in sql.bytes() {
+    hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+  }
+  Ok(PathBuf::from(dir).join(".wtx").join("queries").join(format!("{hash:016x}")))
+}
+
+// Blocking connection that doesn't use TLS, as such, commands should only be described by local
+// or trusted databases.
+fn connect(uri: &UriRef<'_>) -> wtx::Result<TcpStream> {
+  let mut last_err = io::Error::new(io::ErrorKind::NotFound, "no address was resolved");
+  for addr in uri.hostname_with_implied_port().to_socket_addrs()? {
+    match TcpStream::connect_timeout(&addr, TIMEOUT) {
+      Ok(stream) => {
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        return Ok(stream);
+      }
+      Err(err) => last_err = err,
+    }
+  }
+  Err(last_err.into())
+}
+
+// Parameters and columns are stored as `param OID` and `column NOT_NULL OID NAME` lines.
+fn deserialize(content: &str) -> Option<StmtDescription> {
+  let mut columns = Vector::new();
+  let mut params = Vector::new();
+  for line in content.lines() {
+    let mut iter = line.splitn(4, ' ');
+    match iter.next()? {
+      "#" => {}
+      "column" => {
+        let not_null = iter.next()? == "1";
+        let ty = Ty::from(iter.next()?.parse::<u32>().ok()?);
+        let name = iter.next()?.try_into().ok()?;
+        columns.push(ColumnDescription::new(name, not_null, ty)).ok()?;
+      }
+      "param" => {
+        params.push(Ty::from(iter.next()?.parse::<u32>().ok()?)).ok()?;
+      }
+      _ => return None,
+    }
+  }
+  Some(StmtDescription::new(columns, params))
+}
+
+fn live_description(uri: &str, sql: &str) -> wtx::Result<StmtDescription> {
+  let uri = UriRef::new(uri);
+  let mut rng = Xorshift64::from(simple_seed());
+  block_on(async {
+    let mut executor = Executor::<wtx::Error, _, _>::connect(
+      &Config::from_uri(&uri)?,
+      ExecutorBuffer::new(usize::MAX, &mut rng),
+      &mut rng,
+      connect(&uri)?,
+    )
+    .await?;
+    executor.describe(sql).await
+  })
+}
+
+// OIDs of user-defined types are specific to each database, as such, they are stored as `0`.
+fn oid(ty: Ty) -> u32 {
+  if let Ty::Custom(_) = ty {
+    0
+  } else {
+    u32::from(ty)
+  }
+}
+
+fn serialize(sql: &str, desc: &StmtDescription) -> String {
+  let mut rslt = String::new();
+  for line in sql.lines() {
+    let _rslt = writeln!(rslt, "# {}", line.trim());
+  }
+  for ty in desc.params() {
+    let _rslt = writeln!(rslt, "param {}", oid(*ty));
+  }
+  for column in desc.columns() {
+    let not_null = u8::from(column.not_null());
+    let _rslt = writeln!(rslt, "column {not_null} {} {}", oid(column.ty()), column.name());
+  }
+  rslt
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+  fn wake(self: Arc<Self>) {
+    self.0.unpark();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::query::description::{deserialize, serialize};
+  use wtx::{
+    database::client::postgres::{ColumnDescription, StmtDescription, Ty},
+    misc::Vector,
+  };
+
+  #[test]
+  fn serialize_and_deserialize_have_correct_outputs() {
+    let desc = StmtDescription::new(
+      Vector::from(vec![
+        ColumnDescription::new("id".try_into().unwrap(), true, Ty::Int4),
+        ColumnDescription::new("full name".try_into().unwrap(), false, Ty::Custom(16_385)),
+      ]),
+      Vector::from(vec![Ty::Text]),
+    );
+    let content = serialize("SELECT id, name AS \"full name\"\nFROM foo WHERE name = $1", &desc);
+    assert_eq!(
+      content,
+      "# SELECT id, name AS \"full name\"\n# FROM foo WHERE name = $1\nparam 25\n\
+        column 1 23 id\ncolumn 0 0 full name\n"
+    );
+    let expected = StmtDescription::new(
+      Vector::from(vec![
+        ColumnDescription::new("id".try_into().unwrap(), true, Ty::Int4),
+        ColumnDescription::new("full name".try_into().unwrap(), false, Ty::Custom(0)),
+      ]),
+      Vector::from(vec![Ty::Text]),
+    );
+    assert_eq!(deserialize(&content), Some(expected));
+    assert_eq!(deserialize("foo"), None);
+  }
+}
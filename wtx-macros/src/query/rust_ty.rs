@@ -0,0 +1,63 @@
+use syn::Type;
+use wtx::database::client::postgres::Ty;
+
+/// Owned type used to decode non-null values of a column.
+pub(crate) fn column(ty: Ty) -> Option<Type> {
+  if let Some(elem) = scalar(ty) {
+    return Some(elem);
+  }
+  let elem = scalar(array_elem(ty)?)?;
+  Some(syn::parse_quote!(Vec<#elem>))
+}
+
+/// Type used to encode a parameter and if such a type borrows data.
+pub(crate) fn param(ty: Ty) -> Option<(Type, bool)> {
+  Some(match ty {
+    Ty::Bpchar | Ty::Name | Ty::Text | Ty::Varchar => (syn::parse_quote!(&'any str), true),
+    Ty::Bytea => (syn::parse_quote!(&'any [u8]), true),
+    _ => (column(ty)?, false),
+  })
+}
+
+fn array_elem(ty: Ty) -> Option<Ty> {
+  Some(match ty {
+    Ty::BoolArray => Ty::Bool,
+    Ty::BpcharArray => Ty::Bpchar,
+    Ty::DateArray => Ty::Date,
+    Ty::Float4Array => Ty::Float4,
+    Ty::Float8Array => Ty::Float8,
+    Ty::Int2Array => Ty::Int2,
+    Ty::Int4Array => Ty::Int4,
+    Ty::Int8Array => Ty::Int8,
+    Ty::NameArray => Ty::Name,
+    Ty::NumericArray => Ty::Numeric,
+    Ty::TextArray => Ty::Text,
+    Ty::TimeArray => Ty::Time,
+    Ty::TimestampArray => Ty::Timestamp,
+    Ty::TimestamptzArray => Ty::Timestamptz,
+    Ty::UuidArray => Ty::Uuid,
+    Ty::VarcharArray => Ty::Varchar,
+    _ => return None,
+  })
+}
+
+fn scalar(ty: Ty) -> Option<Type> {
+  Some(match ty {
+    Ty::Bool => syn::parse_quote!(bool),
+    Ty::Bpchar | Ty::Name | Ty::Text | Ty::Varchar => syn::parse_quote!(String),
+    Ty::Date => syn::parse_quote!(chrono::NaiveDate),
+    Ty::Float4 => syn::parse_quote!(f32),
+    Ty::Float8 => syn::parse_quote!(f64),
+    Ty::Inet => syn::parse_quote!(core::net::IpAddr),
+    Ty::Int2 => syn::parse_quote!(i16),
+    Ty::Int4 => syn::parse_quote!(i32),
+    Ty::Int8 => syn::parse_quote!(i64),
+    Ty::Interval => syn::parse_quote!(wtx::database::client::postgres::Interval),
+    Ty::Numeric => syn::parse_quote!(rust_decimal::Decimal),
+    Ty::Time => syn::parse_quote!(chrono::NaiveTime),
+    Ty::Timestamp => syn::parse_quote!(chrono::NaiveDateTime),
+    Ty::Timestamptz => syn::parse_quote!(chrono::DateTime<chrono::Utc>),
+    Ty::Uuid => syn::parse_quote!(uuid::Uuid),
+    _ => return None,
+  })
+}
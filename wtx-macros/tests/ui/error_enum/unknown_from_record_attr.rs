@@ -0,0 +1,9 @@
+#[derive(wtx_macros::FromRecord)]
+#[from_record(wtx::database::client::postgres::Postgres<wtx::Error>)]
+struct Foo {
+  #[from_record(column = "bar")]
+  bar: i32,
+}
+
+fn main() {
+}
@@ -0,0 +1,8 @@
+#[derive(wtx_macros::Encode)]
+enum Enum {
+  #[postgres(name = "foo")]
+  Foo,
+}
+
+fn main() {
+}
@@ -0,0 +1,8 @@
+#[derive(wtx_macros::Decode)]
+#[postgres(rename_all = "Title Case")]
+enum Enum {
+  Foo,
+}
+
+fn main() {
+}
@@ -0,0 +1,29 @@
+//! Structures mapped from records by column name or by column index
+
+type Postgres = wtx::database::client::postgres::Postgres<wtx::Error>;
+
+#[derive(wtx_macros::FromRecord)]
+#[from_record(Postgres)]
+struct ByName {
+  id: i32,
+  #[from_record(rename = "user_name")]
+  name: String,
+  nickname: Option<String>,
+}
+
+#[derive(wtx_macros::FromRecord)]
+#[from_record(Postgres)]
+struct ByIdx(i32, Option<String>);
+
+#[derive(wtx_macros::FromRecord)]
+#[from_record(Postgres)]
+struct Generic<T> {
+  value: T,
+}
+
+fn main() {
+  fn assert<T: wtx::database::FromRecord<Postgres>>() {}
+  assert::<ByName>();
+  assert::<ByIdx>();
+  assert::<Generic<i64>>();
+}
@@ -0,0 +1,32 @@
+//! PostgreSQL composite types and enums
+
+use wtx::database::{Decode, Encode, Typed};
+
+type Postgres = wtx::database::client::postgres::Postgres<wtx::Error>;
+
+#[derive(wtx_macros::Decode, wtx_macros::Encode, wtx_macros::Typed)]
+struct Composite {
+  a: i32,
+  #[postgres(ty = "Varchar")]
+  b: Option<String>,
+  c: Enum,
+}
+
+#[derive(wtx_macros::Decode, wtx_macros::Encode, wtx_macros::Typed)]
+#[postgres(oid = 16_385)]
+struct GenericComposite<T>(T, i64);
+
+#[derive(wtx_macros::Decode, wtx_macros::Encode, wtx_macros::Typed)]
+#[postgres(rename_all = "snake_case")]
+enum Enum {
+  FooBar,
+  #[postgres(rename = "baz")]
+  Other,
+}
+
+fn main() {
+  fn assert<T: for<'de> Decode<'de, Postgres> + Encode<Postgres> + Typed<Postgres>>() {}
+  assert::<Composite>();
+  assert::<GenericComposite<Composite>>();
+  assert::<Enum>();
+}
@@ -173,10 +173,21 @@ pub(crate) struct SchemaManager {
   #[command(subcommand)]
   pub(crate) commands: SchemaManagerCommands,
 
+  /// Section of the `environments` table of the configuration file whose parameters override
+  /// the root parameters.
+  #[arg(short = 'e')]
+  pub(crate) env: Option<String>,
+
   /// Number of files (migrations or seeds) that is going to be sent to the database in a
-  /// single transaction.
-  #[arg(default_value_t = wtx::database::schema_manager::DEFAULT_BATCH_SIZE, short = 'f')]
-  pub(crate) files_num: usize,
+  /// single transaction. If not specified, defaults to the optional `batch_size` parameter of the
+  /// configuration file or to 128.
+  #[arg(short = 'f')]
+  pub(crate) files_num: Option<usize>,
+
+  /// Number of seconds spent waiting for the lock held by other instances before migrating,
+  /// rolling back or cleaning.
+  #[arg(default_value_t = wtx::database::schema_manager::DEFAULT_LOCK_TIMEOUT.as_secs(), short = 'l')]
+  pub(crate) lock_timeout: u64,
 
   /// Seeds directory. If not specified, defaults to the optional directory specified in the
   /// configuration file.
@@ -195,16 +206,31 @@ pub(crate) enum SchemaManagerCommands {
   /// Clean all database objects. For example, tables, triggers or procedures
   #[cfg(feature = "schema-manager-dev")]
   Clean {},
+  /// Compares the database with a scratch database where all local migrations are applied.
+  /// Exits with an error if the schemas differ
+  Diff {
+    /// Environment variable name that contains the URI of the scratch database. All objects of
+    /// the scratch database are removed before applying the migrations.
+    #[arg(default_value = "SCRATCH_DATABASE_URI", short = 'v')]
+    scratch_var: String,
+    /// Also prints a migration that reproduces the detected changes
+    #[arg(long)]
+    suggest: bool,
+  },
   /// Process local migrations that aren't in the database
   Migrate {},
   /// Shortcut.
   #[cfg(feature = "schema-manager-dev")]
   MigrateAndSeed {},
+  /// Prints the statements that `migrate` would execute without touching local migrations
+  Plan {},
   /// Returns database state to a point
   Rollback { versions: Vec<i32> },
   /// Populates the database with data intended for testing
   #[cfg(feature = "schema-manager-dev")]
   Seed {},
+  /// Prints the state of each local or inserted migration
+  Status {},
   /// Checks if the database state is in sync with the local data
   Validate {},
 }
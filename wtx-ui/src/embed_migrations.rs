@@ -32,6 +32,7 @@ pub(crate) async fn embed_migrations(elem: EmbedMigrations) -> wtx::Result<()> {
       let migration = rslt?;
       let checksum = migration.checksum();
       let dbs = migration.dbs();
+      let is_transactional = migration.is_transactional();
       let name = migration.name();
       let sql_down = migration.sql_down();
       let sql_up = migration.sql_up();
@@ -45,7 +46,7 @@ pub(crate) async fn embed_migrations(elem: EmbedMigrations) -> wtx::Result<()> {
         buffer.push_str(db.strings().ident);
         buffer.push(',');
       }
-      buffer.write_fmt(format_args!(r#"],"{name}","#))?;
+      buffer.write_fmt(format_args!(r#"],{is_transactional},"{name}","#))?;
       match migration.repeatability() {
         None => buffer.push_str("None"),
         Some(elem) => buffer.write_fmt(format_args!(
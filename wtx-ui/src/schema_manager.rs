@@ -1,11 +1,15 @@
 use crate::clap::{SchemaManager, SchemaManagerCommands};
-use std::{borrow::Cow, env::current_dir, path::Path};
+use std::{borrow::Cow, env::current_dir, path::Path, time::Duration};
 use tokio::net::TcpStream;
 use wtx::{
   database::{
     client::postgres::{Config, Executor, ExecutorBuffer},
-    schema_manager::{Commands, DbMigration, SchemaManagement, DEFAULT_CFG_FILE_NAME},
-    Database, Identifier, DEFAULT_URI_VAR,
+    schema_manager::{
+      misc::{parse_root_cfg, RootCfg},
+      Commands, DbMigration, SchemaManagement, SchemaManagerError, DEFAULT_BATCH_SIZE,
+      DEFAULT_CFG_FILE_NAME,
+    },
+    Database, Executor as _, Identifier, DEFAULT_URI_VAR,
   },
   misc::{simple_seed, UriRef, Vector, Xorshift64},
 };
@@ -17,25 +21,72 @@ pub(crate) async fn schema_manager(sm: SchemaManager) -> wtx::Result<()> {
     wtx::misc::tracing_tree_init(None)?;
   }
 
+  let cfg = root_cfg(&sm)?;
   let var = std::env::var(DEFAULT_URI_VAR)?;
   let uri = UriRef::new(&var);
   match uri.scheme() {
     "postgres" | "postgresql" => {
       let mut rng = Xorshift64::from(simple_seed());
-      let executor = Executor::connect(
-        &Config::from_uri(&uri)?,
-        ExecutorBuffer::new(usize::MAX, &mut rng),
-        &mut rng,
-        TcpStream::connect(uri.hostname_with_implied_port()).await?,
-      )
-      .await?;
-      handle_commands(executor, &sm).await?;
+      let create_schema = creates_schema(&sm.commands);
+      let executor = postgres_executor(&cfg, create_schema, &uri, &mut rng).await?;
+      let scratch = if let SchemaManagerCommands::Diff { scratch_var, .. } = &sm.commands {
+        let scratch_var = std::env::var(scratch_var)?;
+        Some(postgres_executor(&cfg, true, &UriRef::new(&scratch_var), &mut rng).await?)
+      } else {
+        None
+      };
+      handle_commands(&cfg, executor, scratch, &sm).await?;
     }
     _ => return Err(wtx::Error::InvalidUri),
   }
   Ok(())
 }
 
+// Only commands that apply migrations create the schema of the configuration file.
+fn creates_schema(commands: &SchemaManagerCommands) -> bool {
+  match commands {
+    SchemaManagerCommands::Migrate {} => true,
+    #[cfg(feature = "schema-manager-dev")]
+    SchemaManagerCommands::MigrateAndSeed {} => true,
+    _ => false,
+  }
+}
+
+// Unqualified objects are created in the schema of the configuration file, if any.
+async fn postgres_executor(
+  cfg: &RootCfg,
+  create_schema: bool,
+  uri: &UriRef<'_>,
+  rng: &mut Xorshift64,
+) -> wtx::Result<Executor<wtx::Error, ExecutorBuffer, TcpStream>> {
+  let mut executor = Executor::connect(
+    &Config::from_uri(uri)?,
+    ExecutorBuffer::new(usize::MAX, &mut *rng),
+    &mut *rng,
+    TcpStream::connect(uri.hostname_with_implied_port()).await?,
+  )
+  .await?;
+  if let Some(schema) = cfg.schema.as_deref() {
+    let schema = schema.replace('"', "\"\"");
+    let mut cmd = String::new();
+    if create_schema {
+      cmd.push_str(&format!("CREATE SCHEMA IF NOT EXISTS \"{schema}\"; "));
+    }
+    cmd.push_str(&format!("SET search_path TO \"{schema}\""));
+    executor.execute(&cmd, |_| {}).await?;
+  }
+  Ok(executor)
+}
+
+// The configuration file is optional for commands that don't deal with migrations.
+fn root_cfg(sm: &SchemaManager) -> wtx::Result<RootCfg> {
+  let path = toml_file_path(sm)?;
+  if sm.env.is_none() && !path.exists() {
+    return Ok(RootCfg::default());
+  }
+  parse_root_cfg(&path, sm.env.as_deref())
+}
+
 fn toml_file_path(sm: &SchemaManager) -> wtx::Result<Cow<'_, Path>> {
   Ok(if let Some(el) = sm.toml.as_deref() {
     Cow::Borrowed(el)
@@ -48,7 +99,9 @@ fn toml_file_path(sm: &SchemaManager) -> wtx::Result<Cow<'_, Path>> {
 
 #[inline]
 async fn handle_commands<E>(
+  cfg: &RootCfg,
   executor: E,
+  scratch: Option<E>,
   sm: &SchemaManager,
 ) -> Result<(), <E::Database as Database>::Error>
 where
@@ -58,12 +111,36 @@ where
   let _buffer_db_migrations = &mut Vector::<DbMigration>::new();
   let _buffer_idents = &mut Vector::<Identifier>::new();
 
-  let mut commands = Commands::new(sm.files_num, executor);
+  let batch_size = sm.files_num.or(cfg.batch_size).unwrap_or(DEFAULT_BATCH_SIZE);
+  let mut commands =
+    Commands::new(batch_size, executor).set_lock_timeout(Duration::from_secs(sm.lock_timeout));
   match &sm.commands {
     #[cfg(feature = "schema-manager-dev")]
     SchemaManagerCommands::Clean {} => {
       commands.clear((_buffer_cmd, _buffer_idents)).await?;
     }
+    SchemaManagerCommands::Diff { suggest, .. } => {
+      let Some(scratch) = scratch else {
+        return Err(wtx::Error::from(SchemaManagerError::MissingScratchDatabase).into());
+      };
+      let mut scratch_commands = Commands::new(batch_size, scratch);
+      let diff = commands
+        .diff_from_toml(
+          (_buffer_cmd, _buffer_db_migrations, _buffer_idents),
+          &toml_file_path(sm)?,
+          &mut scratch_commands,
+        )
+        .await?;
+      print!("{diff}");
+      if *suggest && diff.has_drifted() {
+        let (mut up, mut down) = (String::new(), String::new());
+        diff.suggested_migration(&mut up, &mut down)?;
+        print!("\n-- wtx IN\n\n{up}\n-- wtx OUT\n\n{down}");
+      }
+      if diff.has_drifted() {
+        return Err(wtx::Error::from(SchemaManagerError::SchemaHasDrifted).into());
+      }
+    }
     SchemaManagerCommands::Migrate {} => {
       commands
         .migrate_from_toml_path((_buffer_cmd, _buffer_db_migrations), &toml_file_path(sm)?)
@@ -71,12 +148,21 @@ where
     }
     #[cfg(feature = "schema-manager-dev")]
     SchemaManagerCommands::MigrateAndSeed {} => {
-      let (migration_groups, seeds) =
-        wtx::database::schema_manager::misc::parse_root_toml(&toml_file_path(sm)?)?;
       commands
-        .migrate_from_groups_paths((_buffer_cmd, _buffer_db_migrations), &migration_groups)
+        .migrate_from_groups_paths((_buffer_cmd, _buffer_db_migrations), &cfg.migration_groups)
         .await?;
-      commands.seed_from_dir(_buffer_cmd, seeds_file_path(sm, seeds.as_deref())?).await?;
+      commands.seed_from_dir(_buffer_cmd, seeds_file_path(sm, cfg.seeds.as_deref())?).await?;
+    }
+    SchemaManagerCommands::Plan {} => {
+      let mut buffer_plan = String::new();
+      commands
+        .migrate_dry_run_from_toml_path(
+          (_buffer_cmd, _buffer_db_migrations),
+          &mut buffer_plan,
+          &toml_file_path(sm)?,
+        )
+        .await?;
+      print!("{buffer_plan}");
     }
     SchemaManagerCommands::Rollback { versions: _versions } => {
       commands
@@ -85,8 +171,20 @@ where
     }
     #[cfg(feature = "schema-manager-dev")]
     SchemaManagerCommands::Seed {} => {
-      let (_, seeds) = wtx::database::schema_manager::misc::parse_root_toml(&toml_file_path(sm)?)?;
-      commands.seed_from_dir(_buffer_cmd, seeds_file_path(sm, seeds.as_deref())?).await?;
+      commands.seed_from_dir(_buffer_cmd, seeds_file_path(sm, cfg.seeds.as_deref())?).await?;
+    }
+    SchemaManagerCommands::Status {} => {
+      let mut results = Vector::new();
+      commands
+        .status_from_toml((_buffer_cmd, _buffer_db_migrations), &toml_file_path(sm)?, &mut results)
+        .await?;
+      for mgs in results.iter() {
+        let mg = mgs.group();
+        println!("{} ({})", mg.name(), mg.version());
+        for (version, name, status) in mgs.migrations() {
+          println!("  {version:>5} {name:<40} {status}");
+        }
+      }
     }
     SchemaManagerCommands::Validate {} => {
       commands
@@ -8,6 +8,7 @@ mod encode;
 mod executor;
 mod from_record;
 mod from_records;
+mod isolation_level;
 mod json;
 mod misc;
 mod record;
@@ -16,6 +17,7 @@ mod records;
 #[cfg(feature = "schema-manager")]
 pub mod schema_manager;
 mod stmt_cmd;
+mod transaction_options;
 mod typed;
 mod value_ident;
 
@@ -26,12 +28,14 @@ pub use encode::Encode;
 pub use executor::Executor;
 pub use from_record::FromRecord;
 pub use from_records::FromRecords;
+pub use isolation_level::IsolationLevel;
 pub use json::Json;
 pub use misc::seek_related_entities;
 pub use record::Record;
 pub use record_values::RecordValues;
 pub use records::Records;
 pub use stmt_cmd::StmtCmd;
+pub use transaction_options::TransactionOptions;
 pub use typed::Typed;
 pub use value_ident::ValueIdent;
 
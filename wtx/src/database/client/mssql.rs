@@ -0,0 +1,86 @@
+//! Microsoft SQL Server is a relational database management system (RDBMS) developed by
+//! Microsoft. Communication happens through the Tabular Data Stream (TDS) protocol, version 7.4.
+
+mod column;
+mod config;
+mod db_error;
+mod decode_value;
+mod encode_value;
+mod executor;
+mod executor_buffer;
+#[cfg(all(feature = "_async-tests", feature = "_integration-tests", test))]
+mod integration_tests;
+mod mssql_error;
+mod packet;
+mod protocol;
+mod record;
+mod records;
+mod statements;
+#[cfg(feature = "tokio")]
+mod tls_prelogin_stream;
+mod token;
+mod ty;
+mod tys;
+
+use crate::database::{Database, DatabaseTy};
+pub use config::Config;
+use core::marker::PhantomData;
+pub use db_error::DbError;
+pub use decode_value::DecodeValue;
+pub use encode_value::EncodeValue;
+pub use executor::Executor;
+pub use executor_buffer::ExecutorBuffer;
+pub use mssql_error::MssqlError;
+pub use record::Record;
+pub use records::Records;
+pub use statements::Statements;
+#[cfg(feature = "tokio")]
+pub use tls_prelogin_stream::TlsPreloginStream;
+pub use ty::Ty;
+
+/// Microsoft SQL Server
+#[derive(Debug)]
+pub struct Mssql<E>(PhantomData<fn() -> E>);
+
+impl<E> Database for Mssql<E>
+where
+  E: From<crate::Error>,
+{
+  const BIND_PREFIX: &'static str = "@P";
+  const IS_BIND_INCREASING: bool = true;
+  const TY: DatabaseTy = DatabaseTy::Mssql;
+
+  type DecodeValue<'exec> = DecodeValue<'exec>;
+  type EncodeValue<'buffer, 'tmp>
+    = EncodeValue<'buffer, 'tmp>
+  where
+    'buffer: 'tmp;
+  type Error = E;
+  type Record<'exec> = Record<'exec, E>;
+  type Records<'exec> = Records<'exec, E>;
+  type Ty = Ty;
+}
+
+impl<E> Default for Mssql<E> {
+  #[inline]
+  fn default() -> Self {
+    Self(PhantomData)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::mssql::{column::Column, Ty};
+
+  pub(crate) fn column0() -> Column {
+    Column::new(8000, "a".try_into().unwrap(), 0, Ty::NVarChar)
+  }
+
+  pub(crate) fn column1() -> Column {
+    Column::new(4, "b".try_into().unwrap(), 0, Ty::Intn)
+  }
+
+  pub(crate) fn column2() -> Column {
+    Column::new(8, "c".try_into().unwrap(), 0, Ty::Intn)
+  }
+}
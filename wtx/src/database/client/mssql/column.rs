@@ -0,0 +1,18 @@
+use crate::database::{client::mssql::Ty, Identifier};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Column {
+  /// Maximum length declared by the `TYPE_INFO` of variable-length types. `0xFFFF` indicates
+  /// `MAX` types, which are transferred as partially length-prefixed bytes.
+  pub(crate) len: u32,
+  pub(crate) name: Identifier,
+  pub(crate) scale: u8,
+  pub(crate) ty: Ty,
+}
+
+impl Column {
+  #[inline]
+  pub(crate) fn new(len: u32, name: Identifier, scale: u8, ty: Ty) -> Self {
+    Self { len, name, scale, ty }
+  }
+}
@@ -0,0 +1,73 @@
+use crate::{
+  database::client::mssql::MssqlError,
+  misc::{str_split1, str_split_once1, UriRef},
+};
+
+/// Configuration
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config<'data> {
+  pub(crate) app_name: &'data str,
+  pub(crate) db: &'data str,
+  pub(crate) host: &'data str,
+  pub(crate) password: &'data str,
+  pub(crate) user: &'data str,
+}
+
+impl<'data> Config<'data> {
+  /// Unwraps the elements of an URI. For example, `mssql://user:pw@localhost:1433/db`.
+  ///
+  /// The only supported query parameter is `app_name`, which is reported to the server.
+  #[inline]
+  pub fn from_uri(uri: &'data UriRef<'_>) -> crate::Result<Config<'data>> {
+    let mut this = Self {
+      app_name: "wtx",
+      db: uri.path().get(1..).unwrap_or_default(),
+      host: uri.hostname(),
+      password: uri.password(),
+      user: uri.user(),
+    };
+    let mut pair_iter = str_split1(uri.query_and_fragment(), b'&');
+    if let Some(mut key_value) = pair_iter.next() {
+      key_value = key_value.get(1..).unwrap_or_default();
+      if let Some((key, value)) = str_split_once1(key_value, b'=') {
+        this.set_param(key, value)?;
+      }
+    }
+    for key_value in pair_iter {
+      if let Some((key, value)) = str_split_once1(key_value, b'=') {
+        this.set_param(key, value)?;
+      }
+    }
+    Ok(this)
+  }
+
+  #[inline]
+  fn set_param(&mut self, key: &str, value: &'data str) -> crate::Result<()> {
+    match key {
+      "app_name" => {
+        self.app_name = value;
+      }
+      _ => return Err(MssqlError::UnknownConfigurationParameter.into()),
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{database::client::mssql::Config, misc::Uri};
+
+  #[test]
+  fn from_uri() {
+    let uri = Uri::new("mssql://ab:cd@ef:1433/gh");
+    let config = Config::from_uri(&uri).unwrap();
+    assert_eq!(config.app_name, "wtx");
+    assert_eq!(config.db, "gh");
+    assert_eq!(config.host, "ef");
+    assert_eq!(config.password, "cd");
+    assert_eq!(config.user, "ab");
+    let uri = Uri::new("mssql://ab:cd@ef:1433/gh?app_name=reports");
+    assert_eq!(Config::from_uri(&uri).unwrap().app_name, "reports");
+    assert!(Config::from_uri(&Uri::new("mssql://ab:cd@ef:1433/gh?ij=kl")).is_err());
+  }
+}
@@ -0,0 +1,116 @@
+use crate::database::client::mssql::{
+  token::{b_varchar, us_varchar},
+  MssqlError,
+};
+use alloc::boxed::Box;
+
+/// An error sent by the server through an `ERROR` token.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DbError {
+  class: u8,
+  line: u32,
+  message: Box<str>,
+  number: u32,
+  procedure: Box<str>,
+  server: Box<str>,
+  state: u8,
+}
+
+impl DbError {
+  /// Severity of the error. Values below 10 indicate informational messages.
+  #[inline]
+  pub fn class(&self) -> u8 {
+    self.class
+  }
+
+  /// If the server closes the connection right after sending the error, which is the case of
+  /// classes greater than or equal to 20.
+  #[inline]
+  pub fn is_fatal(&self) -> bool {
+    self.class >= 20
+  }
+
+  /// Line number of the batch or stored procedure that caused the error.
+  #[inline]
+  pub fn line(&self) -> u32 {
+    self.line
+  }
+
+  /// Human-readable error message.
+  #[inline]
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Server error number. For example, `208` is returned when an object doesn't exist.
+  #[inline]
+  pub fn number(&self) -> u32 {
+    self.number
+  }
+
+  /// Name of the stored procedure that caused the error, if any.
+  #[inline]
+  pub fn procedure(&self) -> &str {
+    &self.procedure
+  }
+
+  /// Name of the server that sent the error.
+  #[inline]
+  pub fn server(&self) -> &str {
+    &self.server
+  }
+
+  /// Error state, used as a modifier of the error number.
+  #[inline]
+  pub fn state(&self) -> u8 {
+    self.state
+  }
+}
+
+// | Number | State | Class | Message | Server | Procedure | Line |
+// |   4    |   1   |   1   |    x    |   x    |     x     |  4   |
+impl TryFrom<&[u8]> for DbError {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    let [a, b, c, d, state, class, rest @ ..] = from else {
+      return Err(MssqlError::InsufficientDbErrorBytes.into());
+    };
+    let (message, rest) = us_varchar(rest)?;
+    let (server, rest) = b_varchar(rest)?;
+    let (procedure, rest) = b_varchar(rest)?;
+    let [e, f, g, h, ..] = rest else {
+      return Err(MssqlError::InsufficientDbErrorBytes.into());
+    };
+    Ok(Self {
+      class: *class,
+      line: u32::from_le_bytes([*e, *f, *g, *h]),
+      message: message.into(),
+      number: u32::from_le_bytes([*a, *b, *c, *d]),
+      procedure: procedure.into(),
+      server: server.into(),
+      state: *state,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::mssql::DbError;
+
+  #[test]
+  fn parses_error_token() {
+    let bytes = [208, 0, 0, 0, 1, 16, 3, 0, b'a', 0, b'b', 0, b'c', 0, 1, b'd', 0, 0, 2, 0, 0, 0];
+    let err = DbError::try_from(&bytes[..]).unwrap();
+    assert_eq!(err.class(), 16);
+    assert_eq!(err.line(), 2);
+    assert_eq!(err.message(), "abc");
+    assert_eq!(err.number(), 208);
+    assert_eq!(err.procedure(), "");
+    assert_eq!(err.server(), "d");
+    assert_eq!(err.state(), 1);
+    assert!(!err.is_fatal());
+    assert!(DbError::try_from(&bytes[..8]).is_err());
+  }
+}
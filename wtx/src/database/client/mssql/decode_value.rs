@@ -0,0 +1,42 @@
+use crate::database::client::mssql::Ty;
+
+/// Struct used for decoding elements in Microsoft SQL Server.
+#[derive(Debug, PartialEq)]
+pub struct DecodeValue<'any> {
+  bytes: &'any [u8],
+  scale: u8,
+  ty: Ty,
+}
+
+impl<'any> DecodeValue<'any> {
+  #[inline]
+  pub(crate) fn new(bytes: &'any [u8], scale: u8, ty: Ty) -> Self {
+    Self { bytes, scale, ty }
+  }
+
+  /// Bytes of a column. Length prefixes are not included.
+  #[inline]
+  pub fn bytes(&self) -> &'any [u8] {
+    self.bytes
+  }
+
+  /// Number of fractional digits of `DECIMAL`, `NUMERIC` and temporal columns. Zero for other
+  /// types.
+  #[inline]
+  pub fn scale(&self) -> u8 {
+    self.scale
+  }
+
+  /// Type of a column.
+  #[inline]
+  pub fn ty(&self) -> &Ty {
+    &self.ty
+  }
+}
+
+impl Default for DecodeValue<'_> {
+  #[inline]
+  fn default() -> Self {
+    Self { bytes: &[], scale: 0, ty: Ty::Null }
+  }
+}
@@ -0,0 +1,59 @@
+use crate::{
+  database::client::mssql::Ty,
+  misc::{FilledBufferWriter, Lease, LeaseMut},
+};
+
+/// Struct used for encoding elements in Microsoft SQL Server.
+#[derive(Debug)]
+pub struct EncodeValue<'buffer, 'tmp> {
+  fbw: &'tmp mut FilledBufferWriter<'buffer>,
+  scale: u8,
+  ty: Ty,
+}
+
+impl<'buffer, 'tmp> EncodeValue<'buffer, 'tmp> {
+  #[inline]
+  pub(crate) fn new(fbw: &'tmp mut FilledBufferWriter<'buffer>) -> Self {
+    Self { fbw, scale: 0, ty: Ty::NVarChar }
+  }
+
+  /// See [`FilledBufferWriter`].
+  #[inline]
+  pub fn fbw(&mut self) -> &mut FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+
+  /// Declares the type of the value that is being encoded, which defaults to [`Ty::NVarChar`].
+  ///
+  /// The actual parameter type sent to the server is derived from `ty` and from the number of
+  /// written bytes. For example, [`Ty::Intn`] with 4 bytes is declared as `INT`. `scale` is only
+  /// used by [`Ty::Decimaln`] and should be zero for other types.
+  #[inline]
+  pub fn set_ty(&mut self, scale: u8, ty: Ty) {
+    self.scale = scale;
+    self.ty = ty;
+  }
+
+  /// Returns the declared type and then resets it to the default value.
+  #[inline]
+  pub(crate) fn take_ty(&mut self) -> (u8, Ty) {
+    let rslt = (self.scale, self.ty);
+    self.scale = 0;
+    self.ty = Ty::NVarChar;
+    rslt
+  }
+}
+
+impl<'buffer> Lease<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
+  #[inline]
+  fn lease(&self) -> &FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+}
+
+impl<'buffer> LeaseMut<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+}
@@ -0,0 +1,277 @@
+mod authentication;
+mod fetch;
+mod simple_query;
+
+use crate::{
+  database::{
+    client::mssql::{
+      executor::authentication::exchange_prelogin,
+      executor_buffer::ExecutorBuffer,
+      packet::DEFAULT_PACKET_SIZE,
+      protocol::{ENCRYPT_NOT_SUP, ENCRYPT_ON, ENCRYPT_REQ},
+      Config, Mssql, MssqlError, Record, Records,
+    },
+    Database, DatabaseError, RecordValues, StmtCmd, TransactionOptions,
+  },
+  misc::{ArrayString, ConnectionState, LeaseMut, Stream, _unlikely_dflt},
+};
+use core::{future::Future, marker::PhantomData};
+
+/// Executor
+#[derive(Debug)]
+pub struct Executor<E, EB, S> {
+  pub(crate) cs: ConnectionState,
+  pub(crate) eb: EB,
+  pub(crate) packet_size: usize,
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  pub(crate) stream: S,
+  pub(crate) transaction_descriptor: u64,
+}
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Connects with an unencrypted stream.
+  ///
+  /// Servers that require encryption refuse the connection.
+  #[inline]
+  pub async fn connect(config: &Config<'_>, mut eb: EB, mut stream: S) -> crate::Result<Self> {
+    eb.lease_mut().clear();
+    let encryption = exchange_prelogin(ENCRYPT_NOT_SUP, eb.lease_mut(), &mut stream).await?;
+    if encryption == ENCRYPT_ON || encryption == ENCRYPT_REQ {
+      return Err(MssqlError::ServerRequiresEncryption.into());
+    }
+    Self::do_connect(config, eb, stream).await
+  }
+
+  /// Initially connects with an unencrypted stream that is upgraded to an encrypted stream by
+  /// `cb` right after the `PRELOGIN` exchange. The whole connection is encrypted afterwards.
+  ///
+  /// The TLS handshake performed by `cb` must use the provided
+  /// [`crate::database::client::mssql::TlsPreloginStream`].
+  #[cfg(feature = "tokio")]
+  #[inline]
+  pub async fn connect_encrypted<F, IS>(
+    config: &Config<'_>,
+    mut eb: EB,
+    mut stream: IS,
+    cb: impl FnOnce(crate::database::client::mssql::TlsPreloginStream<IS>) -> F,
+  ) -> crate::Result<Self>
+  where
+    F: Future<Output = crate::Result<S>>,
+    IS: Stream,
+  {
+    eb.lease_mut().clear();
+    let encryption = exchange_prelogin(ENCRYPT_ON, eb.lease_mut(), &mut stream).await?;
+    if encryption == ENCRYPT_NOT_SUP {
+      return Err(MssqlError::ServerDoesNotSupportEncryption.into());
+    }
+    let is_handshake_done = crate::misc::Arc::new(core::sync::atomic::AtomicBool::new(false));
+    let prelogin_stream =
+      crate::database::client::mssql::TlsPreloginStream::new(is_handshake_done.clone(), stream);
+    let encrypted_stream = cb(prelogin_stream).await?;
+    is_handshake_done.store(true, core::sync::atomic::Ordering::Relaxed);
+    Self::do_connect(config, eb, encrypted_stream).await
+  }
+
+  /// Mutable buffer reference
+  #[inline]
+  pub fn eb_mut(&mut self) -> &mut ExecutorBuffer {
+    self.eb.lease_mut()
+  }
+
+  #[inline]
+  async fn do_connect(config: &Config<'_>, eb: EB, stream: S) -> crate::Result<Self> {
+    let mut this = Self {
+      cs: ConnectionState::Open,
+      eb,
+      packet_size: DEFAULT_PACKET_SIZE,
+      phantom: PhantomData,
+      stream,
+      transaction_descriptor: 0,
+    };
+    this.manage_authentication(config).await?;
+    Ok(this)
+  }
+}
+
+// Errors that leave the stream in an unknown state or that precede the disconnection of the
+// server mark the connection as closed. Regular `ERROR` tokens don't affect the connection.
+#[inline]
+pub(crate) fn manage_conn_state<T>(
+  cs: &mut ConnectionState,
+  rslt: crate::Result<T>,
+) -> crate::Result<T> {
+  if let Err(err) = &rslt {
+    if !matches!(err, crate::Error::MssqlDbError(elem) if !elem.is_fatal()) {
+      *cs = ConnectionState::Closed;
+    }
+  }
+  rslt
+}
+
+impl<E, EB, S> crate::database::Executor for Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  type Database = Mssql<E>;
+
+  #[inline]
+  fn connection_state(&self) -> ConnectionState {
+    self.cs
+  }
+
+  /// SQL Server doesn't support read-only or deferrable transactions, as such,
+  /// [`TransactionOptions::is_read_only`] and [`TransactionOptions::is_deferrable`] are ignored.
+  #[inline]
+  async fn begin(&mut self, to: TransactionOptions) -> crate::Result<()> {
+    let mut cmd = ArrayString::<96>::new();
+    if let Some(elem) = to.isolation_level() {
+      cmd.push_str("SET TRANSACTION ISOLATION LEVEL ")?;
+      cmd.push_str(elem.as_str())?;
+      cmd.push_str(";")?;
+    }
+    cmd.push_str("BEGIN TRANSACTION")?;
+    self.simple_query_execute(&cmd, |_| {}).await
+  }
+
+  /// The isolation level of SQL Server is scoped to the session, as such, transactions that
+  /// specify [`TransactionOptions::isolation_level`] restore the default `READ COMMITTED` level
+  /// after committing or rolling back.
+  #[inline]
+  async fn transaction_with_options<'this, F, R>(
+    &'this mut self,
+    to: TransactionOptions,
+    fun: impl FnOnce(&'this mut Self) -> F,
+  ) -> Result<R, E>
+  where
+    F: Future<Output = (Result<R, E>, &'this mut Self)>,
+  {
+    self.begin(to).await?;
+    let (rslt, this) = fun(self).await;
+    let end_rslt =
+      this.simple_query_execute(if rslt.is_ok() { "COMMIT" } else { "ROLLBACK" }, |_| {}).await;
+    if to.isolation_level().is_some() {
+      this.simple_query_execute("SET TRANSACTION ISOLATION LEVEL READ COMMITTED", |_| {}).await?;
+    }
+    if rslt.is_ok() {
+      end_rslt?;
+    }
+    rslt
+  }
+
+  /// SQL Server doesn't release savepoints, as such, only "SAVE TRANSACTION" and
+  /// "ROLLBACK TRANSACTION" are issued.
+  #[inline]
+  async fn savepoint<'this, F, R>(
+    &'this mut self,
+    name: &str,
+    fun: impl FnOnce(&'this mut Self) -> F,
+  ) -> Result<R, E>
+  where
+    F: Future<Output = (Result<R, E>, &'this mut Self)>,
+  {
+    let is_valid = name.as_bytes().first().is_some_and(|el| !el.is_ascii_digit())
+      && name.bytes().all(|el| el.is_ascii_alphanumeric() || el == b'_');
+    if !is_valid {
+      return Err(crate::Error::from(DatabaseError::InvalidSavepointName).into());
+    }
+    let mut cmd = ArrayString::<96>::new();
+    cmd.push_str("SAVE TRANSACTION ")?;
+    cmd.push_str(name)?;
+    self.simple_query_execute(&cmd, |_| {}).await?;
+    let (rslt, this) = fun(self).await;
+    if rslt.is_err() {
+      cmd.clear();
+      cmd.push_str("ROLLBACK TRANSACTION ")?;
+      cmd.push_str(name)?;
+      this.simple_query_execute(&cmd, |_| {}).await?;
+    }
+    rslt
+  }
+
+  #[inline]
+  async fn execute(&mut self, cmd: &str, cb: impl FnMut(u64)) -> crate::Result<()> {
+    self.simple_query_execute(cmd, cb).await
+  }
+
+  #[inline]
+  async fn execute_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+  ) -> Result<u64, <Self::Database as Database>::Error>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let mut rows: u64 = 0;
+    self.rpc_fetch(|el| rows = rows.wrapping_add(el), rv, sc).await?;
+    Ok(rows)
+  }
+
+  #[inline]
+  async fn fetch_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+  ) -> Result<<Self::Database as Database>::Record<'_>, E>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    self.rpc_fetch(|_| {}, rv, sc).await?;
+    let ExecutorBuffer { columns, nb, rb, vb, .. } = self.eb.lease_mut();
+    let Some(&end) = rb.first() else {
+      return Err(E::from(MssqlError::NoRecord.into()));
+    };
+    Ok(Record {
+      bytes: nb._current(),
+      columns,
+      phantom: PhantomData,
+      values_bytes_offsets: vb.get(..end).unwrap_or_else(_unlikely_dflt),
+    })
+  }
+
+  #[inline]
+  async fn fetch_many_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+    mut cb: impl FnMut(&<Self::Database as Database>::Record<'_>) -> Result<(), E>,
+  ) -> Result<<Self::Database as Database>::Records<'_>, E>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    self.rpc_fetch(|_| {}, rv, sc).await?;
+    let ExecutorBuffer { columns, nb, rb, vb, .. } = self.eb.lease_mut();
+    let records = Records {
+      bytes: nb._current(),
+      columns,
+      phantom: PhantomData,
+      records_values_offsets: rb,
+      values_bytes_offsets: vb,
+    };
+    for record in crate::database::Records::iter(&records) {
+      cb(&record)?;
+    }
+    Ok(records)
+  }
+
+  /// SQL Server caches the plans of commands executed through `sp_executesql`, as such, this
+  /// method only stores `cmd` locally without contacting the server.
+  #[inline]
+  async fn prepare(&mut self, cmd: &str) -> Result<u64, E> {
+    let stmts = &mut self.eb.lease_mut().stmts;
+    let stmt_hash = cmd.hash(stmts.hasher_mut());
+    if stmts.get_by_stmt_hash(stmt_hash).is_none() {
+      let _ = stmts.insert(stmt_hash, cmd)?;
+    }
+    Ok(stmt_hash)
+  }
+}
@@ -0,0 +1,57 @@
+use crate::{
+  database::client::mssql::{
+    executor::fetch::fetch_tokens,
+    packet::{fetch_message, send_message, DEFAULT_PACKET_SIZE, LOGIN7, PRELOGIN, TABULAR_RESULT},
+    protocol::{login7, prelogin, prelogin_encryption},
+    token::Env,
+    Config, Executor, ExecutorBuffer, MssqlError,
+  },
+  misc::{FilledBufferWriter, LeaseMut, Stream},
+};
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Sends a `LOGIN7` request using SQL Server authentication.
+  #[inline]
+  pub(crate) async fn manage_authentication(&mut self, config: &Config<'_>) -> crate::Result<()> {
+    let Self { cs: _, eb, packet_size, phantom: _, stream, transaction_descriptor } = self;
+    let eb_ref = eb.lease_mut();
+    {
+      let mut fbw = FilledBufferWriter::from(&mut eb_ref.nb);
+      login7(config, &mut fbw)?;
+      send_message(*packet_size, fbw._curr_bytes(), stream, LOGIN7).await?;
+    }
+    let env = Env { packet_size, transaction_descriptor };
+    let ExecutorBuffer { columns, nb, rb, vb, .. } = eb_ref;
+    fetch_tokens(columns, |_| {}, env, nb, rb, stream, vb).await?;
+    columns.clear();
+    rb.clear();
+    vb.clear();
+    Ok(())
+  }
+}
+
+/// Exchanges `PRELOGIN` messages, returning the encryption mode chosen by the server.
+#[inline]
+pub(crate) async fn exchange_prelogin<SM>(
+  encryption: u8,
+  eb: &mut ExecutorBuffer,
+  stream: &mut SM,
+) -> crate::Result<u8>
+where
+  SM: Stream,
+{
+  {
+    let mut fbw = FilledBufferWriter::from(&mut eb.nb);
+    prelogin(encryption, &mut fbw)?;
+    send_message(DEFAULT_PACKET_SIZE, fbw._curr_bytes(), stream, PRELOGIN).await?;
+  }
+  let ty = fetch_message(&mut eb.nb, stream).await?;
+  if ty != TABULAR_RESULT && ty != PRELOGIN {
+    return Err(MssqlError::UnexpectedPacket { received: ty }.into());
+  }
+  prelogin_encryption(eb.nb._current())
+}
@@ -0,0 +1,83 @@
+use crate::{
+  database::{
+    client::mssql::{
+      column::Column,
+      executor::manage_conn_state,
+      executor_buffer::ExecutorBufferPartsMut,
+      packet::{fetch_message, send_message, RPC, TABULAR_RESULT},
+      protocol::rpc_execute_sql,
+      token::{process_tokens, Env},
+      Executor, ExecutorBuffer, Mssql, MssqlError,
+    },
+    RecordValues, StmtCmd,
+  },
+  misc::{
+    partitioned_filled_buffer::PartitionedFilledBuffer, FilledBufferWriter, LeaseMut, Stream,
+    StreamReader, Vector,
+  },
+};
+use core::ops::Range;
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Executes a parameterized command through `sp_executesql`. The values of the first result
+  /// set are stored in the buffers of the executor, whose ranges are relative to the current area
+  /// of the network buffer.
+  #[inline]
+  pub(crate) async fn rpc_fetch<RV, SC>(
+    &mut self,
+    done_cb: impl FnMut(u64),
+    rv: RV,
+    sc: SC,
+  ) -> Result<(), E>
+  where
+    RV: RecordValues<Mssql<E>>,
+    SC: StmtCmd,
+  {
+    let Self { cs, eb, packet_size, phantom: _, stream, transaction_descriptor } = self;
+    let ExecutorBufferPartsMut { columns, nb, pb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(columns, nb, rb, vb);
+    let stmt_hash = sc.hash(stmts.hasher_mut());
+    let cmd = match sc.cmd() {
+      Some(elem) => elem,
+      None => stmts
+        .get_by_stmt_hash(stmt_hash)
+        .ok_or_else(|| E::from(MssqlError::UnknownStatementId.into()))?,
+    };
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      rpc_execute_sql(cmd, &mut fbw, pb, rv, *transaction_descriptor)?;
+      manage_conn_state(cs, send_message(*packet_size, fbw._curr_bytes(), stream, RPC).await)?;
+    }
+    let env = Env { packet_size, transaction_descriptor };
+    manage_conn_state(cs, fetch_tokens(columns, done_cb, env, nb, rb, stream, vb).await)?;
+    Ok(())
+  }
+}
+
+/// Reads a tabular result and then processes its tokens.
+#[inline]
+pub(crate) async fn fetch_tokens<SR>(
+  columns: &mut Vector<Column>,
+  done_cb: impl FnMut(u64),
+  env: Env<'_>,
+  nb: &mut PartitionedFilledBuffer,
+  rb: &mut Vector<usize>,
+  stream: &mut SR,
+  vb: &mut Vector<(bool, Range<usize>)>,
+) -> crate::Result<()>
+where
+  SR: StreamReader,
+{
+  let ty = fetch_message(nb, stream).await?;
+  if ty != TABULAR_RESULT {
+    return Err(MssqlError::UnexpectedPacket { received: ty }.into());
+  }
+  let range = nb._current_range();
+  let bytes = nb._buffer_mut().get_mut(range).unwrap_or_default();
+  process_tokens(bytes, columns, env, rb, vb, done_cb)
+}
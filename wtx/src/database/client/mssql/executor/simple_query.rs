@@ -0,0 +1,40 @@
+use crate::{
+  database::client::mssql::{
+    executor::{fetch::fetch_tokens, manage_conn_state},
+    executor_buffer::ExecutorBufferPartsMut,
+    packet::{send_message, SQL_BATCH},
+    protocol::sql_batch,
+    token::Env,
+    Executor, ExecutorBuffer,
+  },
+  misc::{FilledBufferWriter, LeaseMut, Stream},
+};
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Sends a `SQL_BATCH` request, calling `cb` with the number of affected records of each
+  /// statement. Returned rows are ignored.
+  #[inline]
+  pub(crate) async fn simple_query_execute(
+    &mut self,
+    cmd: &str,
+    cb: impl FnMut(u64),
+  ) -> crate::Result<()> {
+    let Self { cs, eb, packet_size, phantom: _, stream, transaction_descriptor } = self;
+    let ExecutorBufferPartsMut { columns, nb, rb, vb, .. } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(columns, nb, rb, vb);
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      sql_batch(cmd, &mut fbw, *transaction_descriptor)?;
+      manage_conn_state(
+        cs,
+        send_message(*packet_size, fbw._curr_bytes(), stream, SQL_BATCH).await,
+      )?;
+    }
+    let env = Env { packet_size, transaction_descriptor };
+    manage_conn_state(cs, fetch_tokens(columns, cb, env, nb, rb, stream, vb).await)
+  }
+}
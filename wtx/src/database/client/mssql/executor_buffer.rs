@@ -0,0 +1,121 @@
+use crate::{
+  database::client::mssql::{column::Column, Statements},
+  misc::{partitioned_filled_buffer::PartitionedFilledBuffer, Lease, LeaseMut, Rng, Vector},
+};
+use core::ops::Range;
+
+#[derive(Debug)]
+#[doc = _internal_buffer_doc!()]
+pub struct ExecutorBuffer {
+  /// Columns of the last result set.
+  pub(crate) columns: Vector<Column>,
+  /// Network Buffer.
+  pub(crate) nb: PartitionedFilledBuffer,
+  /// Parameters Buffer.
+  pub(crate) pb: Vector<u8>,
+  /// Records Buffer.
+  pub(crate) rb: Vector<usize>,
+  /// Statements
+  pub(crate) stmts: Statements,
+  /// Values Buffer.
+  pub(crate) vb: Vector<(bool, Range<usize>)>,
+}
+
+impl ExecutorBuffer {
+  /// With provided capacity.
+  #[inline]
+  pub fn new<RNG>(max_stmts: usize, rng: RNG) -> Self
+  where
+    RNG: Rng,
+  {
+    Self {
+      columns: Vector::new(),
+      nb: PartitionedFilledBuffer::new(),
+      pb: Vector::new(),
+      rb: Vector::new(),
+      stmts: Statements::new(max_stmts, rng),
+      vb: Vector::new(),
+    }
+  }
+
+  /// With default capacity.
+  #[inline]
+  pub fn with_capacity<RNG>(
+    (columns_cap, network_buffer_cap, rows_cap, stmts_cap): (usize, usize, usize, usize),
+    max_stmts: usize,
+    rng: &mut RNG,
+  ) -> crate::Result<Self>
+  where
+    RNG: Rng,
+  {
+    Ok(Self {
+      columns: Vector::with_capacity(columns_cap)?,
+      nb: PartitionedFilledBuffer::_with_capacity(network_buffer_cap)?,
+      pb: Vector::new(),
+      rb: Vector::with_capacity(rows_cap)?,
+      stmts: Statements::with_capacity(network_buffer_cap, max_stmts, rng, stmts_cap)?,
+      vb: Vector::with_capacity(rows_cap.saturating_mul(columns_cap))?,
+    })
+  }
+
+  /// Should be used in a new instance.
+  #[inline]
+  pub(crate) fn clear(&mut self) {
+    let Self { columns, nb, pb, rb, stmts, vb } = self;
+    columns.clear();
+    nb._clear();
+    pb.clear();
+    rb.clear();
+    stmts.clear();
+    vb.clear();
+  }
+
+  /// Should be called before executing commands.
+  #[inline]
+  pub(crate) fn clear_cmd_buffers(
+    columns: &mut Vector<Column>,
+    nb: &mut PartitionedFilledBuffer,
+    rb: &mut Vector<usize>,
+    vb: &mut Vector<(bool, Range<usize>)>,
+  ) {
+    columns.clear();
+    nb._clear_if_following_is_empty();
+    rb.clear();
+    vb.clear();
+  }
+
+  #[inline]
+  pub(crate) fn parts_mut(&mut self) -> ExecutorBufferPartsMut<'_> {
+    ExecutorBufferPartsMut {
+      columns: &mut self.columns,
+      nb: &mut self.nb,
+      pb: &mut self.pb,
+      rb: &mut self.rb,
+      stmts: &mut self.stmts,
+      vb: &mut self.vb,
+    }
+  }
+}
+
+impl Lease<ExecutorBuffer> for ExecutorBuffer {
+  #[inline]
+  fn lease(&self) -> &ExecutorBuffer {
+    self
+  }
+}
+
+impl LeaseMut<ExecutorBuffer> for ExecutorBuffer {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut ExecutorBuffer {
+    self
+  }
+}
+
+pub(crate) struct ExecutorBufferPartsMut<'eb> {
+  pub(crate) columns: &'eb mut Vector<Column>,
+  pub(crate) nb: &'eb mut PartitionedFilledBuffer,
+  pub(crate) pb: &'eb mut Vector<u8>,
+  pub(crate) rb: &'eb mut Vector<usize>,
+  pub(crate) stmts: &'eb mut Statements,
+  pub(crate) vb: &'eb mut Vector<(bool, Range<usize>)>,
+}
@@ -0,0 +1,54 @@
+use crate::database::client::mssql::Ty;
+
+/// Microsoft SQL Server error
+#[derive(Debug)]
+pub enum MssqlError {
+  /// There are no sufficient bytes to decoding an element
+  DecodingError,
+  /// There are no bytes left to build a `DbError`
+  InsufficientDbErrorBytes,
+  /// The response of a `PRELOGIN` request doesn't contain the encryption option.
+  InvalidPreloginResponse,
+  /// Received bytes don't compose a valid token stream.
+  InvalidTokenStream,
+  /// Expected one record but got none.
+  NoRecord,
+  /// `NCHAR`, `NTEXT`, `NVARCHAR` and `XML` values are transferred as UTF-16, as such, they can
+  /// only be decoded into owned strings.
+  NonUtf8String,
+  /// Server does not support encryption
+  ServerDoesNotSupportEncryption,
+  /// Unencrypted connections were refused by the server
+  ServerRequiresEncryption,
+  /// Received size differs from expected size.
+  UnexpectedBufferSize {
+    /// Expected
+    expected: u32,
+    /// Received
+    received: u32,
+  },
+  /// Received a packet whose type is not expected in the current context.
+  UnexpectedPacket {
+    /// Type of the packet
+    received: u8,
+  },
+  /// Received a token that isn't supported.
+  UnexpectedToken {
+    /// Token identifier
+    received: u8,
+  },
+  /// Bytes don't represent expected type
+  UnexpectedValueFromBytes {
+    /// Expected
+    expected: Ty,
+  },
+  /// The system does not support a provided parameter.
+  UnknownConfigurationParameter,
+  /// Received a statement ID that is not present in the local cache.
+  UnknownStatementId,
+  /// The declared type or the length of an encoded value can't be used as a parameter.
+  UnsupportedParameterType {
+    /// Declared type
+    received: Ty,
+  },
+}
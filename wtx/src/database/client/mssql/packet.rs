@@ -0,0 +1,104 @@
+use crate::{
+  database::client::mssql::MssqlError,
+  misc::{
+    partitioned_filled_buffer::PartitionedFilledBuffer, StreamReader, StreamWriter, _read_header,
+    _read_payload,
+  },
+};
+
+/// Packet size requested during the login, which can be modified by the server.
+pub(crate) const DEFAULT_PACKET_SIZE: usize = 4096;
+pub(crate) const HEADER_LEN: usize = 8;
+pub(crate) const LOGIN7: u8 = 16;
+pub(crate) const PRELOGIN: u8 = 18;
+pub(crate) const RPC: u8 = 3;
+pub(crate) const SQL_BATCH: u8 = 1;
+pub(crate) const TABULAR_RESULT: u8 = 4;
+
+const STATUS_EOM: u8 = 1;
+
+/// Reads all the packets of a message into the current area of `nb`, returning the type of the
+/// message. Packet headers are removed so that the payloads form a contiguous sequence of bytes.
+//
+// | Type | Status | Length | SPID | Packet ID | Window | Payload |
+// |  1   |   1    |   2    |  2   |     1     |   1    |    x    |
+#[inline]
+pub(crate) async fn fetch_message<SR>(
+  nb: &mut PartitionedFilledBuffer,
+  stream: &mut SR,
+) -> crate::Result<u8>
+where
+  SR: StreamReader,
+{
+  let begin = nb._current_end_idx();
+  nb._set_indices(begin, 0, nb._following_len())?;
+  loop {
+    nb._reserve(HEADER_LEN)?;
+    let mut read = nb._following_len();
+    let buffer = nb._following_rest_mut();
+    let [ty, status, a, b, ..] =
+      _read_header::<0, HEADER_LEN, SR>(buffer, &mut read, stream).await?;
+    let Some(payload_len) = usize::from(u16::from_be_bytes([a, b])).checked_sub(HEADER_LEN) else {
+      return Err(MssqlError::UnexpectedPacket { received: ty }.into());
+    };
+    let current_end = nb._current_end_idx();
+    _read_payload((HEADER_LEN, payload_len), nb, &mut read, stream).await?;
+    let following_end = nb._following_end_idx();
+    let header_end = current_end.wrapping_add(HEADER_LEN);
+    nb._buffer_mut().copy_within(header_end..following_end, current_end);
+    nb._set_indices(
+      begin,
+      current_end.wrapping_sub(begin).wrapping_add(payload_len),
+      following_end.wrapping_sub(header_end).wrapping_sub(payload_len),
+    )?;
+    if status & STATUS_EOM == STATUS_EOM {
+      return Ok(ty);
+    }
+  }
+}
+
+/// Sends `payload` through one or more packets of at most `packet_size` bytes.
+#[inline]
+pub(crate) async fn send_message<SW>(
+  packet_size: usize,
+  payload: &[u8],
+  stream: &mut SW,
+  ty: u8,
+) -> crate::Result<()>
+where
+  SW: StreamWriter,
+{
+  let mut iter = payload.chunks(packet_size.max(512).wrapping_sub(HEADER_LEN)).peekable();
+  let mut packet_id: u8 = 1;
+  while let Some(chunk) = iter.next() {
+    let status = if iter.peek().is_none() { STATUS_EOM } else { 0 };
+    let header = packet_header(chunk.len(), packet_id, status, ty);
+    stream.write_all_vectored(&[&header, chunk]).await?;
+    packet_id = packet_id.wrapping_add(1);
+  }
+  Ok(())
+}
+
+#[inline]
+pub(crate) fn packet_header(payload_len: usize, packet_id: u8, status: u8, ty: u8) -> [u8; 8] {
+  let len = u16::try_from(payload_len.wrapping_add(HEADER_LEN)).unwrap_or(u16::MAX);
+  let [a, b] = len.to_be_bytes();
+  [ty, status, a, b, 0, 0, packet_id, 0]
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    database::client::mssql::packet::{fetch_message, send_message, TABULAR_RESULT},
+    misc::{partitioned_filled_buffer::PartitionedFilledBuffer, BytesStream},
+  };
+
+  #[tokio::test]
+  async fn merges_packets() {
+    let mut stream = BytesStream::default();
+    send_message(512, &[7; 600], &mut stream, TABULAR_RESULT).await.unwrap();
+    let mut nb = PartitionedFilledBuffer::new();
+    assert_eq!(fetch_message(&mut nb, &mut stream).await.unwrap(), TABULAR_RESULT);
+    assert_eq!(nb._current(), &[7; 600]);
+  }
+}
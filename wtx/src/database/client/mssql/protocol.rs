@@ -0,0 +1,404 @@
+use crate::{
+  database::{
+    client::mssql::{packet::DEFAULT_PACKET_SIZE, Config, EncodeValue, Mssql, MssqlError, Ty},
+    RecordValues,
+  },
+  misc::{FilledBufferWriter, Usize, Vector},
+};
+use core::fmt::Write;
+
+pub(crate) const ENCRYPT_NOT_SUP: u8 = 2;
+pub(crate) const ENCRYPT_ON: u8 = 1;
+pub(crate) const ENCRYPT_REQ: u8 = 3;
+
+/// `Latin1_General_CI_AS`, which is only used to declare parameters. Values are always sent as
+/// UTF-16.
+const COLLATION: [u8; 5] = [9, 4, 208, 0, 52];
+const LOGIN7_FIXED_LEN: usize = 94;
+const MAX_NON_PLP_LEN: usize = 8000;
+const PRELOGIN_ENCRYPTION: u8 = 1;
+const PRELOGIN_TERMINATOR: u8 = 255;
+const SP_EXECUTESQL: u8 = 10;
+const TDS_VERSION: u32 = 0x7400_0004;
+
+/// Headers that must precede `SQL_BATCH` and `RPC` requests.
+//
+// | Total length | Header length | Header type | Transaction descriptor | Outstanding requests |
+// |      4       |       4       |      2      |           8            |          4           |
+#[inline]
+pub(crate) fn all_headers(fbw: &mut FilledBufferWriter<'_>, td: u64) -> crate::Result<()> {
+  fbw._extend_from_slices([
+    &22u32.to_le_bytes()[..],
+    &18u32.to_le_bytes(),
+    &2u16.to_le_bytes(),
+    &td.to_le_bytes(),
+    &1u32.to_le_bytes(),
+  ])
+}
+
+// | Fixed part | Hostname | Username | Password | App name | Server name | ... | Database |
+// |     94     |    x     |    x     |    x     |    x     |      x      | ... |    x     |
+#[inline]
+pub(crate) fn login7(config: &Config<'_>, fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  let start = fbw._len();
+  let mut fixed = [0; LOGIN7_FIXED_LEN];
+  fbw.extend_from_slice(&fixed)?;
+  let strings = [
+    ("", false),
+    (config.user, false),
+    (config.password, true),
+    (config.app_name, false),
+    (config.host, false),
+    ("", false),
+    ("wtx", false),
+    ("", false),
+    (config.db, false),
+  ];
+  let mut offset = LOGIN7_FIXED_LEN;
+  for ((string, is_password), idx) in strings.into_iter().zip((36usize..).step_by(4)) {
+    let before = fbw._len();
+    write_utf16(fbw, string, is_password)?;
+    let len = fbw._len().wrapping_sub(before);
+    let [a, b] = u16::try_from(offset)?.to_le_bytes();
+    let [c, d] = u16::try_from(len / 2)?.to_le_bytes();
+    if let Some(elem) = fixed.get_mut(idx..idx.wrapping_add(4)) {
+      elem.copy_from_slice(&[a, b, c, d]);
+    }
+    offset = offset.wrapping_add(len);
+  }
+  let [a, b] = u16::try_from(offset)?.to_le_bytes();
+  for idx in [78usize, 82, 86] {
+    if let Some(elem) = fixed.get_mut(idx..idx.wrapping_add(2)) {
+      elem.copy_from_slice(&[a, b]);
+    }
+  }
+  let len = u32::try_from(fbw._len().wrapping_sub(start))?;
+  let packet_size = u32::try_from(DEFAULT_PACKET_SIZE)?;
+  for (idx, value) in [(0usize, len), (4, TDS_VERSION), (8, packet_size), (32, 0x0409)] {
+    if let Some(elem) = fixed.get_mut(idx..idx.wrapping_add(4)) {
+      elem.copy_from_slice(&value.to_le_bytes());
+    }
+  }
+  if let Some([flags1, flags2, ..]) = fixed.get_mut(24..) {
+    // Use database and language warnings, set language and use ODBC semantics.
+    *flags1 = 0xE0;
+    *flags2 = 0x03;
+  }
+  if let Some(elem) = fbw._curr_bytes_mut().get_mut(start..start.wrapping_add(LOGIN7_FIXED_LEN)) {
+    elem.copy_from_slice(&fixed);
+  }
+  Ok(())
+}
+
+// | Option headers | Version | Encryption | Instance | Thread ID | MARS |
+// |       26       |    6    |     1      |    1     |     4     |  1   |
+#[inline]
+pub(crate) fn prelogin(encryption: u8, fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  fbw._extend_from_slices([
+    &[0, 0, 26, 0, 6][..],
+    &[PRELOGIN_ENCRYPTION, 0, 32, 0, 1],
+    &[2, 0, 33, 0, 1],
+    &[3, 0, 34, 0, 4],
+    &[4, 0, 38, 0, 1],
+    &[PRELOGIN_TERMINATOR],
+    &[0, 0, 0, 0, 0, 0],
+    &[encryption],
+    &[0],
+    &[0, 0, 0, 0],
+    &[0],
+  ])
+}
+
+/// The encryption mode chosen by the server.
+#[inline]
+pub(crate) fn prelogin_encryption(payload: &[u8]) -> crate::Result<u8> {
+  let mut options = payload;
+  while let [token, a, b, _, _, rest @ ..] = options {
+    if *token == PRELOGIN_ENCRYPTION {
+      let offset = usize::from(u16::from_be_bytes([*a, *b]));
+      if let Some(elem) = payload.get(offset) {
+        return Ok(*elem);
+      }
+      break;
+    }
+    options = rest;
+  }
+  Err(MssqlError::InvalidPreloginResponse.into())
+}
+
+/// Executes `cmd` through `sp_executesql`. Parameters are declared according to the types set
+/// by the encoded values and the declarations are stored in `pb` before being sent.
+//
+// | Headers | Procedure ID | Flags | Statement | Declarations | Value 0 | ... | Value N |
+// |   22    |      4       |   2   |     x     |      x       |    x    | ... |    x    |
+#[inline]
+pub(crate) fn rpc_execute_sql<E, RV>(
+  cmd: &str,
+  fbw: &mut FilledBufferWriter<'_>,
+  pb: &mut Vector<u8>,
+  mut rv: RV,
+  td: u64,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+  RV: RecordValues<Mssql<E>>,
+{
+  all_headers(fbw, td)?;
+  fbw.extend_from_slice(&[255, 255, SP_EXECUTESQL, 0, 0, 0])?;
+  fbw._extend_from_slices([&[0, 0, Ty::NVarChar.into(), 255, 255][..], &COLLATION])?;
+  write_plp(fbw, |local_fbw| write_utf16(local_fbw, cmd, false))?;
+  if rv.len() == 0 {
+    return Ok(());
+  }
+  pb.clear();
+  let values_start = fbw._len();
+  let mut aux = (0u32, None, pb);
+  let _ = rv.encode_values(
+    &mut aux,
+    &mut EncodeValue::new(fbw),
+    |_, _| 0,
+    |(idx, err, local_pb), local_ev, is_null, elem_len| {
+      *idx = idx.wrapping_add(1);
+      // The metadata is written after the value and then both are swapped
+      let end = local_ev.fbw()._len();
+      let start = end.wrapping_sub(elem_len);
+      let is_plp = match param_metadata(elem_len, local_ev, is_null, *idx, local_pb) {
+        Ok(elem) => elem,
+        Err(local_err) => {
+          *err = Some(local_err);
+          return 0;
+        }
+      };
+      let metadata_len = local_ev.fbw()._len().wrapping_sub(end);
+      if let Some(elem) = local_ev.fbw()._curr_bytes_mut().get_mut(start..) {
+        elem.rotate_right(metadata_len);
+      }
+      if is_plp {
+        if let Err(local_err) = local_ev.fbw().extend_from_slice(&[0, 0, 0, 0]) {
+          *err = Some(local_err);
+        }
+        return metadata_len.wrapping_add(4);
+      }
+      metadata_len
+    },
+  )?;
+  let (_, err, local_pb) = aux;
+  if let Some(elem) = err {
+    return Err(E::from(elem));
+  }
+  let declarations_start = fbw._len();
+  fbw._extend_from_slices([&[0, 0, Ty::NVarChar.into(), 255, 255][..], &COLLATION])?;
+  let declarations = local_pb.get(..local_pb.len().saturating_sub(1)).unwrap_or_default();
+  write_plp(fbw, |local_fbw| {
+    for byte in declarations {
+      local_fbw.extend_from_slice(&[*byte, 0])?;
+    }
+    Ok(())
+  })?;
+  let declarations_len = fbw._len().wrapping_sub(declarations_start);
+  if let Some(elem) = fbw._curr_bytes_mut().get_mut(values_start..) {
+    elem.rotate_right(declarations_len);
+  }
+  Ok(())
+}
+
+// | Headers | Command |
+// |   22    |    x    |
+#[inline]
+pub(crate) fn sql_batch(cmd: &str, fbw: &mut FilledBufferWriter<'_>, td: u64) -> crate::Result<()> {
+  all_headers(fbw, td)?;
+  write_utf16(fbw, cmd, false)
+}
+
+// Writes the name, the status, the `TYPE_INFO` and the length prefix of a parameter. Returns
+// `true` if the value is partially length-prefixed.
+#[inline]
+fn param_metadata(
+  elem_len: usize,
+  ev: &mut EncodeValue<'_, '_>,
+  is_null: bool,
+  param_idx: u32,
+  pb: &mut Vector<u8>,
+) -> crate::Result<bool> {
+  let (scale, ty) = ev.take_ty();
+  let fbw = ev.fbw();
+  let len_u8 = u8::try_from(elem_len).unwrap_or(u8::MAX);
+  let len_u16 = u16::try_from(elem_len).unwrap_or(u16::MAX).to_le_bytes();
+  pb.write_fmt(format_args!("@P{param_idx} "))?;
+  fbw.extend_from_slice(&[0, 0])?;
+  if is_null {
+    fbw._extend_from_slices([&[Ty::NVarChar.into(), 64, 31][..], &COLLATION, &[255, 255]])?;
+    pb.write_str("nvarchar(4000),")?;
+    return Ok(false);
+  }
+  let is_plp = elem_len > MAX_NON_PLP_LEN;
+  match (ty, elem_len) {
+    (Ty::BigVarBin, _) => {
+      if is_plp {
+        fbw.extend_from_slice(&[ty.into(), 255, 255])?;
+        pb.write_str("varbinary(max),")?;
+      } else {
+        fbw._extend_from_slices([&[ty.into(), 64, 31][..], &len_u16])?;
+        pb.write_str("varbinary(8000),")?;
+      }
+    }
+    (Ty::Bitn, 1) => {
+      fbw.extend_from_slice(&[ty.into(), 1, 1])?;
+      pb.write_str("bit,")?;
+    }
+    (Ty::Daten, 3) => {
+      fbw.extend_from_slice(&[ty.into(), 3])?;
+      pb.write_str("date,")?;
+    }
+    (Ty::DateTime2n, 8) => {
+      fbw.extend_from_slice(&[ty.into(), 7, 8])?;
+      pb.write_str("datetime2(7),")?;
+    }
+    (Ty::DateTimeOffsetn, 10) => {
+      fbw.extend_from_slice(&[ty.into(), 7, 10])?;
+      pb.write_str("datetimeoffset(7),")?;
+    }
+    (Ty::Decimaln, 17) => {
+      fbw.extend_from_slice(&[ty.into(), 17, 38, scale, 17])?;
+      pb.write_fmt(format_args!("decimal(38,{scale}),"))?;
+    }
+    (Ty::Fltn, 4 | 8) => {
+      fbw.extend_from_slice(&[ty.into(), len_u8, len_u8])?;
+      pb.write_str(if elem_len == 4 { "real," } else { "float," })?;
+    }
+    (Ty::Guid, 16) => {
+      fbw.extend_from_slice(&[ty.into(), 16, 16])?;
+      pb.write_str("uniqueidentifier,")?;
+    }
+    (Ty::Intn, 1 | 2 | 4 | 8) => {
+      fbw.extend_from_slice(&[ty.into(), len_u8, len_u8])?;
+      pb.write_str(match elem_len {
+        1 => "tinyint,",
+        2 => "smallint,",
+        4 => "int,",
+        _ => "bigint,",
+      })?;
+    }
+    (Ty::NVarChar, _) => {
+      if is_plp {
+        fbw._extend_from_slices([&[ty.into(), 255, 255][..], &COLLATION])?;
+        pb.write_str("nvarchar(max),")?;
+      } else {
+        fbw._extend_from_slices([&[ty.into(), 64, 31][..], &COLLATION, &len_u16])?;
+        pb.write_str("nvarchar(4000),")?;
+      }
+    }
+    (Ty::Timen, 5) => {
+      fbw.extend_from_slice(&[ty.into(), 7, 5])?;
+      pb.write_str("time(7),")?;
+    }
+    _ => return Err(MssqlError::UnsupportedParameterType { received: ty }.into()),
+  }
+  if is_plp {
+    let len = Usize::from(elem_len).into_u64();
+    fbw._extend_from_slices([&len.to_le_bytes()[..], &u32::try_from(elem_len)?.to_le_bytes()])?;
+  }
+  Ok(is_plp)
+}
+
+// | Total length | Chunk length | Chunk | Terminator |
+// |      8       |      4       |   x   |     4      |
+//
+// Empty values don't have chunks.
+#[inline]
+fn write_plp<'buffer, E>(
+  fbw: &mut FilledBufferWriter<'buffer>,
+  cb: impl FnOnce(&mut FilledBufferWriter<'buffer>) -> Result<(), E>,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+{
+  let start = fbw._len();
+  fbw.extend_from_slice(&[0; 12])?;
+  cb(fbw)?;
+  let len = fbw._len().wrapping_sub(start).wrapping_sub(12);
+  if len == 0 {
+    return Ok(());
+  }
+  let chunk_len = u32::try_from(len).map_err(crate::Error::from)?;
+  if let Some(elem) = fbw._curr_bytes_mut().get_mut(start..start.wrapping_add(12)) {
+    let [a, b, c, d, e, f, g, h] = Usize::from(len).into_u64().to_le_bytes();
+    let [i, j, k, l] = chunk_len.to_le_bytes();
+    elem.copy_from_slice(&[a, b, c, d, e, f, g, h, i, j, k, l]);
+  }
+  fbw.extend_from_slice(&[0, 0, 0, 0])?;
+  Ok(())
+}
+
+// Passwords are obfuscated by swapping the nibbles of each byte and then applying a XOR.
+#[inline]
+fn write_utf16(
+  fbw: &mut FilledBufferWriter<'_>,
+  string: &str,
+  is_password: bool,
+) -> crate::Result<()> {
+  for unit in string.encode_utf16() {
+    let [mut a, mut b] = unit.to_le_bytes();
+    if is_password {
+      a = a.rotate_left(4) ^ 0xA5;
+      b = b.rotate_left(4) ^ 0xA5;
+    }
+    fbw.extend_from_slice(&[a, b])?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::mssql::{
+      protocol::{login7, prelogin, prelogin_encryption, rpc_execute_sql, ENCRYPT_ON},
+      Config,
+    },
+    misc::{filled_buffer::FilledBuffer, FilledBufferWriter, Uri, Vector},
+  };
+
+  #[test]
+  fn login7_has_correct_offsets() {
+    let uri = Uri::new("mssql://a:b@c:1433/d");
+    let config = Config::from_uri(&uri).unwrap();
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    login7(&config, &mut fbw).unwrap();
+    let bytes = fbw._curr_bytes();
+    assert_eq!(&bytes[..4], &u32::try_from(bytes.len()).unwrap().to_le_bytes());
+    // User name
+    assert_eq!(&bytes[40..44], &[94, 0, 1, 0]);
+    assert_eq!(&bytes[94..96], &[b'a', 0]);
+    // Obfuscated password
+    assert_eq!(&bytes[44..48], &[96, 0, 1, 0]);
+    assert_eq!(&bytes[96..98], &[0x83, 0xA5]);
+    // Database
+    assert_eq!(&bytes[68..72], &[112, 0, 1, 0]);
+    assert_eq!(&bytes[112..], &[b'd', 0]);
+  }
+
+  #[test]
+  fn prelogin_roundtrip() {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    prelogin(ENCRYPT_ON, &mut fbw).unwrap();
+    assert_eq!(fbw._curr_bytes().len(), 39);
+    assert_eq!(prelogin_encryption(fbw._curr_bytes()).unwrap(), ENCRYPT_ON);
+    assert!(prelogin_encryption(&[255]).is_err());
+  }
+
+  #[test]
+  fn rpc_declares_parameters() {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    let mut pb = Vector::new();
+    rpc_execute_sql::<crate::Error, _>("SELECT @P1", &mut fbw, &mut pb, (1i32, None::<i32>), 0)
+      .unwrap();
+    assert_eq!(pb.as_slice(), b"@P1 int,@P2 nvarchar(4000),");
+    let bytes = fbw._curr_bytes();
+    assert!(
+      bytes.ends_with(&[0, 0, 38, 4, 4, 1, 0, 0, 0, 0, 0, 231, 64, 31, 9, 4, 208, 0, 52, 255, 255])
+    );
+  }
+}
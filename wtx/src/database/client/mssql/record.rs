@@ -0,0 +1,125 @@
+use crate::{
+  database::{
+    client::mssql::{column::Column, DecodeValue, Mssql},
+    Database, ValueIdent,
+  },
+  misc::_unlikely_elem,
+};
+use core::{marker::PhantomData, ops::Range};
+
+/// Record
+#[derive(Debug)]
+pub struct Record<'exec, E> {
+  pub(crate) bytes: &'exec [u8],
+  pub(crate) columns: &'exec [Column],
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  pub(crate) values_bytes_offsets: &'exec [(bool, Range<usize>)],
+}
+
+impl<'exec, E> Record<'exec, E> {
+  #[inline]
+  pub(crate) fn _new(
+    bytes: &'exec [u8],
+    columns: &'exec [Column],
+    values_bytes_offsets: &'exec [(bool, Range<usize>)],
+  ) -> Self {
+    Self { bytes, columns, phantom: PhantomData, values_bytes_offsets }
+  }
+}
+
+impl<'exec, E> crate::database::Record<'exec> for Record<'exec, E>
+where
+  E: From<crate::Error>,
+{
+  type Database = Mssql<E>;
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.values_bytes_offsets.len()
+  }
+
+  #[inline]
+  fn value<CI>(&self, ci: CI) -> Option<<Self::Database as Database>::DecodeValue<'exec>>
+  where
+    CI: ValueIdent<Self>,
+  {
+    let idx = ci.idx(self)?;
+    let (is_null, range) = match self.values_bytes_offsets.get(idx) {
+      None => return _unlikely_elem(None),
+      Some(elem) => elem,
+    };
+    if *is_null {
+      None
+    } else {
+      let column = match self.columns.get(idx) {
+        None => return _unlikely_elem(None),
+        Some(elem) => elem,
+      };
+      let bytes = match self.bytes.get(range.clone()) {
+        None => return _unlikely_elem(None),
+        Some(elem) => elem,
+      };
+      Some(DecodeValue::new(bytes, column.scale, column.ty))
+    }
+  }
+}
+
+impl<'exec, E> ValueIdent<Record<'exec, E>> for str {
+  #[inline]
+  fn idx(&self, input: &Record<'exec, E>) -> Option<usize> {
+    input.columns.iter().position(|column| column.name.as_str() == self)
+  }
+}
+
+impl<E> PartialEq for Record<'_, E> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.bytes == other.bytes
+      && self.columns == other.columns
+      && self.phantom == other.phantom
+      && self.values_bytes_offsets == other.values_bytes_offsets
+  }
+}
+
+mod array {
+  use crate::{
+    database::{client::mssql::Mssql, Decode, FromRecord, Record},
+    misc::{into_rslt, ArrayString},
+  };
+
+  impl<E, const N: usize> FromRecord<Mssql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn from_record(
+      record: &crate::database::client::mssql::record::Record<'_, E>,
+    ) -> Result<Self, E> {
+      <Self as Decode<Mssql<E>>>::decode(&into_rslt(record.value(0))?)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::{
+    client::mssql::{
+      tests::{column0, column1, column2},
+      DecodeValue, Record,
+    },
+    Record as _,
+  };
+
+  #[test]
+  fn returns_correct_values() {
+    let columns = &[column0(), column1(), column2()];
+    let bytes = &[b'a', 0, 1, 0, 0, 0];
+    let record =
+      Record::<crate::Error>::_new(bytes, columns, &[(false, 0..2), (false, 2..6), (true, 6..6)]);
+    assert_eq!(record.len(), 3);
+    assert_eq!(record.value(0), Some(DecodeValue::new(&[b'a', 0][..], 0, column0().ty)));
+    assert_eq!(record.value(1), Some(DecodeValue::new(&[1, 0, 0, 0][..], 0, column1().ty)));
+    assert_eq!(record.value(2), None);
+    assert_eq!(record.value("b"), Some(DecodeValue::new(&[1, 0, 0, 0][..], 0, column1().ty)));
+  }
+}
@@ -0,0 +1,102 @@
+use crate::database::client::mssql::{column::Column, Mssql, Record};
+use core::{marker::PhantomData, ops::Range};
+
+/// Records
+#[derive(Debug)]
+pub struct Records<'exec, E> {
+  pub(crate) bytes: &'exec [u8],
+  pub(crate) columns: &'exec [Column],
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  /// Each element represents a record and the end offset of its values in
+  /// `values_bytes_offsets`.
+  pub(crate) records_values_offsets: &'exec [usize],
+  /// Each element represents a value and an offset of `bytes`.
+  pub(crate) values_bytes_offsets: &'exec [(bool, Range<usize>)],
+}
+
+impl<'exec, E> Records<'exec, E> {
+  #[inline]
+  pub(crate) fn _new(
+    bytes: &'exec [u8],
+    columns: &'exec [Column],
+    records_values_offsets: &'exec [usize],
+    values_bytes_offsets: &'exec [(bool, Range<usize>)],
+  ) -> Self {
+    Self { bytes, columns, phantom: PhantomData, records_values_offsets, values_bytes_offsets }
+  }
+}
+
+impl<'exec, E> crate::database::Records<'exec> for Records<'exec, E>
+where
+  E: From<crate::Error>,
+{
+  type Database = Mssql<E>;
+
+  #[inline]
+  fn get(&self, record_idx: usize) -> Option<Record<'exec, E>> {
+    let range = match self.records_values_offsets.get(..record_idx.wrapping_add(1))? {
+      [] => return None,
+      &[to] => 0..to,
+      &[.., from, to] => from..to,
+    };
+    Some(Record {
+      bytes: self.bytes,
+      columns: self.columns,
+      phantom: PhantomData,
+      values_bytes_offsets: self.values_bytes_offsets.get(range)?,
+    })
+  }
+
+  #[inline]
+  fn iter(&self) -> impl Iterator<Item = Record<'exec, E>> {
+    (0..self.len()).filter_map(|idx| self.get(idx))
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.records_values_offsets.len()
+  }
+}
+
+impl<E> Default for Records<'_, E> {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      bytes: &[],
+      columns: &[],
+      phantom: PhantomData,
+      records_values_offsets: &[],
+      values_bytes_offsets: &[],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::{
+    client::mssql::{
+      tests::{column0, column1},
+      DecodeValue, Record, Records,
+    },
+    Record as _, Records as _,
+  };
+
+  #[test]
+  fn returns_correct_values() {
+    let columns = &[column0(), column1()];
+    let bytes = &[b'a', 0, 1, 0, 0, 0, b'b', 0, 2, 0, 0, 0];
+    let values_bytes_offsets = &[(false, 0..2), (false, 2..6), (false, 6..8), (false, 8..12)];
+    let records = Records::<crate::Error>::_new(bytes, columns, &[2, 4], values_bytes_offsets);
+    assert_eq!(records.len(), 2);
+
+    let first_record = records.get(0).unwrap();
+    assert_eq!(first_record, Record::_new(bytes, columns, &[(false, 0..2), (false, 2..6)]));
+    assert_eq!(first_record.value(1).unwrap(), DecodeValue::new(&[1, 0, 0, 0], 0, column1().ty));
+
+    let second_record = records.get(1).unwrap();
+    assert_eq!(second_record, Record::_new(bytes, columns, &[(false, 6..8), (false, 8..12)]));
+    assert_eq!(second_record.value(0).unwrap(), DecodeValue::new(&[b'b', 0], 0, column0().ty));
+
+    assert_eq!(records.iter().count(), 2);
+  }
+}
@@ -0,0 +1,117 @@
+use crate::misc::{from_utf8_basic, BlocksDeque, Rng, _random_state};
+use foldhash::fast::FixedState;
+use hashbrown::HashMap;
+
+/// Statements
+///
+/// SQL Server caches the execution plans of parameterized commands sent through
+/// `sp_executesql`, as such, only the texts of the commands are locally stored to allow
+/// executions through hashes.
+#[derive(Debug)]
+pub struct Statements {
+  max_stmts: usize,
+  rs: FixedState,
+  stmts: BlocksDeque<u8, ()>,
+  stmts_indcs: HashMap<u64, usize>,
+}
+
+impl Statements {
+  #[inline]
+  pub(crate) fn new<RNG>(max_stmts: usize, rng: RNG) -> Self
+  where
+    RNG: Rng,
+  {
+    Self {
+      max_stmts: max_stmts.max(1),
+      rs: _random_state(rng),
+      stmts: BlocksDeque::new(),
+      stmts_indcs: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub(crate) fn with_capacity<RNG>(
+    cmds_bytes: usize,
+    max_stmts: usize,
+    rng: RNG,
+    stmts: usize,
+  ) -> crate::Result<Self>
+  where
+    RNG: Rng,
+  {
+    Ok(Self {
+      max_stmts: max_stmts.max(1),
+      rs: _random_state(rng),
+      stmts: BlocksDeque::with_capacity(stmts, cmds_bytes)?,
+      stmts_indcs: HashMap::with_capacity(stmts),
+    })
+  }
+
+  #[inline]
+  pub(crate) fn clear(&mut self) {
+    let Self { max_stmts: _, rs: _, stmts, stmts_indcs } = self;
+    stmts.clear();
+    stmts_indcs.clear();
+  }
+
+  #[inline]
+  pub(crate) fn get_by_idx(&self, idx: usize) -> Option<&str> {
+    from_utf8_basic(self.stmts.get(idx)?.data).ok()
+  }
+
+  #[inline]
+  pub(crate) fn get_by_stmt_hash(&self, stmt_hash: u64) -> Option<&str> {
+    self.get_by_idx(*self.stmts_indcs.get(&stmt_hash)?)
+  }
+
+  #[inline]
+  pub(crate) fn hasher_mut(&mut self) -> &mut FixedState {
+    &mut self.rs
+  }
+
+  /// Stores `cmd`, evicting old statements if the maximum number of statements was reached.
+  #[inline]
+  pub(crate) fn insert(&mut self, stmt_hash: u64, cmd: &str) -> crate::Result<usize> {
+    if self.stmts.blocks_len() >= self.max_stmts {
+      let to_remove = (self.max_stmts / 2).max(1);
+      for _ in 0..to_remove {
+        let _ = self.stmts.pop_front();
+      }
+      self.stmts_indcs.retain(|_, value| {
+        if *value < to_remove {
+          return false;
+        }
+        *value = value.wrapping_sub(to_remove);
+        true
+      })
+    }
+    let idx = self.stmts.blocks_len();
+    self.stmts.push_back_from_copyable_data([cmd.as_bytes()], ())?;
+    let _ = self.stmts_indcs.insert(stmt_hash, idx);
+    Ok(idx)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::mssql::Statements,
+    misc::{simple_seed, Xorshift64},
+  };
+
+  // FIXME(MIRI): See the `two_statements` test of PostgreSQL.
+  #[cfg_attr(miri, ignore)]
+  #[test]
+  fn evicts_old_statements() {
+    let mut stmts = Statements::new(2, &mut Xorshift64::from(simple_seed()));
+    assert_eq!(stmts.insert(123, "SELECT 1").unwrap(), 0);
+    assert_eq!(stmts.insert(456, "SELECT 2").unwrap(), 1);
+    assert_eq!(stmts.get_by_stmt_hash(123), Some("SELECT 1"));
+    assert_eq!(stmts.insert(789, "SELECT 3").unwrap(), 1);
+    assert_eq!(stmts.get_by_stmt_hash(123), None);
+    assert_eq!(stmts.get_by_stmt_hash(456), Some("SELECT 2"));
+    assert_eq!(stmts.get_by_stmt_hash(789), Some("SELECT 3"));
+    stmts.clear();
+    assert_eq!(stmts.get_by_stmt_hash(456), None);
+  }
+}
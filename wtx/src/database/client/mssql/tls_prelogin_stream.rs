@@ -0,0 +1,137 @@
+use crate::{
+  database::client::mssql::packet::{packet_header, HEADER_LEN, PRELOGIN},
+  misc::{Arc, Vector},
+};
+use core::{
+  pin::Pin,
+  sync::atomic::{AtomicBool, Ordering},
+  task::{ready, Context, Poll},
+};
+use std::io;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// Stream used while performing the TLS handshake of an encrypted connection.
+///
+/// SQL Server expects handshake records to be wrapped by `PRELOGIN` packets, as such, this
+/// structure should be given to a TLS connector. Written bytes are only sent when the stream is
+/// flushed. After the handshake, bytes are transferred without modifications.
+#[derive(Debug)]
+pub struct TlsPreloginStream<S> {
+  header: [u8; 8],
+  header_len: usize,
+  is_handshake_done: Arc<AtomicBool>,
+  payload_len: usize,
+  stream: S,
+  wb: Vector<u8>,
+  wb_idx: usize,
+}
+
+impl<S> TlsPreloginStream<S> {
+  #[inline]
+  pub(crate) fn new(is_handshake_done: Arc<AtomicBool>, stream: S) -> Self {
+    Self {
+      header: [0; 8],
+      header_len: 0,
+      is_handshake_done,
+      payload_len: 0,
+      stream,
+      wb: Vector::new(),
+      wb_idx: 0,
+    }
+  }
+
+  #[inline]
+  fn is_handshake_done(&self) -> bool {
+    self.is_handshake_done.load(Ordering::Relaxed)
+  }
+}
+
+impl<S> AsyncRead for TlsPreloginStream<S>
+where
+  S: AsyncRead + Unpin,
+{
+  #[inline]
+  fn poll_read(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &mut ReadBuf<'_>,
+  ) -> Poll<io::Result<()>> {
+    let this = self.get_mut();
+    if this.is_handshake_done() {
+      return Pin::new(&mut this.stream).poll_read(cx, buf);
+    }
+    while this.payload_len == 0 {
+      let mut header_buf = ReadBuf::new(this.header.get_mut(this.header_len..).unwrap_or_default());
+      ready!(Pin::new(&mut this.stream).poll_read(cx, &mut header_buf))?;
+      let read = header_buf.filled().len();
+      if read == 0 {
+        return Poll::Ready(Ok(()));
+      }
+      this.header_len = this.header_len.wrapping_add(read);
+      if this.header_len == HEADER_LEN {
+        let [_, _, a, b, ..] = this.header;
+        this.header_len = 0;
+        this.payload_len = usize::from(u16::from_be_bytes([a, b])).saturating_sub(HEADER_LEN);
+      }
+    }
+    let len = buf.remaining().min(this.payload_len);
+    let mut payload_buf = buf.take(len);
+    ready!(Pin::new(&mut this.stream).poll_read(cx, &mut payload_buf))?;
+    let read = payload_buf.filled().len();
+    // SAFETY: `read` bytes were initialized by the inner stream
+    unsafe {
+      buf.assume_init(read);
+    }
+    buf.advance(read);
+    this.payload_len = this.payload_len.wrapping_sub(read);
+    Poll::Ready(Ok(()))
+  }
+}
+
+impl<S> AsyncWrite for TlsPreloginStream<S>
+where
+  S: AsyncWrite + Unpin,
+{
+  #[inline]
+  fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+    let this = self.get_mut();
+    if this.is_handshake_done() {
+      return Pin::new(&mut this.stream).poll_write(cx, buf);
+    }
+    if this.wb.is_empty() {
+      if let Err(err) = this.wb.extend_from_copyable_slice(&[0; HEADER_LEN]) {
+        return Poll::Ready(Err(io::Error::other(err)));
+      }
+    }
+    if let Err(err) = this.wb.extend_from_copyable_slice(buf) {
+      return Poll::Ready(Err(io::Error::other(err)));
+    }
+    Poll::Ready(Ok(buf.len()))
+  }
+
+  #[inline]
+  fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    let this = self.get_mut();
+    if this.wb_idx == 0 {
+      let payload_len = this.wb.len().saturating_sub(HEADER_LEN);
+      if let Some(elem) = this.wb.get_mut(..HEADER_LEN) {
+        elem.copy_from_slice(&packet_header(payload_len, 1, 1, PRELOGIN));
+      }
+    }
+    while let Some(bytes @ [_, ..]) = this.wb.get(this.wb_idx..) {
+      let written = ready!(Pin::new(&mut this.stream).poll_write(cx, bytes))?;
+      if written == 0 {
+        return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+      }
+      this.wb_idx = this.wb_idx.wrapping_add(written);
+    }
+    this.wb.clear();
+    this.wb_idx = 0;
+    Pin::new(&mut this.stream).poll_flush(cx)
+  }
+
+  #[inline]
+  fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    Pin::new(&mut self.get_mut().stream).poll_shutdown(cx)
+  }
+}
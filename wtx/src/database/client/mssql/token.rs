@@ -0,0 +1,571 @@
+//! Parsing of the token streams that compose the responses sent by the server.
+
+use crate::{
+  database::{
+    client::mssql::{column::Column, DbError, MssqlError, Ty},
+    Identifier,
+  },
+  misc::{FromRadix10, Usize, Vector},
+};
+use alloc::{boxed::Box, string::String};
+use core::ops::Range;
+
+pub(crate) const COLINFO: u8 = 0xA5;
+pub(crate) const COLMETADATA: u8 = 0x81;
+pub(crate) const DONE: u8 = 0xFD;
+pub(crate) const DONEINPROC: u8 = 0xFF;
+pub(crate) const DONEPROC: u8 = 0xFE;
+pub(crate) const ENVCHANGE: u8 = 0xE3;
+pub(crate) const ERROR: u8 = 0xAA;
+pub(crate) const FEATUREEXTACK: u8 = 0xAE;
+pub(crate) const INFO: u8 = 0xAB;
+pub(crate) const LOGINACK: u8 = 0xAD;
+pub(crate) const NBCROW: u8 = 0xD2;
+pub(crate) const ORDER: u8 = 0xA9;
+pub(crate) const RETURNSTATUS: u8 = 0x79;
+pub(crate) const RETURNVALUE: u8 = 0xAC;
+pub(crate) const ROW: u8 = 0xD1;
+pub(crate) const SESSIONSTATE: u8 = 0xE4;
+pub(crate) const SSPI: u8 = 0xED;
+pub(crate) const TABNAME: u8 = 0xA4;
+
+const DONE_COUNT: u16 = 0x10;
+const ENV_BEGIN_TRANSACTION: u8 = 8;
+const ENV_COMMIT_TRANSACTION: u8 = 9;
+const ENV_PACKET_SIZE: u8 = 4;
+const ENV_ROLLBACK_TRANSACTION: u8 = 10;
+const ENV_TRANSACTION_ENDED: u8 = 17;
+const NO_METADATA: u16 = 0xFFFF;
+const PLP_NULL: u64 = u64::MAX;
+const VAR_MAX: u32 = 0xFFFF;
+
+/// Session parameters that can be modified by `ENVCHANGE` tokens.
+#[derive(Debug)]
+pub(crate) struct Env<'any> {
+  pub(crate) packet_size: &'any mut usize,
+  pub(crate) transaction_descriptor: &'any mut u64,
+}
+
+/// Reads all the tokens of a response.
+///
+/// The columns and the rows of the first result set are stored in `columns`, `rb` and `vb`. The
+/// ranges of `vb` are relative to `bytes`, which is mutable because the chunks of partially
+/// length-prefixed values are merged in place. `done_cb` is called with the number of affected
+/// records of each `DONE` or `DONEINPROC` token.
+///
+/// If the server sent one or more `ERROR` tokens, then the first one is returned after the whole
+/// stream is processed.
+#[inline]
+pub(crate) fn process_tokens(
+  bytes: &mut [u8],
+  columns: &mut Vector<Column>,
+  mut env: Env<'_>,
+  rb: &mut Vector<usize>,
+  vb: &mut Vector<(bool, Range<usize>)>,
+  mut done_cb: impl FnMut(u64),
+) -> crate::Result<()> {
+  let mut db_error = None;
+  let mut first_columns_len = 0;
+  let mut is_first_set_done = false;
+  let mut result_sets: u32 = 0;
+  let mut idx = 0;
+  while let Some(&token) = bytes.get(idx) {
+    idx = idx.wrapping_add(1);
+    match token {
+      COLMETADATA => {
+        let [a, b] = array(bytes, idx)?;
+        idx = idx.wrapping_add(2);
+        let len = u16::from_le_bytes([a, b]);
+        if len == NO_METADATA {
+          continue;
+        }
+        result_sets = result_sets.wrapping_add(1);
+        columns.truncate(first_columns_len);
+        for _ in 0..len {
+          let (column, consumed) = column_metadata(bytes.get(idx..).unwrap_or_default())?;
+          columns.push(column)?;
+          idx = idx.wrapping_add(consumed);
+        }
+        if result_sets == 1 {
+          first_columns_len = columns.len();
+        }
+      }
+      DONE | DONEINPROC | DONEPROC => {
+        let [a, b, _, _, c, d, e, f, g, h, i, j] = array(bytes, idx)?;
+        idx = idx.wrapping_add(12);
+        if token != DONEPROC {
+          let status = u16::from_le_bytes([a, b]);
+          let rows = if status & DONE_COUNT == DONE_COUNT {
+            u64::from_le_bytes([c, d, e, f, g, h, i, j])
+          } else {
+            0
+          };
+          done_cb(rows);
+        }
+        if result_sets > 0 {
+          is_first_set_done = true;
+        }
+      }
+      ENVCHANGE => {
+        let (body, next) = u16_body(bytes, idx)?;
+        env_change(body, &mut env)?;
+        idx = next;
+      }
+      ERROR => {
+        let (body, next) = u16_body(bytes, idx)?;
+        if db_error.is_none() {
+          db_error = Some(DbError::try_from(body)?);
+        }
+        idx = next;
+      }
+      FEATUREEXTACK => loop {
+        let [feature] = array(bytes, idx)?;
+        idx = idx.wrapping_add(1);
+        if feature == 0xFF {
+          break;
+        }
+        let (_, next) = u32_body(bytes, idx)?;
+        idx = next;
+      },
+      COLINFO | INFO | LOGINACK | ORDER | SSPI | TABNAME => {
+        idx = u16_body(bytes, idx)?.1;
+      }
+      RETURNSTATUS => {
+        let _ = array::<4>(bytes, idx)?;
+        idx = idx.wrapping_add(4);
+      }
+      RETURNVALUE => {
+        let [_, _, name_len] = array(bytes, idx)?;
+        idx = idx.wrapping_add(3).wrapping_add(usize::from(name_len).wrapping_mul(2));
+        let _status = array::<1>(bytes, idx)?;
+        idx = idx.wrapping_add(1);
+        let (column, consumed) =
+          column_metadata_without_name(bytes.get(idx..).unwrap_or_default())?;
+        idx = value(bytes, &column, idx.wrapping_add(consumed))?.1;
+      }
+      ROW | NBCROW => {
+        let vb_len = vb.len();
+        let row_columns = if result_sets == 1 {
+          columns.get(..first_columns_len)
+        } else {
+          columns.get(first_columns_len..)
+        };
+        idx = row(bytes, row_columns.unwrap_or_default(), idx, token == NBCROW, vb)?;
+        if result_sets == 1 && !is_first_set_done {
+          rb.push(vb.len())?;
+        } else {
+          vb.truncate(vb_len);
+        }
+      }
+      SESSIONSTATE => {
+        idx = u32_body(bytes, idx)?.1;
+      }
+      _ => return Err(MssqlError::UnexpectedToken { received: token }.into()),
+    }
+  }
+  columns.truncate(first_columns_len);
+  if let Some(elem) = db_error {
+    return Err(crate::Error::MssqlDbError(Box::new(elem)));
+  }
+  Ok(())
+}
+
+/// Byte-length prefixed UTF-16 string. The length is the number of characters.
+#[inline]
+pub(crate) fn b_varchar(bytes: &[u8]) -> crate::Result<(String, &[u8])> {
+  let [len, rest @ ..] = bytes else {
+    return Err(MssqlError::InvalidTokenStream.into());
+  };
+  utf16_prefixed(usize::from(*len), rest)
+}
+
+/// Unsigned short length prefixed UTF-16 string. The length is the number of characters.
+#[inline]
+pub(crate) fn us_varchar(bytes: &[u8]) -> crate::Result<(String, &[u8])> {
+  let [a, b, rest @ ..] = bytes else {
+    return Err(MssqlError::InvalidTokenStream.into());
+  };
+  utf16_prefixed(usize::from(u16::from_le_bytes([*a, *b])), rest)
+}
+
+/// Decodes little-endian UTF-16 bytes.
+#[inline]
+pub(crate) fn utf16_chars(bytes: &[u8]) -> impl Iterator<Item = crate::Result<char>> + '_ {
+  let units = bytes.chunks_exact(2).map(|el| u16::from_le_bytes(el.try_into().unwrap_or_default()));
+  char::decode_utf16(units).map(|el| el.map_err(|_err| MssqlError::DecodingError.into()))
+}
+
+#[inline]
+fn array<const N: usize>(bytes: &[u8], idx: usize) -> crate::Result<[u8; N]> {
+  bytes
+    .get(idx..idx.wrapping_add(N))
+    .and_then(|el| el.try_into().ok())
+    .ok_or_else(|| MssqlError::InvalidTokenStream.into())
+}
+
+// | User type | Flags | Type info | Table name | Column name |
+// |     4     |   2   |     x     |     x      |      x      |
+#[inline]
+fn column_metadata(bytes: &[u8]) -> crate::Result<(Column, usize)> {
+  let (mut column, mut idx) = column_metadata_without_name(bytes)?;
+  if matches!(column.ty, Ty::Image | Ty::NText | Ty::Text) {
+    let [parts] = array(bytes, idx)?;
+    idx = idx.wrapping_add(1);
+    for _ in 0..parts {
+      let [a, b] = array(bytes, idx)?;
+      let len = usize::from(u16::from_le_bytes([a, b])).wrapping_mul(2);
+      idx = idx.wrapping_add(2).wrapping_add(len);
+    }
+  }
+  let [len] = array(bytes, idx)?;
+  idx = idx.wrapping_add(1);
+  let name_len = usize::from(len).wrapping_mul(2);
+  let name_bytes =
+    bytes.get(idx..idx.wrapping_add(name_len)).ok_or(MssqlError::InvalidTokenStream)?;
+  let mut name = Identifier::new();
+  for rslt in utf16_chars(name_bytes) {
+    name.push(rslt?)?;
+  }
+  column.name = name;
+  Ok((column, idx.wrapping_add(name_len)))
+}
+
+#[inline]
+fn column_metadata_without_name(bytes: &[u8]) -> crate::Result<(Column, usize)> {
+  let (len, scale, ty, consumed) = type_info(bytes.get(6..).unwrap_or_default())?;
+  Ok((Column::new(len, Identifier::new(), scale, ty), consumed.wrapping_add(6)))
+}
+
+#[inline]
+fn env_change(body: &[u8], env: &mut Env<'_>) -> crate::Result<()> {
+  let Env { packet_size, transaction_descriptor } = env;
+  match body {
+    [ENV_BEGIN_TRANSACTION, 8, a, b, c, d, e, f, g, h, ..] => {
+      **transaction_descriptor = u64::from_le_bytes([*a, *b, *c, *d, *e, *f, *g, *h]);
+    }
+    [ENV_COMMIT_TRANSACTION | ENV_ROLLBACK_TRANSACTION | ENV_TRANSACTION_ENDED, ..] => {
+      **transaction_descriptor = 0;
+    }
+    [ENV_PACKET_SIZE, rest @ ..] => {
+      let (value, _) = b_varchar(rest)?;
+      **packet_size = usize::from_radix_10(value.as_bytes())?;
+    }
+    _ => {}
+  }
+  Ok(())
+}
+
+// | Column 0 | ... | Column N |
+// |    x     | ... |    x     |
+//
+// `NBCROW` tokens are prefixed by a bitmap that indicates which columns are `NULL`.
+#[inline]
+fn row(
+  bytes: &mut [u8],
+  columns: &[Column],
+  mut idx: usize,
+  is_nbc: bool,
+  vb: &mut Vector<(bool, Range<usize>)>,
+) -> crate::Result<usize> {
+  let bitmap_start = idx;
+  if is_nbc {
+    idx = idx.wrapping_add(columns.len().div_ceil(8));
+  }
+  for (column_idx, column) in columns.iter().enumerate() {
+    if is_nbc {
+      let [byte] = array(bytes, bitmap_start.wrapping_add(column_idx / 8))?;
+      if byte & (1 << (column_idx % 8)) != 0 {
+        vb.push((true, idx..idx))?;
+        continue;
+      }
+    }
+    let (range, next) = value(bytes, column, idx)?;
+    match range {
+      None => vb.push((true, idx..idx))?,
+      Some(elem) => vb.push((false, elem))?,
+    }
+    idx = next;
+  }
+  Ok(idx)
+}
+
+// Returns the length, the scale, the type and the number of consumed bytes.
+#[inline]
+fn type_info(bytes: &[u8]) -> crate::Result<(u32, u8, Ty, usize)> {
+  let [first, ..] = bytes else {
+    return Err(MssqlError::InvalidTokenStream.into());
+  };
+  let ty = Ty::try_from(*first)?;
+  if ty.fixed_len().is_some() {
+    return Ok((0, 0, ty, 1));
+  }
+  Ok(match ty {
+    Ty::Daten => (0, 0, ty, 1),
+    Ty::Binary
+    | Ty::Bitn
+    | Ty::Char
+    | Ty::DateTimn
+    | Ty::Fltn
+    | Ty::Guid
+    | Ty::Intn
+    | Ty::Moneyn
+    | Ty::VarBinary
+    | Ty::VarChar => {
+      let [_, len] = array(bytes, 0)?;
+      (len.into(), 0, ty, 2)
+    }
+    Ty::DateTime2n | Ty::DateTimeOffsetn | Ty::Timen => {
+      let [_, scale] = array(bytes, 0)?;
+      (0, scale, ty, 2)
+    }
+    Ty::Decimal | Ty::Decimaln | Ty::Numeric | Ty::Numericn => {
+      let [_, len, _precision, scale] = array(bytes, 0)?;
+      (len.into(), scale, ty, 4)
+    }
+    Ty::BigBinary | Ty::BigVarBin => {
+      let [_, a, b] = array(bytes, 0)?;
+      (u16::from_le_bytes([a, b]).into(), 0, ty, 3)
+    }
+    Ty::BigChar | Ty::BigVarChr | Ty::NChar | Ty::NVarChar => {
+      let [_, a, b, _, _, _, _, _] = array(bytes, 0)?;
+      (u16::from_le_bytes([a, b]).into(), 0, ty, 8)
+    }
+    Ty::NText | Ty::Text => {
+      let [_, a, b, c, d, _, _, _, _, _] = array(bytes, 0)?;
+      (u32::from_le_bytes([a, b, c, d]), 0, ty, 10)
+    }
+    Ty::Image | Ty::SsVariant => {
+      let [_, a, b, c, d] = array(bytes, 0)?;
+      (u32::from_le_bytes([a, b, c, d]), 0, ty, 5)
+    }
+    Ty::Udt => {
+      let mut rest = bytes.get(3..).unwrap_or_default();
+      for _ in 0..3 {
+        rest = b_varchar(rest)?.1;
+      }
+      rest = us_varchar(rest)?.1;
+      (VAR_MAX, 0, ty, bytes.len().wrapping_sub(rest.len()))
+    }
+    Ty::Xml => {
+      let [_, has_schema] = array(bytes, 0)?;
+      let mut rest = bytes.get(2..).unwrap_or_default();
+      if has_schema == 1 {
+        rest = b_varchar(rest)?.1;
+        rest = b_varchar(rest)?.1;
+        rest = us_varchar(rest)?.1;
+      }
+      (VAR_MAX, 0, ty, bytes.len().wrapping_sub(rest.len()))
+    }
+    _ => return Err(MssqlError::UnexpectedValueFromBytes { expected: ty }.into()),
+  })
+}
+
+#[inline]
+fn u16_body(bytes: &[u8], idx: usize) -> crate::Result<(&[u8], usize)> {
+  let [a, b] = array(bytes, idx)?;
+  let begin = idx.wrapping_add(2);
+  let end = begin.wrapping_add(u16::from_le_bytes([a, b]).into());
+  Ok((bytes.get(begin..end).ok_or(MssqlError::InvalidTokenStream)?, end))
+}
+
+#[inline]
+fn u32_body(bytes: &[u8], idx: usize) -> crate::Result<(&[u8], usize)> {
+  let [a, b, c, d] = array(bytes, idx)?;
+  let begin = idx.wrapping_add(4);
+  let end = begin.wrapping_add(Usize::from(u32::from_le_bytes([a, b, c, d])).into_usize());
+  Ok((bytes.get(begin..end).ok_or(MssqlError::InvalidTokenStream)?, end))
+}
+
+#[inline]
+fn utf16_prefixed(chars: usize, bytes: &[u8]) -> crate::Result<(String, &[u8])> {
+  let Some((lhs, rhs)) = bytes.split_at_checked(chars.wrapping_mul(2)) else {
+    return Err(MssqlError::InvalidTokenStream.into());
+  };
+  let mut string = String::with_capacity(chars);
+  for rslt in utf16_chars(lhs) {
+    string.push(rslt?);
+  }
+  Ok((string, rhs))
+}
+
+// Returns the range of the value, or `None` if `NULL`, and the index of the next element.
+#[inline]
+fn value(
+  bytes: &mut [u8],
+  column: &Column,
+  idx: usize,
+) -> crate::Result<(Option<Range<usize>>, usize)> {
+  if let Some(len) = column.ty.fixed_len() {
+    let end = idx.wrapping_add(len);
+    if end > bytes.len() {
+      return Err(MssqlError::InvalidTokenStream.into());
+    }
+    return Ok((Some(idx..end), end));
+  }
+  let (range, next) = match column.ty {
+    Ty::BigBinary | Ty::BigChar | Ty::BigVarBin | Ty::BigVarChr | Ty::NChar | Ty::NVarChar
+      if column.len != VAR_MAX =>
+    {
+      let [a, b] = array(bytes, idx)?;
+      let len = u16::from_le_bytes([a, b]);
+      let begin = idx.wrapping_add(2);
+      if len == 0xFFFF {
+        (None, begin)
+      } else {
+        let end = begin.wrapping_add(len.into());
+        (Some(begin..end), end)
+      }
+    }
+    Ty::BigBinary
+    | Ty::BigChar
+    | Ty::BigVarBin
+    | Ty::BigVarChr
+    | Ty::NChar
+    | Ty::NVarChar
+    | Ty::Udt
+    | Ty::Xml => return plp_value(bytes, idx),
+    Ty::Image | Ty::NText | Ty::Text => {
+      let [text_ptr_len] = array(bytes, idx)?;
+      if text_ptr_len == 0 {
+        (None, idx.wrapping_add(1))
+      } else {
+        let len_idx = idx.wrapping_add(1).wrapping_add(text_ptr_len.into()).wrapping_add(8);
+        let (_, end) = u32_body(bytes, len_idx)?;
+        (Some(len_idx.wrapping_add(4)..end), end)
+      }
+    }
+    Ty::SsVariant => {
+      let (body, end) = u32_body(bytes, idx)?;
+      if body.is_empty() {
+        (None, end)
+      } else {
+        (Some(idx.wrapping_add(4)..end), end)
+      }
+    }
+    _ => {
+      let [len] = array(bytes, idx)?;
+      let begin = idx.wrapping_add(1);
+      if len == 0 {
+        (None, begin)
+      } else {
+        let end = begin.wrapping_add(len.into());
+        (Some(begin..end), end)
+      }
+    }
+  };
+  if next > bytes.len() {
+    return Err(MssqlError::InvalidTokenStream.into());
+  }
+  Ok((range, next))
+}
+
+// | Total length | Chunk length | Chunk | ... | Terminator |
+// |      8       |      4       |   x   | ... |     4      |
+//
+// Chunks are moved to the beginning of the first chunk to form a contiguous value.
+#[inline]
+fn plp_value(bytes: &mut [u8], idx: usize) -> crate::Result<(Option<Range<usize>>, usize)> {
+  let total_len = u64::from_le_bytes(array(bytes, idx)?);
+  let mut local_idx = idx.wrapping_add(8);
+  if total_len == PLP_NULL {
+    return Ok((None, local_idx));
+  }
+  let begin = local_idx;
+  let mut end = local_idx;
+  loop {
+    let [a, b, c, d] = array(bytes, local_idx)?;
+    local_idx = local_idx.wrapping_add(4);
+    let chunk_len = Usize::from(u32::from_le_bytes([a, b, c, d])).into_usize();
+    if chunk_len == 0 {
+      break;
+    }
+    let chunk_end = local_idx.wrapping_add(chunk_len);
+    if chunk_end > bytes.len() {
+      return Err(MssqlError::InvalidTokenStream.into());
+    }
+    bytes.copy_within(local_idx..chunk_end, end);
+    end = end.wrapping_add(chunk_len);
+    local_idx = chunk_end;
+  }
+  Ok((Some(begin..end), local_idx))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::mssql::{
+      token::{process_tokens, Env},
+      Ty,
+    },
+    misc::Vector,
+  };
+
+  #[test]
+  fn parses_result_set() {
+    let mut bytes = alloc::vec![
+      // COLMETADATA with an INT and a NVARCHAR(MAX) column
+      0x81, 2, 0, //
+      0, 0, 0, 0, 0, 0, 0x26, 4, 1, b'a', 0, //
+      0, 0, 0, 0, 0, 0, 0xE7, 0xFF, 0xFF, 9, 4, 208, 0, 52, 1, b'b', 0, //
+      // ROW
+      0xD1, 4, 1, 0, 0, 0, //
+      4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, b'c', 0, 2, 0, 0, 0, b'd', 0, 0, 0, 0, 0, //
+      // NBCROW with NULL as the second value
+      0xD2, 2, 4, 2, 0, 0, 0, //
+      // DONE with a count of 2
+      0xFD, 16, 0, 193, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut columns = Vector::new();
+    let mut counts = Vector::new();
+    let mut packet_size = 4096;
+    let mut rb = Vector::new();
+    let mut td = 0;
+    let mut vb = Vector::new();
+    let env = Env { packet_size: &mut packet_size, transaction_descriptor: &mut td };
+    process_tokens(&mut bytes, &mut columns, env, &mut rb, &mut vb, |el| {
+      counts.push(el).unwrap();
+    })
+    .unwrap();
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[0].name.as_str(), "a");
+    assert_eq!(columns[0].ty, Ty::Intn);
+    assert_eq!(columns[1].name.as_str(), "b");
+    assert_eq!(columns[1].ty, Ty::NVarChar);
+    assert_eq!(counts.as_slice(), &[2]);
+    assert_eq!(rb.as_slice(), &[2, 4]);
+    let (false, first) = vb[0].clone() else { panic!() };
+    assert_eq!(bytes[first], [1, 0, 0, 0]);
+    let (false, second) = vb[1].clone() else { panic!() };
+    assert_eq!(bytes[second], [b'c', 0, b'd', 0]);
+    let (false, third) = vb[2].clone() else { panic!() };
+    assert_eq!(bytes[third], [2, 0, 0, 0]);
+    assert!(vb[3].0);
+  }
+
+  #[test]
+  fn returns_the_first_error() {
+    let mut bytes = alloc::vec![
+      // ENVCHANGE of the packet size
+      0xE3, 19, 0, 4, 4, b'8', 0, b'1', 0, b'9', 0, b'2', 0, 4, b'4', 0, b'0', 0, b'9', 0, b'6', 0,
+      // ERROR
+      0xAA, 18, 0, 208, 0, 0, 0, 1, 16, 1, 0, b'a', 0, 1, b'b', 0, 0, 1, 0, 0, 0, //
+      // DONE with the error flag
+      0xFD, 2, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut packet_size = 4096;
+    let mut td = 0;
+    let env = Env { packet_size: &mut packet_size, transaction_descriptor: &mut td };
+    let rslt = process_tokens(
+      &mut bytes,
+      &mut Vector::new(),
+      env,
+      &mut Vector::new(),
+      &mut Vector::new(),
+      |_| {},
+    );
+    let Err(crate::Error::MssqlDbError(err)) = rslt else { panic!() };
+    assert_eq!(err.number(), 208);
+    assert_eq!(err.message(), "a");
+    assert_eq!(packet_size, 8192);
+  }
+}
@@ -0,0 +1,112 @@
+create_enum! {
+  /// Column type as defined by the `TYPE_INFO` rule of the TDS protocol.
+  #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+  pub enum Ty<u8> {
+    /// `NULL`
+    Null = (31),
+    /// `IMAGE`
+    Image = (34),
+    /// `TEXT`
+    Text = (35),
+    /// `UNIQUEIDENTIFIER`
+    Guid = (36),
+    /// Legacy `VARBINARY`
+    VarBinary = (37),
+    /// Nullable `TINYINT`, `SMALLINT`, `INT` or `BIGINT`
+    Intn = (38),
+    /// Legacy `VARCHAR`
+    VarChar = (39),
+    /// `DATE`
+    Daten = (40),
+    /// `TIME`
+    Timen = (41),
+    /// `DATETIME2`
+    DateTime2n = (42),
+    /// `DATETIMEOFFSET`
+    DateTimeOffsetn = (43),
+    /// Legacy `BINARY`
+    Binary = (45),
+    /// Legacy `CHAR`
+    Char = (47),
+    /// Non-nullable `TINYINT`
+    Int1 = (48),
+    /// Non-nullable `BIT`
+    Bit = (50),
+    /// Non-nullable `SMALLINT`
+    Int2 = (52),
+    /// Legacy `DECIMAL`
+    Decimal = (55),
+    /// Non-nullable `INT`
+    Int4 = (56),
+    /// Non-nullable `SMALLDATETIME`
+    DateTim4 = (58),
+    /// Non-nullable `REAL`
+    Flt4 = (59),
+    /// Non-nullable `MONEY`
+    Money = (60),
+    /// Non-nullable `DATETIME`
+    DateTime = (61),
+    /// Non-nullable `FLOAT`
+    Flt8 = (62),
+    /// Legacy `NUMERIC`
+    Numeric = (63),
+    /// `SQL_VARIANT`
+    SsVariant = (98),
+    /// `NTEXT`
+    NText = (99),
+    /// Nullable `BIT`
+    Bitn = (104),
+    /// `DECIMAL`
+    Decimaln = (106),
+    /// `NUMERIC`
+    Numericn = (108),
+    /// Nullable `REAL` or `FLOAT`
+    Fltn = (109),
+    /// Nullable `SMALLMONEY` or `MONEY`
+    Moneyn = (110),
+    /// Nullable `SMALLDATETIME` or `DATETIME`
+    DateTimn = (111),
+    /// Non-nullable `SMALLMONEY`
+    Money4 = (122),
+    /// Non-nullable `BIGINT`
+    Int8 = (127),
+    /// `VARBINARY`
+    BigVarBin = (165),
+    /// `VARCHAR`
+    BigVarChr = (167),
+    /// `BINARY`
+    BigBinary = (173),
+    /// `CHAR`
+    BigChar = (175),
+    /// `NVARCHAR`
+    NVarChar = (231),
+    /// `NCHAR`
+    NChar = (239),
+    /// CLR User-Defined Type
+    Udt = (240),
+    /// `XML`
+    Xml = (241),
+  }
+}
+
+impl Ty {
+  /// The number of bytes of types that have a fixed binary representation. Other types are
+  /// prefixed by their lengths.
+  #[inline]
+  pub(crate) const fn fixed_len(self) -> Option<usize> {
+    Some(match self {
+      Self::Null => 0,
+      Self::Bit | Self::Int1 => 1,
+      Self::Int2 => 2,
+      Self::DateTim4 | Self::Flt4 | Self::Int4 | Self::Money4 => 4,
+      Self::DateTime | Self::Flt8 | Self::Int8 | Self::Money => 8,
+      _ => return None,
+    })
+  }
+
+  /// If values are encoded using UCS-2/UTF-16.
+  #[inline]
+  pub(crate) const fn is_utf16(self) -> bool {
+    matches!(self, Self::NChar | Self::NText | Self::NVarChar | Self::Xml)
+  }
+}
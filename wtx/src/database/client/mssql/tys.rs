@@ -0,0 +1,684 @@
+macro_rules! test {
+  ($name:ident, $ty:ty, $instance:expr) => {
+    #[cfg(test)]
+    #[test]
+    fn $name() {
+      let vec = &mut crate::misc::filled_buffer::FilledBuffer::_new();
+      let mut fbw = crate::misc::FilledBufferWriter::new(0, vec);
+      let mut ev = EncodeValue::new(&mut fbw);
+      let instance: $ty = $instance;
+      Encode::<Mssql<crate::Error>>::encode(&instance, &mut ev).unwrap();
+      let (scale, ty) = ev.take_ty();
+      let decoded: $ty =
+        Decode::<Mssql<crate::Error>>::decode(&DecodeValue::new(ev.fbw()._curr_bytes(), scale, ty))
+          .unwrap();
+      assert_eq!(instance, decoded);
+    }
+  };
+}
+
+mod array {
+  use crate::{
+    database::{
+      client::mssql::{tys::decode_string, DecodeValue, EncodeValue, Mssql, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::ArrayString,
+  };
+
+  impl<E, const N: usize> Decode<'_, Mssql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let mut this = ArrayString::new();
+      decode_string(dv, |el| this.push(el))?;
+      Ok(this)
+    }
+  }
+  impl<E, const N: usize> Encode<Mssql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mssql<E>>::encode(self.as_str(), ev)
+    }
+  }
+  impl<E, const N: usize> Typed<Mssql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::NVarChar;
+  }
+
+  test!(array_string, ArrayString<4>, ArrayString::try_from("123").unwrap());
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+  use crate::{
+    database::{
+      client::mssql::{DecodeValue, EncodeValue, Mssql, MssqlError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::_unlikely_elem,
+  };
+  use chrono::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Timelike, Utc,
+  };
+
+  /// Encoded times have 100 nanoseconds of precision.
+  const SCALE: u8 = 7;
+
+  // | Time | Date | Offset |
+  // |  5   |  3   |   2    |
+  //
+  // Date and time are in UTC.
+  impl<E> Decode<'_, Mssql<E>> for DateTime<Utc>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let naive = if *dv.ty() == Ty::DateTimeOffsetn {
+        let bytes = dv.bytes().get(..dv.bytes().len().saturating_sub(2)).unwrap_or_default();
+        datetime2(bytes, dv.scale())?
+      } else {
+        <NaiveDateTime as Decode<Mssql<E>>>::decode(dv)?
+      };
+      Ok(Utc.from_utc_datetime(&naive))
+    }
+  }
+  impl<E, TZ> Encode<Mssql<E>> for DateTime<TZ>
+  where
+    E: From<crate::Error>,
+    TZ: TimeZone,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mssql<E>>::encode(&self.naive_utc(), ev)?;
+      ev.fbw().extend_from_slice(&[0, 0])?;
+      ev.set_ty(SCALE, Ty::DateTimeOffsetn);
+      Ok(())
+    }
+  }
+  impl<E, TZ> Typed<Mssql<E>> for DateTime<TZ>
+  where
+    E: From<crate::Error>,
+    TZ: TimeZone,
+  {
+    const TY: Ty = Ty::DateTimeOffsetn;
+  }
+
+  test!(datetime_utc, DateTime<Utc>, Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap());
+
+  // Days since 0001-01-01
+  //
+  // | Days |
+  // |  3   |
+  impl<E> Decode<'_, Mssql<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(date(dv.bytes())?)
+    }
+  }
+  impl<E> Encode<Mssql<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(0, Ty::Daten);
+      ev.fbw().extend_from_slice(&date_bytes(self)?)?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Daten;
+  }
+
+  test!(naive_date, NaiveDate, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+
+  // `DATETIME2`
+  //
+  // | Time | Date |
+  // |  x   |  3   |
+  //
+  // `DATETIME`
+  //
+  // | Days since 1900-01-01 | 1/300 seconds since midnight |
+  // |           4           |              4               |
+  //
+  // `SMALLDATETIME`
+  //
+  // | Days since 1900-01-01 | Minutes since midnight |
+  // |           2           |           2            |
+  impl<E> Decode<'_, Mssql<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let epoch = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap_or_default().and_time(NaiveTime::MIN);
+      let (days, delta) = match (dv.ty(), dv.bytes()) {
+        (Ty::DateTime | Ty::DateTimn, &[a, b, c, d, e, f, g, h]) => {
+          let ticks = u32::from_le_bytes([e, f, g, h]);
+          let nanos = u64::from(ticks).wrapping_mul(10_000_000) / 3;
+          (i32::from_le_bytes([a, b, c, d]), TimeDelta::nanoseconds(nanos.try_into().unwrap_or(0)))
+        }
+        (Ty::DateTim4 | Ty::DateTimn, &[a, b, c, d]) => {
+          let minutes = u16::from_le_bytes([c, d]);
+          (u16::from_le_bytes([a, b]).into(), TimeDelta::minutes(minutes.into()))
+        }
+        _ => return Ok(datetime2(dv.bytes(), dv.scale())?),
+      };
+      epoch
+        .checked_add_signed(TimeDelta::days(days.into()))
+        .and_then(|el| el.checked_add_signed(delta))
+        .ok_or_else(|| invalid(Ty::DateTime))
+    }
+  }
+  impl<E> Encode<Mssql<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(SCALE, Ty::DateTime2n);
+      ev.fbw().extend_from_slice(&time_bytes(&self.time()))?;
+      ev.fbw().extend_from_slice(&date_bytes(&self.date())?)?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::DateTime2n;
+  }
+
+  test!(
+    naive_date_time,
+    NaiveDateTime,
+    NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_micro_opt(3, 4, 5, 6).unwrap()
+  );
+
+  // Units of 10^-scale seconds since midnight. The number of bytes depends on the scale.
+  impl<E> Decode<'_, Mssql<E>> for NaiveTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(time(dv.bytes(), dv.scale())?)
+    }
+  }
+  impl<E> Encode<Mssql<E>> for NaiveTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(SCALE, Ty::Timen);
+      ev.fbw().extend_from_slice(&time_bytes(self))?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for NaiveTime
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Timen;
+  }
+
+  test!(naive_time, NaiveTime, NaiveTime::from_hms_nano_opt(3, 4, 5, 600).unwrap());
+
+  #[inline]
+  fn date(bytes: &[u8]) -> crate::Result<NaiveDate> {
+    let &[a, b, c] = bytes else {
+      return Err(MssqlError::UnexpectedValueFromBytes { expected: Ty::Daten }.into());
+    };
+    let days = i32::from_le_bytes([a, b, c, 0]);
+    NaiveDate::from_num_days_from_ce_opt(days.wrapping_add(1))
+      .ok_or_else(|| MssqlError::UnexpectedValueFromBytes { expected: Ty::Daten }.into())
+  }
+
+  #[inline]
+  fn date_bytes(date: &NaiveDate) -> crate::Result<[u8; 3]> {
+    let Ok(days) = u32::try_from(date.num_days_from_ce().wrapping_sub(1)) else {
+      return _unlikely_elem(Err(
+        MssqlError::UnexpectedValueFromBytes { expected: Ty::Daten }.into(),
+      ));
+    };
+    let [a, b, c, _] = days.to_le_bytes();
+    Ok([a, b, c])
+  }
+
+  #[inline]
+  fn datetime2(bytes: &[u8], scale: u8) -> crate::Result<NaiveDateTime> {
+    let Some((time_bytes, date_bytes)) = bytes.split_at_checked(bytes.len().saturating_sub(3))
+    else {
+      return Err(MssqlError::UnexpectedValueFromBytes { expected: Ty::DateTime2n }.into());
+    };
+    Ok(NaiveDateTime::new(date(date_bytes)?, time(time_bytes, scale)?))
+  }
+
+  #[inline]
+  fn invalid<E>(expected: Ty) -> E
+  where
+    E: From<crate::Error>,
+  {
+    E::from(MssqlError::UnexpectedValueFromBytes { expected }.into())
+  }
+
+  #[inline]
+  fn time(bytes: &[u8], scale: u8) -> crate::Result<NaiveTime> {
+    let mut buffer = [0; 8];
+    if bytes.len() > 5 || scale > SCALE {
+      return Err(MssqlError::UnexpectedValueFromBytes { expected: Ty::Timen }.into());
+    }
+    buffer.get_mut(..bytes.len()).unwrap_or_default().copy_from_slice(bytes);
+    let nanos = u64::from_le_bytes(buffer).wrapping_mul(10u64.pow(9u32.wrapping_sub(scale.into())));
+    let (Ok(secs), Ok(nanos)) =
+      (u32::try_from(nanos / 1_000_000_000), u32::try_from(nanos % 1_000_000_000))
+    else {
+      return Err(MssqlError::UnexpectedValueFromBytes { expected: Ty::Timen }.into());
+    };
+    NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+      .ok_or_else(|| MssqlError::UnexpectedValueFromBytes { expected: Ty::Timen }.into())
+  }
+
+  #[inline]
+  fn time_bytes(time: &NaiveTime) -> [u8; 5] {
+    let secs = u64::from(time.num_seconds_from_midnight());
+    let units = secs.wrapping_mul(10_000_000).wrapping_add(u64::from(time.nanosecond() / 100));
+    let [a, b, c, d, e, ..] = units.to_le_bytes();
+    [a, b, c, d, e]
+  }
+}
+
+mod collections {
+  use crate::{
+    database::{
+      client::mssql::{tys::decode_string, DecodeValue, EncodeValue, Mssql, MssqlError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::from_utf8_basic,
+  };
+  use alloc::{string::String, vec::Vec};
+
+  // &[u8]
+
+  impl<'exec, E> Decode<'exec, Mssql<E>> for &'exec [u8]
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'exec>) -> Result<Self, E> {
+      Ok(dv.bytes())
+    }
+  }
+  impl<E> Encode<Mssql<E>> for &[u8]
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(0, Ty::BigVarBin);
+      ev.fbw().extend_from_slice(self)?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for &[u8]
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::BigVarBin;
+  }
+  test!(bytes, &[u8], &[1, 2, 3, 4]);
+
+  // Vec<u8>
+
+  impl<E> Decode<'_, Mssql<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(dv.bytes().into())
+    }
+  }
+  impl<E> Encode<Mssql<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mssql<E>>::encode(&self.as_slice(), ev)
+    }
+  }
+  impl<E> Typed<Mssql<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::BigVarBin;
+  }
+  test!(vec_bytes, Vec<u8>, alloc::vec![1, 2, 3, 4]);
+
+  // str
+
+  impl<E> Encode<Mssql<E>> for str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(0, Ty::NVarChar);
+      for unit in self.encode_utf16() {
+        ev.fbw().extend_from_slice(&unit.to_le_bytes())?;
+      }
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for str
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::NVarChar;
+  }
+
+  // &str
+
+  /// Only `CHAR`, `TEXT` and `VARCHAR` values can be borrowed. Other textual types are
+  /// transferred as UTF-16 and should be decoded into owned strings.
+  impl<'exec, E> Decode<'exec, Mssql<E>> for &'exec str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'exec>) -> Result<Self, E> {
+      if dv.ty().is_utf16() {
+        return Err(E::from(MssqlError::NonUtf8String.into()));
+      }
+      Ok(from_utf8_basic(dv.bytes()).map_err(crate::Error::from)?)
+    }
+  }
+  impl<E> Encode<Mssql<E>> for &str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mssql<E>>::encode(*self, ev)
+    }
+  }
+  impl<E> Typed<Mssql<E>> for &str
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::NVarChar;
+  }
+
+  // String
+
+  impl<E> Decode<'_, Mssql<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let mut this = String::with_capacity(dv.bytes().len());
+      decode_string(dv, |el| {
+        this.push(el);
+        Ok(())
+      })?;
+      Ok(this)
+    }
+  }
+  impl<E> Encode<Mssql<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mssql<E>>::encode(self.as_str(), ev)
+    }
+  }
+  impl<E> Typed<Mssql<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::NVarChar;
+  }
+  test!(string, String, String::from("1234"));
+}
+
+mod primitives {
+  use crate::{
+    database::{
+      client::mssql::{DecodeValue, EncodeValue, Mssql, MssqlError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::Usize,
+  };
+
+  // bool
+
+  impl<E> Decode<'_, Mssql<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let &[byte] = dv.bytes() else {
+        return Err(E::from(
+          MssqlError::UnexpectedBufferSize {
+            expected: 1,
+            received: Usize::from(dv.bytes().len()).into_u64().try_into().unwrap_or(u32::MAX),
+          }
+          .into(),
+        ));
+      };
+      Ok(byte != 0)
+    }
+  }
+  impl<E> Encode<Mssql<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(0, Ty::Bitn);
+      ev.fbw()._extend_from_byte((*self).into())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Bitn;
+  }
+
+  test!(bool_true, bool, true);
+
+  macro_rules! impl_primitive_from_array {
+    ($instance:expr, [$($elem:ident),+], $ty:ident, $mssql_ty:expr) => {
+      impl<E> Decode<'_, Mssql<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        #[inline]
+        fn decode(input: &DecodeValue<'_>) -> Result<Self, E> {
+          if let &[$($elem,)+] = input.bytes() {
+            return Ok(<$ty>::from_le_bytes([$($elem),+]));
+          }
+          Err(E::from(MssqlError::UnexpectedBufferSize {
+            expected: Usize::from(size_of::<$ty>()).into_u64().try_into().unwrap_or(u32::MAX),
+            received: Usize::from(input.bytes().len()).into_u64().try_into().unwrap_or(u32::MAX)
+          }.into()))
+        }
+      }
+
+      impl<E> Encode<Mssql<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        #[inline]
+        fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+          ev.set_ty(0, $mssql_ty);
+          ev.fbw().extend_from_slice(&self.to_le_bytes())?;
+          Ok(())
+        }
+      }
+
+      impl<E> Typed<Mssql<E>> for $ty
+      where
+        E: From<crate::Error>
+      {
+        const TY: Ty = $mssql_ty;
+      }
+
+      test!($ty, $ty, $instance);
+    }
+  }
+
+  impl_primitive_from_array!(37, [a], u8, Ty::Intn);
+  impl_primitive_from_array!(37, [a, b], i16, Ty::Intn);
+  impl_primitive_from_array!(37, [a, b, c, d], i32, Ty::Intn);
+  impl_primitive_from_array!(37, [a, b, c, d, e, f, g, h], i64, Ty::Intn);
+
+  impl_primitive_from_array!(37.0, [a, b, c, d], f32, Ty::Fltn);
+  impl_primitive_from_array!(37.0, [a, b, c, d, e, f, g, h], f64, Ty::Fltn);
+}
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+  use crate::database::{
+    client::mssql::{DecodeValue, EncodeValue, Mssql, MssqlError, Ty},
+    Decode, Encode, Typed,
+  };
+  use rust_decimal::Decimal;
+
+  // | Sign | Mantissa |
+  // |  1   |  4..16   |
+  impl<E> Decode<'_, Mssql<E>> for Decimal
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let [sign, mantissa_bytes @ ..] = dv.bytes() else {
+        return Err(E::from(
+          MssqlError::UnexpectedValueFromBytes { expected: Ty::Decimaln }.into(),
+        ));
+      };
+      let mut buffer = [0; 16];
+      let Some(elem) = buffer.get_mut(..mantissa_bytes.len()) else {
+        return Err(E::from(
+          MssqlError::UnexpectedValueFromBytes { expected: Ty::Decimaln }.into(),
+        ));
+      };
+      elem.copy_from_slice(mantissa_bytes);
+      let mut mantissa = i128::from_le_bytes(buffer);
+      if *sign == 0 {
+        mantissa = mantissa.wrapping_neg();
+      }
+      Decimal::try_from_i128_with_scale(mantissa, dv.scale().into()).map_err(|_err| {
+        E::from(MssqlError::UnexpectedValueFromBytes { expected: Ty::Decimaln }.into())
+      })
+    }
+  }
+  impl<E> Encode<Mssql<E>> for Decimal
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      let mantissa = self.mantissa();
+      ev.set_ty(self.scale().try_into().unwrap_or(u8::MAX), Ty::Decimaln);
+      ev.fbw()._extend_from_slices([
+        &[u8::from(mantissa >= 0)][..],
+        &mantissa.unsigned_abs().to_le_bytes(),
+      ])?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for Decimal
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Decimaln;
+  }
+
+  test!(rust_decimal, Decimal, Decimal::new(-123_456, 3));
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+  use crate::database::{
+    client::mssql::{DecodeValue, EncodeValue, Mssql, MssqlError, Ty},
+    Decode, Encode, Typed,
+  };
+  use uuid::Uuid;
+
+  // The first three groups are little-endian.
+  impl<E> Decode<'_, Mssql<E>> for Uuid
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let Ok(bytes) = dv.bytes().try_into() else {
+        return Err(E::from(MssqlError::UnexpectedValueFromBytes { expected: Ty::Guid }.into()));
+      };
+      Ok(Uuid::from_bytes_le(bytes))
+    }
+  }
+  impl<E> Encode<Mssql<E>> for Uuid
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(0, Ty::Guid);
+      ev.fbw().extend_from_slice(&self.to_bytes_le())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mssql<E>> for Uuid
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Guid;
+  }
+
+  test!(uuid, Uuid, Uuid::from_u128(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10));
+}
+
+/// `CHAR`, `TEXT` and `VARCHAR` values are assumed to be UTF-8 while other textual types are
+/// transferred as UTF-16.
+#[inline]
+fn decode_string(
+  dv: &crate::database::client::mssql::DecodeValue<'_>,
+  mut cb: impl FnMut(char) -> crate::Result<()>,
+) -> crate::Result<()> {
+  if dv.ty().is_utf16() {
+    for rslt in crate::database::client::mssql::token::utf16_chars(dv.bytes()) {
+      cb(rslt?)?;
+    }
+  } else {
+    for elem in crate::misc::from_utf8_basic(dv.bytes())?.chars() {
+      cb(elem)?;
+    }
+  }
+  Ok(())
+}
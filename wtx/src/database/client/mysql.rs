@@ -0,0 +1,80 @@
+//! MySQL is an open-source relational database management system (RDBMS). Also works with
+//! MariaDB, a community-developed fork of MySQL.
+
+mod config;
+mod db_error;
+mod decode_value;
+mod encode_value;
+mod executor;
+mod executor_buffer;
+#[cfg(all(feature = "_async-tests", feature = "_integration-tests", test))]
+mod integration_tests;
+mod mysql_error;
+mod packet;
+mod protocol;
+mod record;
+mod records;
+mod statements;
+mod ty;
+mod tys;
+
+use crate::database::{Database, DatabaseTy};
+pub use config::Config;
+use core::marker::PhantomData;
+pub use db_error::DbError;
+pub use decode_value::DecodeValue;
+pub use encode_value::EncodeValue;
+pub use executor::Executor;
+pub use executor_buffer::ExecutorBuffer;
+pub use mysql_error::MysqlError;
+pub use record::Record;
+pub use records::Records;
+pub use statements::Statements;
+pub use ty::Ty;
+
+/// MySQL
+#[derive(Debug)]
+pub struct Mysql<E>(PhantomData<fn() -> E>);
+
+impl<E> Database for Mysql<E>
+where
+  E: From<crate::Error>,
+{
+  const BIND_PREFIX: &'static str = "?";
+  const IS_BIND_INCREASING: bool = false;
+  const TY: DatabaseTy = DatabaseTy::Mysql;
+
+  type DecodeValue<'exec> = DecodeValue<'exec>;
+  type EncodeValue<'buffer, 'tmp>
+    = EncodeValue<'buffer, 'tmp>
+  where
+    'buffer: 'tmp;
+  type Error = E;
+  type Record<'exec> = Record<'exec, E>;
+  type Records<'exec> = Records<'exec, E>;
+  type Ty = Ty;
+}
+
+impl<E> Default for Mysql<E> {
+  #[inline]
+  fn default() -> Self {
+    Self(PhantomData)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::mysql::{statements::column::Column, Ty};
+
+  pub(crate) fn column0() -> Column {
+    Column::new(false, "a".try_into().unwrap(), Ty::VarString)
+  }
+
+  pub(crate) fn column1() -> Column {
+    Column::new(false, "b".try_into().unwrap(), Ty::Long)
+  }
+
+  pub(crate) fn column2() -> Column {
+    Column::new(true, "c".try_into().unwrap(), Ty::LongLong)
+  }
+}
@@ -0,0 +1,41 @@
+use crate::{database::client::mysql::MysqlError, misc::UriRef};
+
+/// Configuration
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config<'data> {
+  pub(crate) db: &'data str,
+  pub(crate) password: &'data str,
+  pub(crate) user: &'data str,
+}
+
+impl<'data> Config<'data> {
+  /// Unwraps the elements of an URI. For example, `mysql://user:pw@localhost:3306/db`.
+  ///
+  /// Query parameters are not supported.
+  #[inline]
+  pub fn from_uri(uri: &'data UriRef<'_>) -> crate::Result<Config<'data>> {
+    if !uri.query_and_fragment().is_empty() {
+      return Err(MysqlError::UnknownConfigurationParameter.into());
+    }
+    Ok(Self {
+      db: uri.path().get(1..).unwrap_or_default(),
+      password: uri.password(),
+      user: uri.user(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{database::client::mysql::Config, misc::Uri};
+
+  #[test]
+  fn from_uri() {
+    let uri = Uri::new("mysql://ab:cd@ef:3306/gh");
+    let config = Config::from_uri(&uri).unwrap();
+    assert_eq!(config.db, "gh");
+    assert_eq!(config.password, "cd");
+    assert_eq!(config.user, "ab");
+    assert!(Config::from_uri(&Uri::new("mysql://ab:cd@ef:3306/gh?ij=kl")).is_err());
+  }
+}
@@ -0,0 +1,83 @@
+use crate::{
+  database::client::mysql::MysqlError,
+  misc::{from_utf8_basic, ArrayString},
+};
+use alloc::boxed::Box;
+
+/// An error sent by the server through an `ERR` packet.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DbError {
+  code: u16,
+  message: Box<str>,
+  sql_state: ArrayString<5>,
+}
+
+impl DbError {
+  /// Server error code. For example, `1146` is returned when a table doesn't exist.
+  #[inline]
+  pub fn code(&self) -> u16 {
+    self.code
+  }
+
+  /// If the server closes the connection right after sending the error, which is the case of
+  /// SQLSTATE class `08` (connection exception), killed connections (`1927`) and inactive
+  /// clients (`4031`).
+  #[inline]
+  pub fn is_fatal(&self) -> bool {
+    self.sql_state.starts_with("08") || matches!(self.code, 1927 | 4031)
+  }
+
+  /// Human-readable error message.
+  #[inline]
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// The five-characters SQLSTATE code of the error.
+  #[inline]
+  pub fn sql_state(&self) -> &str {
+    &self.sql_state
+  }
+}
+
+// | Header | Code | Marker | SQL state | Message |
+// |   1    |  2   |   1    |     5     |    x    |
+impl TryFrom<&[u8]> for DbError {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    let [255, a, b, rest @ ..] = from else {
+      return Err(MysqlError::InsufficientDbErrorBytes.into());
+    };
+    let (sql_state, message) = match rest {
+      [b'#', c, d, e, f, g, message @ ..] => {
+        (from_utf8_basic(&[*c, *d, *e, *f, *g])?.try_into()?, message)
+      }
+      _ => (ArrayString::new(), rest),
+    };
+    Ok(Self {
+      code: u16::from_le_bytes([*a, *b]),
+      message: from_utf8_basic(message)?.into(),
+      sql_state,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::mysql::DbError;
+
+  #[test]
+  fn parses_err_packet() {
+    let bytes = b"\xff\x7a\x04#42S02Table 'wtx.foo' doesn't exist";
+    let db_error = DbError::try_from(&bytes[..]).unwrap();
+    assert_eq!(db_error.code(), 1146);
+    assert_eq!(db_error.message(), "Table 'wtx.foo' doesn't exist");
+    assert_eq!(db_error.sql_state(), "42S02");
+    assert!(!db_error.is_fatal());
+    assert!(DbError::try_from(&b"\xff\x7a"[..]).is_err());
+    let bytes = b"\xff\x1d\x04#08S01Server shutdown in progress";
+    assert!(DbError::try_from(&bytes[..]).unwrap().is_fatal());
+  }
+}
@@ -0,0 +1,33 @@
+use crate::database::client::mysql::Ty;
+
+/// Struct used for decoding elements in MySQL.
+#[derive(Debug, PartialEq)]
+pub struct DecodeValue<'any> {
+  bytes: &'any [u8],
+  ty: Ty,
+}
+
+impl<'any> DecodeValue<'any> {
+  pub(crate) fn new(bytes: &'any [u8], ty: Ty) -> Self {
+    Self { bytes, ty }
+  }
+
+  /// Bytes of a column. Length prefixes are not included.
+  #[inline]
+  pub fn bytes(&self) -> &'any [u8] {
+    self.bytes
+  }
+
+  /// Type of a column.
+  #[inline]
+  pub fn ty(&self) -> &Ty {
+    &self.ty
+  }
+}
+
+impl Default for DecodeValue<'_> {
+  #[inline]
+  fn default() -> Self {
+    Self { bytes: &[], ty: Ty::Null }
+  }
+}
@@ -0,0 +1,57 @@
+use crate::{
+  database::client::mysql::Ty,
+  misc::{FilledBufferWriter, Lease, LeaseMut},
+};
+
+/// Struct used for encoding elements in MySQL.
+#[derive(Debug)]
+pub struct EncodeValue<'buffer, 'tmp> {
+  fbw: &'tmp mut FilledBufferWriter<'buffer>,
+  is_unsigned: bool,
+  ty: Ty,
+}
+
+impl<'buffer, 'tmp> EncodeValue<'buffer, 'tmp> {
+  #[inline]
+  pub(crate) fn new(fbw: &'tmp mut FilledBufferWriter<'buffer>) -> Self {
+    Self { fbw, is_unsigned: false, ty: Ty::Blob }
+  }
+
+  /// See [`FilledBufferWriter`].
+  #[inline]
+  pub fn fbw(&mut self) -> &mut FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+
+  /// Declares the type of the value that is being encoded, which defaults to [`Ty::Blob`].
+  ///
+  /// Values whose types don't have a fixed length are automatically prefixed by their lengths.
+  #[inline]
+  pub fn set_ty(&mut self, is_unsigned: bool, ty: Ty) {
+    self.is_unsigned = is_unsigned;
+    self.ty = ty;
+  }
+
+  /// Returns the declared type and then resets it to the default value.
+  #[inline]
+  pub(crate) fn take_ty(&mut self) -> (bool, Ty) {
+    let rslt = (self.is_unsigned, self.ty);
+    self.is_unsigned = false;
+    self.ty = Ty::Blob;
+    rslt
+  }
+}
+
+impl<'buffer> Lease<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
+  #[inline]
+  fn lease(&self) -> &FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+}
+
+impl<'buffer> LeaseMut<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+}
@@ -0,0 +1,252 @@
+mod authentication;
+mod fetch;
+mod prepare;
+mod simple_query;
+
+use crate::{
+  database::{
+    client::mysql::{
+      executor::fetch::fetch_packet_from_stream,
+      executor_buffer::{ExecutorBuffer, ExecutorBufferPartsMut},
+      packet::Handshake,
+      protocol::{ssl_request, stmt_execute, CAPABILITIES, CLIENT_PROTOCOL_41, CLIENT_SSL},
+      Config, Mysql, MysqlError, Record, Records,
+    },
+    Database, RecordValues, StmtCmd, TransactionOptions,
+  },
+  misc::{
+    ArrayString, ConnectionState, FilledBufferWriter, LeaseMut, Stream, StreamReader,
+    _unlikely_dflt,
+  },
+};
+use core::{future::Future, marker::PhantomData};
+
+/// Executor
+#[derive(Debug)]
+pub struct Executor<E, EB, S> {
+  pub(crate) cs: ConnectionState,
+  pub(crate) eb: EB,
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  pub(crate) stream: S,
+}
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Connects with an unencrypted stream.
+  #[inline]
+  pub async fn connect(config: &Config<'_>, mut eb: EB, mut stream: S) -> crate::Result<Self> {
+    eb.lease_mut().clear();
+    let (handshake, seq) = read_handshake(&mut eb, &mut stream).await?;
+    Self::do_connect(config, eb, handshake, false, seq, stream).await
+  }
+
+  /// Initially connects with an unencrypted stream that is upgraded to an encrypted stream by
+  /// `cb` right after the initial handshake sent by the server.
+  #[inline]
+  pub async fn connect_encrypted<F, IS>(
+    config: &Config<'_>,
+    mut eb: EB,
+    mut stream: IS,
+    cb: impl FnOnce(IS) -> F,
+  ) -> crate::Result<Self>
+  where
+    F: Future<Output = crate::Result<S>>,
+    IS: Stream,
+  {
+    eb.lease_mut().clear();
+    let (handshake, seq) = read_handshake(&mut eb, &mut stream).await?;
+    if handshake.capabilities & CLIENT_SSL != CLIENT_SSL {
+      return Err(MysqlError::ServerDoesNotSupportEncryption.into());
+    }
+    {
+      let mut fbw = FilledBufferWriter::from(&mut eb.lease_mut().nb);
+      ssl_request(CAPABILITIES | CLIENT_SSL, &mut fbw, seq)?;
+      stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let encrypted_stream = cb(stream).await?;
+    Self::do_connect(config, eb, handshake, true, seq.wrapping_add(1), encrypted_stream).await
+  }
+
+  /// Mutable buffer reference
+  #[inline]
+  pub fn eb_mut(&mut self) -> &mut ExecutorBuffer {
+    self.eb.lease_mut()
+  }
+
+  #[inline]
+  async fn do_connect(
+    config: &Config<'_>,
+    eb: EB,
+    handshake: Handshake,
+    is_tls: bool,
+    seq: u8,
+    stream: S,
+  ) -> crate::Result<Self> {
+    let mut this = Self { cs: ConnectionState::Open, eb, phantom: PhantomData, stream };
+    this.manage_authentication(config, handshake, is_tls, seq).await?;
+    Ok(this)
+  }
+}
+
+impl<E, EB, S> crate::database::Executor for Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  type Database = Mysql<E>;
+
+  #[inline]
+  fn connection_state(&self) -> ConnectionState {
+    self.cs
+  }
+
+  /// MySQL doesn't support deferrable transactions, as such, [`TransactionOptions::is_deferrable`]
+  /// is ignored.
+  #[inline]
+  async fn begin(&mut self, to: TransactionOptions) -> crate::Result<()> {
+    let mut cmd = ArrayString::<96>::new();
+    if let Some(elem) = to.isolation_level() {
+      cmd.push_str("SET TRANSACTION ISOLATION LEVEL ")?;
+      cmd.push_str(elem.as_str())?;
+      cmd.push_str(";")?;
+    }
+    cmd.push_str("START TRANSACTION")?;
+    if to.is_read_only() {
+      cmd.push_str(" READ ONLY")?;
+    }
+    self.simple_query_execute(&cmd, |_| {}).await
+  }
+
+  #[inline]
+  async fn execute(&mut self, cmd: &str, cb: impl FnMut(u64)) -> crate::Result<()> {
+    self.simple_query_execute(cmd, cb).await
+  }
+
+  #[inline]
+  async fn execute_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+  ) -> Result<u64, <Self::Database as Database>::Error>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let Self { cs, eb, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    let (_, stmt) = Self::write_send_await_stmt_prot(cs, nb, sc, stmts, stream).await?;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      stmt_execute(&mut fbw, rv, &stmt)?;
+      manage_conn_state(cs, stream.write_all(fbw._curr_bytes()).await)?;
+    }
+    let (rows, _) = Self::fetch_records(cs, nb, rb, &stmt, stream, vb, &mut |_| Ok(())).await?;
+    Ok(rows)
+  }
+
+  #[inline]
+  async fn fetch_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+  ) -> Result<<Self::Database as Database>::Record<'_>, E>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let Self { cs, eb, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    let (_, stmt) = Self::write_send_await_stmt_prot(cs, nb, sc, stmts, stream).await?;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      stmt_execute(&mut fbw, rv, &stmt)?;
+      manage_conn_state(cs, stream.write_all(fbw._curr_bytes()).await)?;
+    }
+    let (_, range) = Self::fetch_records(cs, nb, rb, &stmt, stream, vb, &mut |_| Ok(())).await?;
+    let Some(&end) = rb.first() else {
+      return Err(E::from(MysqlError::NoRecord.into()));
+    };
+    Ok(Record {
+      bytes: nb._buffer().get(range).unwrap_or_default(),
+      phantom: PhantomData,
+      stmt,
+      values_bytes_offsets: vb.get(..end).unwrap_or_else(_unlikely_dflt),
+    })
+  }
+
+  #[inline]
+  async fn fetch_many_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+    mut cb: impl FnMut(&<Self::Database as Database>::Record<'_>) -> Result<(), E>,
+  ) -> Result<<Self::Database as Database>::Records<'_>, E>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let Self { cs, eb, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    let (_, stmt) = Self::write_send_await_stmt_prot(cs, nb, sc, stmts, stream).await?;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      stmt_execute(&mut fbw, rv, &stmt)?;
+      manage_conn_state(cs, stream.write_all(fbw._curr_bytes()).await)?;
+    }
+    let (_, range) = Self::fetch_records(cs, nb, rb, &stmt, stream, vb, &mut cb).await?;
+    Ok(Records {
+      bytes: nb._buffer().get(range).unwrap_or_default(),
+      phantom: PhantomData,
+      records_values_offsets: rb,
+      stmt,
+      values_bytes_offsets: vb,
+    })
+  }
+
+  #[inline]
+  async fn prepare(&mut self, cmd: &str) -> Result<u64, E> {
+    let Self { cs, eb, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    Ok(Self::write_send_await_stmt_prot(cs, nb, cmd, stmts, stream).await?.0)
+  }
+}
+
+// Errors that leave the stream in an unknown state or that precede the disconnection of the
+// server mark the connection as closed. Regular `ERR` packets don't affect the connection.
+#[inline]
+pub(crate) fn manage_conn_state<T>(
+  cs: &mut ConnectionState,
+  rslt: crate::Result<T>,
+) -> crate::Result<T> {
+  if let Err(err) = &rslt {
+    if !matches!(err, crate::Error::MysqlDbError(elem) if !elem.is_fatal()) {
+      *cs = ConnectionState::Closed;
+    }
+  }
+  rslt
+}
+
+// Reads the initial packet sent by the server, returning the sequence number that should be
+// used by the next packet sent by the client.
+#[inline]
+async fn read_handshake<EB, SR>(eb: &mut EB, stream: &mut SR) -> crate::Result<(Handshake, u8)>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  SR: StreamReader,
+{
+  let nb = &mut eb.lease_mut().nb;
+  let seq = fetch_packet_from_stream(nb, stream).await?;
+  let handshake = Handshake::try_from(nb._current())?;
+  if handshake.capabilities & CLIENT_PROTOCOL_41 != CLIENT_PROTOCOL_41 {
+    return Err(MysqlError::ServerDoesNotSupportProtocol41.into());
+  }
+  Ok((handshake, seq.wrapping_add(1)))
+}
@@ -0,0 +1,158 @@
+use crate::{
+  database::client::mysql::{
+    executor::fetch::fetch_packet_from_stream,
+    executor_buffer::ExecutorBuffer,
+    packet::{AuthPlugin, AuthSwitch, Handshake},
+    protocol::{
+      auth_data, handshake_response, plain_text_password, CAPABILITIES, CLIENT_CONNECT_WITH_DB,
+      CLIENT_SSL,
+    },
+    Config, Executor, MysqlError,
+  },
+  misc::{ArrayVector, FilledBufferWriter, LeaseMut, Stream},
+};
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// `seq` is the sequence number of the handshake response.
+  #[inline]
+  pub(crate) async fn manage_authentication(
+    &mut self,
+    config: &Config<'_>,
+    handshake: Handshake,
+    is_tls: bool,
+    mut seq: u8,
+  ) -> crate::Result<()> {
+    let nb = &mut self.eb.lease_mut().nb;
+    {
+      let auth_plugin = handshake.auth_plugin.unwrap_or(AuthPlugin::MysqlNativePassword);
+      let auth_response = scramble(auth_plugin, &handshake.nonce, config.password.as_bytes());
+      let mut capabilities = CAPABILITIES;
+      if is_tls {
+        capabilities |= CLIENT_SSL;
+      }
+      if config.db.is_empty() {
+        capabilities &= !CLIENT_CONNECT_WITH_DB;
+      }
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      handshake_response(
+        (auth_plugin.name(), &auth_response),
+        capabilities,
+        config,
+        &mut fbw,
+        seq,
+      )?;
+      self.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    loop {
+      seq = fetch_packet_from_stream(nb, &mut self.stream).await?.wrapping_add(1);
+      match nb._current() {
+        [0, ..] => return Ok(()),
+        // Fast authentication succeeded, the next packet is an `OK` packet.
+        [1, 3] => {}
+        // Full authentication, which requires the plain text password.
+        [1, 4] => {
+          if !is_tls {
+            return Err(MysqlError::ForbiddenPlainTextPassword.into());
+          }
+          let mut fbw = FilledBufferWriter::from(&mut *nb);
+          plain_text_password(&mut fbw, config.password.as_bytes(), seq)?;
+          self.stream.write_all(fbw._curr_bytes()).await?;
+        }
+        [254, ..] => {
+          let AuthSwitch { auth_plugin, nonce } = AuthSwitch::try_from(nb._current())?;
+          let auth_response = scramble(auth_plugin, &nonce, config.password.as_bytes());
+          let mut fbw = FilledBufferWriter::from(&mut *nb);
+          auth_data(&auth_response, &mut fbw, seq)?;
+          self.stream.write_all(fbw._curr_bytes()).await?;
+        }
+        [first, ..] => {
+          return Err(MysqlError::UnexpectedDatabasePacket { received: *first }.into());
+        }
+        [] => return Err(MysqlError::UnexpectedDatabasePacketBytes.into()),
+      }
+    }
+  }
+}
+
+#[inline]
+fn scramble(auth_plugin: AuthPlugin, nonce: &[u8; 20], password: &[u8]) -> ArrayVector<u8, 32> {
+  if password.is_empty() {
+    return ArrayVector::new();
+  }
+  match auth_plugin {
+    AuthPlugin::CachingSha2Password => {
+      ArrayVector::from_array(scramble_caching_sha2_password(nonce, password))
+    }
+    AuthPlugin::MysqlNativePassword => {
+      let mut data = [0; 32];
+      if let Some(elem) = data.get_mut(..20) {
+        elem.copy_from_slice(&scramble_mysql_native_password(nonce, password));
+      }
+      ArrayVector::from_parts(data, Some(20))
+    }
+  }
+}
+
+// SHA256(password) XOR SHA256(SHA256(SHA256(password)) ‖ nonce)
+#[inline]
+fn scramble_caching_sha2_password(nonce: &[u8; 20], password: &[u8]) -> [u8; 32] {
+  let pw_hash: [u8; 32] = Sha256::digest(password).into();
+  let pw_hash_hash = Sha256::digest(pw_hash);
+  let mut hasher = Sha256::new();
+  hasher.update(pw_hash_hash);
+  hasher.update(nonce);
+  let mut rslt: [u8; 32] = hasher.finalize().into();
+  for (lhs, rhs) in rslt.iter_mut().zip(pw_hash) {
+    *lhs ^= rhs;
+  }
+  rslt
+}
+
+// SHA1(password) XOR SHA1(nonce ‖ SHA1(SHA1(password)))
+#[inline]
+fn scramble_mysql_native_password(nonce: &[u8; 20], password: &[u8]) -> [u8; 20] {
+  let pw_hash: [u8; 20] = Sha1::digest(password).into();
+  let pw_hash_hash = Sha1::digest(pw_hash);
+  let mut hasher = Sha1::new();
+  hasher.update(nonce);
+  hasher.update(pw_hash_hash);
+  let mut rslt: [u8; 20] = hasher.finalize().into();
+  for (lhs, rhs) in rslt.iter_mut().zip(pw_hash) {
+    *lhs ^= rhs;
+  }
+  rslt
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::mysql::executor::authentication::{
+    scramble_caching_sha2_password, scramble_mysql_native_password,
+  };
+
+  const NONCE: [u8; 20] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20];
+
+  #[test]
+  fn caching_sha2_password() {
+    assert_eq!(
+      scramble_caching_sha2_password(&NONCE, b"wtx"),
+      [
+        97, 19, 71, 52, 188, 97, 9, 73, 69, 80, 127, 156, 96, 37, 9, 42, 152, 37, 184, 183, 129,
+        223, 174, 212, 217, 34, 213, 39, 136, 5, 196, 177
+      ]
+    );
+  }
+
+  #[test]
+  fn mysql_native_password() {
+    assert_eq!(
+      scramble_mysql_native_password(&NONCE, b"wtx"),
+      [255, 199, 153, 94, 16, 93, 10, 17, 226, 172, 0, 31, 92, 153, 253, 214, 185, 56, 83, 13]
+    );
+  }
+}
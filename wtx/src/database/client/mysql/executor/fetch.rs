@@ -0,0 +1,169 @@
+use crate::{
+  database::client::mysql::{
+    executor::manage_conn_state,
+    packet::{decode_lenenc_int, eof_status, OkPacket},
+    protocol::SERVER_MORE_RESULTS_EXISTS,
+    statements::statement::Statement,
+    DbError, Executor, ExecutorBuffer, MysqlError, Record,
+  },
+  misc::{
+    partitioned_filled_buffer::PartitionedFilledBuffer, ConnectionState, LeaseMut, Stream,
+    StreamReader, Usize, Vector, _read_header, _read_payload,
+  },
+};
+use alloc::boxed::Box;
+use core::ops::Range;
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Reads all the rows of a binary result set, returning the number of affected records and the
+  /// range of `nb` that contains the received records.
+  ///
+  /// If `cb` returns an error, the remaining packets are still consumed to keep the connection
+  /// in a consistent state.
+  #[inline]
+  pub(crate) async fn fetch_records(
+    cs: &mut ConnectionState,
+    nb: &mut PartitionedFilledBuffer,
+    rb: &mut Vector<usize>,
+    stmt: &Statement<'_>,
+    stream: &mut S,
+    vb: &mut Vector<(bool, Range<usize>)>,
+    cb: &mut impl FnMut(&Record<'_, E>) -> Result<(), E>,
+  ) -> Result<(u64, Range<usize>), E>
+  where
+    E: From<crate::Error>,
+  {
+    let mut cb_rslt = Ok(());
+    let rslt = async {
+      let begin = nb._current_end_idx();
+      let _ = fetch_packet_from_stream(nb, stream).await?;
+      let (columns_len, _) = decode_lenenc_int(nb._current())?;
+      if columns_len == 0 {
+        let ok = OkPacket::try_from(nb._current())?;
+        skip_remaining_results(nb, ok.status, stream).await?;
+        return Ok((ok.affected_rows, begin..begin));
+      }
+      for _ in 0..columns_len {
+        let _ = fetch_packet_from_stream(nb, stream).await?;
+      }
+      expect_eof(nb, stream).await?;
+      let mut rows: u64 = 0;
+      let status = loop {
+        let _ = fetch_packet_from_stream(nb, stream).await?;
+        if let Some(status) = eof_status(nb._current()) {
+          break status;
+        }
+        let current_range = nb._current_range();
+        let payload_range =
+          current_range.start.wrapping_sub(begin)..current_range.end.wrapping_sub(begin);
+        let bytes = nb._buffer().get(begin..current_range.end).unwrap_or_default();
+        let record = Record::parse(bytes, payload_range, stmt.clone(), vb)?;
+        if cb_rslt.is_ok() {
+          cb_rslt = cb(&record);
+        }
+        rb.push(vb.len())?;
+        rows = rows.wrapping_add(1);
+      };
+      let end = nb._current_end_idx();
+      skip_remaining_results(nb, status, stream).await?;
+      Ok((rows, begin..end))
+    }
+    .await;
+    let elem = manage_conn_state(cs, rslt)?;
+    cb_rslt?;
+    Ok(elem)
+  }
+}
+
+/// Reads the contents of a single packet into `nb`, returning its sequence number. `ERR` packets
+/// are converted to [`DbError`].
+//
+// | Payload length | Sequence | Payload |
+// |       3        |    1     |    x    |
+#[inline]
+pub(crate) async fn fetch_packet_from_stream<SR>(
+  nb: &mut PartitionedFilledBuffer,
+  stream: &mut SR,
+) -> crate::Result<u8>
+where
+  SR: StreamReader,
+{
+  nb._reserve(4)?;
+  let mut read = nb._following_len();
+  let buffer = nb._following_rest_mut();
+  let [a, b, c, seq] = _read_header::<0, 4, SR>(buffer, &mut read, stream).await?;
+  let len = u32::from_le_bytes([a, b, c, 0]);
+  if len >= 0xFF_FF_FF {
+    return Err(MysqlError::VeryLargePacket.into());
+  }
+  _read_payload((4, Usize::from(len).into_usize()), nb, &mut read, stream).await?;
+  if let [255, ..] = nb._current() {
+    return Err(crate::Error::MysqlDbError(Box::new(DbError::try_from(nb._current())?)));
+  }
+  Ok(seq)
+}
+
+/// Skips an entire result set, returning the number of affected records and the final status.
+/// Used by commands that don't care about the contents of the returned rows.
+#[inline]
+pub(crate) async fn skip_result<SR>(
+  nb: &mut PartitionedFilledBuffer,
+  stream: &mut SR,
+) -> crate::Result<(u64, u16)>
+where
+  SR: StreamReader,
+{
+  let _ = fetch_packet_from_stream(nb, stream).await?;
+  let (columns_len, _) = decode_lenenc_int(nb._current())?;
+  if columns_len == 0 {
+    let ok = OkPacket::try_from(nb._current())?;
+    return Ok((ok.affected_rows, ok.status));
+  }
+  for _ in 0..columns_len {
+    let _ = fetch_packet_from_stream(nb, stream).await?;
+  }
+  expect_eof(nb, stream).await?;
+  let mut rows: u64 = 0;
+  loop {
+    let _ = fetch_packet_from_stream(nb, stream).await?;
+    if let Some(status) = eof_status(nb._current()) {
+      return Ok((rows, status));
+    }
+    rows = rows.wrapping_add(1);
+  }
+}
+
+#[inline]
+pub(crate) async fn expect_eof<SR>(
+  nb: &mut PartitionedFilledBuffer,
+  stream: &mut SR,
+) -> crate::Result<u16>
+where
+  SR: StreamReader,
+{
+  let _ = fetch_packet_from_stream(nb, stream).await?;
+  eof_status(nb._current()).ok_or_else(|| {
+    let received = nb._current().first().copied().unwrap_or_default();
+    MysqlError::UnexpectedDatabasePacket { received }.into()
+  })
+}
+
+// Statements like `CALL` can return more than one result set.
+#[inline]
+async fn skip_remaining_results<SR>(
+  nb: &mut PartitionedFilledBuffer,
+  mut status: u16,
+  stream: &mut SR,
+) -> crate::Result<()>
+where
+  SR: StreamReader,
+{
+  while status & SERVER_MORE_RESULTS_EXISTS == SERVER_MORE_RESULTS_EXISTS {
+    status = skip_result(nb, stream).await?.1;
+  }
+  Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::{
+  database::{
+    client::mysql::{
+      executor::{
+        fetch::{expect_eof, fetch_packet_from_stream},
+        manage_conn_state,
+      },
+      executor_buffer::ExecutorBuffer,
+      packet::{column, PrepareOk},
+      protocol::{stmt_close, stmt_prepare},
+      statements::{statement::Statement, statements_misc::StatementsMisc},
+      Executor, MysqlError, Statements,
+    },
+    StmtCmd,
+  },
+  misc::{
+    partitioned_filled_buffer::PartitionedFilledBuffer, ConnectionState, FilledBufferWriter,
+    LeaseMut, Stream,
+  },
+};
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Sends a `COM_STMT_PREPARE` command if the statement isn't already cached and stores the
+  /// received column definitions in `stmts`. Statements evicted from the cache are closed.
+  #[inline]
+  pub(crate) async fn write_send_await_stmt_prot<'stmts, SC>(
+    cs: &mut ConnectionState,
+    nb: &mut PartitionedFilledBuffer,
+    sc: SC,
+    stmts: &'stmts mut Statements,
+    stream: &mut S,
+  ) -> Result<(u64, Statement<'stmts>), E>
+  where
+    SC: StmtCmd,
+  {
+    let stmt_hash = sc.hash(stmts.hasher_mut());
+    if stmts.get_by_stmt_hash(stmt_hash).is_some() {
+      // FIXME(stable): Use `if let Some ...` with polonius
+      return Ok((stmt_hash, stmts.get_by_stmt_hash(stmt_hash).unwrap()));
+    }
+
+    let stmt_cmd = sc.cmd().ok_or_else(|| E::from(MysqlError::UnknownStatementId.into()))?;
+
+    let idx = manage_conn_state(
+      cs,
+      Self::send_await_stmt_prot(nb, stmt_cmd, stmt_hash, stmts, stream).await,
+    )?;
+    let Some(stmt) = stmts.get_by_idx(idx) else {
+      return Err(crate::Error::ProgrammingError.into());
+    };
+    Ok((stmt_hash, stmt))
+  }
+
+  #[inline]
+  async fn send_await_stmt_prot(
+    nb: &mut PartitionedFilledBuffer,
+    stmt_cmd: &str,
+    stmt_hash: u64,
+    stmts: &mut Statements,
+    stream: &mut S,
+  ) -> crate::Result<usize> {
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      stmt_prepare(stmt_cmd.as_bytes(), &mut fbw)?;
+      stream.write_all(fbw._curr_bytes()).await?;
+    }
+
+    let _ = fetch_packet_from_stream(nb, stream).await?;
+    let PrepareOk { columns_len, params_len, stmt_id } = PrepareOk::try_from(nb._current())?;
+    if params_len > 0 {
+      for _ in 0..params_len {
+        let _ = fetch_packet_from_stream(nb, stream).await?;
+      }
+      let _ = expect_eof(nb, stream).await?;
+    }
+
+    let mut builder = stmts.builder()?;
+    if columns_len > 0 {
+      let _ = builder.expand(columns_len.into())?;
+      for idx in 0..usize::from(columns_len) {
+        let _ = fetch_packet_from_stream(nb, stream).await?;
+        if let Some(element) = builder.inserted_elements().get_mut(idx) {
+          *element = column(nb._current())?;
+        }
+      }
+      let _ = expect_eof(nb, stream).await?;
+    }
+    let idx = builder.build(stmt_hash, StatementsMisc::new(stmt_id, params_len))?;
+
+    if !stmts.evicted_mut().is_empty() {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      for evicted_id in stmts.evicted_mut().iter().copied() {
+        stmt_close(&mut fbw, evicted_id)?;
+      }
+      stream.write_all(fbw._curr_bytes()).await?;
+      stmts.evicted_mut().clear();
+    }
+    Ok(idx)
+  }
+}
@@ -0,0 +1,40 @@
+use crate::{
+  database::client::mysql::{
+    executor::{fetch::skip_result, manage_conn_state},
+    executor_buffer::ExecutorBufferPartsMut,
+    protocol::{query, SERVER_MORE_RESULTS_EXISTS},
+    Executor, ExecutorBuffer,
+  },
+  misc::{FilledBufferWriter, LeaseMut, Stream},
+};
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Sends a `COM_QUERY` command, calling `cb` with the number of affected records of each
+  /// statement. Returned rows are ignored.
+  #[inline]
+  pub(crate) async fn simple_query_execute(
+    &mut self,
+    cmd: &str,
+    mut cb: impl FnMut(u64),
+  ) -> crate::Result<()> {
+    let Self { cs, eb, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, rb, vb, .. } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      query(cmd.as_bytes(), &mut fbw)?;
+      manage_conn_state(cs, stream.write_all(fbw._curr_bytes()).await)?;
+    }
+    loop {
+      let (rows, status) = manage_conn_state(cs, skip_result(nb, stream).await)?;
+      cb(rows);
+      if status & SERVER_MORE_RESULTS_EXISTS != SERVER_MORE_RESULTS_EXISTS {
+        return Ok(());
+      }
+    }
+  }
+}
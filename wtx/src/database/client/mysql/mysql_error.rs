@@ -0,0 +1,48 @@
+use crate::database::client::mysql::Ty;
+
+/// MySQL error
+#[derive(Debug)]
+pub enum MysqlError {
+  /// There are no sufficient bytes to decoding an element
+  DecodingError,
+  /// The full authentication of `caching_sha2_password` sends the password in plain text, which
+  /// is only allowed through encrypted connections.
+  ForbiddenPlainTextPassword,
+  /// There are no bytes left to build a `DbError`
+  InsufficientDbErrorBytes,
+  /// Received bytes don't compose a valid record.
+  InvalidMysqlRecord,
+  /// Expected one record but got none.
+  NoRecord,
+  /// Server does not support encryption
+  ServerDoesNotSupportEncryption,
+  /// Only servers that support the 4.1 protocol are supported.
+  ServerDoesNotSupportProtocol41,
+  /// Received size differs from expected size.
+  UnexpectedBufferSize {
+    /// Expected
+    expected: u32,
+    /// Received
+    received: u32,
+  },
+  /// Received an unexpected packet.
+  UnexpectedDatabasePacket {
+    /// First byte of the payload
+    received: u8,
+  },
+  /// Received an expected packet but the related bytes are in an unexpected state.
+  UnexpectedDatabasePacketBytes,
+  /// Bytes don't represent expected type
+  UnexpectedValueFromBytes {
+    /// Expected
+    expected: Ty,
+  },
+  /// The system does not support a requested authentication method.
+  UnknownAuthenticationMethod,
+  /// The system does not support a provided parameter.
+  UnknownConfigurationParameter,
+  /// Received a statement ID that is not present in the local cache.
+  UnknownStatementId,
+  /// Packets of 16 MiB or more are not supported.
+  VeryLargePacket,
+}
@@ -0,0 +1,292 @@
+//! Parsers of packets sent by the server.
+
+use crate::{
+  database::client::mysql::{
+    protocol::CLIENT_PLUGIN_AUTH, statements::column::Column, MysqlError, Ty,
+  },
+  misc::from_utf8_basic,
+};
+
+const UNSIGNED_FLAG: u16 = 1 << 5;
+
+/// Authentication methods
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum AuthPlugin {
+  CachingSha2Password,
+  MysqlNativePassword,
+}
+
+impl AuthPlugin {
+  #[inline]
+  pub(crate) fn name(self) -> &'static [u8] {
+    match self {
+      Self::CachingSha2Password => b"caching_sha2_password",
+      Self::MysqlNativePassword => b"mysql_native_password",
+    }
+  }
+}
+
+impl TryFrom<&[u8]> for AuthPlugin {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    Ok(match from {
+      b"caching_sha2_password" => Self::CachingSha2Password,
+      b"mysql_native_password" => Self::MysqlNativePassword,
+      _ => return Err(MysqlError::UnknownAuthenticationMethod.into()),
+    })
+  }
+}
+
+/// Request sent by the server to change the authentication method.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct AuthSwitch {
+  pub(crate) auth_plugin: AuthPlugin,
+  pub(crate) nonce: [u8; 20],
+}
+
+// | Header | Plugin name | Nonce |
+// |   1    |      x      |  20   |
+impl TryFrom<&[u8]> for AuthSwitch {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    let [254, rest @ ..] = from else {
+      return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+    };
+    let (name, rest) = c_str(rest)?;
+    Ok(Self { auth_plugin: name.try_into()?, nonce: nonce(rest, &[])? })
+  }
+}
+
+/// Initial packet sent by the server.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Handshake {
+  /// `None` if the method is unknown, which is probably going to be changed by the server.
+  pub(crate) auth_plugin: Option<AuthPlugin>,
+  pub(crate) capabilities: u32,
+  pub(crate) nonce: [u8; 20],
+}
+
+impl TryFrom<&[u8]> for Handshake {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    let [10, rest @ ..] = from else {
+      return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+    };
+    let (_server_version, rest) = c_str(rest)?;
+    let [_, _, _, _, a, b, c, d, e, f, g, h, _, i, j, _, _, _, k, l, auth_data_len, rest @ ..] =
+      rest
+    else {
+      return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+    };
+    let capabilities = u32::from_le_bytes([*i, *j, *k, *l]);
+    let rest = rest.get(10..).unwrap_or_default();
+    let part2_len = usize::from(auth_data_len.saturating_sub(8)).max(13);
+    let (nonce_part2, auth_plugin_name) = rest.split_at_checked(part2_len).unwrap_or((rest, &[]));
+    let auth_plugin = if capabilities & CLIENT_PLUGIN_AUTH == CLIENT_PLUGIN_AUTH {
+      c_str(auth_plugin_name).ok().and_then(|(name, _)| AuthPlugin::try_from(name).ok())
+    } else {
+      None
+    };
+    Ok(Self {
+      auth_plugin,
+      capabilities,
+      nonce: nonce(nonce_part2, &[*a, *b, *c, *d, *e, *f, *g, *h])?,
+    })
+  }
+}
+
+/// Response of a command that doesn't return records.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct OkPacket {
+  pub(crate) affected_rows: u64,
+  pub(crate) status: u16,
+}
+
+// | Header | Affected rows | Last insert ID | Status | Warnings |
+// |   1    |       x       |       x        |   2    |    2     |
+impl TryFrom<&[u8]> for OkPacket {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    let [0 | 254, rest @ ..] = from else {
+      return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+    };
+    let (affected_rows, rest) = decode_lenenc_int(rest)?;
+    let (_last_insert_id, rest) = decode_lenenc_int(rest)?;
+    let [a, b, ..] = rest else {
+      return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+    };
+    Ok(Self { affected_rows, status: u16::from_le_bytes([*a, *b]) })
+  }
+}
+
+/// Response of a successful `COM_STMT_PREPARE` command.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct PrepareOk {
+  pub(crate) columns_len: u16,
+  pub(crate) params_len: u16,
+  pub(crate) stmt_id: u32,
+}
+
+impl TryFrom<&[u8]> for PrepareOk {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: &[u8]) -> Result<Self, Self::Error> {
+    let [0, a, b, c, d, e, f, g, h, ..] = from else {
+      return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+    };
+    Ok(Self {
+      columns_len: u16::from_le_bytes([*e, *f]),
+      params_len: u16::from_le_bytes([*g, *h]),
+      stmt_id: u32::from_le_bytes([*a, *b, *c, *d]),
+    })
+  }
+}
+
+/// Parses a `ColumnDefinition41` packet.
+#[inline]
+pub(crate) fn column(bytes: &[u8]) -> crate::Result<Column> {
+  let (_catalog, rest) = decode_lenenc_bytes(bytes)?;
+  let (_schema, rest) = decode_lenenc_bytes(rest)?;
+  let (_table, rest) = decode_lenenc_bytes(rest)?;
+  let (_org_table, rest) = decode_lenenc_bytes(rest)?;
+  let (name, rest) = decode_lenenc_bytes(rest)?;
+  let (_org_name, rest) = decode_lenenc_bytes(rest)?;
+  let (_fixed_len, rest) = decode_lenenc_int(rest)?;
+  let [_, _, _, _, _, _, ty, a, b, ..] = rest else {
+    return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+  };
+  let flags = u16::from_le_bytes([*a, *b]);
+  Ok(Column::new(
+    flags & UNSIGNED_FLAG == UNSIGNED_FLAG,
+    from_utf8_basic(name)?.try_into()?,
+    Ty::try_from(*ty)?,
+  ))
+}
+
+/// Returns a bytes sequence prefixed by a length-encoded integer and the remaining bytes.
+#[inline]
+pub(crate) fn decode_lenenc_bytes(bytes: &[u8]) -> crate::Result<(&[u8], &[u8])> {
+  let (len, rest) = decode_lenenc_int(bytes)?;
+  let len = usize::try_from(len)?;
+  rest.split_at_checked(len).ok_or_else(|| MysqlError::UnexpectedDatabasePacketBytes.into())
+}
+
+/// Returns a length-encoded integer and the remaining bytes.
+#[inline]
+pub(crate) fn decode_lenenc_int(bytes: &[u8]) -> crate::Result<(u64, &[u8])> {
+  Ok(match bytes {
+    [a @ 0..=250, rest @ ..] => (u64::from(*a), rest),
+    [252, a, b, rest @ ..] => (u16::from_le_bytes([*a, *b]).into(), rest),
+    [253, a, b, c, rest @ ..] => (u32::from_le_bytes([*a, *b, *c, 0]).into(), rest),
+    [254, a, b, c, d, e, f, g, h, rest @ ..] => {
+      (u64::from_le_bytes([*a, *b, *c, *d, *e, *f, *g, *h]), rest)
+    }
+    _ => return Err(MysqlError::UnexpectedDatabasePacketBytes.into()),
+  })
+}
+
+/// Status flags of an `EOF` packet, which is sent after column definitions and rows.
+#[inline]
+pub(crate) fn eof_status(bytes: &[u8]) -> Option<u16> {
+  if let [254, _, _, a, b] = bytes {
+    Some(u16::from_le_bytes([*a, *b]))
+  } else {
+    None
+  }
+}
+
+#[inline]
+fn c_str(bytes: &[u8]) -> crate::Result<(&[u8], &[u8])> {
+  let idx = bytes
+    .iter()
+    .position(|el| *el == 0)
+    .ok_or_else(|| crate::Error::from(MysqlError::UnexpectedDatabasePacketBytes))?;
+  Ok((bytes.get(..idx).unwrap_or_default(), bytes.get(idx.wrapping_add(1)..).unwrap_or_default()))
+}
+
+#[inline]
+fn nonce(bytes: &[u8], prefix: &[u8]) -> crate::Result<[u8; 20]> {
+  let mut rslt = [0; 20];
+  let (Some((lhs, rhs)), Some(suffix)) =
+    (rslt.split_at_mut_checked(prefix.len()), bytes.get(..20usize.saturating_sub(prefix.len())))
+  else {
+    return Err(MysqlError::UnexpectedDatabasePacketBytes.into());
+  };
+  lhs.copy_from_slice(prefix);
+  rhs.copy_from_slice(suffix);
+  Ok(rslt)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::mysql::{
+    packet::{column, eof_status, AuthPlugin, Handshake, OkPacket, PrepareOk},
+    Ty,
+  };
+  use alloc::vec::Vec;
+
+  const NONCE: [u8; 20] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20];
+
+  #[test]
+  fn parses_column() {
+    let mut bytes = Vec::new();
+    for elem in ["def", "s", "t", "t", "abc", "abc"] {
+      bytes.push(u8::try_from(elem.len()).unwrap());
+      bytes.extend_from_slice(elem.as_bytes());
+    }
+    bytes.extend_from_slice(&[12, 45, 0, 11, 0, 0, 0, 8, 32, 0, 0, 0, 0]);
+    let column = column(&bytes).unwrap();
+    assert!(column.is_unsigned);
+    assert_eq!(column.name.as_str(), "abc");
+    assert_eq!(column.ty, Ty::LongLong);
+  }
+
+  #[test]
+  fn parses_eof() {
+    assert_eq!(eof_status(&[254, 0, 0, 8, 0]), Some(8));
+    assert_eq!(eof_status(&[254, 0, 0, 8, 0, 0]), None);
+  }
+
+  #[test]
+  fn parses_handshake() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"\n8.0.0\0\x01\0\0\0");
+    bytes.extend_from_slice(&NONCE[..8]);
+    bytes.extend_from_slice(&[0, 255, 255, 45, 2, 0, 255, 255, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    bytes.extend_from_slice(&NONCE[8..]);
+    bytes.extend_from_slice(b"\0caching_sha2_password\0");
+    assert_eq!(
+      Handshake::try_from(bytes.as_slice()).unwrap(),
+      Handshake {
+        auth_plugin: Some(AuthPlugin::CachingSha2Password),
+        capabilities: u32::MAX,
+        nonce: NONCE
+      }
+    );
+  }
+
+  #[test]
+  fn parses_ok() {
+    assert_eq!(
+      OkPacket::try_from(&[0, 252, 0, 1, 0, 8, 0, 0, 0][..]).unwrap(),
+      OkPacket { affected_rows: 256, status: 8 }
+    );
+  }
+
+  #[test]
+  fn parses_prepare_ok() {
+    assert_eq!(
+      PrepareOk::try_from(&[0, 1, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0][..]).unwrap(),
+      PrepareOk { columns_len: 2, params_len: 3, stmt_id: 1 }
+    );
+  }
+}
@@ -0,0 +1,297 @@
+use crate::{
+  database::{
+    client::mysql::{statements::statement::Statement, Config, EncodeValue, Mysql, MysqlError, Ty},
+    RecordValues,
+  },
+  misc::FilledBufferWriter,
+};
+
+pub(crate) const CLIENT_CONNECT_WITH_DB: u32 = 1 << 3;
+pub(crate) const CLIENT_LONG_PASSWORD: u32 = 1 << 0;
+pub(crate) const CLIENT_MULTI_RESULTS: u32 = 1 << 17;
+pub(crate) const CLIENT_MULTI_STATEMENTS: u32 = 1 << 16;
+pub(crate) const CLIENT_PLUGIN_AUTH: u32 = 1 << 19;
+pub(crate) const CLIENT_PROTOCOL_41: u32 = 1 << 9;
+pub(crate) const CLIENT_PS_MULTI_RESULTS: u32 = 1 << 18;
+pub(crate) const CLIENT_SECURE_CONNECTION: u32 = 1 << 15;
+pub(crate) const CLIENT_SSL: u32 = 1 << 11;
+pub(crate) const CLIENT_TRANSACTIONS: u32 = 1 << 13;
+pub(crate) const SERVER_MORE_RESULTS_EXISTS: u16 = 1 << 3;
+
+/// Capabilities requested by the client. Servers that don't support a capability simply ignore
+/// it.
+pub(crate) const CAPABILITIES: u32 = CLIENT_CONNECT_WITH_DB
+  | CLIENT_LONG_PASSWORD
+  | CLIENT_MULTI_RESULTS
+  | CLIENT_MULTI_STATEMENTS
+  | CLIENT_PLUGIN_AUTH
+  | CLIENT_PROTOCOL_41
+  | CLIENT_PS_MULTI_RESULTS
+  | CLIENT_SECURE_CONNECTION
+  | CLIENT_TRANSACTIONS;
+
+const COM_QUERY: u8 = 3;
+const COM_STMT_CLOSE: u8 = 25;
+const COM_STMT_EXECUTE: u8 = 23;
+const COM_STMT_PREPARE: u8 = 22;
+const MAX_PACKET_LEN: u32 = 0xFF_FF_FF;
+/// `utf8mb4_general_ci`, which is supported by MySQL and MariaDB.
+const UTF8MB4_GENERAL_CI: u8 = 45;
+
+/// Bytes that are sent after an authentication request, which can be a scramble or a plain
+/// text password.
+#[inline]
+pub(crate) fn auth_data(
+  data: &[u8],
+  fbw: &mut FilledBufferWriter<'_>,
+  seq: u8,
+) -> crate::Result<()> {
+  write_packet(fbw, seq, |local_fbw| local_fbw.extend_from_slice(data))
+}
+
+#[inline]
+pub(crate) fn encode_lenenc_int(fbw: &mut FilledBufferWriter<'_>, n: u64) -> crate::Result<()> {
+  if let Ok(elem @ 0..=250) = u8::try_from(n) {
+    fbw._extend_from_byte(elem)
+  } else if let Ok(elem) = u16::try_from(n) {
+    fbw._extend_from_slices([&[252][..], &elem.to_le_bytes()])
+  } else if n <= 0xFF_FF_FF {
+    let [a, b, c, ..] = n.to_le_bytes();
+    fbw._extend_from_slices([&[253][..], &[a, b, c]])
+  } else {
+    fbw._extend_from_slices([&[254][..], &n.to_le_bytes()])
+  }
+}
+
+#[inline]
+pub(crate) fn handshake_response(
+  (auth_plugin, auth_response): (&[u8], &[u8]),
+  capabilities: u32,
+  config: &Config<'_>,
+  fbw: &mut FilledBufferWriter<'_>,
+  seq: u8,
+) -> crate::Result<()> {
+  write_packet(fbw, seq, |local_fbw| {
+    write_capabilities(capabilities, local_fbw)?;
+    local_fbw._extend_from_slice_c(config.user.as_bytes())?;
+    local_fbw._extend_from_byte(u8::try_from(auth_response.len())?)?;
+    local_fbw.extend_from_slice(auth_response)?;
+    if capabilities & CLIENT_CONNECT_WITH_DB == CLIENT_CONNECT_WITH_DB {
+      local_fbw._extend_from_slice_c(config.db.as_bytes())?;
+    }
+    local_fbw._extend_from_slice_c(auth_plugin)?;
+    Ok(())
+  })
+}
+
+/// Password sent in plain text after a full authentication request, which should only happen
+/// in encrypted connections.
+#[inline]
+pub(crate) fn plain_text_password(
+  fbw: &mut FilledBufferWriter<'_>,
+  password: &[u8],
+  seq: u8,
+) -> crate::Result<()> {
+  write_packet(fbw, seq, |local_fbw| local_fbw._extend_from_slice_c(password))
+}
+
+#[inline]
+pub(crate) fn query(cmd: &[u8], fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  write_packet(fbw, 0, |local_fbw| {
+    local_fbw._extend_from_byte(COM_QUERY)?;
+    local_fbw.extend_from_slice(cmd)
+  })
+}
+
+#[inline]
+pub(crate) fn ssl_request(
+  capabilities: u32,
+  fbw: &mut FilledBufferWriter<'_>,
+  seq: u8,
+) -> crate::Result<()> {
+  write_packet(fbw, seq, |local_fbw| write_capabilities(capabilities, local_fbw))
+}
+
+#[inline]
+pub(crate) fn stmt_close(fbw: &mut FilledBufferWriter<'_>, stmt_id: u32) -> crate::Result<()> {
+  write_packet(fbw, 0, |local_fbw| {
+    local_fbw._extend_from_byte(COM_STMT_CLOSE)?;
+    local_fbw.extend_from_slice(&stmt_id.to_le_bytes())
+  })
+}
+
+// | Command | Statement ID | Flags | Iterations | NULL bitmap | Bound | Types | Values |
+// |    1    |      4       |   1   |     4      |   (n+7)/8   |   1   |  2*n  |   x    |
+#[inline]
+pub(crate) fn stmt_execute<E, RV>(
+  fbw: &mut FilledBufferWriter<'_>,
+  mut rv: RV,
+  stmt: &Statement<'_>,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+  RV: RecordValues<Mysql<E>>,
+{
+  write_packet(fbw, 0, |local_fbw| {
+    local_fbw._extend_from_byte(COM_STMT_EXECUTE)?;
+    local_fbw._extend_from_slices([&stmt.id.to_le_bytes()[..], &[0], &1u32.to_le_bytes()])?;
+    let rv_len = rv.len();
+    if rv_len == 0 {
+      return Ok(());
+    }
+    let null_bitmap_start = local_fbw._len();
+    for _ in 0..rv_len.div_ceil(8) {
+      local_fbw._extend_from_byte(0)?;
+    }
+    local_fbw._extend_from_byte(1)?;
+    let tys_start = local_fbw._len();
+    for _ in 0..rv_len {
+      local_fbw.extend_from_slice(&[0, 0])?;
+    }
+    let _ = rv.encode_values(
+      &mut 0usize,
+      &mut EncodeValue::new(local_fbw),
+      |_, _| 0,
+      |idx, local_ev, is_null, elem_len| {
+        let (is_unsigned, ty) = local_ev.take_ty();
+        let (local_ty, must_prefix) =
+          if is_null { (Ty::Null, false) } else { (ty, ty.fixed_len().is_none()) };
+        let bytes = local_ev.fbw()._curr_bytes_mut();
+        if is_null {
+          if let Some(elem) = bytes.get_mut(null_bitmap_start.wrapping_add(*idx / 8)) {
+            *elem |= 1 << (*idx % 8);
+          }
+        }
+        if let Some([a, b, ..]) = bytes.get_mut(tys_start.wrapping_add(idx.wrapping_mul(2))..) {
+          *a = local_ty.into();
+          *b = if is_unsigned { 128 } else { 0 };
+        }
+        *idx = idx.wrapping_add(1);
+        if !must_prefix {
+          return 0;
+        }
+        // The length is written after the value and then both are swapped
+        let end = local_ev.fbw()._len();
+        let start = end.wrapping_sub(elem_len);
+        let elem_len_u64 = u64::try_from(elem_len).unwrap_or(u64::MAX);
+        let _rslt = encode_lenenc_int(local_ev.fbw(), elem_len_u64);
+        let prefix_len = local_ev.fbw()._len().wrapping_sub(end);
+        if let Some(elem) = local_ev.fbw()._curr_bytes_mut().get_mut(start..) {
+          elem.rotate_right(prefix_len);
+        }
+        prefix_len
+      },
+    )?;
+    Ok::<_, E>(())
+  })
+}
+
+#[inline]
+pub(crate) fn stmt_prepare(cmd: &[u8], fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  write_packet(fbw, 0, |local_fbw| {
+    local_fbw._extend_from_byte(COM_STMT_PREPARE)?;
+    local_fbw.extend_from_slice(cmd)
+  })
+}
+
+/// Payloads are not split into multiple packets, as such, commands of 16 MiB or more are
+/// rejected with [`MysqlError::VeryLargePacket`].
+//
+// | Payload length | Sequence ID | Payload |
+// |       3        |      1      |    x    |
+#[inline]
+pub(crate) fn write_packet<'buffer, E>(
+  fbw: &mut FilledBufferWriter<'buffer>,
+  seq: u8,
+  cb: impl FnOnce(&mut FilledBufferWriter<'buffer>) -> Result<(), E>,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+{
+  let start = fbw._len();
+  fbw.extend_from_slice(&[0, 0, 0, seq])?;
+  cb(fbw)?;
+  let len = u32::try_from(fbw._len().wrapping_sub(start).wrapping_sub(4)).unwrap_or(u32::MAX);
+  if len >= MAX_PACKET_LEN {
+    return Err(E::from(MysqlError::VeryLargePacket.into()));
+  }
+  if let Some([a0, b0, c0, ..]) = fbw._curr_bytes_mut().get_mut(start..) {
+    let [a1, b1, c1, _] = len.to_le_bytes();
+    *a0 = a1;
+    *b0 = b1;
+    *c0 = c1;
+  }
+  Ok(())
+}
+
+#[inline]
+fn write_capabilities(capabilities: u32, fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  fbw._extend_from_slices([
+    &capabilities.to_le_bytes()[..],
+    &MAX_PACKET_LEN.to_le_bytes(),
+    &[UTF8MB4_GENERAL_CI],
+    &[0; 23],
+  ])
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::mysql::{
+      protocol::{encode_lenenc_int, stmt_execute, write_packet},
+      statements::statement::Statement,
+      MysqlError,
+    },
+    misc::{filled_buffer::FilledBuffer, FilledBufferWriter},
+  };
+  use alloc::vec;
+
+  #[test]
+  fn encodes_lenenc_ints() {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    for n in [0, 250, 251, 65_535, 65_536, 16_777_216] {
+      encode_lenenc_int(&mut fbw, n).unwrap();
+    }
+    assert_eq!(
+      fbw._curr_bytes(),
+      &[0, 250, 252, 251, 0, 252, 255, 255, 253, 0, 0, 1, 254, 0, 0, 0, 1, 0, 0, 0, 0]
+    );
+  }
+
+  #[test]
+  fn encodes_stmt_execute() {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    let stmt = Statement::new(&[], 7, 3);
+    stmt_execute::<crate::Error, _>(&mut fbw, (1u16, None::<i32>, "ab"), &stmt).unwrap();
+    assert_eq!(
+      fbw._curr_bytes(),
+      &[23, 0, 0, 0, 23, 7, 0, 0, 0, 0, 1, 0, 0, 0, 2, 1, 2, 128, 6, 0, 253, 0, 1, 0, 2, 97, 98]
+    );
+  }
+
+  #[test]
+  fn writes_packet() {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    write_packet(&mut fbw, 3, |local_fbw| local_fbw.extend_from_slice(&[1, 2])).unwrap();
+    assert_eq!(fbw._curr_bytes(), &[2, 0, 0, 3, 1, 2]);
+  }
+
+  #[test]
+  fn writes_packet_with_max_len() {
+    let payload = vec![0; 0xFF_FF_FF];
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    let rslt = write_packet(&mut fbw, 0, |local_fbw| {
+      local_fbw.extend_from_slice(payload.get(1..).unwrap_or_default())
+    });
+    rslt.unwrap();
+    assert_eq!(fbw._curr_bytes().get(..4), Some(&[254, 255, 255, 0][..]));
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    let rslt = write_packet(&mut fbw, 0, |local_fbw| local_fbw.extend_from_slice(&payload));
+    assert!(matches!(rslt, Err(crate::Error::MysqlError(MysqlError::VeryLargePacket))));
+  }
+}
@@ -0,0 +1,187 @@
+use crate::{
+  database::{
+    client::mysql::{
+      packet::decode_lenenc_int, statements::statement::Statement, DecodeValue, Mysql, MysqlError,
+    },
+    Database, ValueIdent,
+  },
+  misc::{Vector, _unlikely_dflt, _unlikely_elem},
+};
+use core::{marker::PhantomData, ops::Range};
+
+/// Record
+#[derive(Debug)]
+pub struct Record<'exec, E> {
+  pub(crate) bytes: &'exec [u8],
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  pub(crate) stmt: Statement<'exec>,
+  pub(crate) values_bytes_offsets: &'exec [(bool, Range<usize>)],
+}
+
+impl<'exec, E> Record<'exec, E> {
+  #[inline]
+  pub(crate) fn _new(
+    bytes: &'exec [u8],
+    stmt: Statement<'exec>,
+    values_bytes_offsets: &'exec [(bool, Range<usize>)],
+  ) -> Self {
+    Self { bytes, phantom: PhantomData, stmt, values_bytes_offsets }
+  }
+
+  // | Header | NULL bitmap | Values |
+  // |   1    | (n+7+2)/8   |   x    |
+  //
+  // Offsets are relative to `bytes`, which must contain the packet whose payload is delimited by
+  // `payload_range`.
+  pub(crate) fn parse(
+    bytes: &'exec [u8],
+    payload_range: Range<usize>,
+    stmt: Statement<'exec>,
+    values_bytes_offsets: &'exec mut Vector<(bool, Range<usize>)>,
+  ) -> crate::Result<Self> {
+    let values_bytes_offsets_start = values_bytes_offsets.len();
+    let columns_len = stmt.columns.len();
+    let null_bitmap_len = columns_len.wrapping_add(9) / 8;
+    let payload = bytes.get(payload_range.clone()).unwrap_or_default();
+    let Some(([0, null_bitmap @ ..], mut values)) =
+      payload.split_at_checked(null_bitmap_len.wrapping_add(1))
+    else {
+      return Err(MysqlError::InvalidMysqlRecord.into());
+    };
+    let mut curr_value_offset = payload_range.start.wrapping_add(1).wrapping_add(null_bitmap_len);
+    for (idx, column) in stmt.columns.iter().enumerate() {
+      let bit_idx = idx.wrapping_add(2);
+      let byte = null_bitmap.get(bit_idx / 8).copied().unwrap_or_default();
+      if byte & (1 << (bit_idx % 8)) != 0 {
+        values_bytes_offsets.push((true, curr_value_offset..curr_value_offset))?;
+        continue;
+      }
+      let (prefix_len, value_len) = if let Some(elem) = column.ty.fixed_len() {
+        (0, elem)
+      } else {
+        let (len, rest) = decode_lenenc_int(values)?;
+        (values.len().wrapping_sub(rest.len()), usize::try_from(len)?)
+      };
+      let begin = curr_value_offset.wrapping_add(prefix_len);
+      let end = begin.wrapping_add(value_len);
+      values = values
+        .get(prefix_len.wrapping_add(value_len)..)
+        .ok_or_else(|| crate::Error::from(MysqlError::InvalidMysqlRecord))?;
+      values_bytes_offsets.push((false, begin..end))?;
+      curr_value_offset = end;
+    }
+    Ok(Self {
+      bytes,
+      phantom: PhantomData,
+      stmt,
+      values_bytes_offsets: values_bytes_offsets
+        .get(values_bytes_offsets_start..)
+        .unwrap_or_else(_unlikely_dflt),
+    })
+  }
+}
+
+impl<'exec, E> crate::database::Record<'exec> for Record<'exec, E>
+where
+  E: From<crate::Error>,
+{
+  type Database = Mysql<E>;
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.values_bytes_offsets.len()
+  }
+
+  #[inline]
+  fn value<CI>(&self, ci: CI) -> Option<<Self::Database as Database>::DecodeValue<'exec>>
+  where
+    CI: ValueIdent<Self>,
+  {
+    let idx = ci.idx(self)?;
+    let (is_null, range) = match self.values_bytes_offsets.get(idx) {
+      None => return _unlikely_elem(None),
+      Some(elem) => elem,
+    };
+    if *is_null {
+      None
+    } else {
+      let column = match self.stmt.column(idx) {
+        None => return _unlikely_elem(None),
+        Some(elem) => elem,
+      };
+      let bytes = match self.bytes.get(range.clone()) {
+        None => return _unlikely_elem(None),
+        Some(elem) => elem,
+      };
+      Some(DecodeValue::new(bytes, column.ty))
+    }
+  }
+}
+
+impl<'exec, E> ValueIdent<Record<'exec, E>> for str {
+  #[inline]
+  fn idx(&self, input: &Record<'exec, E>) -> Option<usize> {
+    input.stmt.columns.iter().position(|column| column.name.as_str() == self)
+  }
+}
+
+impl<E> PartialEq for Record<'_, E> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.bytes == other.bytes
+      && self.phantom == other.phantom
+      && self.stmt == other.stmt
+      && self.values_bytes_offsets == other.values_bytes_offsets
+  }
+}
+
+mod array {
+  use crate::{
+    database::{client::mysql::Mysql, FromRecord, Record},
+    misc::{from_utf8_basic, into_rslt, ArrayString},
+  };
+
+  impl<E, const N: usize> FromRecord<Mysql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn from_record(
+      record: &crate::database::client::mysql::record::Record<'_, E>,
+    ) -> Result<Self, E> {
+      Ok(from_utf8_basic(into_rslt(record.value(0))?.bytes()).map_err(From::from)?.try_into()?)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::{
+      client::mysql::{
+        statements::statement::Statement,
+        tests::{column0, column1, column2},
+        DecodeValue, Record,
+      },
+      Record as _,
+    },
+    misc::Vector,
+  };
+
+  #[test]
+  fn returns_correct_values() {
+    let columns = &[column0(), column1(), column2()];
+    let bytes = &[9, 9, 0, 16, 2, 1, 2, 3, 4, 5, 6];
+    let mut values_bytes_offsets = Vector::new();
+    let stmt = Statement::new(columns, 0, 0);
+    let record =
+      Record::<crate::Error>::parse(bytes, 2..bytes.len(), stmt.clone(), &mut values_bytes_offsets)
+        .unwrap();
+    assert_eq!(record, Record::_new(bytes, stmt, &[(false, 5..7), (false, 7..11), (true, 11..11)]));
+    assert_eq!(record.len(), 3);
+    assert_eq!(record.value(0), Some(DecodeValue::new(&[1, 2][..], column0().ty)));
+    assert_eq!(record.value(1), Some(DecodeValue::new(&[3, 4, 5, 6][..], column1().ty)));
+    assert_eq!(record.value(2), None);
+    assert_eq!(record.value("b"), Some(DecodeValue::new(&[3, 4, 5, 6][..], column1().ty)));
+  }
+}
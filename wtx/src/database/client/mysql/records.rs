@@ -0,0 +1,122 @@
+use crate::database::client::mysql::{statements::statement::Statement, Mysql, Record};
+use core::{marker::PhantomData, ops::Range};
+
+/// Records
+#[derive(Debug)]
+pub struct Records<'exec, E> {
+  pub(crate) bytes: &'exec [u8],
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  /// Each element represents a record and the end offset of its values in
+  /// `values_bytes_offsets`.
+  pub(crate) records_values_offsets: &'exec [usize],
+  pub(crate) stmt: Statement<'exec>,
+  /// Each element represents a value and an offset of `bytes`.
+  pub(crate) values_bytes_offsets: &'exec [(bool, Range<usize>)],
+}
+
+impl<'exec, E> Records<'exec, E> {
+  #[inline]
+  pub(crate) fn _new(
+    bytes: &'exec [u8],
+    records_values_offsets: &'exec [usize],
+    stmt: Statement<'exec>,
+    values_bytes_offsets: &'exec [(bool, Range<usize>)],
+  ) -> Self {
+    Self { bytes, phantom: PhantomData, records_values_offsets, stmt, values_bytes_offsets }
+  }
+}
+
+impl<'exec, E> crate::database::Records<'exec> for Records<'exec, E>
+where
+  E: From<crate::Error>,
+{
+  type Database = Mysql<E>;
+
+  #[inline]
+  fn get(&self, record_idx: usize) -> Option<Record<'exec, E>> {
+    let range = match self.records_values_offsets.get(..record_idx.wrapping_add(1))? {
+      [] => return None,
+      &[to] => 0..to,
+      &[.., from, to] => from..to,
+    };
+    Some(Record {
+      bytes: self.bytes,
+      phantom: PhantomData,
+      stmt: self.stmt.clone(),
+      values_bytes_offsets: self.values_bytes_offsets.get(range)?,
+    })
+  }
+
+  #[inline]
+  fn iter(&self) -> impl Iterator<Item = Record<'exec, E>> {
+    (0..self.len()).filter_map(|idx| self.get(idx))
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.records_values_offsets.len()
+  }
+}
+
+impl<E> Default for Records<'_, E> {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      bytes: &[],
+      phantom: PhantomData,
+      records_values_offsets: &[],
+      stmt: Statement::default(),
+      values_bytes_offsets: &[],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::{
+      client::mysql::{
+        statements::statement::Statement,
+        tests::{column0, column1},
+        DecodeValue, Record, Records,
+      },
+      Record as _, Records as _,
+    },
+    misc::Vector,
+  };
+
+  #[test]
+  fn returns_correct_values() {
+    let columns = &[column0(), column1()];
+    let bytes = &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 8, 2, 0, 0, 0];
+    let stmt = Statement::new(columns, 0, 0);
+    let mut records_values_offsets = Vector::new();
+    let mut values_bytes_offsets = Vector::new();
+    let _ =
+      Record::<crate::Error>::parse(bytes, 0..8, stmt.clone(), &mut values_bytes_offsets).unwrap();
+    records_values_offsets.push(values_bytes_offsets.len()).unwrap();
+    let _ =
+      Record::<crate::Error>::parse(bytes, 8..16, stmt.clone(), &mut values_bytes_offsets).unwrap();
+    records_values_offsets.push(values_bytes_offsets.len()).unwrap();
+
+    let records = Records::<crate::Error>::_new(
+      bytes,
+      &records_values_offsets,
+      stmt.clone(),
+      &values_bytes_offsets,
+    );
+    assert_eq!(records.len(), 2);
+    assert_eq!(records.records_values_offsets, &[2, 4]);
+
+    let first_record = records.get(0).unwrap();
+    assert_eq!(first_record, Record::_new(bytes, stmt.clone(), &[(false, 3..4), (false, 4..8)]));
+    assert_eq!(first_record.value(0).unwrap(), DecodeValue::new(&[1], column0().ty));
+    assert_eq!(first_record.value(1).unwrap(), DecodeValue::new(&[0, 0, 0, 0], column1().ty));
+
+    let second_record = records.get(1).unwrap();
+    assert_eq!(second_record, Record::_new(bytes, stmt, &[(false, 11..12), (false, 12..16)]));
+    assert_eq!(second_record.value(0).unwrap(), DecodeValue::new(&[8], column0().ty));
+
+    assert_eq!(records.iter().count(), 2);
+  }
+}
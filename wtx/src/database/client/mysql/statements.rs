@@ -0,0 +1,155 @@
+pub(crate) mod column;
+pub(crate) mod statement;
+pub(crate) mod statement_builder;
+pub(crate) mod statements_misc;
+
+use crate::misc::{BlocksDeque, Rng, Vector, _random_state};
+use foldhash::fast::FixedState;
+use hashbrown::HashMap;
+
+/// Statements
+#[derive(Debug)]
+pub struct Statements {
+  /// Server identifiers of statements that were removed from the local cache but not yet
+  /// deallocated by the server.
+  evicted: Vector<u32>,
+  max_stmts: usize,
+  rs: FixedState,
+  stmts: BlocksDeque<column::Column, statements_misc::StatementsMisc>,
+  stmts_indcs: HashMap<u64, usize>,
+}
+
+impl Statements {
+  #[inline]
+  pub(crate) fn new<RNG>(max_stmts: usize, rng: RNG) -> Self
+  where
+    RNG: Rng,
+  {
+    Self {
+      evicted: Vector::new(),
+      max_stmts: max_stmts.max(1),
+      rs: _random_state(rng),
+      stmts: BlocksDeque::new(),
+      stmts_indcs: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub(crate) fn with_capacity<RNG>(
+    columns: usize,
+    max_stmts: usize,
+    rng: RNG,
+    stmts: usize,
+  ) -> crate::Result<Self>
+  where
+    RNG: Rng,
+  {
+    Ok(Self {
+      evicted: Vector::new(),
+      max_stmts: max_stmts.max(1),
+      rs: _random_state(rng),
+      stmts: BlocksDeque::with_capacity(stmts, columns)?,
+      stmts_indcs: HashMap::with_capacity(stmts),
+    })
+  }
+
+  #[inline]
+  pub(crate) fn builder(&mut self) -> crate::Result<statement_builder::StatementBuilder<'_>> {
+    if self.stmts.blocks_len() >= self.max_stmts {
+      let to_remove = (self.max_stmts / 2).max(1);
+      for _ in 0..to_remove {
+        if let Some(elem) = self.stmts.pop_front() {
+          self.evicted.push(elem.id)?;
+        }
+      }
+      self.stmts_indcs.retain(|_, value| {
+        if *value < to_remove {
+          return false;
+        }
+        *value = value.wrapping_sub(to_remove);
+        true
+      })
+    }
+    Ok(statement_builder::StatementBuilder::new(&mut self.stmts, &mut self.stmts_indcs))
+  }
+
+  #[inline]
+  pub(crate) fn clear(&mut self) {
+    let Self { evicted, max_stmts: _, rs: _, stmts, stmts_indcs } = self;
+    evicted.clear();
+    stmts.clear();
+    stmts_indcs.clear();
+  }
+
+  #[inline]
+  pub(crate) fn evicted_mut(&mut self) -> &mut Vector<u32> {
+    &mut self.evicted
+  }
+
+  #[inline]
+  pub(crate) fn get_by_idx(&self, idx: usize) -> Option<statement::Statement<'_>> {
+    let stmt = self.stmts.get(idx)?;
+    Some(statement::Statement::new(stmt.data, stmt.misc.id, stmt.misc.params_len))
+  }
+
+  #[inline]
+  pub(crate) fn get_by_stmt_hash(&self, stmt_hash: u64) -> Option<statement::Statement<'_>> {
+    self.get_by_idx(*self.stmts_indcs.get(&stmt_hash)?)
+  }
+
+  #[inline]
+  pub(crate) fn hasher_mut(&mut self) -> &mut FixedState {
+    &mut self.rs
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::mysql::{
+      statements::statements_misc::StatementsMisc,
+      tests::{column0, column1, column2},
+      Statements,
+    },
+    misc::{simple_seed, Xorshift64},
+  };
+
+  // FIXME(MIRI): See the `two_statements` test of PostgreSQL.
+  #[cfg_attr(miri, ignore)]
+  #[test]
+  fn evicts_old_statements() {
+    let mut stmts = Statements::new(2, &mut Xorshift64::from(simple_seed()));
+
+    let mut builder = stmts.builder().unwrap();
+    let _ = builder.expand(2).unwrap();
+    builder.inserted_elements()[0] = column0();
+    builder.inserted_elements()[1] = column1();
+    let _ = builder.build(123, StatementsMisc::new(1, 0)).unwrap();
+    {
+      let stmt = stmts.get_by_stmt_hash(123).unwrap();
+      assert_eq!(stmt.columns, &[column0(), column1()]);
+      assert_eq!(stmt.id, 1);
+    }
+
+    let mut builder = stmts.builder().unwrap();
+    let _ = builder.expand(1).unwrap();
+    builder.inserted_elements()[0] = column2();
+    let _ = builder.build(456, StatementsMisc::new(2, 3)).unwrap();
+    assert!(stmts.evicted_mut().is_empty());
+
+    let _ = stmts.builder().unwrap().build(789, StatementsMisc::new(3, 0)).unwrap();
+    assert_eq!(stmts.evicted_mut().as_slice(), &[1]);
+    assert_eq!(stmts.get_by_stmt_hash(123), None);
+    {
+      let stmt = stmts.get_by_stmt_hash(456).unwrap();
+      assert_eq!(stmt.columns, &[column2()]);
+      assert_eq!(stmt.id, 2);
+      assert_eq!(stmt.params_len, 3);
+    }
+    assert_eq!(stmts.get_by_stmt_hash(789).unwrap().columns, &[]);
+
+    stmts.clear();
+    assert!(stmts.evicted_mut().is_empty());
+    assert_eq!(stmts.get_by_stmt_hash(456), None);
+  }
+}
@@ -0,0 +1,15 @@
+use crate::database::{client::mysql::Ty, Identifier};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Column {
+  pub(crate) is_unsigned: bool,
+  pub(crate) name: Identifier,
+  pub(crate) ty: Ty,
+}
+
+impl Column {
+  #[inline]
+  pub(crate) fn new(is_unsigned: bool, name: Identifier, ty: Ty) -> Self {
+    Self { is_unsigned, name, ty }
+  }
+}
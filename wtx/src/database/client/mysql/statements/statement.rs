@@ -0,0 +1,26 @@
+use crate::database::client::mysql::statements::column::Column;
+
+/// ```sql
+/// SELECT a,b,c,d FROM table WHERE e = ? AND f = ?
+/// ```
+///
+/// The columns are "a", "b", "c", "d" and the number of parameters is 2. `id` is the identifier
+/// that the server assigned to the prepared statement.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Statement<'stmts> {
+  pub(crate) columns: &'stmts [Column],
+  pub(crate) id: u32,
+  pub(crate) params_len: u16,
+}
+
+impl<'stmts> Statement<'stmts> {
+  #[inline]
+  pub(crate) const fn new(columns: &'stmts [Column], id: u32, params_len: u16) -> Self {
+    Self { columns, id, params_len }
+  }
+
+  #[inline]
+  pub(crate) fn column(&self, idx: usize) -> Option<&Column> {
+    self.columns.get(idx)
+  }
+}
@@ -0,0 +1,12 @@
+#[derive(Debug)]
+pub(crate) struct StatementsMisc {
+  pub(crate) id: u32,
+  pub(crate) params_len: u16,
+}
+
+impl StatementsMisc {
+  #[inline]
+  pub(crate) fn new(id: u32, params_len: u16) -> Self {
+    Self { id, params_len }
+  }
+}
@@ -0,0 +1,78 @@
+create_enum! {
+  /// Column type
+  #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+  pub enum Ty<u8> {
+    /// `DECIMAL`
+    Decimal = (0),
+    /// `TINYINT`
+    Tiny = (1),
+    /// `SMALLINT`
+    Short = (2),
+    /// `INT`
+    Long = (3),
+    /// `FLOAT`
+    Float = (4),
+    /// `DOUBLE`
+    Double = (5),
+    /// `NULL`
+    Null = (6),
+    /// `TIMESTAMP`
+    Timestamp = (7),
+    /// `BIGINT`
+    LongLong = (8),
+    /// `MEDIUMINT`
+    Int24 = (9),
+    /// `DATE`
+    Date = (10),
+    /// `TIME`
+    Time = (11),
+    /// `DATETIME`
+    Datetime = (12),
+    /// `YEAR`
+    Year = (13),
+    /// Internal `DATE`
+    NewDate = (14),
+    /// `VARCHAR`
+    Varchar = (15),
+    /// `BIT`
+    Bit = (16),
+    /// `JSON`
+    Json = (245),
+    /// Precision math `DECIMAL`
+    NewDecimal = (246),
+    /// `ENUM`
+    Enum = (247),
+    /// `SET`
+    Set = (248),
+    /// `TINYBLOB` or `TINYTEXT`
+    TinyBlob = (249),
+    /// `MEDIUMBLOB` or `MEDIUMTEXT`
+    MediumBlob = (250),
+    /// `LONGBLOB` or `LONGTEXT`
+    LongBlob = (251),
+    /// `BLOB` or `TEXT`
+    Blob = (252),
+    /// `VARCHAR` or `VARBINARY`
+    VarString = (253),
+    /// `CHAR` or `BINARY`
+    String = (254),
+    /// `GEOMETRY`
+    Geometry = (255),
+  }
+}
+
+impl Ty {
+  /// The number of bytes of types that have a fixed binary representation. Other types are
+  /// prefixed by their length.
+  #[inline]
+  pub(crate) const fn fixed_len(self) -> Option<usize> {
+    Some(match self {
+      Self::Null => 0,
+      Self::Tiny => 1,
+      Self::Short | Self::Year => 2,
+      Self::Float | Self::Int24 | Self::Long => 4,
+      Self::Double | Self::LongLong => 8,
+      _ => return None,
+    })
+  }
+}
@@ -0,0 +1,489 @@
+macro_rules! kani {
+  ($name:ident, $ty:ty) => {
+    #[cfg(kani)]
+    #[kani::proof]
+    fn $name(instance: $ty) {
+      let mut vec = &mut crate::misc::FilledBuffer::_new();
+      {
+        let mut fbw = crate::misc::FilledBufferWriter::new(0, &mut vec);
+        let mut ev = EncodeValue::new(&mut fbw);
+        Encode::<Mysql<crate::Error>>::encode(&instance, &mut ev).unwrap();
+        let (_, ty) = ev.take_ty();
+        let decoded: $ty =
+          Decode::<Mysql<crate::Error>>::decode(&DecodeValue::new(ev.fbw()._curr_bytes(), ty))
+            .unwrap();
+        assert_eq!(instance, decoded);
+      }
+      vec._clear();
+    }
+  };
+}
+
+macro_rules! test {
+  ($name:ident, $ty:ty, $instance:expr) => {
+    #[cfg(test)]
+    #[test]
+    fn $name() {
+      let vec = &mut crate::misc::filled_buffer::FilledBuffer::_new();
+      let mut fbw = crate::misc::FilledBufferWriter::new(0, vec);
+      let mut ev = EncodeValue::new(&mut fbw);
+      let instance: $ty = $instance;
+      Encode::<Mysql<crate::Error>>::encode(&instance, &mut ev).unwrap();
+      let (_, ty) = ev.take_ty();
+      let decoded: $ty =
+        Decode::<Mysql<crate::Error>>::decode(&DecodeValue::new(ev.fbw()._curr_bytes(), ty))
+          .unwrap();
+      assert_eq!(instance, decoded);
+    }
+  };
+}
+
+mod array {
+  use crate::{
+    database::{
+      client::mysql::{DecodeValue, EncodeValue, Mysql, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::{from_utf8_basic, ArrayString},
+  };
+
+  impl<E, const N: usize> Decode<'_, Mysql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(from_utf8_basic(dv.bytes()).map_err(Into::into)?.try_into()?)
+    }
+  }
+  impl<E, const N: usize> Encode<Mysql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(false, Ty::VarString);
+      ev.fbw().extend_from_slice(self.as_str().as_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E, const N: usize> Typed<Mysql<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::VarString;
+  }
+
+  test!(array_string, ArrayString<4>, ArrayString::try_from("123").unwrap());
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+  use crate::{
+    database::{
+      client::mysql::{DecodeValue, EncodeValue, Mysql, MysqlError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::_unlikely_elem,
+  };
+  use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+
+  impl<E> Decode<'_, Mysql<E>> for DateTime<Utc>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let naive = <NaiveDateTime as Decode<Mysql<E>>>::decode(dv)?;
+      Ok(Utc.from_utc_datetime(&naive))
+    }
+  }
+  impl<E, TZ> Encode<Mysql<E>> for DateTime<TZ>
+  where
+    E: From<crate::Error>,
+    TZ: TimeZone,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mysql<E>>::encode(&self.naive_utc(), ev)?;
+      ev.set_ty(false, Ty::Timestamp);
+      Ok(())
+    }
+  }
+  impl<E, TZ> Typed<Mysql<E>> for DateTime<TZ>
+  where
+    E: From<crate::Error>,
+    TZ: TimeZone,
+  {
+    const TY: Ty = Ty::Timestamp;
+  }
+
+  test!(datetime_utc, DateTime<Utc>, Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap());
+
+  // | Year | Month | Day |
+  // |  2   |   1   |  1  |
+  impl<E> Decode<'_, Mysql<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let (year, month, day) = match dv.bytes() {
+        [] => (0, 0, 0),
+        [a, b, c, d, ..] => (u16::from_le_bytes([*a, *b]), *c, *d),
+        _ => return Err(invalid(Ty::Date)),
+      };
+      NaiveDate::from_ymd_opt(year.into(), month.into(), day.into())
+        .ok_or_else(|| invalid(Ty::Date))
+    }
+  }
+  impl<E> Encode<Mysql<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(false, Ty::Date);
+      ev.fbw().extend_from_slice(&date_bytes(self)?)?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mysql<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Date;
+  }
+
+  test!(naive_date, NaiveDate, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+
+  // | Year | Month | Day | Hour | Minute | Second | Microsecond |
+  // |  2   |   1   |  1  |  1   |   1    |   1    |      4      |
+  impl<E> Decode<'_, Mysql<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let date = <NaiveDate as Decode<Mysql<E>>>::decode(dv)?;
+      let (hour, min, sec, micro) = match dv.bytes() {
+        [] | [_, _, _, _] => (0, 0, 0, 0),
+        [_, _, _, _, a, b, c] => (*a, *b, *c, 0),
+        [_, _, _, _, a, b, c, d, e, f, g] => (*a, *b, *c, u32::from_le_bytes([*d, *e, *f, *g])),
+        _ => return Err(invalid(Ty::Datetime)),
+      };
+      let time = NaiveTime::from_hms_micro_opt(hour.into(), min.into(), sec.into(), micro)
+        .ok_or_else(|| invalid(Ty::Datetime))?;
+      Ok(NaiveDateTime::new(date, time))
+    }
+  }
+  impl<E> Encode<Mysql<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(false, Ty::Datetime);
+      let [a, b, c, d] = date_bytes(&self.date())?;
+      let Ok(hour) = u8::try_from(self.hour()) else {
+        return _unlikely_elem(Err(invalid(Ty::Datetime)));
+      };
+      let Ok(min) = u8::try_from(self.minute()) else {
+        return _unlikely_elem(Err(invalid(Ty::Datetime)));
+      };
+      let Ok(sec) = u8::try_from(self.second()) else {
+        return _unlikely_elem(Err(invalid(Ty::Datetime)));
+      };
+      let [e, f, g, h] = (self.nanosecond() / 1_000).to_le_bytes();
+      ev.fbw().extend_from_slice(&[a, b, c, d, hour, min, sec, e, f, g, h])?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mysql<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Datetime;
+  }
+
+  test!(
+    naive_date_time,
+    NaiveDateTime,
+    NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_micro_opt(3, 4, 5, 6).unwrap()
+  );
+
+  #[inline]
+  fn date_bytes<E>(date: &NaiveDate) -> Result<[u8; 4], E>
+  where
+    E: From<crate::Error>,
+  {
+    let (Ok(year), Ok(month), Ok(day)) =
+      (u16::try_from(date.year()), u8::try_from(date.month()), u8::try_from(date.day()))
+    else {
+      return Err(invalid(Ty::Date));
+    };
+    let [a, b] = year.to_le_bytes();
+    Ok([a, b, month, day])
+  }
+
+  #[inline]
+  fn invalid<E>(expected: Ty) -> E
+  where
+    E: From<crate::Error>,
+  {
+    E::from(MysqlError::UnexpectedValueFromBytes { expected }.into())
+  }
+}
+
+mod collections {
+  use crate::{
+    database::{
+      client::mysql::{DecodeValue, EncodeValue, Mysql, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::from_utf8_basic,
+  };
+  use alloc::{string::String, vec::Vec};
+
+  // &[u8]
+
+  impl<'exec, E> Decode<'exec, Mysql<E>> for &'exec [u8]
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'exec>) -> Result<Self, E> {
+      Ok(dv.bytes())
+    }
+  }
+  impl<E> Encode<Mysql<E>> for &[u8]
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(false, Ty::Blob);
+      ev.fbw().extend_from_slice(self)?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mysql<E>> for &[u8]
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Blob;
+  }
+  test!(bytes, &[u8], &[1, 2, 3, 4]);
+
+  // Vec<u8>
+
+  impl<E> Decode<'_, Mysql<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(dv.bytes().into())
+    }
+  }
+  impl<E> Encode<Mysql<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mysql<E>>::encode(&self.as_slice(), ev)
+    }
+  }
+  impl<E> Typed<Mysql<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Blob;
+  }
+  test!(vec_bytes, Vec<u8>, alloc::vec![1, 2, 3, 4]);
+
+  // str
+
+  impl<E> Encode<Mysql<E>> for str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(false, Ty::VarString);
+      ev.fbw().extend_from_slice(self.as_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mysql<E>> for str
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::VarString;
+  }
+
+  // &str
+
+  impl<'exec, E> Decode<'exec, Mysql<E>> for &'exec str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'exec>) -> Result<Self, E> {
+      Ok(from_utf8_basic(dv.bytes()).map_err(crate::Error::from)?)
+    }
+  }
+  impl<E> Encode<Mysql<E>> for &str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mysql<E>>::encode(*self, ev)
+    }
+  }
+  impl<E> Typed<Mysql<E>> for &str
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::VarString;
+  }
+  test!(str, &str, "1234");
+
+  // String
+
+  impl<E> Decode<'_, Mysql<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      match from_utf8_basic(dv.bytes()).map_err(crate::Error::from) {
+        Ok(elem) => Ok(elem.into()),
+        Err(err) => Err(err.into()),
+      }
+    }
+  }
+  impl<E> Encode<Mysql<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Mysql<E>>::encode(self.as_str(), ev)
+    }
+  }
+  impl<E> Typed<Mysql<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::VarString;
+  }
+
+  kani!(string, String);
+}
+
+mod primitives {
+  use crate::{
+    database::{
+      client::mysql::{DecodeValue, EncodeValue, Mysql, MysqlError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::Usize,
+  };
+
+  // bool
+
+  impl<E> Decode<'_, Mysql<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let &[byte] = dv.bytes() else {
+        return Err(E::from(
+          MysqlError::UnexpectedBufferSize {
+            expected: 1,
+            received: Usize::from(dv.bytes().len()).into_u64().try_into().unwrap_or(u32::MAX),
+          }
+          .into(),
+        ));
+      };
+      Ok(byte != 0)
+    }
+  }
+  impl<E> Encode<Mysql<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(false, Ty::Tiny);
+      ev.fbw()._extend_from_byte((*self).into())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Mysql<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Tiny;
+  }
+
+  kani!(bool_true, bool);
+  kani!(bool_false, bool);
+
+  macro_rules! impl_primitive_from_array {
+    ($instance:expr, [$($elem:ident),+], $ty:ident, $is_unsigned:expr, $mysql_ty:expr) => {
+      impl<E> Decode<'_, Mysql<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        #[inline]
+        fn decode(input: &DecodeValue<'_>) -> Result<Self, E> {
+          if let &[$($elem,)+] = input.bytes() {
+            return Ok(<$ty>::from_le_bytes([$($elem),+]));
+          }
+          Err(E::from(MysqlError::UnexpectedBufferSize {
+            expected: Usize::from(size_of::<$ty>()).into_u64().try_into().unwrap_or(u32::MAX),
+            received: Usize::from(input.bytes().len()).into_u64().try_into().unwrap_or(u32::MAX)
+          }.into()))
+        }
+      }
+
+      impl<E> Encode<Mysql<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        #[inline]
+        fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+          ev.set_ty($is_unsigned, $mysql_ty);
+          ev.fbw().extend_from_slice(&self.to_le_bytes())?;
+          Ok(())
+        }
+      }
+
+      impl<E> Typed<Mysql<E>> for $ty
+      where
+        E: From<crate::Error>
+      {
+        const TY: Ty = $mysql_ty;
+      }
+
+      test!($ty, $ty, $instance);
+    }
+  }
+
+  impl_primitive_from_array!(37, [a], i8, false, Ty::Tiny);
+  impl_primitive_from_array!(37, [a], u8, true, Ty::Tiny);
+  impl_primitive_from_array!(37, [a, b], i16, false, Ty::Short);
+  impl_primitive_from_array!(37, [a, b], u16, true, Ty::Short);
+  impl_primitive_from_array!(37, [a, b, c, d], i32, false, Ty::Long);
+  impl_primitive_from_array!(37, [a, b, c, d], u32, true, Ty::Long);
+  impl_primitive_from_array!(37, [a, b, c, d, e, f, g, h], i64, false, Ty::LongLong);
+  impl_primitive_from_array!(37, [a, b, c, d, e, f, g, h], u64, true, Ty::LongLong);
+
+  impl_primitive_from_array!(37.0, [a, b, c, d], f32, false, Ty::Float);
+  impl_primitive_from_array!(37.0, [a, b, c, d, e, f, g, h], f64, false, Ty::Double);
+}
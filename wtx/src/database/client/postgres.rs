@@ -1,8 +1,13 @@
 //! Postgres, is a free and open-source relational database management system (RDBMS) emphasizing
 //! extensibility and SQL compliance.
 
+mod array;
 mod authentication;
+mod cancel_token;
 mod config;
+mod copy_in;
+mod copy_out;
+mod custom_ty;
 mod db_error;
 mod decode_value;
 mod encode_value;
@@ -10,32 +15,47 @@ mod executor;
 mod executor_buffer;
 #[cfg(all(feature = "_async-tests", feature = "_integration-tests", test))]
 mod integration_tests;
+mod interval;
 mod message;
 mod msg_field;
+mod notification;
+mod pg_range;
+mod pipeline;
 mod postgres_error;
 mod protocol;
 mod record;
 mod records;
 mod sql_state;
 mod statements;
+mod stmt_description;
 mod struct_decoder;
 mod struct_encoder;
 mod ty;
 mod tys;
 
 use crate::database::{Database, DatabaseTy};
+pub use array::{Array, Dimension};
+pub use cancel_token::CancelToken;
 pub use config::Config;
+pub use copy_in::CopyIn;
+pub use copy_out::CopyOut;
 use core::marker::PhantomData;
+pub use custom_ty::{CustomTy, CustomTyKind};
 pub use db_error::{DbError, ErrorPosition, Severity};
 pub use decode_value::DecodeValue;
 pub use encode_value::EncodeValue;
 pub use executor::Executor;
 pub use executor_buffer::ExecutorBuffer;
+pub use interval::Interval;
+pub use notification::Notification;
+pub use pg_range::{PgMultiRange, PgRange};
+pub use pipeline::Pipeline;
 pub use postgres_error::PostgresError;
 pub use record::Record;
 pub use records::Records;
 pub use sql_state::SqlState;
 pub use statements::Statements;
+pub use stmt_description::{ColumnDescription, StmtDescription};
 pub use struct_decoder::StructDecoder;
 pub use struct_encoder::StructEncoder;
 pub use ty::Ty;
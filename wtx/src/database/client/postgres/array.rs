@@ -0,0 +1,172 @@
+use crate::{
+  database::{
+    client::postgres::{DecodeValue, EncodeValue, Postgres, PostgresError, Ty},
+    Encode, Typed,
+  },
+  misc::{Usize, Vector},
+};
+
+/// Multidimensional array whose elements are stored in row-major order.
+///
+/// One-dimensional arrays can also be represented by `Vec`, `Vector` or `[T; N]`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Array<T> {
+  dimensions: Vector<Dimension>,
+  elements: Vector<T>,
+}
+
+impl<T> Array<T> {
+  /// The product of the lengths of all `dimensions` must be equal to the number of `elements`.
+  #[inline]
+  pub fn new(dimensions: Vector<Dimension>, elements: Vector<T>) -> crate::Result<Self> {
+    if elements_len(&dimensions) != Some(elements.len()) {
+      return Err(PostgresError::InvalidArrayDimensions.into());
+    }
+    Ok(Self { dimensions, elements })
+  }
+
+  /// Length and lower bound of each dimension.
+  #[inline]
+  pub fn dimensions(&self) -> &[Dimension] {
+    &self.dimensions
+  }
+
+  /// All elements in row-major order.
+  #[inline]
+  pub fn elements(&self) -> &[T] {
+    &self.elements
+  }
+
+  /// Returns the dimensions and the elements.
+  #[inline]
+  pub fn into_parts(self) -> (Vector<Dimension>, Vector<T>) {
+    (self.dimensions, self.elements)
+  }
+}
+
+/// Length and lower bound of an array dimension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Dimension {
+  len: u32,
+  lower_bound: i32,
+}
+
+impl Dimension {
+  /// PostgreSQL arrays are indexed starting from `1` by default.
+  #[inline]
+  pub const fn new(len: u32, lower_bound: i32) -> Self {
+    Self { len, lower_bound }
+  }
+
+  /// If the dimension doesn't have elements.
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Number of elements.
+  #[inline]
+  pub const fn len(&self) -> u32 {
+    self.len
+  }
+
+  /// Index of the first element.
+  #[inline]
+  pub const fn lower_bound(&self) -> i32 {
+    self.lower_bound
+  }
+}
+
+// Header: number of dimensions, nullability flag, element OID and the dimensions. Each element
+// is prefixed by its length, which is `-1` for nulls.
+#[inline]
+pub(crate) fn decode_array<'de, E>(
+  dv: &DecodeValue<'de>,
+  mut dimension_cb: impl FnMut(Dimension) -> Result<(), E>,
+  mut element_cb: impl FnMut(Option<DecodeValue<'de>>) -> Result<(), E>,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+{
+  let err = || E::from(PostgresError::InvalidArrayDimensions.into());
+  let [a, b, c, d, _, _, _, _, e, f, g, h, header_rest @ ..] = dv.bytes() else {
+    return Err(E::from(PostgresError::DecodingError.into()));
+  };
+  let mut rest = header_rest;
+  let ndim = u32::from_be_bytes([*a, *b, *c, *d]);
+  let ty = Ty::from_arbitrary_u32(u32::from_be_bytes([*e, *f, *g, *h]));
+  let mut remaining: usize = if ndim == 0 { 0 } else { 1 };
+  for _ in 0..ndim {
+    let [i, j, k, l, m, n, o, p, local_rest @ ..] = rest else {
+      return Err(E::from(PostgresError::DecodingError.into()));
+    };
+    let len = u32::try_from(i32::from_be_bytes([*i, *j, *k, *l])).map_err(|_err| err())?;
+    remaining = remaining.checked_mul(*Usize::from(len)).ok_or_else(err)?;
+    dimension_cb(Dimension::new(len, i32::from_be_bytes([*m, *n, *o, *p])))?;
+    rest = local_rest;
+  }
+  while let [i, j, k, l, local_rest @ ..] = rest {
+    remaining = remaining.checked_sub(1).ok_or_else(err)?;
+    let signed_len = i32::from_be_bytes([*i, *j, *k, *l]);
+    if signed_len == -1 {
+      element_cb(None)?;
+      rest = local_rest;
+      continue;
+    }
+    let len =
+      u32::try_from(signed_len).map_err(|_err| E::from(PostgresError::DecodingError.into()))?;
+    let Some((bytes, after)) = local_rest.split_at_checked(*Usize::from(len)) else {
+      return Err(E::from(PostgresError::DecodingError.into()));
+    };
+    element_cb(Some(DecodeValue::new(bytes, ty)))?;
+    rest = after;
+  }
+  if remaining != 0 || !rest.is_empty() {
+    return Err(err());
+  }
+  Ok(())
+}
+
+#[inline]
+pub(crate) fn encode_array<E, T>(
+  dimensions: &[Dimension],
+  elements: &[T],
+  ev: &mut EncodeValue<'_, '_>,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+  T: Encode<Postgres<E>> + Typed<Postgres<E>>,
+{
+  let ndim = i32::try_from(dimensions.len()).map_err(crate::Error::from)?;
+  let has_null = elements.iter().any(Encode::is_null);
+  ev.fbw()._extend_from_slices([
+    &ndim.to_be_bytes()[..],
+    &i32::from(has_null).to_be_bytes(),
+    &u32::from(T::TY).to_be_bytes(),
+  ])?;
+  for dimension in dimensions {
+    let len = i32::try_from(dimension.len).map_err(crate::Error::from)?;
+    ev.fbw()._extend_from_slices([&len.to_be_bytes()[..], &dimension.lower_bound.to_be_bytes()])?;
+  }
+  for element in elements {
+    ev.encode_with_len(element)?;
+  }
+  Ok(())
+}
+
+/// The dimensions of a one-dimensional array with `len` elements.
+#[inline]
+pub(crate) fn one_dimension(len: usize) -> crate::Result<Option<Dimension>> {
+  if len == 0 {
+    return Ok(None);
+  }
+  Ok(Some(Dimension::new(u32::try_from(len)?, 1)))
+}
+
+#[inline]
+fn elements_len(dimensions: &[Dimension]) -> Option<usize> {
+  if dimensions.is_empty() {
+    return Some(0);
+  }
+  dimensions.iter().try_fold(1usize, |acc, el| acc.checked_mul(*Usize::from(el.len)))
+}
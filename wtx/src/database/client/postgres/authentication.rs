@@ -6,6 +6,8 @@ use core::any::type_name;
 
 #[derive(Debug)]
 pub(crate) enum Authentication<'bytes> {
+  CleartextPassword,
+  Md5Password([u8; 4]),
   Ok,
   Sasl(&'bytes [u8]),
   SaslContinue { iterations: u32, payload: &'bytes [u8], nonce: &'bytes [u8], salt: &'bytes [u8] },
@@ -24,6 +26,15 @@ impl<'bytes> TryFrom<&'bytes [u8]> for Authentication<'bytes> {
     };
     Ok(match n {
       0 => Self::Ok,
+      3 => Self::CleartextPassword,
+      5 => {
+        let [a, b, c, d] = rest else {
+          return Err(
+            PostgresError::UnexpectedValueFromBytes { expected: type_name::<Self>() }.into(),
+          );
+        };
+        Self::Md5Password([*a, *b, *c, *d])
+      }
       10 => Self::Sasl(rest),
       11 => {
         let mut iter = bytes_split1(rest, b',');
@@ -0,0 +1,61 @@
+use crate::{
+  database::client::postgres::{executor::upgrade_stream, protocol::cancel_request},
+  misc::{filled_buffer::FilledBuffer, FilledBufferWriter, Stream},
+};
+use core::future::Future;
+
+/// Identifies a connection in order to interrupt its ongoing query from another connection. See
+/// [`crate::database::client::postgres::Executor::cancel_token`].
+///
+/// The server doesn't reply to cancellation requests, as such, a successful method call doesn't
+/// mean that something was cancelled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CancelToken {
+  pid: i32,
+  secret_key: i32,
+}
+
+impl CancelToken {
+  #[inline]
+  pub(crate) const fn new(pid: i32, secret_key: i32) -> Self {
+    Self { pid, secret_key }
+  }
+
+  /// Sends a cancellation request through a new unencrypted `stream` that should point to the
+  /// same server of the original connection.
+  #[inline]
+  pub async fn cancel<S>(&self, mut stream: S) -> crate::Result<()>
+  where
+    S: Stream,
+  {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    cancel_request(&mut fbw, self.pid, self.secret_key)?;
+    stream.write_all(fbw._curr_bytes()).await
+  }
+
+  /// Sends a cancellation request through a new `stream` that is initially unencrypted and later
+  /// upgraded to an encrypted stream.
+  #[inline]
+  pub async fn cancel_encrypted<F, IS, S>(
+    &self,
+    stream: IS,
+    cb: impl FnOnce(IS) -> F,
+  ) -> crate::Result<()>
+  where
+    F: Future<Output = crate::Result<S>>,
+    IS: Stream,
+    S: Stream,
+  {
+    let mut fb = FilledBuffer::_new();
+    let encrypted_stream =
+      upgrade_stream(&mut FilledBufferWriter::new(0, &mut fb), stream, cb).await?;
+    self.cancel(encrypted_stream).await
+  }
+
+  /// The process ID of the backend that executes the queries of the original connection.
+  #[inline]
+  pub fn pid(&self) -> i32 {
+    self.pid
+  }
+}
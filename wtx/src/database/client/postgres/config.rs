@@ -1,6 +1,6 @@
 use crate::{
   database::client::postgres::PostgresError,
-  misc::{into_rslt, str_split1, str_split_once1, FromRadix10, UriRef},
+  misc::{str_split1, str_split_once1, FromRadix10, UriRef, Vector},
 };
 use core::time::Duration;
 
@@ -9,13 +9,13 @@ use core::time::Duration;
 pub struct Config<'data> {
   pub(crate) app_name: &'data str,
   pub(crate) channel_binding: ChannelBinding,
+  pub(crate) cleartext_password: CleartextPassword,
   pub(crate) connect_timeout: Duration,
   pub(crate) db: &'data str,
-  pub(crate) host: &'data str,
+  pub(crate) hosts: Vector<(&'data str, u16)>,
   pub(crate) keepalives: bool,
   pub(crate) load_balance_hosts: LoadBalanceHosts,
   pub(crate) password: &'data str,
-  pub(crate) port: u16,
   pub(crate) target_session_attrs: TargetSessionAttrs,
   pub(crate) tcp_user_timeout: Duration,
   pub(crate) user: &'data str,
@@ -23,22 +23,33 @@ pub struct Config<'data> {
 
 impl<'data> Config<'data> {
   /// Unwraps the elements of an URI.
+  ///
+  /// The authority can contain several comma-separated hosts, each one with an optional port
+  /// that defaults to `5432`. For example, `postgres://user:pw@primary:5432,replica:5433/db`.
   #[inline]
   pub fn from_uri(uri: &'data UriRef<'_>) -> crate::Result<Config<'data>> {
     let mut this = Self {
       app_name: "",
       channel_binding: ChannelBinding::Prefer,
+      cleartext_password: CleartextPassword::RequireTls,
       connect_timeout: Duration::ZERO,
       db: uri.path().get(1..).unwrap_or_default(),
-      host: uri.hostname(),
+      hosts: Vector::new(),
       keepalives: true,
       load_balance_hosts: LoadBalanceHosts::Disable,
       password: uri.password(),
-      port: into_rslt(uri.port())?,
       target_session_attrs: TargetSessionAttrs::Any,
       tcp_user_timeout: Duration::ZERO,
       user: uri.user(),
     };
+    for host in str_split1(uri.host(), b',') {
+      let (hostname, port) = if let Some((hostname, port)) = str_split_once1(host, b':') {
+        (hostname, u16::from_radix_10(port.as_bytes())?)
+      } else {
+        (host, 5432)
+      };
+      this.hosts.push((hostname, port))?;
+    }
     let mut pair_iter = str_split1(uri.query_and_fragment(), b'&');
     if let Some(mut key_value) = pair_iter.next() {
       key_value = key_value.get(1..).unwrap_or_default();
@@ -69,6 +80,14 @@ impl<'data> Config<'data> {
         };
         self.channel_binding = channel_binding;
       }
+      "cleartext_password" => {
+        self.cleartext_password = match value {
+          "allow" => CleartextPassword::Allow,
+          "disable" => CleartextPassword::Disable,
+          "require_tls" => CleartextPassword::RequireTls,
+          _ => return Err(PostgresError::UnknownConfigurationParameter.into()),
+        };
+      }
       "connect_timeout" => {
         if let Ok(timeout) = u64::from_radix_10(value.as_bytes()) {
           self.connect_timeout = Duration::from_secs(timeout);
@@ -84,6 +103,7 @@ impl<'data> Config<'data> {
       "target_session_attrs" => {
         self.target_session_attrs = match value {
           "any" => TargetSessionAttrs::Any,
+          "read-only" => TargetSessionAttrs::ReadOnly,
           "read-write" => TargetSessionAttrs::ReadWrite,
           _ => return Err(PostgresError::UnknownConfigurationParameter.into()),
         };
@@ -106,34 +126,60 @@ pub(crate) enum ChannelBinding {
   Require,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CleartextPassword {
+  Allow,
+  Disable,
+  RequireTls,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum LoadBalanceHosts {
   Disable,
   Random,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum TargetSessionAttrs {
   Any,
+  ReadOnly,
   ReadWrite,
 }
 
 #[cfg(test)]
 mod tests {
   use crate::{
-    database::client::postgres::{config::ChannelBinding, Config},
+    database::client::postgres::{
+      config::{ChannelBinding, CleartextPassword, LoadBalanceHosts, TargetSessionAttrs},
+      Config,
+    },
     misc::Uri,
   };
 
   #[test]
   fn from_uri() {
-    let uri = Uri::new("postgres://ab:cd@ef:5432/gh?application_name=ij&channel_binding=disable");
+    let uri = Uri::new(
+      "postgres://ab:cd@ef:5432/gh?application_name=ij&channel_binding=disable&cleartext_password=allow",
+    );
     let config = Config::from_uri(&uri).unwrap();
     assert_eq!(config.app_name, "ij");
     assert_eq!(config.channel_binding, ChannelBinding::Disable);
+    assert_eq!(config.cleartext_password, CleartextPassword::Allow);
     assert_eq!(config.db, "gh");
-    assert_eq!(config.host, "ef");
+    assert_eq!(config.hosts.as_slice(), [("ef", 5432)]);
     assert_eq!(config.password, "cd");
     assert_eq!(config.user, "ab");
   }
+
+  #[test]
+  fn from_uri_with_multiple_hosts() {
+    let uri = Uri::new(
+      "postgres://ab:cd@ef:5433,gh,ij:5434/kl?load_balance_hosts=random&target_session_attrs=read-only",
+    );
+    let config = Config::from_uri(&uri).unwrap();
+    assert_eq!(config.db, "kl");
+    assert_eq!(config.hosts.as_slice(), [("ef", 5433), ("gh", 5432), ("ij", 5434)]);
+    assert_eq!(config.load_balance_hosts, LoadBalanceHosts::Random);
+    assert_eq!(config.target_session_attrs, TargetSessionAttrs::ReadOnly);
+  }
 }
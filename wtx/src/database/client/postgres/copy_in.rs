@@ -0,0 +1,138 @@
+use crate::{
+  database::{
+    client::postgres::{
+      executor_buffer::ExecutorBufferPartsMut,
+      message::MessageTy,
+      protocol::{copy_data, copy_done, copy_fail, encode_values, write},
+      Executor, ExecutorBuffer, Postgres, PostgresError,
+    },
+    RecordValues,
+  },
+  misc::{FilledBufferWriter, LeaseMut, Stream},
+};
+
+const BINARY_HEADER: &[u8; 19] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+const BINARY_TRAILER: &[u8; 2] = &(-1i16).to_be_bytes();
+// Records are grouped in `CopyData` messages of approximately this length.
+const FRAME_LEN: usize = 64 * 1024;
+
+/// Sink of a `COPY ... FROM STDIN` operation. See [`Executor::copy_in`].
+///
+/// [`CopyIn::finish`] or [`CopyIn::abort`] must be called at the end of the operation, otherwise
+/// the connection will remain in the copy state.
+#[derive(Debug)]
+pub struct CopyIn<'exec, E, EB, S> {
+  exec: &'exec mut Executor<E, EB, S>,
+  has_binary_header: bool,
+  is_binary: bool,
+}
+
+impl<'exec, E, EB, S> CopyIn<'exec, E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  #[inline]
+  pub(crate) fn new(exec: &'exec mut Executor<E, EB, S>, is_binary: bool) -> Self {
+    Self { exec, has_binary_header: false, is_binary }
+  }
+
+  /// Cancels the operation. All data sent so far is discarded by the server.
+  #[inline]
+  pub async fn abort(self, reason: &str) -> crate::Result<()> {
+    let Executor { eb, stream, .. } = self.exec;
+    let nb = &mut eb.lease_mut().nb;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      copy_fail(&mut fbw, reason)?;
+      stream.write_all(fbw._curr_bytes()).await?;
+    }
+    // The server answers with an expected error that is discarded.
+    loop {
+      if Executor::<E, EB, S>::fetch_one_msg_from_stream(nb, stream).await? == b'Z' {
+        return Ok(());
+      }
+    }
+  }
+
+  /// Ends the operation returning the number of copied records.
+  #[inline]
+  pub async fn finish(self) -> crate::Result<u64> {
+    let Executor { cs, eb, pending_rfq, stream, .. } = self.exec;
+    let ExecutorBufferPartsMut { nb, nq, .. } = eb.lease_mut().parts_mut();
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      if self.has_binary_header {
+        copy_data(&mut fbw, BINARY_TRAILER)?;
+      }
+      copy_done(&mut fbw)?;
+      stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let mut rows = 0;
+    loop {
+      let msg =
+        Executor::<E, EB, S>::fetch_msg_from_stream(cs, nb, nq, pending_rfq, stream).await?;
+      match msg.ty {
+        MessageTy::CommandComplete(local_rows) => {
+          rows = local_rows;
+        }
+        MessageTy::ReadyForQuery => return Ok(rows),
+        _ => return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()),
+      }
+    }
+  }
+
+  /// Sends `data` without any modification.
+  ///
+  /// In textual operations, `data` can contain one or more complete or partial rows. In binary
+  /// operations, `data` must also contain the header and the trailer of the format.
+  #[inline]
+  pub async fn write(&mut self, data: &[u8]) -> crate::Result<()> {
+    let len = i32::try_from(data.len().wrapping_add(4))?;
+    let [a, b, c, d] = len.to_be_bytes();
+    self.exec.stream.write_all_vectored(&[&[b'd', a, b, c, d], data]).await
+  }
+
+  /// Encodes and sends each element of `records` using the binary format.
+  ///
+  /// The header of the format is sent in the first call and the trailer is sent by
+  /// [`CopyIn::finish`]. Each value must have the exact same type of its corresponding column.
+  #[inline]
+  pub async fn write_records<RV>(&mut self, records: impl IntoIterator<Item = RV>) -> Result<(), E>
+  where
+    RV: RecordValues<Postgres<E>>,
+  {
+    if !self.is_binary {
+      return Err(E::from(PostgresError::InvalidCopyFormat.into()));
+    }
+    let Executor { eb, stream, .. } = &mut *self.exec;
+    let nb = &mut eb.lease_mut().nb;
+    let mut iter = records.into_iter();
+    let mut is_done = false;
+    while !is_done {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      write(&mut fbw, true, Some(b'd'), |local_fbw| {
+        if !self.has_binary_header {
+          local_fbw.extend_from_slice(BINARY_HEADER)?;
+          self.has_binary_header = true;
+        }
+        loop {
+          let Some(mut rv) = iter.next() else {
+            is_done = true;
+            break;
+          };
+          let len = i16::try_from(rv.len()).map_err(crate::Error::from)?;
+          local_fbw.extend_from_slice(&len.to_be_bytes())?;
+          encode_values(local_fbw, &mut rv)?;
+          if local_fbw._len() >= FRAME_LEN {
+            break;
+          }
+        }
+        Ok::<_, E>(())
+      })?;
+      stream.write_all(fbw._curr_bytes()).await?;
+    }
+    Ok(())
+  }
+}
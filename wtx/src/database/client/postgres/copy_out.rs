@@ -0,0 +1,68 @@
+use crate::{
+  database::client::postgres::{
+    executor_buffer::ExecutorBufferPartsMut, message::MessageTy, Executor, ExecutorBuffer,
+    PostgresError,
+  },
+  misc::{LeaseMut, Stream},
+};
+
+/// Source of a `COPY ... TO STDOUT` operation. See [`Executor::copy_out`].
+///
+/// All data must be consumed with [`CopyOut::next`], otherwise the connection will remain in
+/// the copy state.
+#[derive(Debug)]
+pub struct CopyOut<'exec, E, EB, S> {
+  exec: &'exec mut Executor<E, EB, S>,
+  is_binary: bool,
+  rows: Option<u64>,
+}
+
+impl<'exec, E, EB, S> CopyOut<'exec, E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  #[inline]
+  pub(crate) fn new(exec: &'exec mut Executor<E, EB, S>, is_binary: bool) -> Self {
+    Self { exec, is_binary, rows: None }
+  }
+
+  /// If the data is sent using the binary format.
+  #[inline]
+  pub fn is_binary(&self) -> bool {
+    self.is_binary
+  }
+
+  /// Retrieves the next chunk of data. Each chunk usually corresponds to a single row.
+  ///
+  /// Returns `None` when there is no more data.
+  #[inline]
+  pub async fn next(&mut self) -> crate::Result<Option<&[u8]>> {
+    if self.rows.is_some() {
+      return Ok(None);
+    }
+    let Executor { cs, eb, pending_rfq, stream, .. } = &mut *self.exec;
+    let ExecutorBufferPartsMut { nb, nq, .. } = eb.lease_mut().parts_mut();
+    nb._clear_if_following_is_empty();
+    loop {
+      let msg =
+        Executor::<E, EB, S>::fetch_msg_from_stream(cs, nb, nq, pending_rfq, stream).await?;
+      match msg.ty {
+        MessageTy::CommandComplete(local_rows) => {
+          self.rows = Some(local_rows);
+        }
+        MessageTy::CopyData => break,
+        MessageTy::CopyDone => {}
+        MessageTy::ReadyForQuery => return Ok(None),
+        _ => return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()),
+      }
+    }
+    Ok(Some(nb._current().get(5..).unwrap_or_default()))
+  }
+
+  /// The number of copied records. Only available after all data has been consumed.
+  #[inline]
+  pub fn rows(&self) -> Option<u64> {
+    self.rows
+  }
+}
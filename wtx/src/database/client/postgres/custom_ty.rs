@@ -0,0 +1,75 @@
+use crate::{
+  database::{
+    client::postgres::{Oid, Ty},
+    Identifier,
+  },
+  misc::Vector,
+};
+
+/// User-defined type retrieved from the system catalogs (`pg_type`, `pg_attribute`, `pg_enum`
+/// and `pg_range`) when a statement references an OID that isn't built-in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomTy {
+  kind: CustomTyKind,
+  name: Identifier,
+  oid: Oid,
+}
+
+impl CustomTy {
+  #[inline]
+  pub(crate) fn new(kind: CustomTyKind, name: Identifier, oid: Oid) -> Self {
+    Self { kind, name, oid }
+  }
+
+  /// See [`CustomTyKind`].
+  #[inline]
+  pub fn kind(&self) -> &CustomTyKind {
+    &self.kind
+  }
+
+  /// Name of the type without the schema.
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Object identifier
+  #[inline]
+  pub fn oid(&self) -> Oid {
+    self.oid
+  }
+}
+
+/// The category of a [`CustomTy`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CustomTyKind {
+  /// One-dimensional array whose elements are of the contained type.
+  Array(Ty),
+  /// Base or pseudo type implemented by an extension.
+  Base,
+  /// Composite type with the names and the types of its fields, in declaration order.
+  Composite(Vector<(Identifier, Ty)>),
+  /// Domain over the contained type.
+  Domain(Ty),
+  /// Enum with the labels of its variants, in declaration order.
+  Enum(Vector<Identifier>),
+  /// Multirange whose ranges are of the contained type.
+  MultiRange(Ty),
+  /// Range whose elements are of the contained type.
+  Range(Ty),
+}
+
+impl CustomTyKind {
+  /// Types referenced by this kind.
+  #[inline]
+  pub(crate) fn dependencies(&self) -> impl Iterator<Item = Ty> + '_ {
+    let (single, slice) = match self {
+      Self::Array(elem) | Self::Domain(elem) | Self::MultiRange(elem) | Self::Range(elem) => {
+        (Some(*elem), &[][..])
+      }
+      Self::Base | Self::Enum(_) => (None, &[][..]),
+      Self::Composite(elem) => (None, elem.as_slice()),
+    };
+    single.into_iter().chain(slice.iter().map(|el| el.1))
+  }
+}
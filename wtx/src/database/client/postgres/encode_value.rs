@@ -1,4 +1,7 @@
-use crate::misc::{FilledBufferWriter, Lease, LeaseMut};
+use crate::{
+  database::{client::postgres::Postgres, Encode},
+  misc::{FilledBufferWriter, Lease, LeaseMut},
+};
 
 /// Struct used for encoding elements in PostgreSQL.
 #[derive(Debug)]
@@ -17,6 +20,29 @@ impl<'buffer, 'tmp> EncodeValue<'buffer, 'tmp> {
   pub fn fbw(&mut self) -> &mut FilledBufferWriter<'buffer> {
     self.fbw
   }
+
+  /// Encodes `value` prefixed by its length, which is `-1` for nulls.
+  #[inline]
+  pub(crate) fn encode_with_len<E, T>(&mut self, value: &T) -> Result<(), E>
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>>,
+  {
+    if value.is_null() {
+      self.fbw.extend_from_slice(&(-1i32).to_be_bytes())?;
+      return Ok(());
+    }
+    let len_start = self.fbw._len();
+    self.fbw.extend_from_slice(&[0; 4])?;
+    let elem_start = self.fbw._len();
+    value.encode(self)?;
+    let len =
+      i32::try_from(self.fbw._len().wrapping_sub(elem_start)).map_err(crate::Error::from)?;
+    if let Some([a, b, c, d, ..]) = self.fbw._curr_bytes_mut().get_mut(len_start..) {
+      [*a, *b, *c, *d] = len.to_be_bytes();
+    }
+    Ok(())
+  }
 }
 
 impl<'buffer> Lease<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
@@ -1,21 +1,30 @@
 mod authentication;
 mod commons;
+mod copy;
 mod fetch;
+mod introspection;
+mod notification;
+mod pipeline;
+mod portal;
 mod prepare;
 mod simple_query;
 
 use crate::{
   database::{
     client::postgres::{
+      config::{LoadBalanceHosts, TargetSessionAttrs},
       executor::commons::FetchWithStmtCommons,
       executor_buffer::{ExecutorBuffer, ExecutorBufferPartsMut},
       message::MessageTy,
       protocol::{encrypted_conn, initial_conn_msg},
-      Config, Postgres, PostgresError, Record, Records,
+      CancelToken, Config, Postgres, PostgresError, Records,
     },
     Database, RecordValues, StmtCmd,
   },
-  misc::{ConnectionState, FilledBufferWriter, Lease, LeaseMut, Rng, Stream, StreamWithTls},
+  misc::{
+    ArrayString, ConnectionState, FilledBufferWriter, Lease, LeaseMut, Rng, Stream, StreamWithTls,
+    Vector,
+  },
 };
 use core::{future::Future, marker::PhantomData};
 
@@ -23,7 +32,10 @@ use core::{future::Future, marker::PhantomData};
 #[derive(Debug)]
 pub struct Executor<E, EB, S> {
   pub(crate) cs: ConnectionState,
+  pub(crate) ct: Option<CancelToken>,
   pub(crate) eb: EB,
+  /// An error left a `ReadyForQuery` message that must be discarded before the next command.
+  pub(crate) pending_rfq: bool,
   pub(crate) phantom: PhantomData<fn() -> E>,
   pub(crate) stream: S,
 }
@@ -45,7 +57,7 @@ where
     RNG: Rng,
   {
     eb.lease_mut().clear();
-    Self::do_connect(config, eb, rng, stream, None).await
+    Self::do_connect(config, eb, false, rng, stream, None).await.map_err(|el| el.1)
   }
 
   /// Initially connects with an unencrypted stream that should be later upgraded to an encrypted
@@ -55,7 +67,7 @@ where
     config: &Config<'_>,
     mut eb: EB,
     rng: &mut RNG,
-    mut stream: IS,
+    stream: IS,
     cb: impl FnOnce(IS) -> F,
   ) -> crate::Result<Self>
   where
@@ -65,19 +77,106 @@ where
     S: StreamWithTls,
   {
     eb.lease_mut().clear();
-    {
-      let mut fbw = FilledBufferWriter::from(&mut eb.lease_mut().nb);
-      encrypted_conn(&mut fbw)?;
-      stream.write_all(fbw._curr_bytes()).await?;
+    let stream =
+      upgrade_stream(&mut FilledBufferWriter::from(&mut eb.lease_mut().nb), stream, cb).await?;
+    let tls_server_end_point = stream.tls_server_end_point()?;
+    Self::do_connect(config, eb, true, rng, stream, tls_server_end_point.as_ref().map(Lease::lease))
+      .await
+      .map_err(|el| el.1)
+  }
+
+  /// Tries each host of `config` until a server that satisfies `target_session_attrs` is found.
+  /// Hosts are visited in order or randomly according to `load_balance_hosts` and unencrypted
+  /// streams are created by `cb`.
+  ///
+  /// Returns the error of the last attempt if no host is suitable.
+  #[inline]
+  pub async fn connect_hosts<'data, F, RNG>(
+    config: &Config<'data>,
+    mut eb: EB,
+    rng: &mut RNG,
+    mut cb: impl FnMut(&'data str, u16) -> F,
+  ) -> crate::Result<Self>
+  where
+    F: Future<Output = crate::Result<S>>,
+    RNG: Rng,
+  {
+    let mut last_err = None;
+    for (host, port) in hosts(config, rng) {
+      eb.lease_mut().clear();
+      let stream = match cb(host, port).await {
+        Ok(elem) => elem,
+        Err(err) => {
+          last_err = Some(err);
+          continue;
+        }
+      };
+      match Self::do_connect(config, eb, false, rng, stream, None).await {
+        Ok(this) => return Ok(this),
+        Err((local_eb, err)) => {
+          eb = local_eb;
+          last_err = Some(err);
+        }
+      }
     }
-    let mut buf = [0];
-    let _ = stream.read(&mut buf).await?;
-    if buf[0] != b'S' {
-      return Err(PostgresError::ServerDoesNotSupportEncryption.into());
+    Err(last_err.unwrap_or_else(|| PostgresError::EmptyHostList.into()))
+  }
+
+  /// Encrypted version of [`Self::connect_hosts`]. The stream created by `stream_cb` is upgraded
+  /// by `tls_cb`, which also receives the current host.
+  #[inline]
+  pub async fn connect_hosts_encrypted<'data, F, IS, ISF, RNG>(
+    config: &Config<'data>,
+    mut eb: EB,
+    rng: &mut RNG,
+    mut stream_cb: impl FnMut(&'data str, u16) -> ISF,
+    mut tls_cb: impl FnMut(&'data str, IS) -> F,
+  ) -> crate::Result<Self>
+  where
+    F: Future<Output = crate::Result<S>>,
+    IS: Stream,
+    ISF: Future<Output = crate::Result<IS>>,
+    RNG: Rng,
+    S: StreamWithTls,
+  {
+    let mut last_err = None;
+    for (host, port) in hosts(config, rng) {
+      eb.lease_mut().clear();
+      let rslt = async {
+        let stream = stream_cb(host, port).await?;
+        let fbw = &mut FilledBufferWriter::from(&mut eb.lease_mut().nb);
+        let encrypted_stream = upgrade_stream(fbw, stream, |local| tls_cb(host, local)).await?;
+        let tls_server_end_point = encrypted_stream.tls_server_end_point()?;
+        crate::Result::Ok((encrypted_stream, tls_server_end_point))
+      }
+      .await;
+      let (stream, tls_server_end_point) = match rslt {
+        Ok(elem) => elem,
+        Err(err) => {
+          last_err = Some(err);
+          continue;
+        }
+      };
+      let tsep = tls_server_end_point.as_ref().map(Lease::lease);
+      match Self::do_connect(config, eb, true, rng, stream, tsep).await {
+        Ok(this) => return Ok(this),
+        Err((local_eb, err)) => {
+          eb = local_eb;
+          last_err = Some(err);
+        }
+      }
     }
-    let stream = cb(stream).await?;
-    let tls_server_end_point = stream.tls_server_end_point()?;
-    Self::do_connect(config, eb, rng, stream, tls_server_end_point.as_ref().map(Lease::lease)).await
+    Err(last_err.unwrap_or_else(|| PostgresError::EmptyHostList.into()))
+  }
+
+  /// Token that can be used to cancel ongoing queries of this connection through another
+  /// connection.
+  ///
+  /// Should be retrieved before issuing a query because the executor is mutably borrowed while
+  /// a query is in progress.
+  #[inline]
+  pub fn cancel_token(&self) -> Option<CancelToken> {
+    self.ct
   }
 
   /// Mutable buffer reference
@@ -90,20 +189,53 @@ where
   async fn do_connect<RNG>(
     config: &Config<'_>,
     eb: EB,
+    is_tls: bool,
     rng: &mut RNG,
     stream: S,
     tls_server_end_point: Option<&[u8]>,
-  ) -> crate::Result<Self>
+  ) -> Result<Self, (EB, crate::Error)>
   where
     RNG: Rng,
   {
-    let mut this = Self { eb, cs: ConnectionState::Open, phantom: PhantomData, stream };
-    this.send_initial_conn_msg(config).await?;
-    this.manage_authentication(config, rng, tls_server_end_point).await?;
-    this.read_after_authentication_data().await?;
+    let mut this = Self {
+      cs: ConnectionState::Open,
+      ct: None,
+      eb,
+      pending_rfq: false,
+      phantom: PhantomData,
+      stream,
+    };
+    if let Err(err) = this.init(config, is_tls, rng, tls_server_end_point).await {
+      return Err((this.eb, err));
+    }
     Ok(this)
   }
 
+  #[inline]
+  async fn init<RNG>(
+    &mut self,
+    config: &Config<'_>,
+    is_tls: bool,
+    rng: &mut RNG,
+    tls_server_end_point: Option<&[u8]>,
+  ) -> crate::Result<()>
+  where
+    RNG: Rng,
+  {
+    self.send_initial_conn_msg(config).await?;
+    self.manage_authentication(config, is_tls, rng, tls_server_end_point).await?;
+    self.read_after_authentication_data().await?;
+    let must_be_read_only = match config.target_session_attrs {
+      TargetSessionAttrs::Any => return Ok(()),
+      TargetSessionAttrs::ReadOnly => true,
+      TargetSessionAttrs::ReadWrite => false,
+    };
+    if self.transaction_read_only().await? != must_be_read_only {
+      return Err(PostgresError::UnsatisfiedTargetSessionAttrs.into());
+    }
+    Ok(())
+  }
+
   async fn send_initial_conn_msg(&mut self, config: &Config<'_>) -> crate::Result<()> {
     let mut fbw = FilledBufferWriter::from(&mut self.eb.lease_mut().nb);
     initial_conn_msg(config, &mut fbw)?;
@@ -140,15 +272,16 @@ where
     RV: RecordValues<Self::Database>,
     SC: StmtCmd,
   {
-    let Self { cs, eb, phantom: _, stream } = self;
-    let ExecutorBufferPartsMut { nb, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    let Self { cs, ct: _, eb, pending_rfq, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, nq, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
     ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
     let mut rows = 0;
-    let mut fwsc = FetchWithStmtCommons { cs, stream, tys: &[] };
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
     let (_, stmt_id, stmt) = Self::write_send_await_stmt_prot(&mut fwsc, nb, sc, stmts).await?;
     Self::write_send_await_stmt_initial(&mut fwsc, nb, rv, &stmt, &stmt_id).await?;
     loop {
-      let msg = Self::fetch_msg_from_stream(cs, nb, stream).await?;
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
       match msg.ty {
         MessageTy::CommandComplete(local_rows) => {
           rows = local_rows;
@@ -175,9 +308,9 @@ where
     RV: RecordValues<Self::Database>,
     SC: StmtCmd,
   {
-    let Self { cs, eb, phantom: _, stream } = self;
-    let ExecutorBufferPartsMut { nb, stmts, vb, .. } = eb.lease_mut().parts_mut();
-    let mut fwsc = FetchWithStmtCommons { cs, stream, tys: &[] };
+    let Self { cs, ct: _, eb, pending_rfq, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, nq, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
     let (_, stmt_id, stmt) = Self::write_send_await_stmt_prot(&mut fwsc, nb, sc, stmts).await?;
     Self::write_send_await_fetch_with_stmt_wo_prot(&mut fwsc, nb, rv, stmt, &stmt_id, vb).await
   }
@@ -193,40 +326,20 @@ where
     RV: RecordValues<Self::Database>,
     SC: StmtCmd,
   {
-    let Self { cs, eb, phantom: _, stream } = self;
-    let ExecutorBufferPartsMut { nb, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    let Self { cs, ct: _, eb, pending_rfq, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, nq, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
     ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
-    let mut fwsc = FetchWithStmtCommons { cs, stream, tys: &[] };
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
     let (_, stmt_id, stmt) = Self::write_send_await_stmt_prot(&mut fwsc, nb, sc, stmts).await?;
     Self::write_send_await_stmt_initial(&mut fwsc, nb, rv, &stmt, &stmt_id).await?;
-    let begin = nb._current_end_idx();
-    let begin_data = nb._current_end_idx().wrapping_add(7);
-    loop {
-      let msg = Self::fetch_msg_from_stream(cs, nb, stream).await?;
-      match msg.ty {
-        MessageTy::CommandComplete(_) | MessageTy::EmptyQueryResponse => {}
-        MessageTy::DataRow(len) => {
-          let bytes = nb._buffer().get(begin_data..nb._current_end_idx()).unwrap_or_default();
-          let range_begin = nb._antecedent_end_idx().wrapping_sub(begin);
-          let range_end = nb._current_end_idx().wrapping_sub(begin_data);
-          cb(&Record::parse(bytes, range_begin..range_end, stmt.clone(), vb, len)?)?;
-          rb.push(vb.len())?;
-        }
-        MessageTy::ReadyForQuery => {
-          break;
-        }
-        _ => {
-          return Err(<_>::from(
-            PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into(),
-          ));
-        }
-      }
-    }
+    let (_, range) = Self::fetch_records(&mut fwsc, nb, rb, &stmt, vb, &mut cb).await?;
+    let msg =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+    let MessageTy::ReadyForQuery = msg.ty else {
+      return Err(<_>::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+    };
     Ok(Records {
-      bytes: nb
-        ._buffer()
-        .get(begin_data.wrapping_add(4)..nb._current_end_idx())
-        .unwrap_or_default(),
+      bytes: nb._buffer().get(range).unwrap_or_default(),
       phantom: PhantomData,
       records_values_offsets: rb,
       stmt,
@@ -236,10 +349,49 @@ where
 
   #[inline]
   async fn prepare(&mut self, cmd: &str) -> Result<u64, E> {
-    let Self { cs, eb, phantom: _, stream } = self;
-    let ExecutorBufferPartsMut { nb, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    let Self { cs, ct: _, eb, pending_rfq, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, nq, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
     ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
-    let mut fwsc = FetchWithStmtCommons { cs, stream, tys: &[] };
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
     Ok(Self::write_send_await_stmt_prot(&mut fwsc, nb, cmd, stmts).await?.0)
   }
 }
+
+// Hosts of `config` in the order they should be visited.
+#[inline]
+fn hosts<'data, RNG>(config: &Config<'data>, rng: &mut RNG) -> Vector<(&'data str, u16)>
+where
+  RNG: Rng,
+{
+  let mut hosts = config.hosts.clone();
+  if config.load_balance_hosts == LoadBalanceHosts::Random {
+    rng.shuffle_slice(hosts.as_slice_mut());
+  }
+  hosts
+}
+
+// Sends a SSL request and then calls `cb` if the server accepts it.
+#[inline]
+pub(crate) async fn upgrade_stream<F, IS, S>(
+  fbw: &mut FilledBufferWriter<'_>,
+  mut stream: IS,
+  cb: impl FnOnce(IS) -> F,
+) -> crate::Result<S>
+where
+  F: Future<Output = crate::Result<S>>,
+  IS: Stream,
+{
+  encrypted_conn(fbw)?;
+  stream.write_all(fbw._curr_bytes()).await?;
+  let mut buf = [0];
+  let _ = stream.read(&mut buf).await?;
+  if buf[0] != b'S' {
+    return Err(PostgresError::ServerDoesNotSupportEncryption.into());
+  }
+  cb(stream).await
+}
+
+#[inline]
+pub(crate) fn stmt_id_str(stmt_hash: u64) -> crate::Result<ArrayString<22>> {
+  ArrayString::try_from(format_args!("s{stmt_hash}"))
+}
@@ -2,21 +2,22 @@ use crate::{
   database::{
     client::postgres::{
       authentication::Authentication,
-      config::ChannelBinding,
+      config::{ChannelBinding, CleartextPassword},
       executor_buffer::{ExecutorBuffer, ExecutorBufferPartsMut},
       message::MessageTy,
-      protocol::{sasl_first, sasl_second},
-      Config, Executor, PostgresError,
+      protocol::{password, sasl_first, sasl_second},
+      CancelToken, Config, Executor, Notification, PostgresError,
     },
     Identifier,
   },
   misc::{
     bytes_split1, from_utf8_basic, partitioned_filled_buffer::PartitionedFilledBuffer, ArrayVector,
-    ConnectionState, FilledBufferWriter, LeaseMut, Rng, Stream, Vector,
+    ConnectionState, Deque, FilledBufferWriter, LeaseMut, Rng, Stream, Vector,
   },
 };
 use base64::prelude::{Engine as _, BASE64_STANDARD};
 use hmac::{Hmac, Mac};
+use md5::{Digest, Md5};
 use sha2::Sha256;
 
 impl<E, EB, S> Executor<E, EB, S>
@@ -36,15 +37,35 @@ where
   pub(crate) async fn manage_authentication<RNG>(
     &mut self,
     config: &Config<'_>,
+    is_tls: bool,
     rng: &mut RNG,
     tls_server_end_point: Option<&[u8]>,
   ) -> crate::Result<()>
   where
     RNG: Rng,
   {
-    let ExecutorBufferPartsMut { nb, .. } = self.eb.lease_mut().parts_mut();
-    let msg0 = Self::fetch_msg_from_stream(&mut self.cs, nb, &mut self.stream).await?;
+    let ExecutorBufferPartsMut { nb, nq, .. } = self.eb.lease_mut().parts_mut();
+    let msg0 =
+      Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+        .await?;
     match msg0.ty {
+      MessageTy::Authentication(Authentication::CleartextPassword) => {
+        match (config.cleartext_password, is_tls) {
+          (CleartextPassword::Allow, _) | (CleartextPassword::RequireTls, true) => {}
+          (CleartextPassword::Disable, _) | (CleartextPassword::RequireTls, false) => {
+            return Err(PostgresError::ForbiddenCleartextPassword.into());
+          }
+        }
+        let mut fbw = FilledBufferWriter::from(&mut *nb);
+        password(&mut fbw, config.password.as_bytes())?;
+        self.stream.write_all(fbw._curr_bytes()).await?;
+      }
+      MessageTy::Authentication(Authentication::Md5Password(salt)) => {
+        let hashed = md5_password(config.password, config.user, salt);
+        let mut fbw = FilledBufferWriter::from(&mut *nb);
+        password(&mut fbw, &hashed)?;
+        self.stream.write_all(fbw._curr_bytes()).await?;
+      }
       MessageTy::Authentication(Authentication::Ok) => {
         return Ok(());
       }
@@ -94,6 +115,8 @@ where
           &mut self.cs,
           (method_bytes, method_header),
           nb,
+          nq,
+          &mut self.pending_rfq,
           rng,
           &mut self.stream,
           tls_server_end_point,
@@ -104,7 +127,9 @@ where
         return Err(PostgresError::UnexpectedDatabaseMessage { received: msg0.tag }.into());
       }
     }
-    let msg1 = Self::fetch_msg_from_stream(&mut self.cs, nb, &mut self.stream).await?;
+    let msg1 =
+      Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+        .await?;
     if let MessageTy::Authentication(Authentication::Ok) = msg1.ty {
       Ok(())
     } else {
@@ -116,10 +141,14 @@ where
   pub(crate) async fn read_after_authentication_data(&mut self) -> crate::Result<()> {
     self.eb.lease_mut().nb._reserve(2048)?;
     loop {
-      let ExecutorBufferPartsMut { cp, nb, .. } = self.eb.lease_mut().parts_mut();
-      let msg = Self::fetch_msg_from_stream(&mut self.cs, nb, &mut self.stream).await?;
+      let ExecutorBufferPartsMut { cp, nb, nq, .. } = self.eb.lease_mut().parts_mut();
+      let msg =
+        Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+          .await?;
       match msg.ty {
-        MessageTy::BackendKeyData => {}
+        MessageTy::BackendKeyData(pid, secret_key) => {
+          self.ct = Some(CancelToken::new(pid, secret_key));
+        }
         MessageTy::ParameterStatus(name, value) => {
           let name = from_utf8_basic(name)?.try_into()?;
           let value = from_utf8_basic(value)?.try_into()?;
@@ -141,6 +170,8 @@ where
     cs: &mut ConnectionState,
     (method_bytes, method_header): (&[u8], &[u8]),
     nb: &mut PartitionedFilledBuffer,
+    nq: &mut Deque<Notification>,
+    pending_rfq: &mut bool,
     rng: &mut RNG,
     stream: &mut S,
     tls_server_end_point: Option<&[u8]>,
@@ -158,7 +189,7 @@ where
     }
 
     let (mut auth_data, response_nonce, salted_password) = {
-      let msg = Self::fetch_msg_from_stream(cs, &mut *nb, stream).await?;
+      let msg = Self::fetch_msg_from_stream(cs, &mut *nb, nq, pending_rfq, stream).await?;
       let MessageTy::Authentication(Authentication::SaslContinue {
         iterations,
         nonce,
@@ -196,7 +227,7 @@ where
     }
 
     {
-      let msg = Self::fetch_msg_from_stream(cs, &mut *nb, stream).await?;
+      let msg = Self::fetch_msg_from_stream(cs, &mut *nb, nq, pending_rfq, stream).await?;
       let MessageTy::Authentication(Authentication::SaslFinal(verifier_slice)) = msg.ty else {
         return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into());
       };
@@ -216,6 +247,31 @@ where
   }
 }
 
+// "md5" followed by the hexadecimal representation of `md5(md5(password + user) + salt)`.
+#[inline]
+fn md5_password(password: &str, user: &str, salt: [u8; 4]) -> [u8; 35] {
+  let mut inner = [0; 32];
+  hex(&Md5::new().chain_update(password).chain_update(user).finalize().into(), &mut inner);
+  let mut rslt = [0; 35];
+  let (prefix, outer) = rslt.split_at_mut(3);
+  prefix.copy_from_slice(b"md5");
+  hex(&Md5::new().chain_update(inner).chain_update(salt).finalize().into(), outer);
+  rslt
+}
+
+#[inline]
+fn hex(bytes: &[u8; 16], out: &mut [u8]) {
+  const CHARS: &[u8; 16] = b"0123456789abcdef";
+  for (byte, chunk) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+    if let ([a, b], Some(high), Some(low)) =
+      (chunk, CHARS.get(usize::from(byte >> 4)), CHARS.get(usize::from(byte & 0xf)))
+    {
+      *a = *high;
+      *b = *low;
+    }
+  }
+}
+
 #[inline]
 fn nonce<RNG>(rng: &mut RNG) -> [u8; 24]
 where
@@ -257,3 +313,13 @@ fn salted_password(len: u32, salt: &[u8], str: &str) -> crate::Result<[u8; 32]>
   }
   Ok(salted_password)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::postgres::executor::authentication::md5_password;
+
+  #[test]
+  fn hashes_md5_passwords() {
+    assert_eq!(&md5_password("pass", "user", [1, 2, 3, 4]), b"md56cf524962d8413e6b0cdf79fddff891c");
+  }
+}
@@ -1,7 +1,12 @@
-use crate::{database::client::postgres::Ty, misc::ConnectionState};
+use crate::{
+  database::client::postgres::{Notification, Ty},
+  misc::{ConnectionState, Deque},
+};
 
 pub(crate) struct FetchWithStmtCommons<'others, S> {
   pub(crate) cs: &'others mut ConnectionState,
+  pub(crate) nq: &'others mut Deque<Notification>,
+  pub(crate) pending_rfq: &'others mut bool,
   pub(crate) stream: &'others mut S,
   /// Pre-specified types
   pub(crate) tys: &'others [Ty],
@@ -0,0 +1,68 @@
+use crate::{
+  database::client::postgres::{
+    executor_buffer::ExecutorBufferPartsMut, message::MessageTy, protocol::query, CopyIn, CopyOut,
+    Executor, ExecutorBuffer, PostgresError,
+  },
+  misc::{FilledBufferWriter, LeaseMut, Stream},
+};
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Starts a `COPY ... FROM STDIN` operation described by `cmd`, returning a sink where the data
+  /// should be written.
+  ///
+  /// ```sql
+  /// COPY some_table (a, b) FROM STDIN WITH (FORMAT binary)
+  /// ```
+  #[inline]
+  pub async fn copy_in(&mut self, cmd: &str) -> crate::Result<CopyIn<'_, E, EB, S>> {
+    let format = {
+      let ExecutorBufferPartsMut { nb, nq, rb, vb, .. } = self.eb.lease_mut().parts_mut();
+      ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+      {
+        let mut fbw = FilledBufferWriter::from(&mut *nb);
+        query(cmd.as_bytes(), &mut fbw)?;
+        self.stream.write_all(fbw._curr_bytes()).await?;
+      }
+      let msg =
+        Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+          .await?;
+      let MessageTy::CopyInResponse(format) = msg.ty else {
+        return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into());
+      };
+      format
+    };
+    Ok(CopyIn::new(self, format == 1))
+  }
+
+  /// Starts a `COPY ... TO STDOUT` operation described by `cmd`, returning a source where the data
+  /// should be read.
+  ///
+  /// ```sql
+  /// COPY (SELECT a, b FROM some_table) TO STDOUT
+  /// ```
+  #[inline]
+  pub async fn copy_out(&mut self, cmd: &str) -> crate::Result<CopyOut<'_, E, EB, S>> {
+    let format = {
+      let ExecutorBufferPartsMut { nb, nq, rb, vb, .. } = self.eb.lease_mut().parts_mut();
+      ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+      {
+        let mut fbw = FilledBufferWriter::from(&mut *nb);
+        query(cmd.as_bytes(), &mut fbw)?;
+        self.stream.write_all(fbw._curr_bytes()).await?;
+      }
+      let msg =
+        Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+          .await?;
+      let MessageTy::CopyOutResponse(format) = msg.ty else {
+        return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into());
+      };
+      format
+    };
+    Ok(CopyOut::new(self, format == 1))
+  }
+}
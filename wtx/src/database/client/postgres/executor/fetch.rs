@@ -4,12 +4,12 @@ use crate::{
       executor::commons::FetchWithStmtCommons,
       message::{Message, MessageTy},
       statements::statement::Statement,
-      Executor, ExecutorBuffer, Postgres, PostgresError, Record,
+      Executor, ExecutorBuffer, Notification, Postgres, PostgresError, Record,
     },
     RecordValues,
   },
   misc::{
-    ConnectionState, LeaseMut, Stream, Usize, Vector, _read_header, _read_payload,
+    ConnectionState, Deque, LeaseMut, Stream, Usize, Vector, _read_header, _read_payload,
     partitioned_filled_buffer::PartitionedFilledBuffer,
   },
 };
@@ -20,6 +20,48 @@ where
   EB: LeaseMut<ExecutorBuffer>,
   S: Stream,
 {
+  /// Reads all `DataRow` messages of a command, returning the number of affected records and the
+  /// range of `nb` that contains the received records.
+  ///
+  /// The number of affected records is [`Option::None`] if the portal was suspended.
+  #[inline]
+  pub(crate) async fn fetch_records(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+    rb: &mut Vector<usize>,
+    stmt: &Statement<'_>,
+    vb: &mut Vector<(bool, Range<usize>)>,
+    cb: &mut impl FnMut(&Record<'_, E>) -> Result<(), E>,
+  ) -> Result<(Option<u64>, Range<usize>), E>
+  where
+    E: From<crate::Error>,
+  {
+    let begin = nb._current_end_idx();
+    let begin_data = nb._current_end_idx().wrapping_add(7);
+    let rows = loop {
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+      match msg.ty {
+        MessageTy::CommandComplete(rows) => break Some(rows),
+        MessageTy::EmptyQueryResponse => break Some(0),
+        MessageTy::PortalSuspended => break None,
+        MessageTy::DataRow(len) => {
+          let bytes = nb._buffer().get(begin_data..nb._current_end_idx()).unwrap_or_default();
+          let range_begin = nb._antecedent_end_idx().wrapping_sub(begin);
+          let range_end = nb._current_end_idx().wrapping_sub(begin_data);
+          cb(&Record::parse(bytes, range_begin..range_end, stmt.clone(), vb, len)?)?;
+          rb.push(vb.len())?;
+        }
+        _ => {
+          return Err(E::from(
+            PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into(),
+          ));
+        }
+      }
+    };
+    Ok((rows, begin_data.wrapping_add(4)..nb._current_end_idx()))
+  }
+
   #[inline]
   pub(crate) async fn write_send_await_fetch_with_stmt_wo_prot<'any, RV>(
     fwsc: &mut FetchWithStmtCommons<'_, S>,
@@ -36,7 +78,8 @@ where
     Self::write_send_await_stmt_initial(fwsc, nb, rv, &stmt, stmt_id_str).await?;
     let mut data_row_msg_range = None;
     loop {
-      let msg = Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.stream).await?;
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
       match msg.ty {
         MessageTy::CommandComplete(_) | MessageTy::EmptyQueryResponse => {}
         MessageTy::DataRow(len) => {
@@ -60,13 +103,47 @@ where
     }
   }
 
+  /// A previous error left a pending `ReadyForQuery` message that precedes the responses of
+  /// subsequent commands. Notifications received in the meantime are stored in `nq`.
+  #[inline]
+  pub(crate) async fn drain_pending_rfq(
+    cs: &mut ConnectionState,
+    nb: &mut PartitionedFilledBuffer,
+    nq: &mut Deque<Notification>,
+    pending_rfq: &mut bool,
+    stream: &mut S,
+  ) -> crate::Result<()> {
+    if !*pending_rfq {
+      return Ok(());
+    }
+    loop {
+      match Self::fetch_one_msg_from_stream(&mut *nb, stream).await? {
+        b'A' => {
+          if let MessageTy::NotificationResponse(pid, channel, payload) =
+            MessageTy::try_from((&mut *cs, nb._current()))?
+          {
+            nq.push_back(Notification::new(pid, channel, payload)?)?;
+          }
+        }
+        b'Z' => break,
+        _ => {}
+      }
+    }
+    *pending_rfq = false;
+    Ok(())
+  }
+
+  /// Notifications received in the meantime are stored in `nq`.
   #[inline]
   pub(crate) async fn fetch_msg_from_stream<'nb>(
     cs: &mut ConnectionState,
     nb: &'nb mut PartitionedFilledBuffer,
+    nq: &mut Deque<Notification>,
+    pending_rfq: &mut bool,
     stream: &mut S,
   ) -> crate::Result<Message<'nb>> {
-    let tag = Self::fetch_representative_msg_from_stream(nb, stream).await?;
+    let tag =
+      Self::fetch_representative_msg_from_stream(&mut *cs, nb, nq, pending_rfq, stream).await?;
     Ok(Message { tag, ty: MessageTy::try_from((cs, nb._current()))? })
   }
 
@@ -75,7 +152,7 @@ where
   //
   // The value of `Len` is payload length plus 4, therefore, the frame length is `Len` plus 1.
   #[inline]
-  async fn fetch_one_msg_from_stream(
+  pub(crate) async fn fetch_one_msg_from_stream(
     nb: &mut PartitionedFilledBuffer,
     stream: &mut S,
   ) -> crate::Result<u8> {
@@ -90,13 +167,32 @@ where
 
   #[inline]
   async fn fetch_representative_msg_from_stream(
+    cs: &mut ConnectionState,
     nb: &mut PartitionedFilledBuffer,
+    nq: &mut Deque<Notification>,
+    pending_rfq: &mut bool,
     stream: &mut S,
   ) -> crate::Result<u8> {
-    let mut tag = Self::fetch_one_msg_from_stream(&mut *nb, stream).await?;
-    while tag == b'N' {
-      tag = Self::fetch_one_msg_from_stream(nb, stream).await?;
+    Self::drain_pending_rfq(&mut *cs, nb, nq, pending_rfq, stream).await?;
+    loop {
+      let tag = Self::fetch_one_msg_from_stream(&mut *nb, stream).await?;
+      match tag {
+        b'E' => {
+          // Errors are always followed by a `Sync` message, as such, the server will also send
+          // a `ReadyForQuery` message that must be discarded later.
+          *pending_rfq = true;
+          return Ok(tag);
+        }
+        b'A' => {
+          if let MessageTy::NotificationResponse(pid, channel, payload) =
+            MessageTy::try_from((&mut *cs, nb._current()))?
+          {
+            nq.push_back(Notification::new(pid, channel, payload)?)?;
+          }
+        }
+        b'N' => {}
+        _ => return Ok(tag),
+      }
     }
-    Ok(tag)
   }
 }
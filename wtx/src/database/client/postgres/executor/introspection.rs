@@ -0,0 +1,232 @@
+use crate::{
+  database::{
+    client::postgres::{
+      executor::commons::FetchWithStmtCommons, executor_buffer::ExecutorBufferPartsMut,
+      message::MessageTy, protocol::query, ColumnDescription, CustomTy, CustomTyKind, Executor,
+      ExecutorBuffer, Oid, PostgresError, Statements, StmtDescription, Ty,
+    },
+    Identifier,
+  },
+  misc::{
+    from_utf8_basic, partitioned_filled_buffer::PartitionedFilledBuffer, ArrayString,
+    FilledBufferWriter, FromRadix10, LeaseMut, Stream, Vector,
+  },
+};
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Prepares `cmd` and returns the types of its parameters as well as the names, the types and
+  /// the nullability of its columns.
+  ///
+  /// Nullability is retrieved from `pg_attribute`, as such, only columns that directly reference
+  /// a table column are considered non-nullable.
+  #[inline]
+  pub async fn describe(&mut self, cmd: &str) -> Result<StmtDescription, E> {
+    let Self { cs, eb, pending_rfq, stream, .. } = self;
+    let ExecutorBufferPartsMut { nb, nq, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
+    let mut parts = Vector::new();
+    let mut params = Vector::new();
+    {
+      let (_, _, stmt) = Self::write_send_await_stmt_prot(&mut fwsc, nb, cmd, stmts).await?;
+      for column in stmt.columns() {
+        parts.push((column.name, column.origin, column.ty))?;
+      }
+      for ty in stmt.tys() {
+        params.push(*ty)?;
+      }
+    }
+    let mut columns = Vector::with_capacity(parts.len())?;
+    for (name, origin, ty) in parts {
+      let not_null = match origin {
+        Some((table_oid, column_id)) => {
+          Self::fetch_not_null(&mut fwsc, nb, table_oid, column_id).await?
+        }
+        None => false,
+      };
+      columns.push(ColumnDescription::new(name, not_null, ty))?;
+    }
+    Ok(StmtDescription::new(columns, params))
+  }
+
+  /// Retrieves from the system catalogs all user-defined types referenced by the statement
+  /// located at `stmt_idx`, including the types referenced by other user-defined types. Domains
+  /// of the statement are then replaced by their underlying types.
+  #[inline]
+  pub(crate) async fn resolve_custom_tys(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+    stmt_idx: usize,
+    stmts: &mut Statements,
+  ) -> crate::Result<()> {
+    let mut oids = Vector::new();
+    stmts.unresolved_oids(&mut oids, stmt_idx)?;
+    if oids.is_empty() {
+      return Ok(());
+    }
+    while let Some(oid) = oids.pop() {
+      if stmts.custom_ty(oid).is_some() {
+        continue;
+      }
+      let Some(custom_ty) = Self::fetch_custom_ty(fwsc, nb, oid).await? else {
+        continue;
+      };
+      for ty in custom_ty.kind().dependencies() {
+        if let Ty::Custom(elem) = ty {
+          if elem != oid && stmts.custom_ty(elem).is_none() && !oids.contains(&elem) {
+            oids.push(elem)?;
+          }
+        }
+      }
+      stmts.insert_custom_ty(custom_ty);
+    }
+    stmts.resolve_domains(stmt_idx);
+    Ok(())
+  }
+
+  // Each returned row is composed by a kind (`t`ype, `a`ttribute or `e`num label), a name, the
+  // concatenation of `typtype` and `typcategory`, a related OID and a sorting key.
+  //
+  // The related OID of a type is the base type of a domain, the subtype of a range, the range of a
+  // multirange or the element of an array.
+  async fn fetch_custom_ty(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+    oid: Oid,
+  ) -> crate::Result<Option<CustomTy>> {
+    let cmd = ArrayString::<768>::try_from(format_args!(
+      "SELECT 't', t.typname::text, t.typtype::text || t.typcategory::text, \
+        COALESCE(CASE t.typtype WHEN 'd' THEN t.typbasetype WHEN 'm' THEN m.rngtypid \
+        WHEN 'r' THEN r.rngsubtype ELSE t.typelem END, 0), \
+        0::float8 \
+      FROM pg_type t LEFT JOIN pg_range r ON r.rngtypid = t.oid \
+      LEFT JOIN pg_range m ON m.rngmultitypid = t.oid WHERE t.oid = {oid} \
+      UNION ALL \
+      SELECT 'a', a.attname::text, '', a.atttypid, a.attnum::float8 \
+      FROM pg_attribute a JOIN pg_type t ON t.typrelid = a.attrelid \
+      WHERE t.oid = {oid} AND a.attnum > 0 AND NOT a.attisdropped \
+      UNION ALL \
+      SELECT 'e', e.enumlabel::text, '', 0::oid, e.enumsortorder::float8 \
+      FROM pg_enum e WHERE e.enumtypid = {oid} \
+      ORDER BY 1, 5"
+    ))?;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      query(cmd.as_bytes(), &mut fbw)?;
+      fwsc.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let mut attributes = Vector::new();
+    let mut labels = Vector::new();
+    let mut ty_opt = None;
+    loop {
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+      match msg.ty {
+        MessageTy::CommandComplete(_) | MessageTy::RowDescription(..) => {}
+        MessageTy::DataRow(_) => {
+          let [kind, name, typtype, related_oid] = data_row_values::<4>(nb._current())?;
+          let name = Identifier::try_from(from_utf8_basic(name)?)?;
+          let related_ty = Ty::from_arbitrary_u32(u32::from_radix_10(related_oid)?);
+          match kind {
+            b"a" => attributes.push((name, related_ty))?,
+            b"e" => labels.push(name)?,
+            _ => {
+              ty_opt = Some((name, [typtype.first().copied(), typtype.get(1).copied()], related_ty))
+            }
+          }
+        }
+        MessageTy::ReadyForQuery => break,
+        _ => return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()),
+      }
+    }
+    let Some((name, typtype, related_ty)) = ty_opt else {
+      return Ok(None);
+    };
+    let kind = match typtype {
+      [Some(b'b'), Some(b'A')] => CustomTyKind::Array(related_ty),
+      [Some(b'c'), _] => CustomTyKind::Composite(attributes),
+      [Some(b'd'), _] => CustomTyKind::Domain(related_ty),
+      [Some(b'e'), _] => CustomTyKind::Enum(labels),
+      [Some(b'm'), _] => CustomTyKind::MultiRange(related_ty),
+      [Some(b'r'), _] => CustomTyKind::Range(related_ty),
+      _ => CustomTyKind::Base,
+    };
+    Ok(Some(CustomTy::new(kind, name, oid)))
+  }
+
+  async fn fetch_not_null(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+    table_oid: Oid,
+    column_id: i16,
+  ) -> crate::Result<bool> {
+    let cmd = ArrayString::<128>::try_from(format_args!(
+      "SELECT attnotnull FROM pg_attribute WHERE attrelid = {table_oid} AND attnum = {column_id}"
+    ))?;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      query(cmd.as_bytes(), &mut fbw)?;
+      fwsc.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let mut not_null = false;
+    loop {
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+      match msg.ty {
+        MessageTy::CommandComplete(_) | MessageTy::RowDescription(..) => {}
+        MessageTy::DataRow(_) => {
+          let [value] = data_row_values::<1>(nb._current())?;
+          not_null = value == b"t";
+        }
+        MessageTy::ReadyForQuery => return Ok(not_null),
+        _ => return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()),
+      }
+    }
+  }
+}
+
+// | Tag | Len | Columns | (Value Len | Value)* |
+// |  1  |  4  |    2    |  (  4      |   x  )* |
+//
+// Only the first `N` values are returned. Nulls are represented as empty slices.
+fn data_row_values<const N: usize>(mut bytes: &[u8]) -> crate::Result<[&[u8]; N]> {
+  bytes = bytes.get(7..).unwrap_or_default();
+  let mut rslt: [&[u8]; N] = [&[]; N];
+  for elem in &mut rslt {
+    let [a, b, c, d, rest @ ..] = bytes else {
+      return Err(PostgresError::UnexpectedDatabaseMessageBytes.into());
+    };
+    let len = i32::from_be_bytes([*a, *b, *c, *d]);
+    let Ok(len) = usize::try_from(len) else {
+      bytes = rest;
+      continue;
+    };
+    let Some((value, local_rest)) = rest.split_at_checked(len) else {
+      return Err(PostgresError::UnexpectedDatabaseMessageBytes.into());
+    };
+    *elem = value;
+    bytes = local_rest;
+  }
+  Ok(rslt)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::postgres::executor::introspection::data_row_values;
+
+  #[test]
+  fn data_row_values_has_correct_output() {
+    let bytes = [
+      b'D', 0, 0, 0, 30, 0, 5, 0, 0, 0, 1, b't', 0, 0, 0, 3, b'f', b'o', b'o', 255, 255, 255, 255,
+      0, 0, 0, 2, b'2', b'3', 0, 0, 0, 1, b'0',
+    ];
+    assert_eq!(data_row_values::<4>(&bytes).unwrap(), [&b"t"[..], b"foo", b"", b"23"]);
+    assert_eq!(data_row_values::<1>(&bytes).unwrap(), [&b"t"[..]]);
+    assert!(data_row_values::<4>(&bytes[..12]).is_err());
+  }
+}
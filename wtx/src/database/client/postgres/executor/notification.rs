@@ -0,0 +1,82 @@
+use crate::{
+  database::client::postgres::{
+    executor_buffer::ExecutorBufferPartsMut, message::MessageTy, Executor, ExecutorBuffer,
+    Notification, PostgresError,
+  },
+  misc::{from_utf8_basic, LeaseMut, Stream},
+};
+use alloc::string::String;
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Registers the current session as a listener on the notification channel named `channel`.
+  ///
+  /// Notifications can be retrieved with [`Self::notification`].
+  #[inline]
+  pub async fn listen(&mut self, channel: &str) -> crate::Result<()> {
+    self.simple_query_execute(&channel_cmd("LISTEN ", channel), |_| {}).await
+  }
+
+  /// Awaits the next notification sent by the server.
+  ///
+  /// Notifications received while performing other operations are returned first, otherwise,
+  /// the stream is read until a notification arrives. Leftovers of previously failed commands
+  /// are discarded beforehand.
+  #[inline]
+  pub async fn notification(&mut self) -> crate::Result<Notification> {
+    let ExecutorBufferPartsMut { cp, nb, nq, .. } = self.eb.lease_mut().parts_mut();
+    Self::drain_pending_rfq(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream).await?;
+    if let Some(elem) = nq.pop_front() {
+      return Ok(elem);
+    }
+    loop {
+      nb._clear_if_following_is_empty();
+      let tag = Self::fetch_one_msg_from_stream(nb, &mut self.stream).await?;
+      match MessageTy::try_from((&mut self.cs, nb._current()))? {
+        MessageTy::NoticeResponse => {}
+        MessageTy::NotificationResponse(pid, channel, payload) => {
+          return Notification::new(pid, channel, payload);
+        }
+        MessageTy::ParameterStatus(name, value) => {
+          let name = from_utf8_basic(name)?.try_into()?;
+          let value = from_utf8_basic(value)?.try_into()?;
+          let _ = cp.insert(name, value);
+        }
+        _ => return Err(PostgresError::UnexpectedDatabaseMessage { received: tag }.into()),
+      }
+    }
+  }
+
+  /// Removes a previously registered listener on the notification channel named `channel`.
+  ///
+  /// Notifications already received are still available through [`Self::notification`].
+  #[inline]
+  pub async fn unlisten(&mut self, channel: &str) -> crate::Result<()> {
+    self.simple_query_execute(&channel_cmd("UNLISTEN ", channel), |_| {}).await
+  }
+
+  /// Removes all listeners registered by the current session.
+  #[inline]
+  pub async fn unlisten_all(&mut self) -> crate::Result<()> {
+    self.simple_query_execute("UNLISTEN *", |_| {}).await
+  }
+}
+
+// Channels are identifiers, as such, they must be quoted to preserve case and special characters.
+#[inline]
+fn channel_cmd(prefix: &str, channel: &str) -> String {
+  let mut cmd = String::with_capacity(prefix.len().wrapping_add(channel.len()).wrapping_add(2));
+  cmd.push_str(prefix);
+  cmd.push('"');
+  for elem in channel.chars() {
+    if elem == '"' {
+      cmd.push('"');
+    }
+    cmd.push(elem);
+  }
+  cmd.push('"');
+  cmd
+}
@@ -0,0 +1,142 @@
+use crate::{
+  database::client::postgres::{
+    executor::commons::FetchWithStmtCommons,
+    executor_buffer::{ExecutorBuffer, ExecutorBufferPartsMut},
+    message::MessageTy,
+    protocol::sync,
+    Executor, Pipeline, PostgresError, Records,
+  },
+  misc::{
+    filled_buffer::FilledBuffer, partitioned_filled_buffer::PartitionedFilledBuffer,
+    FilledBufferWriter, LeaseMut, Stream, Vector,
+  },
+};
+use core::marker::PhantomData;
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Sends all statements queued by `queue` in a single round trip and then calls `cb` with the
+  /// index, the number of affected records and the returned records of each statement, in the
+  /// same order of the queue.
+  ///
+  /// All statements are executed within the same implicit transaction, as such, if one statement
+  /// fails, the effects of the previous statements are discarded, the following statements are
+  /// not executed and the error is returned. In this case, `cb` is only called for the statements
+  /// that precede the failed statement.
+  ///
+  /// Statements that weren't previously prepared, as well as their user-defined types, are
+  /// retrieved in an additional round trip that precedes the execution of the pipeline.
+  #[inline]
+  pub async fn pipeline(
+    &mut self,
+    queue: impl FnOnce(&mut Pipeline<'_, '_, E>) -> Result<(), E>,
+    mut cb: impl FnMut(usize, u64, Records<'_, E>) -> Result<(), E>,
+  ) -> Result<(), E> {
+    let Self { cs, ct: _, eb, pending_rfq, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, nq, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    let mut items = Vector::new();
+    let mut prepare_fb = FilledBuffer::_new();
+    // Only used when there are statements to prepare.
+    let mut rest = Vector::new();
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      let mut prepare_fbw = FilledBufferWriter::new(0, &mut prepare_fb);
+      queue(&mut Pipeline::new(&mut fbw, &mut items, &mut prepare_fbw, stmts))?;
+      sync(&mut fbw)?;
+      if prepare_fbw._len() == 0 {
+        stream.write_all(fbw._curr_bytes()).await?;
+      } else {
+        sync(&mut prepare_fbw)?;
+        stream.write_all(prepare_fbw._curr_bytes()).await?;
+        rest.extend_from_copyable_slice(fbw._curr_bytes())?;
+      }
+    }
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
+    if !rest.is_empty() {
+      let rslt = async {
+        for (stmt_hash, has_parse) in items.iter().copied() {
+          if has_parse {
+            let _stmt = Self::await_stmt_prot(&mut fwsc, nb, stmt_hash, stmts).await?;
+          }
+        }
+        Self::await_rfq(&mut fwsc, nb).await
+      }
+      .await;
+      if let Err(err) = rslt {
+        Self::discard_until_rfq(&mut fwsc, nb).await;
+        return Err(err);
+      }
+      for (stmt_hash, has_parse) in items.iter().copied() {
+        if !has_parse {
+          continue;
+        }
+        if let Some(stmt_idx) = stmts.idx_by_stmt_hash(stmt_hash) {
+          ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+          Self::resolve_custom_tys(&mut fwsc, nb, stmt_idx, stmts).await?;
+        }
+      }
+      fwsc.stream.write_all(&rest).await?;
+    }
+    let rslt = async {
+      for (idx, (stmt_hash, _)) in items.iter().copied().enumerate() {
+        ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+        let Some(stmt) = stmts.get_by_stmt_hash(stmt_hash) else {
+          return Err(E::from(PostgresError::UnknownStatementId.into()));
+        };
+        let msg =
+          Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+        let MessageTy::BindComplete = msg.ty else {
+          return Err(E::from(
+            PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into(),
+          ));
+        };
+        let (rows, range) =
+          Self::fetch_records(&mut fwsc, nb, rb, &stmt, vb, &mut |_| Ok(())).await?;
+        let records = Records {
+          bytes: nb._buffer().get(range).unwrap_or_default(),
+          phantom: PhantomData,
+          records_values_offsets: rb,
+          stmt,
+          values_bytes_offsets: vb,
+        };
+        cb(idx, rows.unwrap_or_default(), records)?;
+      }
+      Self::await_rfq(&mut fwsc, nb).await
+    }
+    .await;
+    if let Err(err) = rslt {
+      Self::discard_until_rfq(&mut fwsc, nb).await;
+      return Err(err);
+    }
+    Ok(())
+  }
+
+  #[inline]
+  async fn await_rfq(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+  ) -> Result<(), E> {
+    let msg =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+    let MessageTy::ReadyForQuery = msg.ty else {
+      return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+    };
+    Ok(())
+  }
+
+  // `Sync` was already sent, as such, the remaining messages are discarded up to `ReadyForQuery`
+  // to keep the connection usable. Errors are ignored in favor of the original error.
+  #[inline]
+  async fn discard_until_rfq(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+  ) {
+    *fwsc.pending_rfq = true;
+    let _rslt = Self::drain_pending_rfq(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await;
+  }
+}
@@ -0,0 +1,135 @@
+use crate::{
+  database::{
+    client::postgres::{
+      executor::commons::FetchWithStmtCommons,
+      executor_buffer::{ExecutorBuffer, ExecutorBufferPartsMut},
+      message::MessageTy,
+      protocol::{bind, close, execute, flush, sync},
+      Executor, Postgres, PostgresError, Records,
+    },
+    RecordValues, StmtCmd,
+  },
+  misc::{
+    partitioned_filled_buffer::PartitionedFilledBuffer, FilledBufferWriter, LeaseMut, Stream, Usize,
+  },
+};
+use core::marker::PhantomData;
+
+impl<E, EB, S> Executor<E, EB, S>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  /// Binds `sc` to a named portal that is executed repeatedly, returning at most `max_rows`
+  /// records per round trip. Each chunk of records is given to `cb` and then discarded, as such,
+  /// large result sets can be processed with bounded memory.
+  ///
+  /// A `max_rows` of zero fetches everything in a single chunk and empty chunks are not given to
+  /// `cb`. Returns the total number of received records.
+  #[inline]
+  pub async fn fetch_chunks_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+    max_rows: u32,
+    mut cb: impl FnMut(Records<'_, E>) -> Result<(), E>,
+  ) -> Result<u64, E>
+  where
+    RV: RecordValues<Postgres<E>>,
+    SC: StmtCmd,
+  {
+    let Self { cs, ct: _, eb, pending_rfq, phantom: _, stream } = self;
+    let ExecutorBufferPartsMut { nb, nq, rb, stmts, vb, .. } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    let max_rows = i32::try_from(max_rows).map_err(crate::Error::from)?;
+    let mut fwsc = FetchWithStmtCommons { cs, nq, pending_rfq, stream, tys: &[] };
+    let (_, stmt_id, stmt) = Self::write_send_await_stmt_prot(&mut fwsc, nb, sc, stmts).await?;
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      bind(&mut fbw, &stmt_id, rv, &stmt, &stmt_id)?;
+      execute(&mut fbw, max_rows, &stmt_id)?;
+      flush(&mut fbw)?;
+      fwsc.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let chunks_rslt: Result<(Result<(), E>, u64), E> = async {
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+      let MessageTy::BindComplete = msg.ty else {
+        return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+      };
+      let mut total: u64 = 0;
+      loop {
+        ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+        let (rows, range) =
+          Self::fetch_records(&mut fwsc, nb, rb, &stmt, vb, &mut |_| Ok(())).await?;
+        total = total.wrapping_add(Usize::from(rb.len()).into_u64());
+        let rslt = if rb.is_empty() {
+          Ok(())
+        } else {
+          cb(Records {
+            bytes: nb._buffer().get(range).unwrap_or_default(),
+            phantom: PhantomData,
+            records_values_offsets: rb,
+            stmt: stmt.clone(),
+            values_bytes_offsets: vb,
+          })
+        };
+        if rows.is_some() || rslt.is_err() {
+          break Ok((rslt, total));
+        }
+        let mut fbw = FilledBufferWriter::from(&mut *nb);
+        execute(&mut fbw, max_rows, &stmt_id)?;
+        flush(&mut fbw)?;
+        fwsc.stream.write_all(fbw._curr_bytes()).await?;
+      }
+    }
+    .await;
+    let (cb_rslt, total) = match chunks_rslt {
+      Ok(elem) => elem,
+      Err(err) => {
+        // After an error, the server ignores all messages until a `Sync` is received. Remaining
+        // records of the portal, if any, are discarded along with the `ReadyForQuery` message.
+        let _rslt = Self::sync_and_drain(&mut fwsc, nb).await;
+        return Err(err);
+      }
+    };
+    ExecutorBuffer::clear_cmd_buffers(nb, rb, vb);
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      close(&stmt_id, &mut fbw, b'P')?;
+      sync(&mut fbw)?;
+      fwsc.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let msg =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+    let MessageTy::CloseComplete = msg.ty else {
+      // `Sync` was already sent, as such, the next command discards everything up to
+      // `ReadyForQuery`.
+      *fwsc.pending_rfq = msg.tag != b'Z';
+      return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+    };
+    let msg =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+    let MessageTy::ReadyForQuery = msg.ty else {
+      *fwsc.pending_rfq = true;
+      return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+    };
+    cb_rslt?;
+    Ok(total)
+  }
+
+  #[inline]
+  async fn sync_and_drain(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+  ) -> crate::Result<()> {
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      sync(&mut fbw)?;
+      fwsc.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    *fwsc.pending_rfq = true;
+    Self::drain_pending_rfq(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await
+  }
+}
@@ -1,7 +1,7 @@
 use crate::{
   database::{
     client::postgres::{
-      executor::commons::FetchWithStmtCommons,
+      executor::{commons::FetchWithStmtCommons, stmt_id_str},
       executor_buffer::ExecutorBuffer,
       message::MessageTy,
       msg_field::MsgField,
@@ -24,82 +24,44 @@ where
   EB: LeaseMut<ExecutorBuffer>,
   S: Stream,
 {
+  /// Reads the responses of the `Parse` and `Describe` messages of a statement, which is then
+  /// stored in `stmts`.
   #[inline]
-  pub(crate) async fn write_send_await_stmt_initial<RV>(
+  pub(crate) async fn await_stmt_prot<'stmts>(
     fwsc: &mut FetchWithStmtCommons<'_, S>,
     nb: &mut PartitionedFilledBuffer,
-    rv: RV,
-    stmt: &Statement<'_>,
-    stmt_id_str: &str,
-  ) -> Result<(), E>
-  where
-    RV: RecordValues<Postgres<E>>,
-  {
-    {
-      let mut fbw = FilledBufferWriter::from(&mut *nb);
-      bind(&mut fbw, "", rv, stmt, stmt_id_str)?;
-      execute(&mut fbw, 0, "")?;
-      sync(&mut fbw)?;
-      fwsc.stream.write_all(fbw._curr_bytes()).await?;
-    }
-    let msg = Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.stream).await?;
-    let MessageTy::BindComplete = msg.ty else {
-      return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
-    };
-    Ok(())
-  }
-
-  #[inline]
-  pub(crate) async fn write_send_await_stmt_prot<'stmts, SC>(
-    fwsc: &mut FetchWithStmtCommons<'_, S>,
-    nb: &mut PartitionedFilledBuffer,
-    sc: SC,
+    stmt_hash: u64,
     stmts: &'stmts mut Statements,
-  ) -> Result<(u64, ArrayString<22>, Statement<'stmts>), E>
-  where
-    SC: StmtCmd,
-  {
-    let stmt_hash = sc.hash(stmts.hasher_mut());
-    let stmt_id_str = Self::stmt_id_str(stmt_hash)?;
-    if stmts.get_by_stmt_hash(stmt_hash).is_some() {
-      // FIXME(stable): Use `if let Some ...` with polonius
-      return Ok((stmt_hash, stmt_id_str, stmts.get_by_stmt_hash(stmt_hash).unwrap()));
-    }
-
-    let stmt_cmd = sc.cmd().ok_or_else(|| E::from(PostgresError::UnknownStatementId.into()))?;
-
-    {
-      let mut fbw = FilledBufferWriter::from(&mut *nb);
-      parse(stmt_cmd, &mut fbw, fwsc.tys.iter().copied().map(Into::into), &stmt_id_str)?;
-      describe(&stmt_id_str, &mut fbw, b'S')?;
-      sync(&mut fbw)?;
-      fwsc.stream.write_all(fbw._curr_bytes()).await?;
-    }
-
-    let msg0 = Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.stream).await?;
+  ) -> Result<Statement<'stmts>, E> {
+    let msg0 =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
     let MessageTy::ParseComplete = msg0.ty else {
       return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg0.tag }.into()));
     };
 
-    let msg1 = Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.stream).await?;
+    let msg1 =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
     let MessageTy::ParameterDescription(types_len, mut pd) = msg1.ty else {
       return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg1.tag }.into()));
     };
 
-    let mut builder = stmts.builder();
+    let mut builder = stmts.builder()?;
     let _ = builder.expand(types_len.into())?;
+    let mut has_unresolved_tys = false;
 
     {
       let elements = builder.inserted_elements();
       for idx in 0..types_len {
         let element_opt = elements.get_mut(usize::from(idx));
         let ([a, b, c, d, sub_data @ ..], Some(element)) = (pd, element_opt) else { break };
-        element.1 = Ty::Custom(u32::from_be_bytes([*a, *b, *c, *d]));
+        element.1 = Ty::from_arbitrary_u32(u32::from_be_bytes([*a, *b, *c, *d]));
+        has_unresolved_tys |= matches!(element.1, Ty::Custom(_));
         pd = sub_data;
       }
     }
 
-    let msg2 = Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.stream).await?;
+    let msg2 =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
     let columns_len = match msg2.ty {
       MessageTy::NoData => 0,
       MessageTy::RowDescription(columns_len, mut rd) => {
@@ -109,11 +71,16 @@ where
         let elements = builder.inserted_elements();
         for idx in 0..columns_len {
           let (read, msg_field) = MsgField::parse(rd)?;
-          let ty = Ty::Custom(msg_field.type_oid);
+          let ty = Ty::from_arbitrary_u32(msg_field.type_oid);
+          has_unresolved_tys |= matches!(ty, Ty::Custom(_));
           let Some(element) = elements.get_mut(usize::from(idx)) else {
             break;
           };
-          element.0 = Column::new(msg_field.name.try_into()?, ty);
+          let mut column = Column::new(msg_field.name.try_into()?, ty);
+          if msg_field.table_oid != 0 {
+            column.origin = Some((msg_field.table_oid, msg_field.column_id));
+          }
+          element.0 = column;
           if let Some(elem @ [_not_empty, ..]) = rd.get(read..) {
             rd = elem;
           } else {
@@ -129,21 +96,74 @@ where
       }
     };
 
-    let msg3 = Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.stream).await?;
-    let MessageTy::ReadyForQuery = msg3.ty else {
-      return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg3.tag }.into()));
-    };
-
-    let sm = StatementsMisc::new(columns_len.into(), types_len.into());
+    let mut sm = StatementsMisc::new(columns_len.into(), types_len.into());
+    sm.has_unresolved_tys = has_unresolved_tys;
     let idx = builder.build(stmt_hash, sm)?;
     let Some(stmt) = stmts.get_by_idx(idx) else {
       return Err(crate::Error::ProgrammingError.into());
     };
-    Ok((stmt_hash, stmt_id_str, stmt))
+    Ok(stmt)
+  }
+
+  #[inline]
+  pub(crate) async fn write_send_await_stmt_initial<RV>(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+    rv: RV,
+    stmt: &Statement<'_>,
+    stmt_id_str: &str,
+  ) -> Result<(), E>
+  where
+    RV: RecordValues<Postgres<E>>,
+  {
+    {
+      let mut fbw = FilledBufferWriter::from(&mut *nb);
+      bind(&mut fbw, "", rv, stmt, stmt_id_str)?;
+      execute(&mut fbw, 0, "")?;
+      sync(&mut fbw)?;
+      fwsc.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let msg =
+      Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+    let MessageTy::BindComplete = msg.ty else {
+      return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+    };
+    Ok(())
   }
 
   #[inline]
-  fn stmt_id_str(stmt_hash: u64) -> crate::Result<ArrayString<22>> {
-    ArrayString::try_from(format_args!("s{stmt_hash}"))
+  pub(crate) async fn write_send_await_stmt_prot<'stmts, SC>(
+    fwsc: &mut FetchWithStmtCommons<'_, S>,
+    nb: &mut PartitionedFilledBuffer,
+    sc: SC,
+    stmts: &'stmts mut Statements,
+  ) -> Result<(u64, ArrayString<22>, Statement<'stmts>), E>
+  where
+    SC: StmtCmd,
+  {
+    let stmt_hash = sc.hash(stmts.hasher_mut());
+    let stmt_id_str = stmt_id_str(stmt_hash)?;
+    let stmt_idx = if let Some(elem) = stmts.idx_by_stmt_hash(stmt_hash) {
+      elem
+    } else {
+      let stmt_cmd = sc.cmd().ok_or_else(|| E::from(PostgresError::UnknownStatementId.into()))?;
+      {
+        let mut fbw = FilledBufferWriter::from(&mut *nb);
+        parse(stmt_cmd, &mut fbw, fwsc.tys.iter().copied().map(Into::into), &stmt_id_str)?;
+        describe(&stmt_id_str, &mut fbw, b'S')?;
+        sync(&mut fbw)?;
+        fwsc.stream.write_all(fbw._curr_bytes()).await?;
+      }
+      let _stmt = Self::await_stmt_prot(fwsc, nb, stmt_hash, stmts).await?;
+      let msg =
+        Self::fetch_msg_from_stream(fwsc.cs, nb, fwsc.nq, fwsc.pending_rfq, fwsc.stream).await?;
+      let MessageTy::ReadyForQuery = msg.ty else {
+        return Err(E::from(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()));
+      };
+      stmts.idx_by_stmt_hash(stmt_hash).ok_or_else(|| E::from(crate::Error::ProgrammingError))?
+    };
+    Self::resolve_custom_tys(fwsc, nb, stmt_idx, stmts).await?;
+    let stmt = stmts.get_by_idx(stmt_idx).ok_or_else(|| E::from(crate::Error::ProgrammingError))?;
+    Ok((stmt_hash, stmt_id_str, stmt))
   }
 }
@@ -3,7 +3,7 @@ use crate::{
     executor_buffer::ExecutorBufferPartsMut, message::MessageTy, protocol::query, Executor,
     ExecutorBuffer, PostgresError,
   },
-  misc::{FilledBufferWriter, LeaseMut, Stream},
+  misc::{into_rslt, FilledBufferWriter, LeaseMut, Stream},
 };
 
 impl<E, EB, S> Executor<E, EB, S>
@@ -25,8 +25,10 @@ where
       self.stream.write_all(fbw._curr_bytes()).await?;
     }
     loop {
-      let nb = &mut self.eb.lease_mut().nb;
-      let msg = Self::fetch_msg_from_stream(&mut self.cs, nb, &mut self.stream).await?;
+      let ExecutorBufferPartsMut { nb, nq, .. } = self.eb.lease_mut().parts_mut();
+      let msg =
+        Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+          .await?;
       match msg.ty {
         MessageTy::CommandComplete(n) => cb(n),
         MessageTy::EmptyQueryResponse => {
@@ -37,4 +39,32 @@ where
       }
     }
   }
+
+  /// Sends `SHOW transaction_read_only` and returns `true` if the session can't write data.
+  #[inline]
+  pub(crate) async fn transaction_read_only(&mut self) -> crate::Result<bool> {
+    {
+      let mut fbw = FilledBufferWriter::from(&mut self.eb.lease_mut().nb);
+      query(b"SHOW transaction_read_only", &mut fbw)?;
+      self.stream.write_all(fbw._curr_bytes()).await?;
+    }
+    let mut is_read_only = None;
+    loop {
+      let ExecutorBufferPartsMut { nb, nq, .. } = self.eb.lease_mut().parts_mut();
+      let msg =
+        Self::fetch_msg_from_stream(&mut self.cs, nb, nq, &mut self.pending_rfq, &mut self.stream)
+          .await?;
+      match msg.ty {
+        MessageTy::CommandComplete(_) | MessageTy::RowDescription(..) => {}
+        MessageTy::DataRow(_) => {
+          // Tag, message length, number of columns and value length
+          if let [_, _, _, _, _, _, _, _, _, _, _, value @ ..] = nb._current() {
+            is_read_only = Some(value == b"on");
+          }
+        }
+        MessageTy::ReadyForQuery => return into_rslt(is_read_only),
+        _ => return Err(PostgresError::UnexpectedDatabaseMessage { received: msg.tag }.into()),
+      }
+    }
+  }
 }
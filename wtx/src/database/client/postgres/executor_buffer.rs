@@ -1,6 +1,9 @@
 use crate::{
-  database::{client::postgres::Statements, Identifier},
-  misc::{partitioned_filled_buffer::PartitionedFilledBuffer, Lease, LeaseMut, Rng, Vector},
+  database::{
+    client::postgres::{Notification, Statements},
+    Identifier,
+  },
+  misc::{partitioned_filled_buffer::PartitionedFilledBuffer, Deque, Lease, LeaseMut, Rng, Vector},
 };
 use core::ops::Range;
 use hashbrown::HashMap;
@@ -12,6 +15,8 @@ pub struct ExecutorBuffer {
   pub(crate) cp: HashMap<Identifier, Identifier>,
   /// Network Buffer.
   pub(crate) nb: PartitionedFilledBuffer,
+  /// Notifications Queue. Notifications received while waiting for other messages.
+  pub(crate) nq: Deque<Notification>,
   /// Records Buffer.
   pub(crate) rb: Vector<usize>,
   /// Statements
@@ -30,6 +35,7 @@ impl ExecutorBuffer {
     Self {
       cp: HashMap::new(),
       nb: PartitionedFilledBuffer::new(),
+      nq: Deque::new(),
       rb: Vector::new(),
       stmts: Statements::new(max_stmts, rng),
       vb: Vector::new(),
@@ -49,18 +55,26 @@ impl ExecutorBuffer {
     Ok(Self {
       cp: HashMap::with_capacity(4),
       nb: PartitionedFilledBuffer::_with_capacity(network_buffer_cap)?,
+      nq: Deque::new(),
       rb: Vector::with_capacity(rows_cap)?,
       stmts: Statements::with_capacity(columns_cap, max_stmts, rng, stmts_cap)?,
       vb: Vector::with_capacity(rows_cap.saturating_mul(columns_cap))?,
     })
   }
 
+  /// Cached statements and user-defined types.
+  #[inline]
+  pub fn stmts(&self) -> &Statements {
+    &self.stmts
+  }
+
   /// Should be used in a new instance.
   #[inline]
   pub(crate) fn clear(&mut self) {
-    let Self { cp, nb, rb, stmts, vb } = self;
+    let Self { cp, nb, nq, rb, stmts, vb } = self;
     cp.clear();
     nb._clear();
+    nq.clear();
     rb.clear();
     stmts.clear();
     vb.clear();
@@ -83,6 +97,7 @@ impl ExecutorBuffer {
     ExecutorBufferPartsMut {
       cp: &mut self.cp,
       nb: &mut self.nb,
+      nq: &mut self.nq,
       rb: &mut self.rb,
       stmts: &mut self.stmts,
       vb: &mut self.vb,
@@ -107,6 +122,7 @@ impl LeaseMut<ExecutorBuffer> for ExecutorBuffer {
 pub(crate) struct ExecutorBufferPartsMut<'eb> {
   pub(crate) cp: &'eb mut HashMap<Identifier, Identifier>,
   pub(crate) nb: &'eb mut PartitionedFilledBuffer,
+  pub(crate) nq: &'eb mut Deque<Notification>,
   pub(crate) rb: &'eb mut Vector<usize>,
   pub(crate) stmts: &'eb mut Statements,
   pub(crate) vb: &'eb mut Vector<(bool, Range<usize>)>,
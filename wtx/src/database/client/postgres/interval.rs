@@ -0,0 +1,36 @@
+/// Period of time composed by months, days and microseconds.
+///
+/// The components are stored separately because the number of days in a month and the number of
+/// microseconds in a day are not fixed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Interval {
+  days: i32,
+  microseconds: i64,
+  months: i32,
+}
+
+impl Interval {
+  /// Constructor
+  #[inline]
+  pub const fn new(months: i32, days: i32, microseconds: i64) -> Self {
+    Self { days, microseconds, months }
+  }
+
+  /// Number of days.
+  #[inline]
+  pub const fn days(&self) -> i32 {
+    self.days
+  }
+
+  /// Number of microseconds.
+  #[inline]
+  pub const fn microseconds(&self) -> i64 {
+    self.microseconds
+  }
+
+  /// Number of months.
+  #[inline]
+  pub const fn months(&self) -> i32 {
+    self.months
+  }
+}
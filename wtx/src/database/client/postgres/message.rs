@@ -16,7 +16,7 @@ pub(crate) enum MessageTy<'bytes> {
   /// See [Authentication].
   Authentication(Authentication<'bytes>),
   /// Data that the frontend must use to issue a cancellation request.
-  BackendKeyData,
+  BackendKeyData(i32, i32),
   /// Bind request was successful.
   BindComplete,
   /// Close request was successful.
@@ -27,10 +27,12 @@ pub(crate) enum MessageTy<'bytes> {
   CopyData,
   /// COPY command finished.
   CopyDone,
-  /// Starting of a COPY command from the client to the server.
-  CopyInResponse,
-  /// Starting of a COPY command from the server to the client.
-  CopyOutResponse,
+  /// Starting of a COPY command from the client to the server. Contains the overall format,
+  /// where 0 is textual and 1 is binary.
+  CopyInResponse(u8),
+  /// Starting of a COPY command from the server to the client. Contains the overall format,
+  /// where 0 is textual and 1 is binary.
+  CopyOutResponse(u8),
   /// Row containing the number of columns with values.
   DataRow(u16),
   /// Empty query response.
@@ -39,8 +41,9 @@ pub(crate) enum MessageTy<'bytes> {
   NoData,
   /// Information response.
   NoticeResponse,
-  /// Notification response.
-  NotificationResponse,
+  /// Notification response. Contains the process ID of the notifying backend, the channel name
+  /// and the payload.
+  NotificationResponse(i32, &'bytes [u8], &'bytes [u8]),
   /// Parameters of a query.
   ParameterDescription(u16, &'bytes [u8]),
   /// Parameter status report.
@@ -64,7 +67,16 @@ impl<'bytes> TryFrom<(&mut ConnectionState, &'bytes [u8])> for MessageTy<'bytes>
       [b'1', ..] => Self::ParseComplete,
       [b'2', ..] => Self::BindComplete,
       [b'3', ..] => Self::CloseComplete,
-      [b'A', ..] => Self::NotificationResponse,
+      [b'A', _, _, _, _, a, b, c, d, rest @ ..] => {
+        let rslt = || {
+          let mut iter = bytes_split1(rest, b'\0');
+          let channel = iter.next()?;
+          let payload = iter.next()?;
+          Some((channel, payload))
+        };
+        let (channel, payload) = rslt().ok_or(PostgresError::UnexpectedDatabaseMessageBytes)?;
+        Self::NotificationResponse(i32::from_be_bytes([*a, *b, *c, *d]), channel, payload)
+      }
       [b'C', _, _, _, _, rest @ ..] => {
         let rows = bytes_rsplit1(rest, b' ')
           .next()
@@ -83,10 +95,13 @@ impl<'bytes> TryFrom<(&mut ConnectionState, &'bytes [u8])> for MessageTy<'bytes>
         *from.0 = ConnectionState::Closed;
         return Err(DbError::try_from(from_utf8_basic(rest)?)?.into());
       }
-      [b'G', ..] => Self::CopyInResponse,
-      [b'H', ..] => Self::CopyOutResponse,
+      [b'G', _, _, _, _, format, ..] => Self::CopyInResponse(*format),
+      [b'H', _, _, _, _, format, ..] => Self::CopyOutResponse(*format),
       [b'I', ..] => Self::EmptyQueryResponse,
-      [b'K', _, _, _, _, _a, _b, _c, _d, _e, _f, _g, _h] => Self::BackendKeyData,
+      [b'K', _, _, _, _, a, b, c, d, e, f, g, h] => Self::BackendKeyData(
+        i32::from_be_bytes([*a, *b, *c, *d]),
+        i32::from_be_bytes([*e, *f, *g, *h]),
+      ),
       [b'N', ..] => Self::NoticeResponse,
       [b'R', _, _, _, _, rest @ ..] => Self::Authentication(rest.try_into()?),
       [b'S', _, _, _, _, rest @ ..] => {
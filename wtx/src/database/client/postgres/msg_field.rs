@@ -5,7 +5,9 @@ use crate::{
 
 #[derive(Debug)]
 pub(crate) struct MsgField<'bytes> {
+  pub(crate) column_id: i16,
   pub(crate) name: &'bytes str,
+  pub(crate) table_oid: Oid,
   pub(crate) type_oid: Oid,
 }
 
@@ -22,13 +24,13 @@ impl<'bytes> MsgField<'bytes> {
           return None;
         };
         let name = from_utf8_basic(name_bytes).ok()?;
-        let _table_oid = u32::from_be_bytes([b1, b2, b3, b4]);
-        let _column_id = i16::from_be_bytes([b5, b6]);
+        let table_oid = u32::from_be_bytes([b1, b2, b3, b4]);
+        let column_id = i16::from_be_bytes([b5, b6]);
         let type_oid = u32::from_be_bytes([b7, b8, b9, b10]);
         let _type_size = i16::from_be_bytes([b11, b12]);
         let _type_modifier = i32::from_be_bytes([b13, b14, b15, b16]);
         let _format = i16::from_be_bytes([b17, b18]);
-        Some((name_bytes.len().wrapping_add(19), Self { name, type_oid }))
+        Some((name_bytes.len().wrapping_add(19), Self { column_id, name, table_oid, type_oid }))
       })
       .ok_or_else(|| PostgresError::UnexpectedDatabaseMessageBytes.into())
   }
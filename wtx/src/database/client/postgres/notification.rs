@@ -0,0 +1,40 @@
+use crate::misc::from_utf8_basic;
+use alloc::string::String;
+
+/// Asynchronous message sent by the server as a result of a `NOTIFY` command issued on a channel
+/// that the current session is listening to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Notification {
+  buffer: String,
+  channel_len: usize,
+  pid: i32,
+}
+
+impl Notification {
+  #[inline]
+  pub(crate) fn new(pid: i32, channel: &[u8], payload: &[u8]) -> crate::Result<Self> {
+    let channel_str = from_utf8_basic(channel)?;
+    let payload_str = from_utf8_basic(payload)?;
+    let mut buffer = String::from(channel_str);
+    buffer.push_str(payload_str);
+    Ok(Self { buffer, channel_len: channel_str.len(), pid })
+  }
+
+  /// The name of the channel that the notify has been raised on.
+  #[inline]
+  pub fn channel(&self) -> &str {
+    self.buffer.get(..self.channel_len).unwrap_or_default()
+  }
+
+  /// The "payload" string passed from the notifying process.
+  #[inline]
+  pub fn payload(&self) -> &str {
+    self.buffer.get(self.channel_len..).unwrap_or_default()
+  }
+
+  /// The process ID of the notifying backend process.
+  #[inline]
+  pub fn pid(&self) -> i32 {
+    self.pid
+  }
+}
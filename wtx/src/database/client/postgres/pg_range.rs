@@ -0,0 +1,85 @@
+use crate::misc::Vector;
+use core::ops::Bound;
+
+/// Continuous set of values delimited by two bounds. Represents types like `int4range` or
+/// `tstzrange`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PgRange<T> {
+  is_empty: bool,
+  lower: Bound<T>,
+  upper: Bound<T>,
+}
+
+impl<T> PgRange<T> {
+  /// Range that contains all values between `lower` and `upper`.
+  #[inline]
+  pub const fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+    Self { is_empty: false, lower, upper }
+  }
+
+  /// Range that contains no values.
+  #[inline]
+  pub const fn empty() -> Self {
+    Self { is_empty: true, lower: Bound::Unbounded, upper: Bound::Unbounded }
+  }
+
+  /// If the range contains no values. Both bounds are unbounded in this case.
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.is_empty
+  }
+
+  /// Lower bound
+  #[inline]
+  pub const fn lower(&self) -> &Bound<T> {
+    &self.lower
+  }
+
+  /// Upper bound
+  #[inline]
+  pub const fn upper(&self) -> &Bound<T> {
+    &self.upper
+  }
+}
+
+impl<T> From<core::ops::Range<T>> for PgRange<T> {
+  #[inline]
+  fn from(from: core::ops::Range<T>) -> Self {
+    Self::new(Bound::Included(from.start), Bound::Excluded(from.end))
+  }
+}
+
+impl<T> From<core::ops::RangeInclusive<T>> for PgRange<T> {
+  #[inline]
+  fn from(from: core::ops::RangeInclusive<T>) -> Self {
+    let (start, end) = from.into_inner();
+    Self::new(Bound::Included(start), Bound::Included(end))
+  }
+}
+
+/// Ordered set of non-contiguous ranges. Represents types like `int4multirange` or
+/// `tstzmultirange`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PgMultiRange<T> {
+  ranges: Vector<PgRange<T>>,
+}
+
+impl<T> PgMultiRange<T> {
+  /// Constructor
+  #[inline]
+  pub const fn new(ranges: Vector<PgRange<T>>) -> Self {
+    Self { ranges }
+  }
+
+  /// All ranges
+  #[inline]
+  pub fn ranges(&self) -> &[PgRange<T>] {
+    &self.ranges
+  }
+
+  /// Returns the underlying ranges.
+  #[inline]
+  pub fn into_ranges(self) -> Vector<PgRange<T>> {
+    self.ranges
+  }
+}
@@ -0,0 +1,79 @@
+use crate::{
+  database::{
+    client::postgres::{
+      executor::stmt_id_str,
+      protocol::{bind, describe, execute, parse},
+      statements::statement::Statement,
+      Postgres, PostgresError, Statements,
+    },
+    RecordValues, StmtCmd,
+  },
+  misc::{FilledBufferWriter, Vector},
+};
+use core::marker::PhantomData;
+
+/// Queue of statements that are sent in a single round trip. See
+/// [`crate::database::client::postgres::Executor::pipeline`].
+#[derive(Debug)]
+pub struct Pipeline<'any, 'buffer, E> {
+  fbw: &'any mut FilledBufferWriter<'buffer>,
+  /// Each element is the hash of a statement and a flag indicating that it was parsed in this
+  /// pipeline.
+  items: &'any mut Vector<(u64, bool)>,
+  phantom: PhantomData<fn() -> E>,
+  /// Messages of statements that aren't cached, which are sent before the rest of the pipeline.
+  prepare_fbw: &'any mut FilledBufferWriter<'buffer>,
+  stmts: &'any mut Statements,
+}
+
+impl<'any, 'buffer, E> Pipeline<'any, 'buffer, E>
+where
+  E: From<crate::Error>,
+{
+  #[inline]
+  pub(crate) fn new(
+    fbw: &'any mut FilledBufferWriter<'buffer>,
+    items: &'any mut Vector<(u64, bool)>,
+    prepare_fbw: &'any mut FilledBufferWriter<'buffer>,
+    stmts: &'any mut Statements,
+  ) -> Self {
+    Self { fbw, items, phantom: PhantomData, prepare_fbw, stmts }
+  }
+
+  /// The number of queued statements.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.items.len()
+  }
+
+  /// If there are no queued statements.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+
+  /// Queues a **single** statement automatically binding the values of `rv` to the referenced
+  /// `sc`.
+  ///
+  /// Statements that aren't cached are prepared in a preceding round trip.
+  #[inline]
+  pub fn push<RV, SC>(&mut self, sc: SC, rv: RV) -> Result<(), E>
+  where
+    RV: RecordValues<Postgres<E>>,
+    SC: StmtCmd,
+  {
+    let stmt_hash = sc.hash(self.stmts.hasher_mut());
+    let stmt_id_str = stmt_id_str(stmt_hash)?;
+    let has_parse = self.stmts.get_by_stmt_hash(stmt_hash).is_none()
+      && !self.items.iter().any(|(hash, local_has_parse)| *hash == stmt_hash && *local_has_parse);
+    if has_parse {
+      let stmt_cmd = sc.cmd().ok_or_else(|| E::from(PostgresError::UnknownStatementId.into()))?;
+      parse(stmt_cmd, self.prepare_fbw, [], &stmt_id_str)?;
+      describe(&stmt_id_str, self.prepare_fbw, b'S')?;
+    }
+    bind(self.fbw, "", rv, &Statement::default(), &stmt_id_str)?;
+    execute(self.fbw, 0, "")?;
+    self.items.push((stmt_hash, has_parse))?;
+    Ok(())
+  }
+}
@@ -5,8 +5,17 @@ pub enum PostgresError {
   DecimalCanNotBeConvertedFromNaN,
   /// There are no sufficient bytes to decoding an element
   DecodingError,
+  /// The configuration doesn't contain any host.
+  EmptyHostList,
+  /// The server requested a cleartext password but the configuration forbids sending it
+  /// through the current connection.
+  ForbiddenCleartextPassword,
   /// There are no bytes left to build a `DbError`
   InsufficientDbErrorBytes,
+  /// The dimensions of an array don't match its elements or aren't supported by the target type.
+  InvalidArrayDimensions,
+  /// Records can only be written in binary `COPY` operations.
+  InvalidCopyFormat,
   /// Invalid IP format
   InvalidIpFormat,
   /// JSONB is the only supported JSON format
@@ -21,6 +30,8 @@ pub enum PostgresError {
   /// It is required to connect using a TLS channel but the server didn't provide any. Probably
   /// because the connection is unencrypted.
   MissingChannel,
+  /// Found a `NULL` element in an array whose elements aren't optional.
+  NullArrayElement,
   /// Expected one record but got none.
   NoRecord,
   /// It is required to connect without using a TLS channel but the server only provided a way to
@@ -49,6 +60,9 @@ pub enum PostgresError {
     /// Expected
     expected: &'static str,
   },
+  /// The server doesn't satisfy the `target_session_attrs` parameter. For example, a
+  /// `read-write` session was required but the server is a read-only replica.
+  UnsatisfiedTargetSessionAttrs,
   /// The system does not support a requested authentication method.
   UnknownAuthenticationMethod,
   /// The system does not support a provided parameter.
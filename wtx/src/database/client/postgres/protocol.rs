@@ -32,35 +32,8 @@ where
       Ok(())
     })?;
 
-    {
-      local_fbw.extend_from_slice(&i16::try_from(rv_len).map_err(Into::into)?.to_be_bytes())?;
-      let mut aux = (0usize, 0);
-      let _ = rv.encode_values(
-        &mut aux,
-        &mut EncodeValue::new(local_fbw),
-        |(counter, start), local_ev| {
-          *counter = counter.wrapping_add(1);
-          *start = local_ev.fbw()._len();
-          let _rslt = local_ev.fbw().extend_from_slice(&[0; 4]);
-          4
-        },
-        |(_, start), local_ev, is_null, elem_len| {
-          let written = if is_null { -1i32 } else { i32::try_from(elem_len).unwrap_or(i32::MAX) };
-          let bytes_opt = local_ev.fbw()._curr_bytes_mut().get_mut(*start..);
-          if let Some([a0, b0, c0, d0, ..]) = bytes_opt {
-            let [a1, b1, c1, d1] = written.to_be_bytes();
-            *a0 = a1;
-            *b0 = b1;
-            *c0 = c1;
-            *d0 = d1;
-          }
-          0
-        },
-      )?;
-      if aux.0 != rv_len {
-        return Err(E::from(PostgresError::InvalidRecordValuesIterator.into()));
-      }
-    }
+    local_fbw.extend_from_slice(&i16::try_from(rv_len).map_err(Into::into)?.to_be_bytes())?;
+    encode_values(local_fbw, &mut rv)?;
 
     write_iter(local_fbw, &[1i16], None, |elem, local_local_fbw| {
       local_local_fbw.extend_from_slice(&elem.to_be_bytes())?;
@@ -71,6 +44,50 @@ where
   })
 }
 
+#[inline]
+pub(crate) fn cancel_request(
+  fbw: &mut FilledBufferWriter<'_>,
+  pid: i32,
+  secret_key: i32,
+) -> crate::Result<()> {
+  write(fbw, true, None, |local_fbw| {
+    local_fbw._extend_from_slices([
+      &0b0000_0100_1101_0010_0001_0110_0010_1110i32.to_be_bytes()[..],
+      &pid.to_be_bytes(),
+      &secret_key.to_be_bytes(),
+    ])?;
+    Ok::<_, crate::Error>(())
+  })
+}
+
+#[inline]
+pub(crate) fn close(
+  data: &str,
+  fbw: &mut FilledBufferWriter<'_>,
+  variant: u8,
+) -> crate::Result<()> {
+  write(fbw, true, Some(b'C'), |local_fbw| {
+    local_fbw._extend_from_byte(variant)?;
+    local_fbw._extend_from_slice_c(data.as_bytes())?;
+    Ok(())
+  })
+}
+
+#[inline]
+pub(crate) fn copy_data(fbw: &mut FilledBufferWriter<'_>, data: &[u8]) -> crate::Result<()> {
+  write(fbw, true, Some(b'd'), |local_fbw| local_fbw.extend_from_slice(data))
+}
+
+#[inline]
+pub(crate) fn copy_done(fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  write(fbw, true, Some(b'c'), |_| Ok::<_, crate::Error>(()))
+}
+
+#[inline]
+pub(crate) fn copy_fail(fbw: &mut FilledBufferWriter<'_>, msg: &str) -> crate::Result<()> {
+  write(fbw, true, Some(b'f'), |local_fbw| local_fbw._extend_from_slice_c(msg.as_bytes()))
+}
+
 #[inline]
 pub(crate) fn describe(
   data: &str,
@@ -84,6 +101,44 @@ pub(crate) fn describe(
   })
 }
 
+/// Writes each value of `rv` prefixed by its length, where `-1` means `NULL`. Doesn't write the
+/// number of values.
+#[inline]
+pub(crate) fn encode_values<E, RV>(fbw: &mut FilledBufferWriter<'_>, rv: &mut RV) -> Result<(), E>
+where
+  E: From<crate::Error>,
+  RV: RecordValues<Postgres<E>>,
+{
+  let rv_len = rv.len();
+  let mut aux = (0usize, 0);
+  let _ = rv.encode_values(
+    &mut aux,
+    &mut EncodeValue::new(fbw),
+    |(counter, start), local_ev| {
+      *counter = counter.wrapping_add(1);
+      *start = local_ev.fbw()._len();
+      let _rslt = local_ev.fbw().extend_from_slice(&[0; 4]);
+      4
+    },
+    |(_, start), local_ev, is_null, elem_len| {
+      let written = if is_null { -1i32 } else { i32::try_from(elem_len).unwrap_or(i32::MAX) };
+      let bytes_opt = local_ev.fbw()._curr_bytes_mut().get_mut(*start..);
+      if let Some([a0, b0, c0, d0, ..]) = bytes_opt {
+        let [a1, b1, c1, d1] = written.to_be_bytes();
+        *a0 = a1;
+        *b0 = b1;
+        *c0 = c1;
+        *d0 = d1;
+      }
+      0
+    },
+  )?;
+  if aux.0 != rv_len {
+    return Err(E::from(PostgresError::InvalidRecordValuesIterator.into()));
+  }
+  Ok(())
+}
+
 #[inline]
 pub(crate) fn encrypted_conn(fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
   write(fbw, true, None, |local_fbw| {
@@ -105,6 +160,11 @@ pub(crate) fn execute(
   })
 }
 
+#[inline]
+pub(crate) fn flush(fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
+  write(fbw, true, Some(b'H'), |_| Ok::<_, crate::Error>(()))
+}
+
 #[inline]
 pub(crate) fn initial_conn_msg(
   config: &Config<'_>,
@@ -146,6 +206,14 @@ pub(crate) fn parse(
   })
 }
 
+#[inline]
+pub(crate) fn password(fbw: &mut FilledBufferWriter<'_>, password: &[u8]) -> crate::Result<()> {
+  write(fbw, true, Some(b'p'), |local_fbw| {
+    local_fbw._extend_from_slice_c(password)?;
+    Ok::<_, crate::Error>(())
+  })
+}
+
 #[inline]
 pub(crate) fn query(cmd: &[u8], fbw: &mut FilledBufferWriter<'_>) -> crate::Result<()> {
   write(fbw, true, Some(b'Q'), |local_fbw| {
@@ -296,3 +364,19 @@ where
   }
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::postgres::protocol::encode_values,
+    misc::{filled_buffer::FilledBuffer, FilledBufferWriter, Wrapper},
+  };
+
+  #[test]
+  fn encodes_values_of_iterators() {
+    let mut fb = FilledBuffer::_new();
+    let mut fbw = FilledBufferWriter::new(0, &mut fb);
+    encode_values::<crate::Error, _>(&mut fbw, &mut Wrapper([1i32, 2].into_iter())).unwrap();
+    assert_eq!(fbw._curr_bytes(), &[0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 2]);
+  }
+}
@@ -4,15 +4,16 @@ pub(crate) mod statement_builder;
 pub(crate) mod statements_misc;
 
 use crate::{
-  database::client::postgres::ty::Ty,
-  misc::{BlocksDeque, Rng, _random_state},
+  database::client::postgres::{ty::Ty, CustomTy, CustomTyKind, Oid},
+  misc::{BlocksDeque, Rng, Vector, _random_state},
 };
 use foldhash::fast::FixedState;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 
 /// Statements
 #[derive(Debug)]
 pub struct Statements {
+  custom_tys: HashMap<Oid, CustomTy>,
   max_stmts: usize,
   rs: FixedState,
   stmts: BlocksDeque<(column::Column, Ty), statements_misc::StatementsMisc>,
@@ -26,6 +27,7 @@ impl Statements {
     RNG: Rng,
   {
     Self {
+      custom_tys: HashMap::new(),
       max_stmts: max_stmts.max(1),
       rs: _random_state(rng),
       stmts: BlocksDeque::new(),
@@ -44,6 +46,7 @@ impl Statements {
     RNG: Rng,
   {
     Ok(Self {
+      custom_tys: HashMap::new(),
       max_stmts: max_stmts.max(1),
       rs: _random_state(rng),
       stmts: BlocksDeque::with_capacity(stmts, columns)?,
@@ -51,13 +54,41 @@ impl Statements {
     })
   }
 
+  /// User-defined type previously retrieved from the system catalogs.
+  ///
+  /// Types are retrieved when a statement that references them is prepared and are kept until
+  /// all statements that reference them are evicted from the cache, as such, types that are
+  /// dropped and then re-created while statements are cached are not updated.
   #[inline]
-  pub(crate) fn builder(&mut self) -> statement_builder::StatementBuilder<'_> {
+  pub fn custom_ty(&self, oid: Oid) -> Option<&CustomTy> {
+    self.custom_tys.get(&oid)
+  }
+
+  /// Follows domains until a type that isn't a domain is found.
+  #[inline]
+  pub fn underlying_ty(&self, ty: Ty) -> Ty {
+    underlying_ty(&self.custom_tys, ty)
+  }
+
+  #[inline]
+  pub(crate) fn builder(&mut self) -> crate::Result<statement_builder::StatementBuilder<'_>> {
     if self.stmts.blocks_len() >= self.max_stmts {
       let to_remove = (self.max_stmts / 2).max(1);
+      let mut evicted_oids = HashSet::new();
       for _ in 0..to_remove {
+        if let Some(stmt) = self.stmts.get(0) {
+          referenced_oids(&self.custom_tys, stmt.data, &mut evicted_oids)?;
+        }
         let _ = self.stmts.pop_front();
       }
+      // Types of evicted statements are kept if they are still referenced by other statements.
+      let mut remaining_oids = HashSet::new();
+      for stmt in self.stmts.iter() {
+        referenced_oids(&self.custom_tys, stmt.data, &mut remaining_oids)?;
+      }
+      for oid in evicted_oids.difference(&remaining_oids) {
+        let _ = self.custom_tys.remove(oid);
+      }
       self.stmts_indcs.retain(|_, value| {
         if *value < to_remove {
           return false;
@@ -66,12 +97,13 @@ impl Statements {
         true
       })
     }
-    statement_builder::StatementBuilder::new(&mut self.stmts, &mut self.stmts_indcs)
+    Ok(statement_builder::StatementBuilder::new(&mut self.stmts, &mut self.stmts_indcs))
   }
 
   #[inline]
   pub(crate) fn clear(&mut self) {
-    let Self { max_stmts: _, rs: _, stmts, stmts_indcs } = self;
+    let Self { custom_tys, max_stmts: _, rs: _, stmts, stmts_indcs } = self;
+    custom_tys.clear();
     stmts.clear();
     stmts_indcs.clear();
   }
@@ -84,13 +116,107 @@ impl Statements {
 
   #[inline]
   pub(crate) fn get_by_stmt_hash(&self, stmt_hash: u64) -> Option<statement::Statement<'_>> {
-    self.get_by_idx(*self.stmts_indcs.get(&stmt_hash)?)
+    self.get_by_idx(self.idx_by_stmt_hash(stmt_hash)?)
+  }
+
+  #[inline]
+  pub(crate) fn idx_by_stmt_hash(&self, stmt_hash: u64) -> Option<usize> {
+    self.stmts_indcs.get(&stmt_hash).copied()
   }
 
   #[inline]
   pub(crate) fn hasher_mut(&mut self) -> &mut FixedState {
     &mut self.rs
   }
+
+  #[inline]
+  pub(crate) fn insert_custom_ty(&mut self, custom_ty: CustomTy) {
+    let _ = self.custom_tys.insert(custom_ty.oid(), custom_ty);
+  }
+
+  /// Replaces domains by their underlying types in the statement located at `idx`, which is then
+  /// marked as resolved.
+  #[inline]
+  pub(crate) fn resolve_domains(&mut self, idx: usize) {
+    let Self { custom_tys, max_stmts: _, rs: _, stmts, stmts_indcs: _ } = self;
+    let Some(stmt) = stmts.get_mut(idx) else {
+      return;
+    };
+    stmt.misc.has_unresolved_tys = false;
+    for (column, ty) in stmt.data {
+      column.ty = underlying_ty(custom_tys, column.ty);
+      *ty = underlying_ty(custom_tys, *ty);
+    }
+  }
+
+  /// Pushes into `buffer` all the OIDs of the statement located at `idx` that weren't retrieved
+  /// from the system catalogs.
+  #[inline]
+  pub(crate) fn unresolved_oids(&self, buffer: &mut Vector<Oid>, idx: usize) -> crate::Result<()> {
+    let Some(stmt) = self.stmts.get(idx) else {
+      return Ok(());
+    };
+    if !stmt.misc.has_unresolved_tys {
+      return Ok(());
+    }
+    for (column, ty) in stmt.data {
+      for elem in [column.ty, *ty] {
+        if let Ty::Custom(oid) = elem {
+          if !self.custom_tys.contains_key(&oid) && !buffer.contains(&oid) {
+            buffer.push(oid)?;
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+// Inserts into `buffer` the custom types referenced by `data` as well as their dependencies.
+#[inline]
+fn referenced_oids(
+  custom_tys: &HashMap<Oid, CustomTy>,
+  data: &[(column::Column, Ty)],
+  buffer: &mut HashSet<Oid>,
+) -> crate::Result<()> {
+  let mut stack: Vector<Oid> = Vector::new();
+  for (column, ty) in data {
+    for elem in [column.ty, *ty] {
+      if let Ty::Custom(oid) = elem {
+        if buffer.insert(oid) {
+          stack.push(oid)?;
+        }
+      }
+    }
+  }
+  while let Some(oid) = stack.pop() {
+    let Some(custom_ty) = custom_tys.get(&oid) else {
+      continue;
+    };
+    for dependency in custom_ty.kind().dependencies() {
+      if let Ty::Custom(elem) = dependency {
+        if buffer.insert(elem) {
+          stack.push(elem)?;
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
+#[inline]
+fn underlying_ty(custom_tys: &HashMap<Oid, CustomTy>, mut ty: Ty) -> Ty {
+  // Domains can't be recursive but the catalog is a remote input.
+  for _ in 0..8 {
+    let Ty::Custom(oid) = ty else {
+      break;
+    };
+    let Some(CustomTyKind::Domain(base)) = custom_tys.get(&oid).map(CustomTy::kind) else {
+      break;
+    };
+    ty = *base;
+  }
+  ty
 }
 
 #[cfg(test)]
@@ -100,7 +226,7 @@ mod tests {
       statements::statements_misc::StatementsMisc,
       tests::{column0, column1, column2, column3},
       ty::Ty,
-      Statements,
+      CustomTy, CustomTyKind, Statements,
     },
     misc::{simple_seed, Xorshift64},
   };
@@ -119,7 +245,7 @@ mod tests {
     let mut stmts = Statements::new(2, &mut Xorshift64::from(simple_seed()));
 
     let stmt_id0 = 123;
-    let mut builder = stmts.builder();
+    let mut builder = stmts.builder().unwrap();
     let _ = builder.expand(2).unwrap();
     builder.inserted_elements()[0] = (column0(), Ty::Int2);
     builder.inserted_elements()[1] = (column1(), Ty::Int2);
@@ -134,7 +260,7 @@ mod tests {
     }
 
     let stmt_id1 = 456;
-    let mut builder = stmts.builder();
+    let mut builder = stmts.builder().unwrap();
     let _ = builder.expand(1).unwrap();
     builder.inserted_elements()[0] = (column2(), Ty::Int4);
     let _ = builder.build(stmt_id1, StatementsMisc::new(1, 1)).unwrap();
@@ -155,7 +281,7 @@ mod tests {
     }
 
     let stmt_id2 = 789;
-    let mut builder = stmts.builder();
+    let mut builder = stmts.builder().unwrap();
     let _ = builder.expand(1).unwrap();
     builder.inserted_elements()[0].0 = column3();
     let _ = builder.build(stmt_id2, StatementsMisc::new(1, 0)).unwrap();
@@ -179,4 +305,26 @@ mod tests {
     assert_eq!(stmts.get_by_stmt_hash(stmt_id1), None);
     assert_eq!(stmts.get_by_stmt_hash(stmt_id2), None);
   }
+  #[cfg_attr(miri, ignore)]
+  #[test]
+  fn evicted_custom_tys_shared_with_cached_statements_are_kept() {
+    let mut stmts = Statements::new(2, &mut Xorshift64::from(simple_seed()));
+    stmts.insert_custom_ty(CustomTy::new(CustomTyKind::Base, "a".try_into().unwrap(), 1));
+    stmts.insert_custom_ty(CustomTy::new(CustomTyKind::Base, "b".try_into().unwrap(), 2));
+    stmts.insert_custom_ty(CustomTy::new(
+      CustomTyKind::Array(Ty::Custom(2)),
+      "c".try_into().unwrap(),
+      3,
+    ));
+    for (stmt_id, ty) in [(1, Ty::Custom(1)), (2, Ty::Custom(3)), (3, Ty::Int4)] {
+      let mut builder = stmts.builder().unwrap();
+      let _ = builder.expand(1).unwrap();
+      builder.inserted_elements()[0] = (column0(), ty);
+      let _ = builder.build(stmt_id, StatementsMisc::new(0, 1)).unwrap();
+    }
+    assert_eq!(stmts.get_by_stmt_hash(1), None);
+    assert!(stmts.custom_ty(1).is_none());
+    assert!(stmts.custom_ty(2).is_some());
+    assert!(stmts.custom_ty(3).is_some());
+  }
 }
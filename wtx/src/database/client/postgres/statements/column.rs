@@ -1,14 +1,19 @@
-use crate::database::{client::postgres::Ty, Identifier};
+use crate::database::{
+  client::postgres::{Oid, Ty},
+  Identifier,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct Column {
   pub(crate) name: Identifier,
+  /// Table OID and attribute number of the column, if the column is a reference to a table.
+  pub(crate) origin: Option<(Oid, i16)>,
   pub(crate) ty: Ty,
 }
 
 impl Column {
   #[inline]
   pub(crate) fn new(name: Identifier, ty: Ty) -> Self {
-    Self { name, ty }
+    Self { name, origin: None, ty }
   }
 }
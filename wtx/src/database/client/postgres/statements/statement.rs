@@ -40,7 +40,6 @@ impl<'stmts> Statement<'stmts> {
     Some(self.values.get(..self.tys_len)?.get(idx)?.1)
   }
 
-  #[cfg(test)]
   #[inline]
   pub(crate) fn tys(&self) -> impl Iterator<Item = &Ty> {
     self.values.get(..self.tys_len).unwrap_or_default().iter().map(|el| &el.1)
@@ -1,12 +1,15 @@
 #[derive(Debug)]
 pub(crate) struct StatementsMisc {
   pub(crate) columns_len: usize,
+  /// If columns or parameters reference user-defined types that weren't retrieved from the system
+  /// catalogs.
+  pub(crate) has_unresolved_tys: bool,
   pub(crate) types_len: usize,
 }
 
 impl StatementsMisc {
   #[inline]
   pub(crate) fn new(columns_len: usize, types_len: usize) -> Self {
-    Self { columns_len, types_len }
+    Self { columns_len, has_unresolved_tys: false, types_len }
   }
 }
@@ -0,0 +1,67 @@
+use crate::{
+  database::{client::postgres::Ty, Identifier},
+  misc::Vector,
+};
+
+/// Parameters and columns of a prepared statement, as seen by the server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StmtDescription {
+  columns: Vector<ColumnDescription>,
+  params: Vector<Ty>,
+}
+
+impl StmtDescription {
+  /// New instance
+  #[inline]
+  pub const fn new(columns: Vector<ColumnDescription>, params: Vector<Ty>) -> Self {
+    Self { columns, params }
+  }
+
+  /// Returned columns, in order.
+  #[inline]
+  pub fn columns(&self) -> &[ColumnDescription] {
+    &self.columns
+  }
+
+  /// Types of the `$N` parameters, in order.
+  #[inline]
+  pub fn params(&self) -> &[Ty] {
+    &self.params
+  }
+}
+
+/// Column of a [`StmtDescription`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ColumnDescription {
+  name: Identifier,
+  not_null: bool,
+  ty: Ty,
+}
+
+impl ColumnDescription {
+  /// New instance
+  #[inline]
+  pub const fn new(name: Identifier, not_null: bool, ty: Ty) -> Self {
+    Self { name, not_null, ty }
+  }
+
+  /// Name or alias
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// If the column directly references a table column declared as `NOT NULL`.
+  ///
+  /// Outer joins can still produce `NULL` values for such columns.
+  #[inline]
+  pub fn not_null(&self) -> bool {
+    self.not_null
+  }
+
+  /// Type after the resolution of domains.
+  #[inline]
+  pub fn ty(&self) -> Ty {
+    self.ty
+  }
+}
@@ -325,6 +325,22 @@ pub enum Ty {
   Regcollation,
   /// `RegcollationArray`
   RegcollationArray,
+  /// `Int4MultiRange`
+  Int4MultiRange,
+  /// `NumMultiRange`
+  NumMultiRange,
+  /// `TsMultiRange`
+  TsMultiRange,
+  /// `TstzMultiRange`
+  TstzMultiRange,
+  /// `DateMultiRange`
+  DateMultiRange,
+  /// `Int8MultiRange`
+  Int8MultiRange,
+  /// `AnyMultiRange`
+  AnyMultiRange,
+  /// `AnycompatibleMultiRange`
+  AnycompatibleMultiRange,
   /// `PgBrinBloomSummary`
   PgBrinBloomSummary,
   /// `PgBrinMinmaxMultiSummary`
@@ -345,11 +361,140 @@ pub enum Ty {
   Anycompatiblenonarray,
   /// `AnycompatibleRange`
   AnycompatibleRange,
+  /// `Int4MultiRangeArray`
+  Int4MultiRangeArray,
+  /// `NumMultiRangeArray`
+  NumMultiRangeArray,
+  /// `TsMultiRangeArray`
+  TsMultiRangeArray,
+  /// `TstzMultiRangeArray`
+  TstzMultiRangeArray,
+  /// `DateMultiRangeArray`
+  DateMultiRangeArray,
+  /// `Int8MultiRangeArray`
+  Int8MultiRangeArray,
   /// `Custom`
   Custom(u32),
 }
 
 impl Ty {
+  /// The type of a one-dimensional array whose elements are of type `self`. Unknown types are
+  /// mapped to [`Ty::Anyarray`].
+  #[inline]
+  pub const fn array(self) -> Self {
+    match self {
+      Self::Bool => Self::BoolArray,
+      Self::Bytea => Self::ByteaArray,
+      Self::Char => Self::CharArray,
+      Self::Name => Self::NameArray,
+      Self::Int8 => Self::Int8Array,
+      Self::Int2 => Self::Int2Array,
+      Self::Int2Vector => Self::Int2VectorArray,
+      Self::Int4 => Self::Int4Array,
+      Self::Regproc => Self::RegprocArray,
+      Self::Text => Self::TextArray,
+      Self::Oid => Self::OidArray,
+      Self::Tid => Self::TidArray,
+      Self::Xid => Self::XidArray,
+      Self::Cid => Self::CidArray,
+      Self::OidVector => Self::OidVectorArray,
+      Self::Json => Self::JsonArray,
+      Self::Xml => Self::XmlArray,
+      Self::Point => Self::PointArray,
+      Self::Lseg => Self::LsegArray,
+      Self::Path => Self::PathArray,
+      Self::Box => Self::BoxArray,
+      Self::Polygon => Self::PolygonArray,
+      Self::Line => Self::LineArray,
+      Self::Cidr => Self::CidrArray,
+      Self::Float4 => Self::Float4Array,
+      Self::Float8 => Self::Float8Array,
+      Self::Circle => Self::CircleArray,
+      Self::Macaddr8 => Self::Macaddr8Array,
+      Self::Money => Self::MoneyArray,
+      Self::Macaddr => Self::MacaddrArray,
+      Self::Inet => Self::InetArray,
+      Self::Aclitem => Self::AclitemArray,
+      Self::Bpchar => Self::BpcharArray,
+      Self::Varchar => Self::VarcharArray,
+      Self::Date => Self::DateArray,
+      Self::Time => Self::TimeArray,
+      Self::Timestamp => Self::TimestampArray,
+      Self::Timestamptz => Self::TimestamptzArray,
+      Self::Interval => Self::IntervalArray,
+      Self::Timetz => Self::TimetzArray,
+      Self::Bit => Self::BitArray,
+      Self::Varbit => Self::VarbitArray,
+      Self::Numeric => Self::NumericArray,
+      Self::Refcursor => Self::RefcursorArray,
+      Self::Regprocedure => Self::RegprocedureArray,
+      Self::Regoper => Self::RegoperArray,
+      Self::Regoperator => Self::RegoperatorArray,
+      Self::Regclass => Self::RegclassArray,
+      Self::Regtype => Self::RegtypeArray,
+      Self::Record => Self::RecordArray,
+      Self::Cstring => Self::CstringArray,
+      Self::Uuid => Self::UuidArray,
+      Self::TxidSnapshot => Self::TxidSnapshotArray,
+      Self::PgLsn => Self::PgLsnArray,
+      Self::TsVector => Self::TsVectorArray,
+      Self::Tsquery => Self::TsqueryArray,
+      Self::GtsVector => Self::GtsVectorArray,
+      Self::Regconfig => Self::RegconfigArray,
+      Self::Regdictionary => Self::RegdictionaryArray,
+      Self::Jsonb => Self::JsonbArray,
+      Self::Int4Range => Self::Int4RangeArray,
+      Self::NumRange => Self::NumRangeArray,
+      Self::TsRange => Self::TsRangeArray,
+      Self::TstzRange => Self::TstzRangeArray,
+      Self::DateRange => Self::DateRangeArray,
+      Self::Int8Range => Self::Int8RangeArray,
+      Self::Jsonpath => Self::JsonpathArray,
+      Self::Regnamespace => Self::RegnamespaceArray,
+      Self::Regrole => Self::RegroleArray,
+      Self::Regcollation => Self::RegcollationArray,
+      Self::PgSnapshot => Self::PgSnapshotArray,
+      Self::Xid8 => Self::Xid8Array,
+      Self::Int4MultiRange => Self::Int4MultiRangeArray,
+      Self::NumMultiRange => Self::NumMultiRangeArray,
+      Self::TsMultiRange => Self::TsMultiRangeArray,
+      Self::TstzMultiRange => Self::TstzMultiRangeArray,
+      Self::DateMultiRange => Self::DateMultiRangeArray,
+      Self::Int8MultiRange => Self::Int8MultiRangeArray,
+      _ => Self::Anyarray,
+    }
+  }
+
+  /// The type of a multirange whose ranges have elements of type `self`. Unknown types are
+  /// mapped to [`Ty::AnyMultiRange`].
+  #[inline]
+  pub const fn multi_range(self) -> Self {
+    match self {
+      Self::Int4 => Self::Int4MultiRange,
+      Self::Int8 => Self::Int8MultiRange,
+      Self::Numeric => Self::NumMultiRange,
+      Self::Timestamp => Self::TsMultiRange,
+      Self::Timestamptz => Self::TstzMultiRange,
+      Self::Date => Self::DateMultiRange,
+      _ => Self::AnyMultiRange,
+    }
+  }
+
+  /// The type of a range whose elements are of type `self`. Unknown types are mapped to
+  /// [`Ty::AnyRange`].
+  #[inline]
+  pub const fn range(self) -> Self {
+    match self {
+      Self::Int4 => Self::Int4Range,
+      Self::Int8 => Self::Int8Range,
+      Self::Numeric => Self::NumRange,
+      Self::Timestamp => Self::TsRange,
+      Self::Timestamptz => Self::TstzRange,
+      Self::Date => Self::DateRange,
+      _ => Self::AnyRange,
+    }
+  }
+
   #[inline]
   pub(crate) fn from_arbitrary_u32(value: u32) -> Self {
     Self::from_known_u32(value).unwrap_or(Self::Custom(value))
@@ -519,6 +664,14 @@ impl Ty {
       4097 => Self::RegroleArray,
       4191 => Self::Regcollation,
       4192 => Self::RegcollationArray,
+      4451 => Self::Int4MultiRange,
+      4532 => Self::NumMultiRange,
+      4533 => Self::TsMultiRange,
+      4534 => Self::TstzMultiRange,
+      4535 => Self::DateMultiRange,
+      4536 => Self::Int8MultiRange,
+      4537 => Self::AnyMultiRange,
+      4538 => Self::AnycompatibleMultiRange,
       4600 => Self::PgBrinBloomSummary,
       4601 => Self::PgBrinMinmaxMultiSummary,
       5017 => Self::PgMcvList,
@@ -529,11 +682,25 @@ impl Ty {
       5078 => Self::Anycompatiblearray,
       5079 => Self::Anycompatiblenonarray,
       5080 => Self::AnycompatibleRange,
+      6150 => Self::Int4MultiRangeArray,
+      6151 => Self::NumMultiRangeArray,
+      6152 => Self::TsMultiRangeArray,
+      6153 => Self::TstzMultiRangeArray,
+      6155 => Self::DateMultiRangeArray,
+      6157 => Self::Int8MultiRangeArray,
       _ => return None,
     })
   }
 }
 
+impl From<u32> for Ty {
+  /// Unknown OIDs are mapped to [`Ty::Custom`].
+  #[inline]
+  fn from(from: u32) -> Self {
+    Self::from_arbitrary_u32(from)
+  }
+}
+
 impl From<Ty> for u32 {
   #[inline]
   fn from(from: Ty) -> Self {
@@ -699,6 +866,14 @@ impl From<Ty> for u32 {
       Ty::RegroleArray => 4097,
       Ty::Regcollation => 4191,
       Ty::RegcollationArray => 4192,
+      Ty::Int4MultiRange => 4451,
+      Ty::NumMultiRange => 4532,
+      Ty::TsMultiRange => 4533,
+      Ty::TstzMultiRange => 4534,
+      Ty::DateMultiRange => 4535,
+      Ty::Int8MultiRange => 4536,
+      Ty::AnyMultiRange => 4537,
+      Ty::AnycompatibleMultiRange => 4538,
       Ty::PgBrinBloomSummary => 4600,
       Ty::PgBrinMinmaxMultiSummary => 4601,
       Ty::PgMcvList => 5017,
@@ -709,6 +884,12 @@ impl From<Ty> for u32 {
       Ty::Anycompatiblearray => 5078,
       Ty::Anycompatiblenonarray => 5079,
       Ty::AnycompatibleRange => 5080,
+      Ty::Int4MultiRangeArray => 6150,
+      Ty::NumMultiRangeArray => 6151,
+      Ty::TsMultiRangeArray => 6152,
+      Ty::TstzMultiRangeArray => 6153,
+      Ty::DateMultiRangeArray => 6155,
+      Ty::Int8MultiRangeArray => 6157,
       Ty::Custom(elem) => elem,
     }
   }
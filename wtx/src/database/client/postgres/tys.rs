@@ -81,10 +81,12 @@ mod array {
 #[cfg(feature = "chrono")]
 mod chrono {
   use crate::database::{
-    client::postgres::{DecodeValue, EncodeValue, Postgres, PostgresError, Ty},
+    client::postgres::{DecodeValue, EncodeValue, Interval, Postgres, PostgresError, Ty},
     Decode, Encode, Typed,
   };
-  use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc};
+  use chrono::{
+    DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc,
+  };
 
   const MIN_PG_ND: Option<NaiveDate> = NaiveDate::from_ymd_opt(-4713, 1, 1);
   const MAX_CHRONO_ND: Option<NaiveDate> = NaiveDate::from_ymd_opt(262142, 1, 1);
@@ -209,6 +211,121 @@ mod chrono {
     const TY: Ty = Ty::Timestamp;
   }
 
+  impl<E> Decode<'_, Postgres<E>> for NaiveTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let microseconds = Decode::<Postgres<E>>::decode(dv)?;
+      let (time, overflow) =
+        NaiveTime::MIN.overflowing_add_signed(Duration::microseconds(microseconds));
+      if overflow != 0 {
+        return Err(E::from(PostgresError::UnexpectedValueFromBytes { expected: "time" }.into()));
+      }
+      Ok(time)
+    }
+  }
+  impl<E> Encode<Postgres<E>> for NaiveTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      let Some(microseconds) = self.signed_duration_since(NaiveTime::MIN).num_microseconds() else {
+        return Err(E::from(PostgresError::UnexpectedValueFromBytes { expected: "time" }.into()));
+      };
+      Encode::<Postgres<E>>::encode(&microseconds, ev)
+    }
+  }
+  impl<E> Typed<Postgres<E>> for NaiveTime
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Time;
+  }
+
+  // PostgreSQL stores the offset in seconds west of UTC.
+  impl<E> Decode<'_, Postgres<E>> for (NaiveTime, FixedOffset)
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let [a, b, c, d, e, f, g, h, i, j, k, l] = dv.bytes() else {
+        return Err(E::from(PostgresError::UnexpectedValueFromBytes { expected: "timetz" }.into()));
+      };
+      let time = <NaiveTime as Decode<Postgres<E>>>::decode(&DecodeValue::new(
+        &[*a, *b, *c, *d, *e, *f, *g, *h],
+        Ty::Time,
+      ))?;
+      let offset = i32::from_be_bytes([*i, *j, *k, *l])
+        .checked_neg()
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| {
+          E::from(PostgresError::UnexpectedValueFromBytes { expected: "timetz" }.into())
+        })?;
+      Ok((time, offset))
+    }
+  }
+  impl<E> Encode<Postgres<E>> for (NaiveTime, FixedOffset)
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Postgres<E>>::encode(&self.0, ev)?;
+      Encode::<Postgres<E>>::encode(&self.1.utc_minus_local(), ev)
+    }
+  }
+  impl<E> Typed<Postgres<E>> for (NaiveTime, FixedOffset)
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Timetz;
+  }
+
+  // Months can't be represented because their number of days varies.
+  impl<E> Decode<'_, Postgres<E>> for TimeDelta
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let interval: Interval = Decode::<Postgres<E>>::decode(dv)?;
+      if interval.months() != 0 {
+        return Err(E::from(
+          PostgresError::UnexpectedValueFromBytes { expected: "interval" }.into(),
+        ));
+      }
+      TimeDelta::try_days(interval.days().into())
+        .and_then(|el| el.checked_add(&TimeDelta::microseconds(interval.microseconds())))
+        .ok_or_else(|| {
+          E::from(PostgresError::UnexpectedValueFromBytes { expected: "interval" }.into())
+        })
+    }
+  }
+  impl<E> Encode<Postgres<E>> for TimeDelta
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      let Some(microseconds) = self.num_microseconds() else {
+        return Err(E::from(
+          PostgresError::UnexpectedValueFromBytes { expected: "interval" }.into(),
+        ));
+      };
+      Encode::<Postgres<E>>::encode(&Interval::new(0, 0, microseconds), ev)
+    }
+  }
+  impl<E> Typed<Postgres<E>> for TimeDelta
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Interval;
+  }
+
   fn pg_epoch_nd() -> Option<NaiveDate> {
     NaiveDate::from_ymd_opt(2000, 1, 1)
   }
@@ -218,17 +335,25 @@ mod chrono {
   }
 
   test!(datetime_utc, DateTime<Utc>, Utc.from_utc_datetime(&pg_epoch_ndt().unwrap()));
+  test!(naive_time, NaiveTime, NaiveTime::from_hms_micro_opt(1, 2, 3, 4).unwrap());
+  test!(time_delta, TimeDelta, TimeDelta::try_days(2).unwrap() + TimeDelta::microseconds(3));
+  test!(
+    timetz,
+    (NaiveTime, FixedOffset),
+    (NaiveTime::from_hms_opt(1, 2, 3).unwrap(), FixedOffset::east_opt(-3600).unwrap())
+  );
 }
 
 mod collections {
   use crate::{
     database::{
-      client::postgres::{DecodeValue, EncodeValue, Postgres, PostgresError, Ty},
+      client::postgres::{DecodeValue, EncodeValue, Postgres, Ty},
       Decode, Encode, Typed,
     },
     misc::from_utf8_basic,
   };
-  use alloc::{string::String, vec::Vec};
+  use alloc::string::String;
+
   // &[u8]
 
   impl<'exec, E> Decode<'exec, Postgres<E>> for &'exec [u8]
@@ -337,79 +462,55 @@ mod collections {
     const TY: Ty = Ty::Text;
   }
 
-  // Text Array
+  kani!(string, String);
+}
 
-  impl<E> Decode<'_, Postgres<E>> for Vec<String>
+mod interval {
+  use crate::database::{
+    client::postgres::{DecodeValue, EncodeValue, Interval, Postgres, PostgresError, Ty},
+    Decode, Encode, Typed,
+  };
+
+  impl<E> Decode<'_, Postgres<E>> for Interval
   where
     E: From<crate::Error>,
   {
     #[inline]
     fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
-      let mut bytes = dv.bytes().to_vec();
-      let bytes_mut = bytes.as_mut_slice();
-      let [a, b, c, d, _, _, _, _, e, f, g, h, i, j, k, l, _, _, _, _, ref mut rest @ ..] =
-        bytes_mut
-      else {
-        return Err(E::from(PostgresError::DecodingError.into()));
+      let [a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p] = dv.bytes() else {
+        return Err(E::from(
+          PostgresError::UnexpectedValueFromBytes { expected: "interval" }.into(),
+        ));
       };
-      let dims = u32::from_be_bytes([*a, *b, *c, *d]);
-      let oid = u32::from_be_bytes([*e, *f, *g, *h]);
-      let len = u32::from_be_bytes([*i, *j, *k, *l]);
-
-      if dims != 1 || oid != 25 {
-        return Err(E::from(PostgresError::DecodingError.into()));
-      }
-
-      let mut vec = Vec::with_capacity(len as usize);
-      let mut new_rest = &mut *rest;
-      loop {
-        let lenbytes = &new_rest[..4];
-        let len = u32::from_be_bytes(lenbytes.try_into().unwrap());
-        new_rest = &mut new_rest[4..];
-
-        let str = std::str::from_utf8(&new_rest[..(len as usize)])
-          .map_err(|err| E::from(PostgresError::DecodingError.into()))?;
-        vec.push(str.into());
-        new_rest = &mut new_rest[len as usize..];
-
-        if new_rest.is_empty() {
-          break;
-        }
-      }
-
-      Ok(vec)
+      Ok(Interval::new(
+        i32::from_be_bytes([*m, *n, *o, *p]),
+        i32::from_be_bytes([*i, *j, *k, *l]),
+        i64::from_be_bytes([*a, *b, *c, *d, *e, *f, *g, *h]),
+      ))
     }
   }
-  impl<E> Encode<Postgres<E>> for Vec<String>
+  impl<E> Encode<Postgres<E>> for Interval
   where
     E: From<crate::Error>,
   {
     #[inline]
-    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {/*
-      */
-
-      std::dbg!(&ev);
-
-      ev.fbw().extend_from_slice(&1_u32.to_be_bytes())?;
-      ev.fbw().extend_from_slice(&0_u32.to_be_bytes())?;
-      ev.fbw().extend_from_slice(&25_u32.to_be_bytes())?;
-      ev.fbw().extend_from_slice(&self.len().to_be_bytes())?;
-
-      for s in self {
-        ev.fbw().extend_from_slice(&s.len().to_be_bytes())?;
-        ev.fbw().extend_from_slice(s.as_bytes())?;
-      }
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.fbw()._extend_from_slices([
+        &self.microseconds().to_be_bytes()[..],
+        &self.days().to_be_bytes(),
+        &self.months().to_be_bytes(),
+      ])?;
       Ok(())
     }
   }
-  impl<E> Typed<Postgres<E>> for Vec<String>
+  impl<E> Typed<Postgres<E>> for Interval
   where
     E: From<crate::Error>,
   {
-    const TY: Ty = Ty::TextArray;
+    const TY: Ty = Ty::Interval;
   }
 
-  kani!(string, String);
+  test!(interval, Interval, Interval::new(1, -2, 3));
 }
 
 mod ip {
@@ -514,6 +615,222 @@ mod ip {
   test!(ipv6, Ipv6Addr, Ipv6Addr::new(1, 2, 3, 4, 5, 6, 7, 8));
 }
 
+mod pg_array {
+  use crate::{
+    database::{
+      client::postgres::{
+        array::{decode_array, encode_array, one_dimension},
+        Array, DecodeValue, Dimension, EncodeValue, Postgres, PostgresError, Ty,
+      },
+      Decode, Encode, Typed,
+    },
+    misc::Vector,
+  };
+  use alloc::vec::Vec;
+
+  impl<'de, E, T> Decode<'de, Postgres<E>> for Vec<T>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      Ok(<Vector<T> as Decode<Postgres<E>>>::decode(dv)?.into())
+    }
+  }
+  impl<'de, E, T> Decode<'de, Postgres<E>> for Vec<Option<T>>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      Ok(<Vector<Option<T>> as Decode<Postgres<E>>>::decode(dv)?.into())
+    }
+  }
+  impl<E, T> Encode<Postgres<E>> for Vec<T>
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>> + Typed<Postgres<E>>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      encode_array(one_dimension(self.len())?.as_slice(), self, ev)
+    }
+  }
+  impl<E, T> Typed<Postgres<E>> for Vec<T>
+  where
+    E: From<crate::Error>,
+    T: Typed<Postgres<E>>,
+  {
+    const TY: Ty = T::TY.array();
+  }
+
+  impl<'de, E, T> Decode<'de, Postgres<E>> for Vector<T>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      let mut rslt = Vector::new();
+      decode_array::<E>(dv, one_dimension_cb(), |elem| {
+        let elem = elem.ok_or_else(|| E::from(PostgresError::NullArrayElement.into()))?;
+        rslt.push(T::decode(&elem)?)?;
+        Ok(())
+      })?;
+      Ok(rslt)
+    }
+  }
+  impl<'de, E, T> Decode<'de, Postgres<E>> for Vector<Option<T>>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      let mut rslt = Vector::new();
+      decode_array::<E>(dv, one_dimension_cb(), |elem| {
+        rslt.push(elem.map(|el| T::decode(&el)).transpose()?)?;
+        Ok(())
+      })?;
+      Ok(rslt)
+    }
+  }
+  impl<E, T> Encode<Postgres<E>> for Vector<T>
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>> + Typed<Postgres<E>>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      encode_array(one_dimension(self.len())?.as_slice(), self, ev)
+    }
+  }
+  impl<E, T> Typed<Postgres<E>> for Vector<T>
+  where
+    E: From<crate::Error>,
+    T: Typed<Postgres<E>>,
+  {
+    const TY: Ty = T::TY.array();
+  }
+
+  impl<'de, E, T> Decode<'de, Postgres<E>> for Array<T>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      let mut dimensions = Vector::new();
+      let mut elements = Vector::new();
+      decode_array::<E>(
+        dv,
+        |dimension| Ok(dimensions.push(dimension)?),
+        |elem| {
+          let elem = elem.ok_or_else(|| E::from(PostgresError::NullArrayElement.into()))?;
+          elements.push(T::decode(&elem)?)?;
+          Ok(())
+        },
+      )?;
+      Ok(Array::new(dimensions, elements)?)
+    }
+  }
+  impl<'de, E, T> Decode<'de, Postgres<E>> for Array<Option<T>>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      let mut dimensions = Vector::new();
+      let mut elements = Vector::new();
+      decode_array::<E>(
+        dv,
+        |dimension| Ok(dimensions.push(dimension)?),
+        |elem| {
+          elements.push(elem.map(|el| T::decode(&el)).transpose()?)?;
+          Ok(())
+        },
+      )?;
+      Ok(Array::new(dimensions, elements)?)
+    }
+  }
+  impl<E, T> Encode<Postgres<E>> for Array<T>
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>> + Typed<Postgres<E>>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      encode_array(self.dimensions(), self.elements(), ev)
+    }
+  }
+  impl<E, T> Typed<Postgres<E>> for Array<T>
+  where
+    E: From<crate::Error>,
+    T: Typed<Postgres<E>>,
+  {
+    const TY: Ty = T::TY.array();
+  }
+
+  impl<E, T, const N: usize> Encode<Postgres<E>> for [T; N]
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>> + Typed<Postgres<E>>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      encode_array(one_dimension(N)?.as_slice(), self, ev)
+    }
+  }
+  impl<E, T, const N: usize> Typed<Postgres<E>> for [T; N]
+  where
+    E: From<crate::Error>,
+    T: Typed<Postgres<E>>,
+  {
+    const TY: Ty = T::TY.array();
+  }
+
+  // Types with a single dimension can't represent multidimensional arrays.
+  #[inline]
+  fn one_dimension_cb<E>() -> impl FnMut(Dimension) -> Result<(), E>
+  where
+    E: From<crate::Error>,
+  {
+    let mut has_dimension = false;
+    move |_| {
+      if has_dimension {
+        return Err(E::from(PostgresError::InvalidArrayDimensions.into()));
+      }
+      has_dimension = true;
+      Ok(())
+    }
+  }
+
+  test!(array_i32, Array<i32>, {
+    let dimensions = Vector::from_iter([Dimension::new(2, 1), Dimension::new(3, 1)]).unwrap();
+    Array::new(dimensions, Vector::from_iter([1, 2, 3, 4, 5, 6]).unwrap()).unwrap()
+  });
+  test!(vec_empty, Vec<i32>, Vec::new());
+
+  #[test]
+  fn vec_with_invalid_element_len() {
+    let bytes = [0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 23, 0, 0, 0, 1, 0, 0, 0, 1, 255, 255, 255, 254];
+    let rslt: crate::Result<Vec<Option<i32>>> =
+      Decode::<Postgres<crate::Error>>::decode(&DecodeValue::new(&bytes, Ty::Any));
+    assert!(rslt.is_err());
+  }
+  test!(vec_i64, Vec<i64>, alloc::vec![1, 2, 3]);
+  test!(vec_opt_i32, Vec<Option<i32>>, alloc::vec![Some(1), None, Some(3)]);
+  test!(
+    vec_string,
+    Vec<alloc::string::String>,
+    alloc::vec![alloc::string::String::from("foo"), alloc::string::String::new()]
+  );
+  test!(vector_bool, Vector<bool>, Vector::from_iter([true, false]).unwrap());
+}
+
 mod pg_numeric {
   use crate::{
     database::{
@@ -636,6 +953,187 @@ mod pg_numeric {
   }
 }
 
+mod pg_range {
+  use crate::{
+    database::{
+      client::postgres::{
+        DecodeValue, EncodeValue, PgMultiRange, PgRange, Postgres, PostgresError, Ty,
+      },
+      Decode, Encode, Typed,
+    },
+    misc::{Usize, Vector},
+  };
+  use core::ops::Bound;
+
+  const EMPTY: u8 = 0b0000_0001;
+  const LOWER_INCLUSIVE: u8 = 0b0000_0010;
+  const UPPER_INCLUSIVE: u8 = 0b0000_0100;
+  const LOWER_UNBOUNDED: u8 = 0b0000_1000;
+  const UPPER_UNBOUNDED: u8 = 0b0001_0000;
+
+  impl<'de, E, T> Decode<'de, Postgres<E>> for PgRange<T>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      let [flags, rest @ ..] = dv.bytes() else {
+        return Err(E::from(PostgresError::DecodingError.into()));
+      };
+      if flags & EMPTY == EMPTY {
+        return Ok(PgRange::empty());
+      }
+      let (lower, after_lower) =
+        decode_bound::<E, T>(*flags, LOWER_INCLUSIVE, LOWER_UNBOUNDED, rest)?;
+      let (upper, after_upper) =
+        decode_bound::<E, T>(*flags, UPPER_INCLUSIVE, UPPER_UNBOUNDED, after_lower)?;
+      if !after_upper.is_empty() {
+        return Err(E::from(PostgresError::DecodingError.into()));
+      }
+      Ok(PgRange::new(lower, upper))
+    }
+  }
+  impl<E, T> Encode<Postgres<E>> for PgRange<T>
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      if self.is_empty() {
+        ev.fbw()._extend_from_byte(EMPTY)?;
+        return Ok(());
+      }
+      let lower_flags = match self.lower() {
+        Bound::Excluded(_) => 0,
+        Bound::Included(_) => LOWER_INCLUSIVE,
+        Bound::Unbounded => LOWER_UNBOUNDED,
+      };
+      let upper_flags = match self.upper() {
+        Bound::Excluded(_) => 0,
+        Bound::Included(_) => UPPER_INCLUSIVE,
+        Bound::Unbounded => UPPER_UNBOUNDED,
+      };
+      ev.fbw()._extend_from_byte(lower_flags | upper_flags)?;
+      for bound in [self.lower(), self.upper()] {
+        if let Bound::Excluded(elem) | Bound::Included(elem) = bound {
+          ev.encode_with_len(elem)?;
+        }
+      }
+      Ok(())
+    }
+  }
+  impl<E, T> Typed<Postgres<E>> for PgRange<T>
+  where
+    E: From<crate::Error>,
+    T: Typed<Postgres<E>>,
+  {
+    const TY: Ty = T::TY.range();
+  }
+
+  impl<'de, E, T> Decode<'de, Postgres<E>> for PgMultiRange<T>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'de>) -> Result<Self, E> {
+      let [a, b, c, d, bytes @ ..] = dv.bytes() else {
+        return Err(E::from(PostgresError::DecodingError.into()));
+      };
+      let len = u32::from_be_bytes([*a, *b, *c, *d]);
+      let mut rest = bytes;
+      let mut ranges = Vector::with_capacity(*Usize::from(len))?;
+      for _ in 0..len {
+        let (Some(range_bytes), local_rest) = decode_len_prefixed::<E>(rest)? else {
+          return Err(E::from(PostgresError::DecodingError.into()));
+        };
+        ranges.push(PgRange::<T>::decode(&DecodeValue::new(range_bytes, Ty::Any))?)?;
+        rest = local_rest;
+      }
+      if !rest.is_empty() {
+        return Err(E::from(PostgresError::DecodingError.into()));
+      }
+      Ok(PgMultiRange::new(ranges))
+    }
+  }
+  impl<E, T> Encode<Postgres<E>> for PgMultiRange<T>
+  where
+    E: From<crate::Error>,
+    T: Encode<Postgres<E>>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      let len = u32::try_from(self.ranges().len()).map_err(crate::Error::from)?;
+      ev.fbw().extend_from_slice(&len.to_be_bytes())?;
+      for range in self.ranges() {
+        ev.encode_with_len(range)?;
+      }
+      Ok(())
+    }
+  }
+  impl<E, T> Typed<Postgres<E>> for PgMultiRange<T>
+  where
+    E: From<crate::Error>,
+    T: Typed<Postgres<E>>,
+  {
+    const TY: Ty = T::TY.multi_range();
+  }
+
+  #[inline]
+  fn decode_bound<'de, E, T>(
+    flags: u8,
+    inclusive: u8,
+    unbounded: u8,
+    bytes: &'de [u8],
+  ) -> Result<(Bound<T>, &'de [u8]), E>
+  where
+    E: From<crate::Error>,
+    T: Decode<'de, Postgres<E>>,
+  {
+    if flags & unbounded == unbounded {
+      return Ok((Bound::Unbounded, bytes));
+    }
+    let (Some(elem_bytes), rest) = decode_len_prefixed::<E>(bytes)? else {
+      return Err(E::from(PostgresError::DecodingError.into()));
+    };
+    let elem = T::decode(&DecodeValue::new(elem_bytes, Ty::Any))?;
+    if flags & inclusive == inclusive {
+      Ok((Bound::Included(elem), rest))
+    } else {
+      Ok((Bound::Excluded(elem), rest))
+    }
+  }
+
+  #[inline]
+  fn decode_len_prefixed<E>(bytes: &[u8]) -> Result<(Option<&[u8]>, &[u8]), E>
+  where
+    E: From<crate::Error>,
+  {
+    let [a, b, c, d, rest @ ..] = bytes else {
+      return Err(E::from(PostgresError::DecodingError.into()));
+    };
+    let Ok(len) = u32::try_from(i32::from_be_bytes([*a, *b, *c, *d])) else {
+      return Ok((None, rest));
+    };
+    let Some((elem, after)) = rest.split_at_checked(*Usize::from(len)) else {
+      return Err(E::from(PostgresError::DecodingError.into()));
+    };
+    Ok((Some(elem), after))
+  }
+
+  test!(pg_range_empty, PgRange<i32>, PgRange::empty());
+  test!(pg_range_exclusive, PgRange<i64>, PgRange::from(1..10));
+  test!(pg_range_inclusive, PgRange<i32>, PgRange::from(1..=10));
+  test!(pg_range_unbounded, PgRange<i32>, PgRange::new(Bound::Unbounded, Bound::Excluded(4)));
+  test!(
+    pg_multi_range,
+    PgMultiRange<i32>,
+    PgMultiRange::new(Vector::from_iter([PgRange::from(1..3), PgRange::from(5..=7)]).unwrap())
+  );
+}
+
 mod primitives {
   use crate::{
     database::{
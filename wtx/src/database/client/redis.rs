@@ -0,0 +1,31 @@
+//! Redis is an in-memory data structure store that is commonly used as a cache, a message broker
+//! or a session store. Also works with compatible servers like Valkey or KeyDB.
+//!
+//! Communication happens through RESP2 or RESP3 and commands are composed by tuples or slices of
+//! [`Arg`] elements, for example, `("SET", "key", 1)`.
+
+mod client;
+mod client_buffer;
+mod command;
+mod config;
+mod db_error;
+mod frame;
+mod frame_ty;
+mod from_frame;
+#[cfg(all(feature = "_async-tests", feature = "_integration-tests", test))]
+mod integration_tests;
+mod pipeline;
+mod pub_sub;
+mod redis_error;
+
+pub use client::Client;
+pub use client_buffer::ClientBuffer;
+pub use command::{encode_arg, Arg, Command};
+pub use config::{Config, Protocol};
+pub use db_error::DbError;
+pub use frame::{Aggregate, Frame};
+pub use frame_ty::FrameTy;
+pub use from_frame::FromFrame;
+pub use pipeline::Pipeline;
+pub use pub_sub::{Message, PubSub};
+pub use redis_error::RedisError;
@@ -0,0 +1,300 @@
+use crate::{
+  database::client::redis::{
+    command::{encode_arg, encode_command},
+    frame::frame_len,
+    Arg, ClientBuffer, Command, Config, DbError, Frame, FromFrame, Pipeline, Protocol, PubSub,
+  },
+  misc::{
+    partitioned_filled_buffer::PartitionedFilledBuffer, ArrayVector, ConnectionState, LeaseMut,
+    Stream, StreamReader, Vector,
+  },
+};
+use alloc::boxed::Box;
+use core::ops::Range;
+
+const READ_LEN: usize = 1024;
+
+/// Sends commands and receives replies through a single connection.
+#[derive(Debug)]
+pub struct Client<CB, S> {
+  pub(crate) cb: CB,
+  pub(crate) cs: ConnectionState,
+  pub(crate) stream: S,
+}
+
+impl<CB, S> Client<CB, S>
+where
+  CB: LeaseMut<ClientBuffer>,
+  S: Stream,
+{
+  /// Performs the handshake, authenticates if a password was provided and selects the logical
+  /// database.
+  ///
+  /// RESP3 connections are negotiated through `HELLO`, which requires Redis 6 or newer.
+  #[inline]
+  pub async fn connect(config: &Config<'_>, mut cb: CB, stream: S) -> crate::Result<Self> {
+    cb.lease_mut().clear();
+    let mut this = Self { cb, cs: ConnectionState::Open, stream };
+    match config.protocol {
+      Protocol::Resp2 => {
+        if !config.password.is_empty() {
+          if config.user.is_empty() {
+            this.execute::<_, ()>(("AUTH", config.password)).await?;
+          } else {
+            this.execute::<_, ()>(("AUTH", config.user, config.password)).await?;
+          }
+        }
+        if !config.client_name.is_empty() {
+          this.execute::<_, ()>(("CLIENT", "SETNAME", config.client_name)).await?;
+        }
+      }
+      Protocol::Resp3 => {
+        let mut hello = ArrayVector::<&str, 7>::new();
+        hello.extend_from_copyable_slice(&["HELLO", "3"])?;
+        if !config.password.is_empty() {
+          let user = if config.user.is_empty() { "default" } else { config.user };
+          hello.extend_from_copyable_slice(&["AUTH", user, config.password])?;
+        }
+        if !config.client_name.is_empty() {
+          hello.extend_from_copyable_slice(&["SETNAME", config.client_name])?;
+        }
+        this.execute::<_, ()>(hello.as_slice()).await?;
+      }
+    }
+    if config.db != 0 {
+      this.execute::<_, ()>(("SELECT", config.db)).await?;
+    }
+    Ok(this)
+  }
+
+  /// See [`ConnectionState`].
+  #[inline]
+  pub fn connection_state(&self) -> ConnectionState {
+    self.cs
+  }
+
+  /// Sends `cmd` and converts the returned reply into `T`.
+  ///
+  /// Error replies are returned as [`crate::Error::RedisDbError`] and out-of-band pushes that
+  /// precede the reply are ignored.
+  #[inline]
+  pub async fn execute<'this, C, T>(&'this mut self, cmd: C) -> crate::Result<T>
+  where
+    C: Command,
+    T: FromFrame<'this>,
+  {
+    let Self { cb, cs, stream } = self;
+    let ClientBuffer { nb, wb } = cb.lease_mut();
+    send(cs, nb, wb, stream, |local_wb| encode_command(&cmd, local_wb)).await?;
+    let range = read_reply(cs, nb, stream).await?;
+    T::from_frame(Frame::parse(self.cb.lease().nb._buffer().get(range).unwrap_or_default())?)
+  }
+
+  /// Sends all commands queued by `queue` in a single round trip. Each reply is then passed to
+  /// `cb` along with the index of its command.
+  ///
+  /// Error replies are passed to `cb` as [`Frame::Error`] and don't interrupt the processing of
+  /// the remaining replies.
+  #[inline]
+  pub async fn pipeline(
+    &mut self,
+    queue: impl FnOnce(&mut Pipeline<'_>) -> crate::Result<()>,
+    mut cb: impl FnMut(usize, Frame<'_>) -> crate::Result<()>,
+  ) -> crate::Result<()> {
+    let Self { cb: client_buffer, cs, stream } = self;
+    let ClientBuffer { nb, wb } = client_buffer.lease_mut();
+    let mut len = 0;
+    send(cs, nb, wb, stream, |local_wb| queue(&mut Pipeline::new(&mut len, local_wb))).await?;
+    for idx in 0..len {
+      let range = loop {
+        read_frame(nb, stream).await?;
+        if nb._current().first() != Some(&b'>') {
+          break nb._current_range();
+        }
+      };
+      cb(idx, Frame::parse(nb._buffer().get(range).unwrap_or_default())?)?;
+    }
+    *cs = ConnectionState::Open;
+    Ok(())
+  }
+
+  /// Converts this instance into a connection dedicated to Pub/Sub messages.
+  #[inline]
+  pub fn into_pub_sub(self) -> PubSub<CB, S> {
+    PubSub::new(self)
+  }
+
+  /// Removes the specified keys, returning the number of keys that were removed.
+  #[inline]
+  pub async fn del<K>(&mut self, keys: &[K]) -> crate::Result<u64>
+  where
+    K: Arg,
+  {
+    self.execute(PrefixedCommand { args: keys, name: "DEL" }).await
+  }
+
+  /// If `key` exists.
+  #[inline]
+  pub async fn exists<K>(&mut self, key: K) -> crate::Result<bool>
+  where
+    K: Arg,
+  {
+    self.execute(("EXISTS", key)).await
+  }
+
+  /// Sets a timeout of `secs` seconds on `key`. Returns `false` if `key` doesn't exist.
+  #[inline]
+  pub async fn expire<K>(&mut self, key: K, secs: u64) -> crate::Result<bool>
+  where
+    K: Arg,
+  {
+    self.execute(("EXPIRE", key, secs)).await
+  }
+
+  /// Gets the value of `key`. Use an `Option` to handle nonexistent keys.
+  #[inline]
+  pub async fn get<'this, K, T>(&'this mut self, key: K) -> crate::Result<T>
+  where
+    K: Arg,
+    T: FromFrame<'this>,
+  {
+    self.execute(("GET", key)).await
+  }
+
+  /// Increments the integer value of `key` by one, returning the new value.
+  #[inline]
+  pub async fn incr<K>(&mut self, key: K) -> crate::Result<i64>
+  where
+    K: Arg,
+  {
+    self.execute(("INCR", key)).await
+  }
+
+  /// Checks if the connection is alive.
+  #[inline]
+  pub async fn ping(&mut self) -> crate::Result<()> {
+    self.execute(("PING",)).await
+  }
+
+  /// Posts `msg` to `channel`, returning the number of clients that received the message.
+  #[inline]
+  pub async fn publish<C, M>(&mut self, channel: C, msg: M) -> crate::Result<u64>
+  where
+    C: Arg,
+    M: Arg,
+  {
+    self.execute(("PUBLISH", channel, msg)).await
+  }
+
+  /// Sets `key` to hold `value`. Any previous value or timeout is discarded.
+  #[inline]
+  pub async fn set<K, V>(&mut self, key: K, value: V) -> crate::Result<()>
+  where
+    K: Arg,
+    V: Arg,
+  {
+    self.execute(("SET", key, value)).await
+  }
+}
+
+/// A command composed by a fixed name followed by a variable number of arguments.
+pub(crate) struct PrefixedCommand<'any, A> {
+  pub(crate) args: &'any [A],
+  pub(crate) name: &'static str,
+}
+
+impl<A> Command for PrefixedCommand<'_, A>
+where
+  A: Arg,
+{
+  #[inline]
+  fn encode_args(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    encode_arg(self.name, wb)?;
+    self.args.encode_args(wb)
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.args.len().wrapping_add(1)
+  }
+}
+
+/// Reads a single frame into the current area of `nb`.
+#[inline]
+pub(crate) async fn read_frame<SR>(
+  nb: &mut PartitionedFilledBuffer,
+  stream: &mut SR,
+) -> crate::Result<()>
+where
+  SR: StreamReader,
+{
+  loop {
+    let following_len = nb._following_len();
+    let following = nb._following().get(..following_len).unwrap_or_default();
+    if let Some(len) = frame_len(following)? {
+      nb._set_indices(nb._current_end_idx(), len, following_len.wrapping_sub(len))?;
+      return Ok(());
+    }
+    nb._reserve(READ_LEN)?;
+    let buffer = nb._following_rest_mut().get_mut(following_len..).unwrap_or_default();
+    let read = stream.read(buffer).await?;
+    if read == 0 {
+      return Err(crate::Error::ClosedConnection);
+    }
+    let antecedent_len = nb._antecedent_end_idx();
+    let current_len = nb._current_end_idx().wrapping_sub(antecedent_len);
+    nb._set_indices(antecedent_len, current_len, following_len.wrapping_add(read))?;
+  }
+}
+
+/// Reads frames until a reply is found. Error replies are converted into
+/// [`crate::Error::RedisDbError`].
+#[inline]
+pub(crate) async fn read_reply<SR>(
+  cs: &mut ConnectionState,
+  nb: &mut PartitionedFilledBuffer,
+  stream: &mut SR,
+) -> crate::Result<Range<usize>>
+where
+  SR: StreamReader,
+{
+  loop {
+    read_frame(nb, stream).await?;
+    match nb._current().first() {
+      Some(b'-' | b'!') => {
+        *cs = ConnectionState::Open;
+        let message = match Frame::parse(nb._current())? {
+          Frame::Error(elem) => elem,
+          _ => "",
+        };
+        return Err(crate::Error::RedisDbError(Box::new(DbError::new(message.into()))));
+      }
+      Some(b'>') => {}
+      _ => {
+        *cs = ConnectionState::Open;
+        return Ok(nb._current_range());
+      }
+    }
+  }
+}
+
+/// Encodes commands through `cb` and sends them. The connection is considered closed until the
+/// corresponding replies are received.
+#[inline]
+pub(crate) async fn send<S>(
+  cs: &mut ConnectionState,
+  nb: &mut PartitionedFilledBuffer,
+  wb: &mut Vector<u8>,
+  stream: &mut S,
+  cb: impl FnOnce(&mut Vector<u8>) -> crate::Result<()>,
+) -> crate::Result<()>
+where
+  S: Stream,
+{
+  nb._clear_if_following_is_empty();
+  wb.clear();
+  cb(wb)?;
+  *cs = ConnectionState::Closed;
+  stream.write_all(wb).await?;
+  Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::misc::{partitioned_filled_buffer::PartitionedFilledBuffer, Lease, LeaseMut, Vector};
+
+#[derive(Debug)]
+#[doc = _internal_buffer_doc!()]
+pub struct ClientBuffer {
+  /// Network Buffer.
+  pub(crate) nb: PartitionedFilledBuffer,
+  /// Write Buffer.
+  pub(crate) wb: Vector<u8>,
+}
+
+impl ClientBuffer {
+  /// New instance
+  #[inline]
+  pub const fn new() -> Self {
+    Self { nb: PartitionedFilledBuffer::new(), wb: Vector::new() }
+  }
+
+  /// With provided capacity.
+  #[inline]
+  pub fn with_capacity(
+    (network_buffer_cap, write_buffer_cap): (usize, usize),
+  ) -> crate::Result<Self> {
+    Ok(Self {
+      nb: PartitionedFilledBuffer::_with_capacity(network_buffer_cap)?,
+      wb: Vector::with_capacity(write_buffer_cap)?,
+    })
+  }
+
+  /// Should be used in a new instance.
+  #[inline]
+  pub(crate) fn clear(&mut self) {
+    let Self { nb, wb } = self;
+    nb._clear();
+    wb.clear();
+  }
+}
+
+impl Default for ClientBuffer {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Lease<ClientBuffer> for ClientBuffer {
+  #[inline]
+  fn lease(&self) -> &ClientBuffer {
+    self
+  }
+}
+
+impl LeaseMut<ClientBuffer> for ClientBuffer {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut ClientBuffer {
+    self
+  }
+}
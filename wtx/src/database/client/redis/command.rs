@@ -0,0 +1,194 @@
+use crate::misc::{ArrayString, Vector};
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+/// A single argument of a command, which is always sent as a bulk string.
+pub trait Arg {
+  /// Writes the raw contents of the argument. Length prefixes and delimiters are handled by the
+  /// caller.
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()>;
+}
+
+impl<T> Arg for &T
+where
+  T: Arg + ?Sized,
+{
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    (**self).write_bytes(wb)
+  }
+}
+
+impl Arg for [u8] {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    wb.extend_from_copyable_slice(self)?;
+    Ok(())
+  }
+}
+
+impl<const N: usize> Arg for [u8; N] {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_slice().write_bytes(wb)
+  }
+}
+
+impl<const N: usize> Arg for ArrayString<N> {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_bytes().write_bytes(wb)
+  }
+}
+
+impl Arg for str {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_bytes().write_bytes(wb)
+  }
+}
+
+impl Arg for String {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_bytes().write_bytes(wb)
+  }
+}
+
+impl Arg for Vec<u8> {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_slice().write_bytes(wb)
+  }
+}
+
+impl Arg for Vector<u8> {
+  #[inline]
+  fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_slice().write_bytes(wb)
+  }
+}
+
+macro_rules! display_arg {
+  ($($ty:ty),+) => {
+    $(
+      impl Arg for $ty {
+        #[inline]
+        fn write_bytes(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+          wb.write_fmt(format_args!("{self}"))?;
+          Ok(())
+        }
+      }
+    )+
+  };
+}
+
+display_arg!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A sequence of arguments that compose a command. The first argument is usually the name of the
+/// command, for example, `("SET", "key", 1)`.
+pub trait Command {
+  /// Encodes each argument with [`encode_arg`].
+  fn encode_args(&self, wb: &mut Vector<u8>) -> crate::Result<()>;
+
+  /// If there are no arguments.
+  #[inline]
+  fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// The number of arguments.
+  fn len(&self) -> usize;
+}
+
+impl<T> Command for &T
+where
+  T: Command + ?Sized,
+{
+  #[inline]
+  fn encode_args(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    (**self).encode_args(wb)
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    (**self).len()
+  }
+}
+
+impl<T> Command for [T]
+where
+  T: Arg,
+{
+  #[inline]
+  fn encode_args(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    for elem in self {
+      encode_arg(elem, wb)?;
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.len()
+  }
+}
+
+impl<T> Command for Vec<T>
+where
+  T: Arg,
+{
+  #[inline]
+  fn encode_args(&self, wb: &mut Vector<u8>) -> crate::Result<()> {
+    self.as_slice().encode_args(wb)
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.as_slice().len()
+  }
+}
+
+/// Writes `arg` as a bulk string.
+#[inline]
+pub fn encode_arg<A>(arg: &A, wb: &mut Vector<u8>) -> crate::Result<()>
+where
+  A: Arg + ?Sized,
+{
+  let start = wb.len();
+  arg.write_bytes(wb)?;
+  let end = wb.len();
+  wb.write_fmt(format_args!("${}\r\n", end.wrapping_sub(start)))?;
+  let header_len = wb.len().wrapping_sub(end);
+  wb.get_mut(start..).unwrap_or_default().rotate_right(header_len);
+  wb.extend_from_copyable_slice(b"\r\n")?;
+  Ok(())
+}
+
+/// Writes `cmd` as an array of bulk strings.
+#[inline]
+pub(crate) fn encode_command<C>(cmd: &C, wb: &mut Vector<u8>) -> crate::Result<()>
+where
+  C: Command + ?Sized,
+{
+  wb.write_fmt(format_args!("*{}\r\n", cmd.len()))?;
+  cmd.encode_args(wb)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{database::client::redis::command::encode_command, misc::Vector};
+
+  #[test]
+  fn encodes_commands() {
+    let mut wb = Vector::new();
+    encode_command(&("SET", "key", -12, 1.5), &mut wb).unwrap();
+    assert_eq!(wb.as_slice(), b"*4\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\n-12\r\n$3\r\n1.5\r\n");
+    wb.clear();
+    encode_command(["DEL", "a", "b"].as_slice(), &mut wb).unwrap();
+    assert_eq!(wb.as_slice(), b"*3\r\n$3\r\nDEL\r\n$1\r\na\r\n$1\r\nb\r\n");
+    wb.clear();
+    encode_command(&("SET", "bin", b"a\r\nb".as_slice()), &mut wb).unwrap();
+    assert_eq!(wb.as_slice(), b"*3\r\n$3\r\nSET\r\n$3\r\nbin\r\n$4\r\na\r\nb\r\n");
+  }
+}
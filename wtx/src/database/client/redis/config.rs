@@ -0,0 +1,101 @@
+use crate::{
+  database::client::redis::RedisError,
+  misc::{str_split1, str_split_once1, FromRadix10, UriRef},
+};
+
+/// Configuration
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config<'data> {
+  pub(crate) client_name: &'data str,
+  pub(crate) db: u32,
+  pub(crate) password: &'data str,
+  pub(crate) protocol: Protocol,
+  pub(crate) user: &'data str,
+}
+
+impl<'data> Config<'data> {
+  /// Unwraps the elements of an URI. For example, `redis://user:pw@localhost:6379/0`.
+  ///
+  /// The path, if any, is the logical database index. Supported query parameters are
+  /// `client_name` and `protocol`, which must be `2` or `3`.
+  #[inline]
+  pub fn from_uri(uri: &'data UriRef<'_>) -> crate::Result<Config<'data>> {
+    let db = match uri.path().get(1..).unwrap_or_default() {
+      "" => 0,
+      elem => u32::from_radix_10(elem.as_bytes())?,
+    };
+    let mut this = Self {
+      client_name: "",
+      db,
+      password: uri.password(),
+      protocol: Protocol::Resp3,
+      user: uri.user(),
+    };
+    let mut pair_iter = str_split1(uri.query_and_fragment(), b'&');
+    if let Some(mut key_value) = pair_iter.next() {
+      key_value = key_value.get(1..).unwrap_or_default();
+      if let Some((key, value)) = str_split_once1(key_value, b'=') {
+        this.set_param(key, value)?;
+      }
+    }
+    for key_value in pair_iter {
+      if let Some((key, value)) = str_split_once1(key_value, b'=') {
+        this.set_param(key, value)?;
+      }
+    }
+    Ok(this)
+  }
+
+  #[inline]
+  fn set_param(&mut self, key: &str, value: &'data str) -> crate::Result<()> {
+    match key {
+      "client_name" => {
+        self.client_name = value;
+      }
+      "protocol" => {
+        self.protocol = match value {
+          "2" => Protocol::Resp2,
+          "3" => Protocol::Resp3,
+          _ => return Err(RedisError::UnknownConfigurationParameter.into()),
+        };
+      }
+      _ => return Err(RedisError::UnknownConfigurationParameter.into()),
+    }
+    Ok(())
+  }
+}
+
+/// Serialization protocol used after the handshake.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protocol {
+  /// Supported by all server versions. Pub/Sub messages are only received by dedicated
+  /// connections.
+  Resp2,
+  /// Requires Redis 6 or newer. Adds types like maps, doubles or out-of-band pushes.
+  Resp3,
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::redis::{config::Protocol, Config},
+    misc::Uri,
+  };
+
+  #[test]
+  fn from_uri() {
+    let uri = Uri::new("redis://ab:cd@ef:6379/2?protocol=2&client_name=gh");
+    let config = Config::from_uri(&uri).unwrap();
+    assert_eq!(config.client_name, "gh");
+    assert_eq!(config.db, 2);
+    assert_eq!(config.password, "cd");
+    assert_eq!(config.protocol, Protocol::Resp2);
+    assert_eq!(config.user, "ab");
+    let uri = Uri::new("redis://ef:6379");
+    let config = Config::from_uri(&uri).unwrap();
+    assert_eq!(config.db, 0);
+    assert_eq!(config.protocol, Protocol::Resp3);
+    assert!(Config::from_uri(&Uri::new("redis://ef:6379?protocol=4")).is_err());
+    assert!(Config::from_uri(&Uri::new("redis://ef:6379?ij=kl")).is_err());
+  }
+}
@@ -0,0 +1,41 @@
+use crate::misc::str_split_once1;
+use alloc::boxed::Box;
+
+/// An error sent by the server through a simple or bulk error frame.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DbError {
+  message: Box<str>,
+}
+
+impl DbError {
+  #[inline]
+  pub(crate) fn new(message: Box<str>) -> Self {
+    Self { message }
+  }
+
+  /// The first word of the message, which is conventionally used to categorize errors. For
+  /// example, `ERR`, `WRONGTYPE` or `NOAUTH`.
+  #[inline]
+  pub fn kind(&self) -> &str {
+    str_split_once1(&self.message, b' ').map_or(&self.message, |el| el.0)
+  }
+
+  /// Human-readable error message, including the kind.
+  #[inline]
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::redis::DbError;
+
+  #[test]
+  fn kind_and_message() {
+    let err = DbError::new("WRONGTYPE Operation against a key".into());
+    assert_eq!(err.kind(), "WRONGTYPE");
+    assert_eq!(err.message(), "WRONGTYPE Operation against a key");
+    assert_eq!(DbError::new("ERR".into()).kind(), "ERR");
+  }
+}
@@ -0,0 +1,327 @@
+use crate::{
+  database::client::redis::{FrameTy, RedisError},
+  misc::{bytes_pos1, from_utf8_basic, FromRadix10},
+};
+
+const MAX_DEPTH: u8 = 32;
+
+/// A RESP2 or RESP3 value that borrows the bytes sent by the server.
+///
+/// Attributes are skipped and RESP2 null bulk strings or null arrays are represented as
+/// [`Frame::Null`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Frame<'any> {
+  /// See [`FrameTy::Array`].
+  Array(Aggregate<'any>),
+  /// See [`FrameTy::BigNumber`].
+  BigNumber(&'any str),
+  /// See [`FrameTy::Boolean`].
+  Boolean(bool),
+  /// See [`FrameTy::BulkString`].
+  BulkString(&'any [u8]),
+  /// See [`FrameTy::Double`].
+  Double(f64),
+  /// A nested simple or bulk error. Top-level errors are returned as
+  /// [`crate::Error::RedisDbError`].
+  Error(&'any str),
+  /// See [`FrameTy::Integer`].
+  Integer(i64),
+  /// See [`FrameTy::Map`]. Keys and values are interleaved.
+  Map(Aggregate<'any>),
+  /// See [`FrameTy::Null`].
+  Null,
+  /// See [`FrameTy::Push`].
+  Push(Aggregate<'any>),
+  /// See [`FrameTy::Set`].
+  Set(Aggregate<'any>),
+  /// See [`FrameTy::SimpleString`].
+  SimpleString(&'any str),
+  /// See [`FrameTy::VerbatimString`]. Doesn't include the encoding hint.
+  VerbatimString(&'any [u8]),
+}
+
+impl<'any> Frame<'any> {
+  /// Parses the first frame of `bytes`, which must be complete.
+  #[inline]
+  pub fn parse(bytes: &'any [u8]) -> crate::Result<Self> {
+    Ok(Self::parse_with_len(bytes, 0)?.0)
+  }
+
+  /// Type of the frame.
+  #[inline]
+  pub fn ty(&self) -> FrameTy {
+    match self {
+      Self::Array(_) => FrameTy::Array,
+      Self::BigNumber(_) => FrameTy::BigNumber,
+      Self::Boolean(_) => FrameTy::Boolean,
+      Self::BulkString(_) => FrameTy::BulkString,
+      Self::Double(_) => FrameTy::Double,
+      Self::Error(_) => FrameTy::SimpleError,
+      Self::Integer(_) => FrameTy::Integer,
+      Self::Map(_) => FrameTy::Map,
+      Self::Null => FrameTy::Null,
+      Self::Push(_) => FrameTy::Push,
+      Self::Set(_) => FrameTy::Set,
+      Self::SimpleString(_) => FrameTy::SimpleString,
+      Self::VerbatimString(_) => FrameTy::VerbatimString,
+    }
+  }
+
+  #[inline]
+  pub(crate) fn parse_with_len(bytes: &'any [u8], depth: u8) -> crate::Result<(Self, usize)> {
+    if depth > MAX_DEPTH {
+      return Err(RedisError::NestingLimitReached.into());
+    }
+    let (ty, line, header_len) = header(bytes)?.ok_or(RedisError::InvalidFrame)?;
+    let frame = match ty {
+      FrameTy::Array | FrameTy::Map | FrameTy::Push | FrameTy::Set => {
+        let Some(len) = aggregate_len(line, ty)? else {
+          return Ok((Frame::Null, header_len));
+        };
+        let mut idx = header_len;
+        for _ in 0..len {
+          let rest = bytes.get(idx..).unwrap_or_default();
+          idx = idx.wrapping_add(Self::parse_with_len(rest, depth.wrapping_add(1))?.1);
+        }
+        let aggregate = Aggregate {
+          bytes: bytes.get(header_len..idx).unwrap_or_default(),
+          depth: depth.wrapping_add(1),
+          len: if ty == FrameTy::Map { len / 2 } else { len },
+        };
+        let frame = match ty {
+          FrameTy::Array => Frame::Array(aggregate),
+          FrameTy::Map => Frame::Map(aggregate),
+          FrameTy::Push => Frame::Push(aggregate),
+          _ => Frame::Set(aggregate),
+        };
+        return Ok((frame, idx));
+      }
+      FrameTy::Attribute => {
+        let len = aggregate_len(line, ty)?.unwrap_or_default();
+        let mut idx = header_len;
+        for _ in 0..len {
+          let rest = bytes.get(idx..).unwrap_or_default();
+          idx = idx.wrapping_add(Self::parse_with_len(rest, depth.wrapping_add(1))?.1);
+        }
+        let rest = bytes.get(idx..).unwrap_or_default();
+        let (frame, frame_len) = Self::parse_with_len(rest, depth)?;
+        return Ok((frame, idx.wrapping_add(frame_len)));
+      }
+      FrameTy::BigNumber => Frame::BigNumber(from_utf8_basic(line)?),
+      FrameTy::Boolean => match line {
+        b"f" => Frame::Boolean(false),
+        b"t" => Frame::Boolean(true),
+        _ => return Err(RedisError::InvalidFrame.into()),
+      },
+      FrameTy::BulkError | FrameTy::BulkString | FrameTy::VerbatimString => {
+        let Some(data) = bulk(bytes, line, header_len)? else {
+          return Ok((Frame::Null, header_len));
+        };
+        let frame = match ty {
+          FrameTy::BulkError => Frame::Error(from_utf8_basic(data)?),
+          FrameTy::BulkString => Frame::BulkString(data),
+          _ => Frame::VerbatimString(data.get(4..).unwrap_or_default()),
+        };
+        return Ok((frame, header_len.wrapping_add(data.len()).wrapping_add(2)));
+      }
+      FrameTy::Double => Frame::Double(
+        from_utf8_basic(line)?
+          .parse()
+          .map_err(|_err| crate::Error::from(RedisError::InvalidFrame))?,
+      ),
+      FrameTy::Integer => Frame::Integer(i64::from_radix_10(line)?),
+      FrameTy::Null => Frame::Null,
+      FrameTy::SimpleError => Frame::Error(from_utf8_basic(line)?),
+      FrameTy::SimpleString => Frame::SimpleString(from_utf8_basic(line)?),
+    };
+    Ok((frame, header_len))
+  }
+}
+
+/// A sequence of frames that is lazily parsed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aggregate<'any> {
+  bytes: &'any [u8],
+  depth: u8,
+  len: usize,
+}
+
+impl<'any> Aggregate<'any> {
+  /// If there are no elements.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Iterator over all inner frames. Map keys and values are interleaved.
+  #[inline]
+  pub fn iter(&self) -> impl Iterator<Item = Frame<'any>> {
+    let mut bytes = self.bytes;
+    let depth = self.depth;
+    core::iter::from_fn(move || {
+      let (frame, len) = Frame::parse_with_len(bytes, depth).ok()?;
+      bytes = bytes.get(len..).unwrap_or_default();
+      Some(frame)
+    })
+  }
+
+  /// The number of elements. For maps, it is the number of key-value pairs.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.len
+  }
+}
+
+/// Returns the length of the first frame of `bytes` or `None` if `bytes` doesn't contain a
+/// complete frame.
+#[inline]
+pub(crate) fn frame_len(bytes: &[u8]) -> crate::Result<Option<usize>> {
+  do_frame_len(bytes, 0)
+}
+
+// Integers are always returned as `usize` because lengths are represented as `i64`.
+#[inline]
+fn aggregate_len(line: &[u8], ty: FrameTy) -> crate::Result<Option<usize>> {
+  let len = i64::from_radix_10(line)?;
+  if len < 0 {
+    return Ok(None);
+  }
+  let elements = usize::try_from(len)?;
+  Ok(Some(if matches!(ty, FrameTy::Attribute | FrameTy::Map) {
+    elements.wrapping_mul(2)
+  } else {
+    elements
+  }))
+}
+
+#[inline]
+fn bulk<'any>(
+  bytes: &'any [u8],
+  line: &[u8],
+  header_len: usize,
+) -> crate::Result<Option<&'any [u8]>> {
+  let len = i64::from_radix_10(line)?;
+  if len < 0 {
+    return Ok(None);
+  }
+  let begin = header_len;
+  let end = begin.wrapping_add(usize::try_from(len)?);
+  let Some([data @ .., b'\r', b'\n']) = bytes.get(begin..end.wrapping_add(2)) else {
+    return Err(RedisError::InvalidFrame.into());
+  };
+  Ok(Some(data))
+}
+
+#[inline]
+fn do_frame_len(bytes: &[u8], depth: u8) -> crate::Result<Option<usize>> {
+  if depth > MAX_DEPTH {
+    return Err(RedisError::NestingLimitReached.into());
+  }
+  let Some((ty, line, header_len)) = header(bytes)? else {
+    return Ok(None);
+  };
+  match ty {
+    FrameTy::Array | FrameTy::Attribute | FrameTy::Map | FrameTy::Push | FrameTy::Set => {
+      let Some(len) = aggregate_len(line, ty)? else {
+        return Ok(Some(header_len));
+      };
+      let mut idx = header_len;
+      for _ in 0..len {
+        let Some(elem) = do_frame_len(bytes.get(idx..).unwrap_or_default(), depth.wrapping_add(1))?
+        else {
+          return Ok(None);
+        };
+        idx = idx.wrapping_add(elem);
+      }
+      if ty == FrameTy::Attribute {
+        let Some(elem) = do_frame_len(bytes.get(idx..).unwrap_or_default(), depth)? else {
+          return Ok(None);
+        };
+        idx = idx.wrapping_add(elem);
+      }
+      Ok(Some(idx))
+    }
+    FrameTy::BulkError | FrameTy::BulkString | FrameTy::VerbatimString => {
+      let len = i64::from_radix_10(line)?;
+      if len < 0 {
+        return Ok(Some(header_len));
+      }
+      let total = header_len.wrapping_add(usize::try_from(len)?).wrapping_add(2);
+      Ok(if bytes.len() >= total { Some(total) } else { None })
+    }
+    _ => Ok(Some(header_len)),
+  }
+}
+
+// Returns the type, the contents of the first line without the type byte and the line length,
+// including the type byte and the trailing CRLF.
+#[inline]
+fn header(bytes: &[u8]) -> crate::Result<Option<(FrameTy, &[u8], usize)>> {
+  let [first, rest @ ..] = bytes else {
+    return Ok(None);
+  };
+  let ty = FrameTy::try_from(*first)
+    .map_err(|_err| crate::Error::from(RedisError::UnknownFrameTy { received: *first }))?;
+  let Some(lf_idx) = bytes_pos1(rest, b'\n') else {
+    return Ok(None);
+  };
+  let Some([line @ .., b'\r']) = rest.get(..lf_idx) else {
+    return Err(RedisError::InvalidFrame.into());
+  };
+  Ok(Some((ty, line, lf_idx.wrapping_add(2))))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::redis::{frame::frame_len, Frame};
+  use alloc::vec::Vec;
+
+  #[test]
+  fn aggregates() {
+    let bytes = b"*3\r\n:1\r\n$-1\r\n%1\r\n+a\r\n,1.5\r\n";
+    assert_eq!(frame_len(bytes).unwrap(), Some(bytes.len()));
+    let Frame::Array(array) = Frame::parse(bytes).unwrap() else { panic!() };
+    assert_eq!(array.len(), 3);
+    let frames: Vec<_> = array.iter().collect();
+    assert_eq!(frames[0], Frame::Integer(1));
+    assert_eq!(frames[1], Frame::Null);
+    let Frame::Map(map) = frames[2] else { panic!() };
+    assert_eq!(map.len(), 1);
+    assert!(!map.is_empty());
+    assert_eq!(map.iter().collect::<Vec<_>>(), [Frame::SimpleString("a"), Frame::Double(1.5)]);
+  }
+
+  #[test]
+  fn attributes_are_skipped() {
+    let bytes = b"|1\r\n+key\r\n+value\r\n:7\r\n";
+    assert_eq!(frame_len(bytes).unwrap(), Some(bytes.len()));
+    assert_eq!(Frame::parse(bytes).unwrap(), Frame::Integer(7));
+  }
+
+  #[test]
+  fn empty_aggregates() {
+    let Frame::Array(array) = Frame::parse(b"*0\r\n").unwrap() else { panic!() };
+    assert!(array.is_empty());
+    assert_eq!(array.iter().count(), 0);
+  }
+
+  #[test]
+  fn incomplete_frames() {
+    assert_eq!(frame_len(b"").unwrap(), None);
+    assert_eq!(frame_len(b"+OK\r").unwrap(), None);
+    assert_eq!(frame_len(b"$5\r\nhel").unwrap(), None);
+    assert_eq!(frame_len(b"*2\r\n:1\r\n").unwrap(), None);
+    assert!(frame_len(b"?\r\n").is_err());
+  }
+
+  #[test]
+  fn scalars() {
+    assert_eq!(Frame::parse(b"#t\r\n").unwrap(), Frame::Boolean(true));
+    assert_eq!(Frame::parse(b"$5\r\nhe\r\no\r\n").unwrap(), Frame::BulkString(b"he\r\no"));
+    assert_eq!(Frame::parse(b"(123\r\n").unwrap(), Frame::BigNumber("123"));
+    assert_eq!(Frame::parse(b"-ERR x\r\n").unwrap(), Frame::Error("ERR x"));
+    assert_eq!(Frame::parse(b":-2\r\n").unwrap(), Frame::Integer(-2));
+    assert_eq!(Frame::parse(b"_\r\n").unwrap(), Frame::Null);
+    assert_eq!(Frame::parse(b"=7\r\ntxt:abc\r\n").unwrap(), Frame::VerbatimString(b"abc"));
+  }
+}
@@ -0,0 +1,36 @@
+create_enum! {
+  /// The first byte of a frame, which identifies the type of the following data.
+  #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+  pub enum FrameTy<u8> {
+    /// Ordered collection of frames
+    Array = (b'*'),
+    /// Map of auxiliary data that precedes a frame
+    Attribute = (b'|'),
+    /// Signed integer with arbitrary precision
+    BigNumber = (b'('),
+    /// `true` or `false`
+    Boolean = (b'#'),
+    /// Binary-safe error
+    BulkError = (b'!'),
+    /// Binary-safe string
+    BulkString = (b'$'),
+    /// Floating point number
+    Double = (b','),
+    /// 64-bit signed integer
+    Integer = (b':'),
+    /// Unordered collection of key-value pairs
+    Map = (b'%'),
+    /// Absence of value
+    Null = (b'_'),
+    /// Out-of-band data sent by the server
+    Push = (b'>'),
+    /// Unordered collection of unique frames
+    Set = (b'~'),
+    /// Error without line breaks
+    SimpleError = (b'-'),
+    /// String without line breaks
+    SimpleString = (b'+'),
+    /// Binary-safe string with an encoding hint
+    VerbatimString = (b'='),
+  }
+}
@@ -0,0 +1,157 @@
+use crate::{
+  database::client::redis::{Frame, FrameTy, RedisError},
+  misc::{from_utf8_basic, FromRadix10},
+};
+use alloc::{string::String, vec::Vec};
+
+/// Converts a frame sent by the server into a concrete type.
+pub trait FromFrame<'de>: Sized {
+  /// Performs the conversion.
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self>;
+}
+
+impl<'de> FromFrame<'de> for () {
+  #[inline]
+  fn from_frame(_: Frame<'de>) -> crate::Result<Self> {
+    Ok(())
+  }
+}
+
+impl<'de> FromFrame<'de> for Frame<'de> {
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    Ok(frame)
+  }
+}
+
+impl<'de> FromFrame<'de> for bool {
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    match frame {
+      Frame::Boolean(elem) => Ok(elem),
+      Frame::Integer(elem) => Ok(elem != 0),
+      Frame::SimpleString("OK") => Ok(true),
+      Frame::Null => Ok(false),
+      _ => Err(unexpected(FrameTy::Boolean, &frame)),
+    }
+  }
+}
+
+impl<'de> FromFrame<'de> for &'de [u8] {
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    match frame {
+      Frame::BigNumber(elem) | Frame::SimpleString(elem) => Ok(elem.as_bytes()),
+      Frame::BulkString(elem) | Frame::VerbatimString(elem) => Ok(elem),
+      _ => Err(unexpected(FrameTy::BulkString, &frame)),
+    }
+  }
+}
+
+impl<'de> FromFrame<'de> for &'de str {
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    Ok(from_utf8_basic(<&[u8]>::from_frame(frame)?)?)
+  }
+}
+
+impl<'de> FromFrame<'de> for f64 {
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    match frame {
+      Frame::Double(elem) => Ok(elem),
+      Frame::BulkString(elem) | Frame::VerbatimString(elem) => {
+        from_utf8_basic(elem)?.parse().map_err(|_err| unexpected(FrameTy::Double, &frame))
+      }
+      _ => Err(unexpected(FrameTy::Double, &frame)),
+    }
+  }
+}
+
+impl<'de, T> FromFrame<'de> for Option<T>
+where
+  T: FromFrame<'de>,
+{
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    match frame {
+      Frame::Null => Ok(None),
+      _ => Ok(Some(T::from_frame(frame)?)),
+    }
+  }
+}
+
+impl<'de> FromFrame<'de> for String {
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    Ok(<&str>::from_frame(frame)?.into())
+  }
+}
+
+impl<'de, T> FromFrame<'de> for Vec<T>
+where
+  T: FromFrame<'de>,
+{
+  #[inline]
+  fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+    match frame {
+      Frame::Array(elem) | Frame::Map(elem) | Frame::Push(elem) | Frame::Set(elem) => {
+        let mut rslt = Vec::with_capacity(elem.len());
+        for inner in elem.iter() {
+          rslt.push(T::from_frame(inner)?);
+        }
+        Ok(rslt)
+      }
+      Frame::Null => Ok(Vec::new()),
+      _ => Err(unexpected(FrameTy::Array, &frame)),
+    }
+  }
+}
+
+macro_rules! integer_from_frame {
+  ($($ty:ty),+) => {
+    $(
+      impl<'de> FromFrame<'de> for $ty {
+        #[inline]
+        fn from_frame(frame: Frame<'de>) -> crate::Result<Self> {
+          match frame {
+            Frame::Integer(elem) => Ok(i128::from(elem).try_into()?),
+            Frame::BigNumber(elem) | Frame::SimpleString(elem) => {
+              Ok(<$ty>::from_radix_10(elem.as_bytes())?)
+            }
+            Frame::BulkString(elem) => Ok(<$ty>::from_radix_10(elem)?),
+            _ => Err(unexpected(FrameTy::Integer, &frame)),
+          }
+        }
+      }
+    )+
+  };
+}
+
+integer_from_frame!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+#[inline]
+fn unexpected(expected: FrameTy, frame: &Frame<'_>) -> crate::Error {
+  RedisError::UnexpectedFrame { expected, received: frame.ty() }.into()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::redis::{Frame, FromFrame};
+  use alloc::{string::String, vec::Vec};
+
+  #[test]
+  fn conversions() {
+    assert_eq!(i64::from_frame(Frame::Integer(-3)).unwrap(), -3);
+    assert_eq!(u32::from_frame(Frame::BulkString(b"12")).unwrap(), 12);
+    assert!(u8::from_frame(Frame::Integer(-1)).is_err());
+    assert_eq!(<&str>::from_frame(Frame::SimpleString("OK")).unwrap(), "OK");
+    assert_eq!(Option::<String>::from_frame(Frame::Null).unwrap(), None);
+    assert_eq!(
+      Vec::<i64>::from_frame(Frame::parse(b"*2\r\n:1\r\n$1\r\n2\r\n").unwrap()).unwrap(),
+      [1, 2]
+    );
+    assert!(bool::from_frame(Frame::Integer(1)).unwrap());
+    assert!(<&[u8]>::from_frame(Frame::Integer(1)).is_err());
+  }
+}
@@ -0,0 +1,42 @@
+use crate::{
+  database::client::redis::{command::encode_command, Command},
+  misc::Vector,
+};
+
+/// Queue of commands that are sent in a single round trip. See
+/// [`crate::database::client::redis::Client::pipeline`].
+#[derive(Debug)]
+pub struct Pipeline<'any> {
+  len: &'any mut usize,
+  wb: &'any mut Vector<u8>,
+}
+
+impl<'any> Pipeline<'any> {
+  #[inline]
+  pub(crate) fn new(len: &'any mut usize, wb: &'any mut Vector<u8>) -> Self {
+    Self { len, wb }
+  }
+
+  /// The number of queued commands.
+  #[inline]
+  pub fn len(&self) -> usize {
+    *self.len
+  }
+
+  /// If there are no queued commands.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    *self.len == 0
+  }
+
+  /// Queues a **single** command.
+  #[inline]
+  pub fn push<C>(&mut self, cmd: C) -> crate::Result<()>
+  where
+    C: Command,
+  {
+    encode_command(&cmd, self.wb)?;
+    *self.len = self.len.wrapping_add(1);
+    Ok(())
+  }
+}
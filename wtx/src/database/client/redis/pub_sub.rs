@@ -0,0 +1,149 @@
+use crate::{
+  database::client::redis::{
+    client::{read_frame, send, PrefixedCommand},
+    command::encode_command,
+    Arg, Client, ClientBuffer, DbError, Frame, FromFrame, RedisError,
+  },
+  misc::{ConnectionState, LeaseMut, Stream},
+};
+use alloc::boxed::Box;
+
+/// A connection dedicated to Pub/Sub messages. See [`Client::into_pub_sub`].
+///
+/// Subscription confirmations are processed by [`PubSub::next_message`], which means that
+/// [`PubSub::subscriptions`] is only updated after awaiting new messages.
+#[derive(Debug)]
+pub struct PubSub<CB, S> {
+  client: Client<CB, S>,
+  subscriptions: u64,
+}
+
+impl<CB, S> PubSub<CB, S>
+where
+  CB: LeaseMut<ClientBuffer>,
+  S: Stream,
+{
+  #[inline]
+  pub(crate) fn new(client: Client<CB, S>) -> Self {
+    Self { client, subscriptions: 0 }
+  }
+
+  /// Awaits the next message published to any of the subscribed channels or patterns.
+  #[inline]
+  pub async fn next_message(&mut self) -> crate::Result<Message<'_>> {
+    let Client { cb, cs: _, stream } = &mut self.client;
+    let nb = &mut cb.lease_mut().nb;
+    loop {
+      nb._clear_if_following_is_empty();
+      read_frame(nb, stream).await?;
+      let mut iter = match Frame::parse(nb._current())? {
+        Frame::Array(elem) | Frame::Push(elem) => elem.iter(),
+        Frame::Error(elem) => {
+          return Err(crate::Error::RedisDbError(Box::new(DbError::new(elem.into()))));
+        }
+        _ => return Err(RedisError::UnexpectedPubSubMessage.into()),
+      };
+      let kind = <&[u8]>::from_frame(iter.next().unwrap_or(Frame::Null))?;
+      match kind {
+        b"message" | b"pmessage" | b"smessage" => break,
+        b"psubscribe" | b"punsubscribe" | b"ssubscribe" | b"subscribe" | b"sunsubscribe"
+        | b"unsubscribe" => {
+          let _channel = iter.next();
+          self.subscriptions = u64::from_frame(iter.next().unwrap_or(Frame::Null))?;
+        }
+        b"pong" => {}
+        _ => return Err(RedisError::UnexpectedPubSubMessage.into()),
+      }
+    }
+    let mut iter = match Frame::parse(nb._current())? {
+      Frame::Array(elem) | Frame::Push(elem) => elem.iter(),
+      _ => return Err(RedisError::UnexpectedPubSubMessage.into()),
+    };
+    let mut next = || <&[u8]>::from_frame(iter.next().unwrap_or(Frame::Null));
+    let pattern = if next()? == b"pmessage" { Some(next()?) } else { None };
+    Ok(Message { channel: next()?, pattern, payload: next()? })
+  }
+
+  /// Subscribes to all channels that match the given patterns.
+  #[inline]
+  pub async fn psubscribe<P>(&mut self, patterns: &[P]) -> crate::Result<()>
+  where
+    P: Arg,
+  {
+    self.send(PrefixedCommand { args: patterns, name: "PSUBSCRIBE" }).await
+  }
+
+  /// Unsubscribes from the given patterns or from all patterns if `patterns` is empty.
+  #[inline]
+  pub async fn punsubscribe<P>(&mut self, patterns: &[P]) -> crate::Result<()>
+  where
+    P: Arg,
+  {
+    self.send(PrefixedCommand { args: patterns, name: "PUNSUBSCRIBE" }).await
+  }
+
+  /// Subscribes to the given channels.
+  #[inline]
+  pub async fn subscribe<C>(&mut self, channels: &[C]) -> crate::Result<()>
+  where
+    C: Arg,
+  {
+    self.send(PrefixedCommand { args: channels, name: "SUBSCRIBE" }).await
+  }
+
+  /// The number of channels and patterns that this connection is subscribed to, as reported by
+  /// the last confirmation sent by the server.
+  #[inline]
+  pub fn subscriptions(&self) -> u64 {
+    self.subscriptions
+  }
+
+  /// Unsubscribes from the given channels or from all channels if `channels` is empty.
+  #[inline]
+  pub async fn unsubscribe<C>(&mut self, channels: &[C]) -> crate::Result<()>
+  where
+    C: Arg,
+  {
+    self.send(PrefixedCommand { args: channels, name: "UNSUBSCRIBE" }).await
+  }
+
+  #[inline]
+  async fn send<A>(&mut self, cmd: PrefixedCommand<'_, A>) -> crate::Result<()>
+  where
+    A: Arg,
+  {
+    let Client { cb, cs, stream } = &mut self.client;
+    let ClientBuffer { nb, wb } = cb.lease_mut();
+    send(cs, nb, wb, stream, |local_wb| encode_command(&cmd, local_wb)).await?;
+    *cs = ConnectionState::Open;
+    Ok(())
+  }
+}
+
+/// A message published to a channel.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Message<'any> {
+  channel: &'any [u8],
+  pattern: Option<&'any [u8]>,
+  payload: &'any [u8],
+}
+
+impl<'any> Message<'any> {
+  /// The channel that received the message.
+  #[inline]
+  pub fn channel(&self) -> &'any [u8] {
+    self.channel
+  }
+
+  /// The matched pattern if the message was received through a pattern subscription.
+  #[inline]
+  pub fn pattern(&self) -> Option<&'any [u8]> {
+    self.pattern
+  }
+
+  /// Contents of the message.
+  #[inline]
+  pub fn payload(&self) -> &'any [u8] {
+    self.payload
+  }
+}
@@ -0,0 +1,26 @@
+use crate::database::client::redis::FrameTy;
+
+/// Redis error
+#[derive(Debug)]
+pub enum RedisError {
+  /// Received bytes don't compose a valid frame.
+  InvalidFrame,
+  /// Frames nested beyond the supported depth.
+  NestingLimitReached,
+  /// Server closed the subscription or sent a message that isn't related to Pub/Sub.
+  UnexpectedPubSubMessage,
+  /// Received a frame that can't be converted into the requested type.
+  UnexpectedFrame {
+    /// Expected
+    expected: FrameTy,
+    /// Received
+    received: FrameTy,
+  },
+  /// Unknown configuration parameter or an invalid value.
+  UnknownConfigurationParameter,
+  /// The first byte of a frame isn't a known type.
+  UnknownFrameTy {
+    /// Received byte
+    received: u8,
+  },
+}
@@ -0,0 +1,76 @@
+//! SQLite is a self-contained, serverless and zero-configuration SQL database engine that is
+//! embedded into the application.
+//!
+//! Unlike other clients, all operations are performed through the bundled C library in the
+//! current thread, as such, futures returned by the executor never yield.
+
+mod db_error;
+mod decode_value;
+mod encode_value;
+mod executor;
+mod executor_buffer;
+mod handle;
+#[cfg(all(feature = "_async-tests", test))]
+mod integration_tests;
+mod record;
+mod records;
+mod sqlite_error;
+mod statements;
+mod ty;
+mod tys;
+
+use crate::database::{Database, DatabaseTy};
+use core::marker::PhantomData;
+pub use db_error::DbError;
+pub use decode_value::DecodeValue;
+pub use encode_value::EncodeValue;
+pub use executor::Executor;
+pub use executor_buffer::ExecutorBuffer;
+pub use record::Record;
+pub use records::Records;
+pub use sqlite_error::SqliteError;
+pub use statements::Statements;
+pub use ty::Ty;
+
+/// SQLite
+#[derive(Debug)]
+pub struct Sqlite<E>(PhantomData<fn() -> E>);
+
+impl<E> Database for Sqlite<E>
+where
+  E: From<crate::Error>,
+{
+  const BIND_PREFIX: &'static str = "?";
+  const IS_BIND_INCREASING: bool = true;
+  const TY: DatabaseTy = DatabaseTy::Sqlite;
+
+  type DecodeValue<'exec> = DecodeValue<'exec>;
+  type EncodeValue<'buffer, 'tmp>
+    = EncodeValue<'buffer, 'tmp>
+  where
+    'buffer: 'tmp;
+  type Error = E;
+  type Record<'exec> = Record<'exec, E>;
+  type Records<'exec> = Records<'exec, E>;
+  type Ty = Ty;
+}
+
+impl<E> Default for Sqlite<E> {
+  #[inline]
+  fn default() -> Self {
+    Self(PhantomData)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::client::sqlite::statements::column::Column;
+
+  pub(crate) fn column0() -> Column {
+    Column::new("a".try_into().unwrap())
+  }
+
+  pub(crate) fn column1() -> Column {
+    Column::new("b".try_into().unwrap())
+  }
+}
@@ -0,0 +1,35 @@
+use alloc::boxed::Box;
+
+/// An error returned by the SQLite library.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DbError {
+  code: i32,
+  message: Box<str>,
+}
+
+impl DbError {
+  #[inline]
+  pub(crate) fn new(code: i32, message: Box<str>) -> Self {
+    Self { code, message }
+  }
+
+  /// Extended result code. For example, `2067` (`SQLITE_CONSTRAINT_UNIQUE`) is returned when a
+  /// unique constraint is violated.
+  #[inline]
+  pub fn code(&self) -> i32 {
+    self.code
+  }
+
+  /// Human-readable error message.
+  #[inline]
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Primary result code, which is composed by the 8 least significant bits of the extended
+  /// result code.
+  #[inline]
+  pub fn primary_code(&self) -> i32 {
+    self.code & 0xFF
+  }
+}
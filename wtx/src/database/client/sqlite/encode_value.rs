@@ -0,0 +1,55 @@
+use crate::{
+  database::client::sqlite::Ty,
+  misc::{FilledBufferWriter, Lease, LeaseMut},
+};
+
+/// Struct used for encoding elements in SQLite.
+#[derive(Debug)]
+pub struct EncodeValue<'buffer, 'tmp> {
+  fbw: &'tmp mut FilledBufferWriter<'buffer>,
+  ty: Ty,
+}
+
+impl<'buffer, 'tmp> EncodeValue<'buffer, 'tmp> {
+  #[inline]
+  pub(crate) fn new(fbw: &'tmp mut FilledBufferWriter<'buffer>) -> Self {
+    Self { fbw, ty: Ty::Blob }
+  }
+
+  /// See [`FilledBufferWriter`].
+  #[inline]
+  pub fn fbw(&mut self) -> &mut FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+
+  /// Declares the storage class of the value that is being encoded, which defaults to
+  /// [`Ty::Blob`].
+  ///
+  /// [`Ty::Integer`] and [`Ty::Float`] expect 8 little-endian bytes.
+  #[inline]
+  pub fn set_ty(&mut self, ty: Ty) {
+    self.ty = ty;
+  }
+
+  /// Returns the declared type and then resets it to the default value.
+  #[inline]
+  pub(crate) fn take_ty(&mut self) -> Ty {
+    let rslt = self.ty;
+    self.ty = Ty::Blob;
+    rslt
+  }
+}
+
+impl<'buffer> Lease<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
+  #[inline]
+  fn lease(&self) -> &FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+}
+
+impl<'buffer> LeaseMut<FilledBufferWriter<'buffer>> for EncodeValue<'buffer, '_> {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut FilledBufferWriter<'buffer> {
+    self.fbw
+  }
+}
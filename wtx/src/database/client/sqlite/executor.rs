@@ -0,0 +1,170 @@
+mod fetch;
+mod prepare;
+
+use crate::{
+  database::{
+    client::sqlite::{
+      executor::fetch::{bind, changes, reset, step},
+      executor_buffer::{ExecutorBuffer, ExecutorBufferPartsMut},
+      handle::{DbHandle, StmtHandle},
+      Record, Records, Sqlite, SqliteError,
+    },
+    Database, RecordValues, StmtCmd, TransactionOptions,
+  },
+  misc::{ConnectionState, LeaseMut, _unlikely_dflt},
+};
+use core::{ffi::c_int, marker::PhantomData, ptr};
+use libsqlite3_sys::{sqlite3_finalize, sqlite3_prepare_v2, SQLITE_OK};
+
+/// Executor
+#[derive(Debug)]
+pub struct Executor<E, EB> {
+  pub(crate) cs: ConnectionState,
+  pub(crate) db: DbHandle,
+  pub(crate) eb: EB,
+  pub(crate) phantom: PhantomData<fn() -> E>,
+}
+
+impl<E, EB> Executor<E, EB>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+{
+  /// Opens or creates the database file located at `path`.
+  ///
+  /// `:memory:` creates a private in-memory database and URI filenames like
+  /// `file:data.db?mode=ro` are also accepted.
+  #[inline]
+  pub fn open(path: &str, mut eb: EB) -> crate::Result<Self> {
+    eb.lease_mut().clear();
+    Ok(Self { cs: ConnectionState::Open, db: DbHandle::open(path)?, eb, phantom: PhantomData })
+  }
+
+  /// Mutable buffer reference
+  #[inline]
+  pub fn eb_mut(&mut self) -> &mut ExecutorBuffer {
+    self.eb.lease_mut()
+  }
+}
+
+impl<E, EB> crate::database::Executor for Executor<E, EB>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+{
+  type Database = Sqlite<E>;
+
+  #[inline]
+  fn connection_state(&self) -> ConnectionState {
+    self.cs
+  }
+
+  /// SQLite transactions are always serializable and can't be read-only, as such,
+  /// [`TransactionOptions`] is ignored.
+  #[inline]
+  async fn begin(&mut self, _: TransactionOptions) -> crate::Result<()> {
+    self.execute("BEGIN", |_| {}).await
+  }
+
+  #[inline]
+  async fn execute(&mut self, cmd: &str, mut cb: impl FnMut(u64)) -> crate::Result<()> {
+    let mut rest = cmd.as_bytes();
+    while !rest.is_empty() {
+      let mut handle = ptr::null_mut();
+      let mut tail = ptr::null();
+      let len = c_int::try_from(rest.len())?;
+      // SAFETY: the connection is valid and `rest` is a valid slice of `len` bytes
+      let rc = unsafe {
+        sqlite3_prepare_v2(self.db.as_ptr(), rest.as_ptr().cast(), len, &mut handle, &mut tail)
+      };
+      if rc != SQLITE_OK {
+        return Err(self.db.error());
+      }
+      // SAFETY: `tail` points to the end of the first statement within `rest`
+      let consumed = unsafe { tail.cast::<u8>().offset_from(rest.as_ptr()) };
+      rest = rest.get(usize::try_from(consumed)?..).unwrap_or_default();
+      // Whitespaces and comments don't produce statements
+      if handle.is_null() {
+        continue;
+      }
+      let stmt = StmtHandle::new(handle);
+      let rslt = changes(&self.db, || {
+        while step(&self.db, stmt)? {}
+        Ok(())
+      });
+      // SAFETY: the statement is valid and is never used again
+      let _ = unsafe { sqlite3_finalize(handle) };
+      cb(rslt?);
+    }
+    Ok(())
+  }
+
+  #[inline]
+  async fn execute_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+  ) -> Result<u64, <Self::Database as Database>::Error>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let Self { cs: _, db, eb, phantom: _ } = self;
+    let ExecutorBufferPartsMut { bb, fb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(bb, rb, vb);
+    let (_, stmt) = Self::stmt(db, sc, stmts)?;
+    let rslt = bind(db, fb, rv, &stmt).and_then(|_| {
+      Ok(changes(db, || {
+        while step(db, stmt.handle)? {}
+        Ok(())
+      })?)
+    });
+    reset(stmt.handle);
+    rslt
+  }
+
+  #[inline]
+  async fn fetch_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+  ) -> Result<<Self::Database as Database>::Record<'_>, E>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let Self { cs: _, db, eb, phantom: _ } = self;
+    let ExecutorBufferPartsMut { bb, fb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(bb, rb, vb);
+    let (_, stmt) = Self::stmt(db, sc, stmts)?;
+    Self::fetch_records(db, bb, fb, rb, rv, &stmt, vb, Some(1), &mut |_| Ok(()))?;
+    let Some(&end) = rb.first() else {
+      return Err(E::from(SqliteError::NoRecord.into()));
+    };
+    Ok(Record::new(bb, stmt, vb.get(..end).unwrap_or_else(_unlikely_dflt)))
+  }
+
+  #[inline]
+  async fn fetch_many_with_stmt<SC, RV>(
+    &mut self,
+    sc: SC,
+    rv: RV,
+    mut cb: impl FnMut(&<Self::Database as Database>::Record<'_>) -> Result<(), E>,
+  ) -> Result<<Self::Database as Database>::Records<'_>, E>
+  where
+    RV: RecordValues<Self::Database>,
+    SC: StmtCmd,
+  {
+    let Self { cs: _, db, eb, phantom: _ } = self;
+    let ExecutorBufferPartsMut { bb, fb, rb, stmts, vb } = eb.lease_mut().parts_mut();
+    ExecutorBuffer::clear_cmd_buffers(bb, rb, vb);
+    let (_, stmt) = Self::stmt(db, sc, stmts)?;
+    Self::fetch_records(db, bb, fb, rb, rv, &stmt, vb, None, &mut cb)?;
+    Ok(Records::new(bb, rb, stmt, vb))
+  }
+
+  #[inline]
+  async fn prepare(&mut self, cmd: &str) -> Result<u64, E> {
+    let Self { cs: _, db, eb, phantom: _ } = self;
+    Ok(Self::stmt(db, cmd, &mut eb.lease_mut().stmts)?.0)
+  }
+}
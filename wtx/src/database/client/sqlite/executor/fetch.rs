@@ -0,0 +1,215 @@
+use crate::{
+  database::{
+    client::sqlite::{
+      handle::{DbHandle, StmtHandle},
+      statements::statement::Statement,
+      EncodeValue, Executor, ExecutorBuffer, Record, Sqlite, SqliteError, Ty,
+    },
+    RecordValues,
+  },
+  misc::{filled_buffer::FilledBuffer, FilledBufferWriter, LeaseMut, Vector, _unlikely_dflt},
+};
+use core::{
+  ffi::{c_int, c_void},
+  ops::Range,
+  slice,
+};
+use libsqlite3_sys::{
+  sqlite3_bind_blob, sqlite3_bind_double, sqlite3_bind_int64, sqlite3_bind_null,
+  sqlite3_bind_parameter_count, sqlite3_bind_text, sqlite3_changes64, sqlite3_clear_bindings,
+  sqlite3_column_blob, sqlite3_column_bytes, sqlite3_column_count, sqlite3_column_double,
+  sqlite3_column_int64, sqlite3_column_text, sqlite3_column_type, sqlite3_reset, sqlite3_step,
+  sqlite3_total_changes64, SQLITE_DONE, SQLITE_OK, SQLITE_ROW, SQLITE_TRANSIENT,
+};
+
+impl<E, EB> Executor<E, EB>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+{
+  /// Binds `rv` and copies at most `max_records` rows into `bb`. The statement is always reset
+  /// at the end.
+  #[inline]
+  pub(crate) fn fetch_records<RV>(
+    db: &DbHandle,
+    bb: &mut Vector<u8>,
+    fb: &mut FilledBuffer,
+    rb: &mut Vector<usize>,
+    rv: RV,
+    stmt: &Statement<'_>,
+    vb: &mut Vector<(Ty, Range<usize>)>,
+    max_records: Option<usize>,
+    cb: &mut impl FnMut(&Record<'_, E>) -> Result<(), E>,
+  ) -> Result<(), E>
+  where
+    E: From<crate::Error>,
+    RV: RecordValues<Sqlite<E>>,
+  {
+    let rslt = (|| {
+      bind(db, fb, rv, stmt)?;
+      while max_records.map_or(true, |el| rb.len() < el) && step(db, stmt.handle)? {
+        let values_start = vb.len();
+        copy_row(bb, stmt.handle, vb)?;
+        cb(&Record::new(bb, stmt.clone(), vb.get(values_start..).unwrap_or_else(_unlikely_dflt)))?;
+        rb.push(vb.len()).map_err(crate::Error::from)?;
+      }
+      Ok(())
+    })();
+    reset(stmt.handle);
+    rslt
+  }
+}
+
+/// Binds all values of `rv` to the parameters of `stmt`.
+#[inline]
+pub(crate) fn bind<E, RV>(
+  db: &DbHandle,
+  fb: &mut FilledBuffer,
+  mut rv: RV,
+  stmt: &Statement<'_>,
+) -> Result<(), E>
+where
+  E: From<crate::Error>,
+  RV: RecordValues<Sqlite<E>>,
+{
+  let mut fbw = FilledBufferWriter::new(0, fb);
+  let mut aux: (usize, c_int) = (0, SQLITE_OK);
+  let _ = rv.encode_values(
+    &mut aux,
+    &mut EncodeValue::new(&mut fbw),
+    |_, _| 0,
+    |(idx, rc), ev, is_null, elem_len| {
+      *idx = idx.wrapping_add(1);
+      let ty = ev.take_ty();
+      if *rc != SQLITE_OK {
+        return 0;
+      }
+      let bytes = ev.fbw()._curr_bytes();
+      let value = bytes.get(bytes.len().wrapping_sub(elem_len)..).unwrap_or_default();
+      *rc = bind_value(stmt.handle, *idx, if is_null { Ty::Null } else { ty }, value);
+      0
+    },
+  )?;
+  // SAFETY: the statement is valid
+  let params = unsafe { sqlite3_bind_parameter_count(stmt.handle.as_ptr()) };
+  if usize::try_from(params).ok() != Some(aux.0) {
+    return Err(E::from(
+      SqliteError::UnexpectedBindParametersLen {
+        expected: u32::try_from(params).unwrap_or_default(),
+        received: u32::try_from(aux.0).unwrap_or(u32::MAX),
+      }
+      .into(),
+    ));
+  }
+  if aux.1 != SQLITE_OK {
+    return Err(E::from(db.error()));
+  }
+  Ok(())
+}
+
+/// Number of records modified by `cb`. Statements that don't insert, update or delete records
+/// return zero.
+#[inline]
+pub(crate) fn changes(db: &DbHandle, cb: impl FnOnce() -> crate::Result<()>) -> crate::Result<u64> {
+  // SAFETY: the connection is valid
+  let before = unsafe { sqlite3_total_changes64(db.as_ptr()) };
+  cb()?;
+  // SAFETY: the connection is valid
+  let after = unsafe { sqlite3_total_changes64(db.as_ptr()) };
+  if before == after {
+    return Ok(0);
+  }
+  // SAFETY: the connection is valid
+  Ok(u64::try_from(unsafe { sqlite3_changes64(db.as_ptr()) })?)
+}
+
+/// Allows the re-execution of a cached statement.
+#[inline]
+pub(crate) fn reset(stmt: StmtHandle) {
+  // SAFETY: the statement is valid
+  unsafe {
+    let _ = sqlite3_reset(stmt.as_ptr());
+    let _ = sqlite3_clear_bindings(stmt.as_ptr());
+  }
+}
+
+/// Returns `true` if a new row is available.
+#[inline]
+pub(crate) fn step(db: &DbHandle, stmt: StmtHandle) -> crate::Result<bool> {
+  // SAFETY: the statement is valid
+  match unsafe { sqlite3_step(stmt.as_ptr()) } {
+    SQLITE_DONE => Ok(false),
+    SQLITE_ROW => Ok(true),
+    _ => Err(db.error()),
+  }
+}
+
+#[inline]
+fn bind_value(stmt: StmtHandle, idx: usize, ty: Ty, value: &[u8]) -> c_int {
+  let (Ok(idx), Ok(len)) = (c_int::try_from(idx), c_int::try_from(value.len())) else {
+    return libsqlite3_sys::SQLITE_TOOBIG;
+  };
+  let ptr = stmt.as_ptr();
+  let data = value.as_ptr().cast::<c_void>();
+  // SAFETY: the statement is valid and `SQLITE_TRANSIENT` instructs SQLite to copy the provided
+  // bytes before returning.
+  unsafe {
+    match (ty, value) {
+      (Ty::Float, &[a, b, c, d, e, f, g, h]) => {
+        sqlite3_bind_double(ptr, idx, f64::from_le_bytes([a, b, c, d, e, f, g, h]))
+      }
+      (Ty::Integer, &[a, b, c, d, e, f, g, h]) => {
+        sqlite3_bind_int64(ptr, idx, i64::from_le_bytes([a, b, c, d, e, f, g, h]))
+      }
+      (Ty::Null, _) => sqlite3_bind_null(ptr, idx),
+      (Ty::Text, _) => sqlite3_bind_text(ptr, idx, data.cast(), len, SQLITE_TRANSIENT()),
+      _ => sqlite3_bind_blob(ptr, idx, data, len, SQLITE_TRANSIENT()),
+    }
+  }
+}
+
+#[inline]
+fn copy_row(
+  bb: &mut Vector<u8>,
+  stmt: StmtHandle,
+  vb: &mut Vector<(Ty, Range<usize>)>,
+) -> crate::Result<()> {
+  let ptr = stmt.as_ptr();
+  // SAFETY: the statement is valid
+  let columns_len = unsafe { sqlite3_column_count(ptr) };
+  for idx in 0..columns_len {
+    let begin = bb.len();
+    // SAFETY: the statement is valid and points to a row
+    let ty = Ty::try_from(u8::try_from(unsafe { sqlite3_column_type(ptr, idx) })?)?;
+    match ty {
+      // SAFETY: the statement is valid and points to a row
+      Ty::Float => {
+        bb.extend_from_copyable_slice(&unsafe { sqlite3_column_double(ptr, idx) }.to_le_bytes())?
+      }
+      // SAFETY: the statement is valid and points to a row
+      Ty::Integer => {
+        bb.extend_from_copyable_slice(&unsafe { sqlite3_column_int64(ptr, idx) }.to_le_bytes())?
+      }
+      Ty::Blob | Ty::Text => {
+        // SAFETY: the statement is valid and points to a row. `sqlite3_column_bytes` must be
+        // called after the retrieval of the pointer, which is only valid until the next step.
+        let bytes = unsafe {
+          let data = if ty == Ty::Blob {
+            sqlite3_column_blob(ptr, idx).cast::<u8>()
+          } else {
+            sqlite3_column_text(ptr, idx)
+          };
+          let len = usize::try_from(sqlite3_column_bytes(ptr, idx))?;
+          if data.is_null() {
+            &[]
+          } else {
+            slice::from_raw_parts(data, len)
+          }
+        };
+        bb.extend_from_copyable_slice(bytes)?;
+      }
+      Ty::Null => {}
+    }
+    vb.push((ty, begin..bb.len()))?;
+  }
+  Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::{
+  database::{
+    client::sqlite::{
+      executor_buffer::ExecutorBuffer,
+      handle::{DbHandle, StmtHandle},
+      statements::{statement::Statement, statements_misc::StatementsMisc},
+      Executor, SqliteError, Statements,
+    },
+    StmtCmd,
+  },
+  misc::{from_utf8_basic, LeaseMut},
+};
+use core::{
+  ffi::{c_int, CStr},
+  ptr,
+};
+use libsqlite3_sys::{
+  sqlite3_bind_parameter_count, sqlite3_column_count, sqlite3_column_name, sqlite3_prepare_v2,
+  SQLITE_OK,
+};
+
+impl<E, EB> Executor<E, EB>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+{
+  /// Compiles `sc` if the statement isn't already cached and stores the returned column names in
+  /// `stmts`.
+  ///
+  /// Only the first statement of a command is compiled, the rest is ignored.
+  #[inline]
+  pub(crate) fn stmt<'stmts, SC>(
+    db: &DbHandle,
+    sc: SC,
+    stmts: &'stmts mut Statements,
+  ) -> Result<(u64, Statement<'stmts>), E>
+  where
+    SC: StmtCmd,
+  {
+    let stmt_hash = sc.hash(stmts.hasher_mut());
+    if stmts.get_by_stmt_hash(stmt_hash).is_some() {
+      // FIXME(stable): Use `if let Some ...` with polonius
+      return Ok((stmt_hash, stmts.get_by_stmt_hash(stmt_hash).unwrap()));
+    }
+
+    let stmt_cmd = sc.cmd().ok_or_else(|| E::from(SqliteError::UnknownStatementId.into()))?;
+    let mut handle = ptr::null_mut();
+    let len = c_int::try_from(stmt_cmd.len()).map_err(crate::Error::from)?;
+    // SAFETY: the connection is valid and `stmt_cmd` is a valid slice of `len` bytes
+    let rc = unsafe {
+      sqlite3_prepare_v2(db.as_ptr(), stmt_cmd.as_ptr().cast(), len, &mut handle, ptr::null_mut())
+    };
+    if rc != SQLITE_OK {
+      return Err(E::from(db.error()));
+    }
+    let stmt_handle = StmtHandle::new(handle);
+    let idx = match Self::store(stmt_handle, stmt_hash, stmts) {
+      Ok(elem) => elem,
+      Err(err) => {
+        stmt_handle.finalize();
+        return Err(E::from(err));
+      }
+    };
+
+    let Some(stmt) = stmts.get_by_idx(idx) else {
+      return Err(crate::Error::ProgrammingError.into());
+    };
+    Ok((stmt_hash, stmt))
+  }
+
+  #[inline]
+  fn store(handle: StmtHandle, stmt_hash: u64, stmts: &mut Statements) -> crate::Result<usize> {
+    // SAFETY: the statement is valid or null, which returns zero in both cases
+    let (columns_len, params_len) = unsafe {
+      (sqlite3_column_count(handle.as_ptr()), sqlite3_bind_parameter_count(handle.as_ptr()))
+    };
+    let mut builder = stmts.builder();
+    if columns_len > 0 {
+      let _ = builder.expand(usize::try_from(columns_len)?)?;
+      for (idx, column) in (0..columns_len).zip(builder.inserted_elements()) {
+        // SAFETY: the statement is valid and `idx` is within bounds
+        let name = unsafe { sqlite3_column_name(handle.as_ptr(), idx) };
+        if !name.is_null() {
+          // SAFETY: returned names are always NUL-terminated
+          column.name = from_utf8_basic(unsafe { CStr::from_ptr(name) }.to_bytes())?.try_into()?;
+        }
+      }
+    }
+    builder.build(stmt_hash, StatementsMisc::new(handle, u16::try_from(params_len)?))
+  }
+}
@@ -0,0 +1,112 @@
+use crate::{
+  database::client::sqlite::{Statements, Ty},
+  misc::{filled_buffer::FilledBuffer, Lease, LeaseMut, Rng, Vector},
+};
+use core::ops::Range;
+
+#[derive(Debug)]
+#[doc = _internal_buffer_doc!()]
+pub struct ExecutorBuffer {
+  /// Bytes Buffer. Contains the values of the fetched records.
+  pub(crate) bb: Vector<u8>,
+  /// Encoding Buffer. Used to temporally store the encoded parameters.
+  pub(crate) fb: FilledBuffer,
+  /// Records Buffer.
+  pub(crate) rb: Vector<usize>,
+  /// Statements
+  pub(crate) stmts: Statements,
+  /// Values Buffer.
+  pub(crate) vb: Vector<(Ty, Range<usize>)>,
+}
+
+impl ExecutorBuffer {
+  /// With provided capacity.
+  #[inline]
+  pub fn new<RNG>(max_stmts: usize, rng: RNG) -> Self
+  where
+    RNG: Rng,
+  {
+    Self {
+      bb: Vector::new(),
+      fb: FilledBuffer::_new(),
+      rb: Vector::new(),
+      stmts: Statements::new(max_stmts, rng),
+      vb: Vector::new(),
+    }
+  }
+
+  /// With default capacity.
+  #[inline]
+  pub fn with_capacity<RNG>(
+    (columns_cap, bytes_cap, rows_cap, stmts_cap): (usize, usize, usize, usize),
+    max_stmts: usize,
+    rng: &mut RNG,
+  ) -> crate::Result<Self>
+  where
+    RNG: Rng,
+  {
+    Ok(Self {
+      bb: Vector::with_capacity(bytes_cap)?,
+      fb: FilledBuffer::_new(),
+      rb: Vector::with_capacity(rows_cap)?,
+      stmts: Statements::with_capacity(columns_cap, max_stmts, rng, stmts_cap)?,
+      vb: Vector::with_capacity(rows_cap.saturating_mul(columns_cap))?,
+    })
+  }
+
+  /// Should be used in a new instance.
+  #[inline]
+  pub(crate) fn clear(&mut self) {
+    let Self { bb, fb, rb, stmts, vb } = self;
+    bb.clear();
+    fb._clear();
+    rb.clear();
+    stmts.clear();
+    vb.clear();
+  }
+
+  /// Should be called before executing commands.
+  #[inline]
+  pub(crate) fn clear_cmd_buffers(
+    bb: &mut Vector<u8>,
+    rb: &mut Vector<usize>,
+    vb: &mut Vector<(Ty, Range<usize>)>,
+  ) {
+    bb.clear();
+    rb.clear();
+    vb.clear();
+  }
+
+  #[inline]
+  pub(crate) fn parts_mut(&mut self) -> ExecutorBufferPartsMut<'_> {
+    ExecutorBufferPartsMut {
+      bb: &mut self.bb,
+      fb: &mut self.fb,
+      rb: &mut self.rb,
+      stmts: &mut self.stmts,
+      vb: &mut self.vb,
+    }
+  }
+}
+
+impl Lease<ExecutorBuffer> for ExecutorBuffer {
+  #[inline]
+  fn lease(&self) -> &ExecutorBuffer {
+    self
+  }
+}
+
+impl LeaseMut<ExecutorBuffer> for ExecutorBuffer {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut ExecutorBuffer {
+    self
+  }
+}
+
+pub(crate) struct ExecutorBufferPartsMut<'eb> {
+  pub(crate) bb: &'eb mut Vector<u8>,
+  pub(crate) fb: &'eb mut FilledBuffer,
+  pub(crate) rb: &'eb mut Vector<usize>,
+  pub(crate) stmts: &'eb mut Statements,
+  pub(crate) vb: &'eb mut Vector<(Ty, Range<usize>)>,
+}
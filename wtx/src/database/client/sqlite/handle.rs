@@ -0,0 +1,110 @@
+//! Thin wrappers around the raw pointers of the C interface.
+
+use crate::database::client::sqlite::{DbError, SqliteError};
+use alloc::{ffi::CString, string::String};
+use core::{
+  ffi::{c_int, CStr},
+  ptr::NonNull,
+};
+use libsqlite3_sys::{
+  sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, sqlite3_extended_result_codes,
+  sqlite3_finalize, sqlite3_open_v2, sqlite3_stmt, SQLITE_OK, SQLITE_OPEN_CREATE,
+  SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_READWRITE, SQLITE_OPEN_URI,
+};
+
+// Not exported by the pre-generated bindings but always present in the bundled library.
+extern "C" {
+  fn sqlite3_close_v2(db: *mut sqlite3) -> c_int;
+}
+
+/// Owned database connection.
+#[derive(Debug)]
+pub(crate) struct DbHandle(NonNull<sqlite3>);
+
+impl DbHandle {
+  #[inline]
+  pub(crate) fn open(path: &str) -> crate::Result<Self> {
+    let c_path = CString::new(path).map_err(|_err| SqliteError::InteriorNulByte)?;
+    let flags = SQLITE_OPEN_CREATE | SQLITE_OPEN_NOMUTEX | SQLITE_OPEN_READWRITE | SQLITE_OPEN_URI;
+    let mut db = core::ptr::null_mut();
+    // SAFETY: `c_path` is NUL-terminated and `db` is a valid output location
+    let rc = unsafe { sqlite3_open_v2(c_path.as_ptr(), &mut db, flags, core::ptr::null()) };
+    let Some(ptr) = NonNull::new(db) else {
+      return Err(DbError::new(rc, "out of memory".into()).into());
+    };
+    let this = Self(ptr);
+    if rc != SQLITE_OK {
+      return Err(this.error());
+    }
+    // SAFETY: the connection is valid
+    let _ = unsafe { sqlite3_extended_result_codes(this.as_ptr(), 1) };
+    Ok(this)
+  }
+
+  #[inline]
+  pub(crate) fn as_ptr(&self) -> *mut sqlite3 {
+    self.0.as_ptr()
+  }
+
+  /// Converts the last error registered by the connection into a [`crate::Error`].
+  #[inline]
+  pub(crate) fn error(&self) -> crate::Error {
+    // SAFETY: the connection is valid and the returned message is always NUL-terminated
+    let (code, message) = unsafe {
+      let code = sqlite3_extended_errcode(self.as_ptr());
+      let message = CStr::from_ptr(sqlite3_errmsg(self.as_ptr()));
+      (code, String::from_utf8_lossy(message.to_bytes()).into())
+    };
+    DbError::new(code, message).into()
+  }
+}
+
+impl Drop for DbHandle {
+  #[inline]
+  fn drop(&mut self) {
+    // Cached statements may outlive the connection, which is kept alive in a "zombie" state
+    // until all of them are finalized.
+    //
+    // SAFETY: the connection is valid and is never used again
+    let _ = unsafe { sqlite3_close_v2(self.as_ptr()) };
+  }
+}
+
+// SAFETY: connections are opened in multi-thread mode, which allows usage in different threads
+// as long as the same connection isn't used simultaneously.
+unsafe impl Send for DbHandle {}
+
+/// Compiled statement. Ownership is managed by [`crate::database::client::sqlite::Statements`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct StmtHandle(*mut sqlite3_stmt);
+
+impl StmtHandle {
+  #[inline]
+  pub(crate) fn new(ptr: *mut sqlite3_stmt) -> Self {
+    Self(ptr)
+  }
+
+  #[inline]
+  pub(crate) fn as_ptr(self) -> *mut sqlite3_stmt {
+    self.0
+  }
+
+  /// Invoking this method with a null pointer is a harmless no-op.
+  #[inline]
+  pub(crate) fn finalize(self) {
+    // SAFETY: the pointer is either null or a statement that wasn't finalized yet
+    let _ = unsafe { sqlite3_finalize(self.0) };
+  }
+}
+
+impl Default for StmtHandle {
+  #[inline]
+  fn default() -> Self {
+    Self(core::ptr::null_mut())
+  }
+}
+
+// SAFETY: see `DbHandle`
+unsafe impl Send for StmtHandle {}
+// SAFETY: shared references never access the underlying statement
+unsafe impl Sync for StmtHandle {}
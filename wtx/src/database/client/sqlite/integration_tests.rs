@@ -0,0 +1,219 @@
+use crate::{
+  database::{
+    client::sqlite::{Executor, ExecutorBuffer, SqliteError},
+    Executor as _, Record, Records as _,
+  },
+  misc::{simple_seed, Xorshift64},
+};
+use alloc::{string::String, vec::Vec};
+
+#[tokio::test]
+async fn bind_parameters_len() {
+  let mut exec = executor::<crate::Error>();
+  for rslt in [
+    exec.execute_with_stmt("SELECT ?, ?", (1,)).await,
+    exec.execute_with_stmt("SELECT ?", (1, 2)).await,
+  ] {
+    assert!(matches!(
+      rslt,
+      Err(crate::Error::SqliteError(SqliteError::UnexpectedBindParametersLen { .. }))
+    ));
+  }
+  assert_eq!(exec.execute_with_stmt("SELECT ?1, ?1", (1,)).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn db_error() {
+  let mut exec = executor::<crate::Error>();
+  let Err(crate::Error::SqliteDbError(err)) =
+    exec.execute("SELECT * FROM nonexistent_table", |_| {}).await
+  else {
+    panic!();
+  };
+  assert_eq!(err.code(), 1);
+  assert_eq!(err.message(), "no such table: nonexistent_table");
+  exec.execute("CREATE TABLE db_error_test (id INTEGER UNIQUE)", |_| {}).await.unwrap();
+  exec.execute("INSERT INTO db_error_test VALUES (1)", |_| {}).await.unwrap();
+  let Err(crate::Error::SqliteDbError(err)) =
+    exec.execute_with_stmt("INSERT INTO db_error_test VALUES (?)", (1,)).await
+  else {
+    panic!();
+  };
+  assert_eq!(err.code(), 2067);
+  assert_eq!(err.primary_code(), 19);
+  let record = exec.fetch_with_stmt("SELECT 1", ()).await.unwrap();
+  assert_eq!(record.decode::<_, i64>(0).unwrap(), 1);
+}
+
+#[tokio::test]
+async fn execute() {
+  let mut exec = executor::<crate::Error>();
+  assert_eq!(exec.execute_with_stmt("CREATE TABLE execute_test(id INTEGER)", ()).await.unwrap(), 0);
+  assert_eq!(exec.execute_with_stmt("INSERT INTO execute_test VALUES (1)", ()).await.unwrap(), 1);
+  assert_eq!(
+    exec.execute_with_stmt("INSERT INTO execute_test VALUES (?), (?)", (2, 3)).await.unwrap(),
+    2
+  );
+  let mut affected = Vec::new();
+  exec
+    .execute("DELETE FROM execute_test WHERE id = 1; -- comment\nDROP TABLE execute_test;", |el| {
+      affected.push(el)
+    })
+    .await
+    .unwrap();
+  assert_eq!(affected, [1, 0]);
+}
+
+#[tokio::test]
+async fn record() {
+  let mut exec = executor::<crate::Error>();
+  assert!(matches!(
+    exec.fetch_with_stmt("SELECT 1 WHERE 1 = 0", ()).await,
+    Err(crate::Error::SqliteError(SqliteError::NoRecord))
+  ));
+  let record =
+    exec.fetch_with_stmt("SELECT ? AS a, ? AS b, NULL AS c", (1i64, "two")).await.unwrap();
+  assert_eq!(record.len(), 3);
+  assert_eq!(record.decode::<_, i64>("a").unwrap(), 1);
+  assert_eq!(record.decode::<_, &str>("b").unwrap(), "two");
+  assert_eq!(record.decode_opt::<_, &str>("c").unwrap(), None);
+}
+
+#[tokio::test]
+async fn records() {
+  let mut exec = executor::<crate::Error>();
+  exec
+    .execute("CREATE TABLE records_test (id INTEGER, name TEXT, score REAL, data BLOB)", |_| {})
+    .await
+    .unwrap();
+  let _ = exec
+    .execute_with_stmt(
+      "INSERT INTO records_test VALUES (?, ?, ?, ?), (?, ?, ?, ?)",
+      (
+        1u32,
+        "one",
+        1.5f64,
+        &[1u8, 2][..],
+        2u32,
+        String::from("two"),
+        None::<f64>,
+        Vec::<u8>::new(),
+      ),
+    )
+    .await
+    .unwrap();
+  let mut names = Vec::new();
+  let records = exec
+    .fetch_many_with_stmt("SELECT * FROM records_test ORDER BY id", (), |record| {
+      names.push(String::from(record.decode::<_, &str>(1)?));
+      Ok(())
+    })
+    .await
+    .unwrap();
+  assert_eq!(records.len(), 2);
+  let first = records.get(0).unwrap();
+  assert_eq!(first.decode::<_, u32>(0).unwrap(), 1);
+  assert_eq!(first.decode::<_, f64>(2).unwrap(), 1.5);
+  assert_eq!(first.decode::<_, &[u8]>(3).unwrap(), &[1, 2]);
+  let second = records.get(1).unwrap();
+  assert_eq!(second.decode::<_, u32>(0).unwrap(), 2);
+  assert_eq!(second.decode_opt::<_, f64>(2).unwrap(), None);
+  assert!(second.decode::<_, &[u8]>(3).unwrap().is_empty());
+  assert_eq!(names, ["one", "two"]);
+}
+
+#[tokio::test]
+async fn reuses_cached_statement() {
+  let mut exec = executor::<crate::Error>();
+  let cmd = "SELECT ?";
+  for idx in 0..3i64 {
+    let record = exec.fetch_with_stmt(cmd, (idx,)).await.unwrap();
+    assert_eq!(record.decode::<_, i64>(0).unwrap(), idx);
+  }
+  let stmt_id = exec.prepare(cmd).await.unwrap();
+  let record = exec.fetch_with_stmt(stmt_id, (3i64,)).await.unwrap();
+  assert_eq!(record.decode::<_, i64>(0).unwrap(), 3);
+}
+
+#[tokio::test]
+async fn savepoint() {
+  let mut exec = executor::<crate::Error>();
+  exec.execute("CREATE TABLE savepoint_test (id INTEGER)", |_| {}).await.unwrap();
+  exec
+    .transaction(|this| async {
+      let rslt = async {
+        this.execute("INSERT INTO savepoint_test VALUES (1)", |_| {}).await?;
+        let savepoint_rslt = this
+          .savepoint("first", |local_this| async {
+            let _rslt = local_this.execute("INSERT INTO savepoint_test VALUES (2)", |_| {}).await;
+            (Err::<(), _>(crate::Error::SqliteError(SqliteError::NoRecord)), local_this)
+          })
+          .await;
+        assert!(savepoint_rslt.is_err());
+        Ok(())
+      }
+      .await;
+      (rslt, this)
+    })
+    .await
+    .unwrap();
+  let records =
+    exec.fetch_many_with_stmt("SELECT id FROM savepoint_test", (), |_| Ok(())).await.unwrap();
+  let ids: Vec<i32> = records.iter().map(|el| el.decode(0).unwrap()).collect();
+  assert_eq!(ids, [1]);
+}
+
+#[cfg(feature = "schema-manager")]
+#[tokio::test]
+async fn schema_manager() {
+  use crate::{
+    database::{
+      schema_manager::{
+        doc_tests::{migration, migration_group},
+        Commands, DbMigration, SchemaManagement,
+      },
+      Identifier,
+    },
+    misc::Vector,
+  };
+  let mut buffer_cmd = String::new();
+  let mut buffer_db_migrations = Vector::<DbMigration>::new();
+  let mut buffer_idents = Vector::<Identifier>::new();
+  let mut commands = Commands::with_executor(executor());
+  let mg = migration_group();
+  let migrations = [migration()];
+  commands
+    .migrate((&mut buffer_cmd, &mut buffer_db_migrations), &mg, migrations.iter())
+    .await
+    .unwrap();
+  commands.executor.migrations(&mut buffer_cmd, &mg, &mut buffer_db_migrations).await.unwrap();
+  assert_eq!(buffer_db_migrations.len(), 1);
+  assert_eq!(buffer_db_migrations[0].name(), "create_author");
+  commands.executor.table_names(&mut buffer_cmd, &mut buffer_idents, "").await.unwrap();
+  assert_eq!(buffer_idents.len(), 3);
+  buffer_idents.clear();
+  commands.executor.clear((&mut buffer_cmd, &mut buffer_idents)).await.unwrap();
+  commands.executor.table_names(&mut buffer_cmd, &mut buffer_idents, "").await.unwrap();
+  assert_eq!(buffer_idents.len(), 0);
+}
+
+#[tokio::test]
+async fn transaction() {
+  let mut exec = executor::<crate::Error>();
+  exec.execute("CREATE TABLE transaction_test (id INTEGER)", |_| {}).await.unwrap();
+  let rslt = exec
+    .transaction(|this| async {
+      let _rslt = this.execute("INSERT INTO transaction_test VALUES (1)", |_| {}).await;
+      (Err::<(), _>(crate::Error::SqliteError(SqliteError::NoRecord)), this)
+    })
+    .await;
+  assert!(rslt.is_err());
+  let records =
+    exec.fetch_many_with_stmt("SELECT id FROM transaction_test", (), |_| Ok(())).await.unwrap();
+  assert_eq!(records.len(), 0);
+}
+
+fn executor<E>() -> Executor<E, ExecutorBuffer> {
+  let mut rng = Xorshift64::from(simple_seed());
+  Executor::open(":memory:", ExecutorBuffer::new(usize::MAX, &mut rng)).unwrap()
+}
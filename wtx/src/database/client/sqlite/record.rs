@@ -0,0 +1,122 @@
+use crate::{
+  database::{
+    client::sqlite::{statements::statement::Statement, DecodeValue, Sqlite, Ty},
+    Database, ValueIdent,
+  },
+  misc::_unlikely_elem,
+};
+use core::{marker::PhantomData, ops::Range};
+
+/// Record
+#[derive(Debug)]
+pub struct Record<'exec, E> {
+  pub(crate) bytes: &'exec [u8],
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  pub(crate) stmt: Statement<'exec>,
+  pub(crate) values_bytes_offsets: &'exec [(Ty, Range<usize>)],
+}
+
+impl<'exec, E> Record<'exec, E> {
+  #[inline]
+  pub(crate) fn new(
+    bytes: &'exec [u8],
+    stmt: Statement<'exec>,
+    values_bytes_offsets: &'exec [(Ty, Range<usize>)],
+  ) -> Self {
+    Self { bytes, phantom: PhantomData, stmt, values_bytes_offsets }
+  }
+}
+
+impl<'exec, E> crate::database::Record<'exec> for Record<'exec, E>
+where
+  E: From<crate::Error>,
+{
+  type Database = Sqlite<E>;
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.values_bytes_offsets.len()
+  }
+
+  #[inline]
+  fn value<CI>(&self, ci: CI) -> Option<<Self::Database as Database>::DecodeValue<'exec>>
+  where
+    CI: ValueIdent<Self>,
+  {
+    let idx = ci.idx(self)?;
+    let (ty, range) = match self.values_bytes_offsets.get(idx) {
+      None => return _unlikely_elem(None),
+      Some(elem) => elem,
+    };
+    if *ty == Ty::Null {
+      None
+    } else {
+      let bytes = match self.bytes.get(range.clone()) {
+        None => return _unlikely_elem(None),
+        Some(elem) => elem,
+      };
+      Some(DecodeValue::new(bytes, *ty))
+    }
+  }
+}
+
+impl<'exec, E> ValueIdent<Record<'exec, E>> for str {
+  #[inline]
+  fn idx(&self, input: &Record<'exec, E>) -> Option<usize> {
+    input.stmt.columns.iter().position(|column| column.name.as_str() == self)
+  }
+}
+
+impl<E> PartialEq for Record<'_, E> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.bytes == other.bytes
+      && self.phantom == other.phantom
+      && self.stmt == other.stmt
+      && self.values_bytes_offsets == other.values_bytes_offsets
+  }
+}
+
+mod array {
+  use crate::{
+    database::{client::sqlite::Sqlite, FromRecord, Record},
+    misc::{from_utf8_basic, into_rslt, ArrayString},
+  };
+
+  impl<E, const N: usize> FromRecord<Sqlite<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn from_record(
+      record: &crate::database::client::sqlite::record::Record<'_, E>,
+    ) -> Result<Self, E> {
+      Ok(from_utf8_basic(into_rslt(record.value(0))?.bytes()).map_err(From::from)?.try_into()?)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::{
+    client::sqlite::{
+      statements::statement::Statement,
+      tests::{column0, column1},
+      DecodeValue, Record, Ty,
+    },
+    Record as _,
+  };
+
+  #[test]
+  fn returns_correct_values() {
+    let columns = &[column0(), column1()];
+    let bytes = &[1, 0, 0, 0, 0, 0, 0, 0, 2, 3];
+    let stmt = Statement::new(columns, Default::default(), 0);
+    let values_bytes_offsets = &[(Ty::Integer, 0..8), (Ty::Null, 8..8)];
+    let record = Record::<crate::Error>::new(bytes, stmt, values_bytes_offsets);
+    assert_eq!(record.len(), 2);
+    assert_eq!(record.value(0), Some(DecodeValue::new(&[1, 0, 0, 0, 0, 0, 0, 0][..], Ty::Integer)));
+    assert_eq!(record.value(1), None);
+    assert_eq!(record.value("a"), record.value(0));
+  }
+}
@@ -0,0 +1,108 @@
+use crate::database::client::sqlite::{statements::statement::Statement, Record, Sqlite, Ty};
+use core::{marker::PhantomData, ops::Range};
+
+/// Records
+#[derive(Debug)]
+pub struct Records<'exec, E> {
+  pub(crate) bytes: &'exec [u8],
+  pub(crate) phantom: PhantomData<fn() -> E>,
+  /// Each element represents a record and the end offset of its values in
+  /// `values_bytes_offsets`.
+  pub(crate) records_values_offsets: &'exec [usize],
+  pub(crate) stmt: Statement<'exec>,
+  /// Each element represents a value and an offset of `bytes`.
+  pub(crate) values_bytes_offsets: &'exec [(Ty, Range<usize>)],
+}
+
+impl<'exec, E> Records<'exec, E> {
+  #[inline]
+  pub(crate) fn new(
+    bytes: &'exec [u8],
+    records_values_offsets: &'exec [usize],
+    stmt: Statement<'exec>,
+    values_bytes_offsets: &'exec [(Ty, Range<usize>)],
+  ) -> Self {
+    Self { bytes, phantom: PhantomData, records_values_offsets, stmt, values_bytes_offsets }
+  }
+}
+
+impl<'exec, E> crate::database::Records<'exec> for Records<'exec, E>
+where
+  E: From<crate::Error>,
+{
+  type Database = Sqlite<E>;
+
+  #[inline]
+  fn get(&self, record_idx: usize) -> Option<Record<'exec, E>> {
+    let range = match self.records_values_offsets.get(..record_idx.wrapping_add(1))? {
+      [] => return None,
+      &[to] => 0..to,
+      &[.., from, to] => from..to,
+    };
+    Some(Record {
+      bytes: self.bytes,
+      phantom: PhantomData,
+      stmt: self.stmt.clone(),
+      values_bytes_offsets: self.values_bytes_offsets.get(range)?,
+    })
+  }
+
+  #[inline]
+  fn iter(&self) -> impl Iterator<Item = Record<'exec, E>> {
+    (0..self.len()).filter_map(|idx| self.get(idx))
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.records_values_offsets.len()
+  }
+}
+
+impl<E> Default for Records<'_, E> {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      bytes: &[],
+      phantom: PhantomData,
+      records_values_offsets: &[],
+      stmt: Statement::default(),
+      values_bytes_offsets: &[],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::database::{
+    client::sqlite::{
+      statements::statement::Statement,
+      tests::{column0, column1},
+      DecodeValue, Record, Records, Ty,
+    },
+    Record as _, Records as _,
+  };
+
+  #[test]
+  fn returns_correct_values() {
+    let columns = &[column0(), column1()];
+    let bytes = &[1, 2, 3, 4];
+    let stmt = Statement::new(columns, Default::default(), 0);
+    let values_bytes_offsets =
+      &[(Ty::Text, 0..1), (Ty::Blob, 1..2), (Ty::Text, 2..3), (Ty::Null, 3..3)];
+    let records = Records::<crate::Error>::new(bytes, &[2, 4], stmt.clone(), values_bytes_offsets);
+    assert_eq!(records.len(), 2);
+
+    let first_record = records.get(0).unwrap();
+    assert_eq!(
+      first_record,
+      Record::new(bytes, stmt.clone(), &[(Ty::Text, 0..1), (Ty::Blob, 1..2)])
+    );
+    assert_eq!(first_record.value(1).unwrap(), DecodeValue::new(&[2], Ty::Blob));
+
+    let second_record = records.get(1).unwrap();
+    assert_eq!(second_record, Record::new(bytes, stmt, &[(Ty::Text, 2..3), (Ty::Null, 3..3)]));
+    assert_eq!(second_record.value(1), None);
+
+    assert_eq!(records.iter().count(), 2);
+  }
+}
@@ -0,0 +1,36 @@
+/// SQLite error
+#[derive(Debug)]
+pub enum SqliteError {
+  /// Commands or paths can't contain interior NUL bytes.
+  InteriorNulByte,
+  /// Expected one record but got none.
+  NoRecord,
+  /// The number of bound values differs from the number of parameters of the statement.
+  UnexpectedBindParametersLen {
+    /// Number of parameters
+    expected: u32,
+    /// Number of values
+    received: u32,
+  },
+  /// Received size differs from expected size.
+  UnexpectedBufferSize {
+    /// Expected
+    expected: u32,
+    /// Received
+    received: u32,
+  },
+  /// The storage class of a value is not compatible with the requested type.
+  UnexpectedValueTy {
+    /// Expected
+    expected: crate::database::client::sqlite::Ty,
+    /// Received
+    received: crate::database::client::sqlite::Ty,
+  },
+  /// Bytes don't represent expected type
+  UnexpectedValueFromBytes {
+    /// Expected
+    expected: crate::database::client::sqlite::Ty,
+  },
+  /// Received a statement ID that is not present in the local cache.
+  UnknownStatementId,
+}
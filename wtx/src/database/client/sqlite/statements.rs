@@ -0,0 +1,142 @@
+pub(crate) mod column;
+pub(crate) mod statement;
+pub(crate) mod statement_builder;
+pub(crate) mod statements_misc;
+
+use crate::misc::{BlocksDeque, Rng, _random_state};
+use foldhash::fast::FixedState;
+use hashbrown::HashMap;
+
+/// Statements
+///
+/// Prepared statements are owned by this structure and are finalized when evicted, cleared or
+/// dropped.
+#[derive(Debug)]
+pub struct Statements {
+  max_stmts: usize,
+  rs: FixedState,
+  stmts: BlocksDeque<column::Column, statements_misc::StatementsMisc>,
+  stmts_indcs: HashMap<u64, usize>,
+}
+
+impl Statements {
+  #[inline]
+  pub(crate) fn new<RNG>(max_stmts: usize, rng: RNG) -> Self
+  where
+    RNG: Rng,
+  {
+    Self {
+      max_stmts: max_stmts.max(1),
+      rs: _random_state(rng),
+      stmts: BlocksDeque::new(),
+      stmts_indcs: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub(crate) fn with_capacity<RNG>(
+    columns: usize,
+    max_stmts: usize,
+    rng: RNG,
+    stmts: usize,
+  ) -> crate::Result<Self>
+  where
+    RNG: Rng,
+  {
+    Ok(Self {
+      max_stmts: max_stmts.max(1),
+      rs: _random_state(rng),
+      stmts: BlocksDeque::with_capacity(stmts, columns)?,
+      stmts_indcs: HashMap::with_capacity(stmts),
+    })
+  }
+
+  #[inline]
+  pub(crate) fn builder(&mut self) -> statement_builder::StatementBuilder<'_> {
+    if self.stmts.blocks_len() >= self.max_stmts {
+      let to_remove = (self.max_stmts / 2).max(1);
+      for _ in 0..to_remove {
+        if let Some(elem) = self.stmts.pop_front() {
+          elem.handle.finalize();
+        }
+      }
+      self.stmts_indcs.retain(|_, value| {
+        if *value < to_remove {
+          return false;
+        }
+        *value = value.wrapping_sub(to_remove);
+        true
+      })
+    }
+    statement_builder::StatementBuilder::new(&mut self.stmts, &mut self.stmts_indcs)
+  }
+
+  #[inline]
+  pub(crate) fn clear(&mut self) {
+    let Self { max_stmts: _, rs: _, stmts, stmts_indcs } = self;
+    for elem in stmts.iter() {
+      elem.misc.handle.finalize();
+    }
+    stmts.clear();
+    stmts_indcs.clear();
+  }
+
+  #[inline]
+  pub(crate) fn get_by_idx(&self, idx: usize) -> Option<statement::Statement<'_>> {
+    let stmt = self.stmts.get(idx)?;
+    Some(statement::Statement::new(stmt.data, stmt.misc.handle, stmt.misc.params_len))
+  }
+
+  #[inline]
+  pub(crate) fn get_by_stmt_hash(&self, stmt_hash: u64) -> Option<statement::Statement<'_>> {
+    self.get_by_idx(*self.stmts_indcs.get(&stmt_hash)?)
+  }
+
+  #[inline]
+  pub(crate) fn hasher_mut(&mut self) -> &mut FixedState {
+    &mut self.rs
+  }
+}
+
+impl Drop for Statements {
+  #[inline]
+  fn drop(&mut self) {
+    self.clear();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::sqlite::{
+      handle::StmtHandle,
+      statements::statements_misc::StatementsMisc,
+      tests::{column0, column1},
+      Statements,
+    },
+    misc::{simple_seed, Xorshift64},
+  };
+
+  // FIXME(MIRI): See the `two_statements` test of PostgreSQL.
+  #[cfg_attr(miri, ignore)]
+  #[test]
+  fn evicts_old_statements() {
+    let mut stmts = Statements::new(2, &mut Xorshift64::from(simple_seed()));
+
+    let mut builder = stmts.builder();
+    let _ = builder.expand(2).unwrap();
+    builder.inserted_elements()[0] = column0();
+    builder.inserted_elements()[1] = column1();
+    let _ = builder.build(123, StatementsMisc::new(StmtHandle::default(), 0)).unwrap();
+    assert_eq!(stmts.get_by_stmt_hash(123).unwrap().columns, &[column0(), column1()]);
+
+    let _ = stmts.builder().build(456, StatementsMisc::new(StmtHandle::default(), 3)).unwrap();
+    let _ = stmts.builder().build(789, StatementsMisc::new(StmtHandle::default(), 0)).unwrap();
+    assert_eq!(stmts.get_by_stmt_hash(123), None);
+    assert_eq!(stmts.get_by_stmt_hash(456).unwrap().params_len, 3);
+    assert_eq!(stmts.get_by_stmt_hash(789).unwrap().columns, &[]);
+
+    stmts.clear();
+    assert_eq!(stmts.get_by_stmt_hash(456), None);
+  }
+}
@@ -0,0 +1,13 @@
+use crate::database::Identifier;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Column {
+  pub(crate) name: Identifier,
+}
+
+impl Column {
+  #[inline]
+  pub(crate) fn new(name: Identifier) -> Self {
+    Self { name }
+  }
+}
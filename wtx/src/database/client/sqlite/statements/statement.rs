@@ -0,0 +1,21 @@
+use crate::database::client::sqlite::{handle::StmtHandle, statements::column::Column};
+
+/// ```sql
+/// SELECT a,b,c,d FROM table WHERE e = ? AND f = ?
+/// ```
+///
+/// The columns are "a", "b", "c", "d" and the number of parameters is 2. `handle` is the
+/// compiled statement owned by [`crate::database::client::sqlite::Statements`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Statement<'stmts> {
+  pub(crate) columns: &'stmts [Column],
+  pub(crate) handle: StmtHandle,
+  pub(crate) params_len: u16,
+}
+
+impl<'stmts> Statement<'stmts> {
+  #[inline]
+  pub(crate) const fn new(columns: &'stmts [Column], handle: StmtHandle, params_len: u16) -> Self {
+    Self { columns, handle, params_len }
+  }
+}
@@ -0,0 +1,45 @@
+use crate::{
+  database::{
+    client::sqlite::statements::{column::Column, statements_misc::StatementsMisc},
+    Identifier,
+  },
+  misc::{BlocksDeque, BlocksDequeBuilder, BufferMode},
+};
+use hashbrown::HashMap;
+
+#[derive(Debug)]
+pub(crate) struct StatementBuilder<'stmts> {
+  pub(crate) builder: BlocksDequeBuilder<'stmts, Column, StatementsMisc, true>,
+  pub(crate) curr_len: usize,
+  pub(crate) indcs: &'stmts mut HashMap<u64, usize>,
+}
+
+impl<'stmts> StatementBuilder<'stmts> {
+  #[inline]
+  pub(crate) fn new(
+    stmts: &'stmts mut BlocksDeque<Column, StatementsMisc>,
+    stmts_indcs: &'stmts mut HashMap<u64, usize>,
+  ) -> Self {
+    let curr_len = stmts.blocks_len();
+    Self { builder: stmts.builder_back(), curr_len, indcs: stmts_indcs }
+  }
+
+  #[inline]
+  pub(crate) fn build(self, hash: u64, sm: StatementsMisc) -> crate::Result<usize> {
+    let _ = self.indcs.insert(hash, self.curr_len);
+    self.builder.build(sm)?;
+    Ok(self.curr_len)
+  }
+
+  #[inline]
+  pub(crate) fn expand(&mut self, additional: usize) -> crate::Result<&mut Self> {
+    let _ =
+      self.builder.expand(BufferMode::Additional(additional), Column::new(Identifier::new()))?;
+    Ok(self)
+  }
+
+  #[inline]
+  pub(crate) fn inserted_elements(&mut self) -> &mut [Column] {
+    self.builder.inserted_elements()
+  }
+}
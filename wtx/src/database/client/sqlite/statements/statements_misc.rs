@@ -0,0 +1,14 @@
+use crate::database::client::sqlite::handle::StmtHandle;
+
+#[derive(Debug)]
+pub(crate) struct StatementsMisc {
+  pub(crate) handle: StmtHandle,
+  pub(crate) params_len: u16,
+}
+
+impl StatementsMisc {
+  #[inline]
+  pub(crate) fn new(handle: StmtHandle, params_len: u16) -> Self {
+    Self { handle, params_len }
+  }
+}
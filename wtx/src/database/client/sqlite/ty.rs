@@ -0,0 +1,17 @@
+create_enum! {
+  /// Storage class of a value. The numeric representation matches the fundamental datatypes of
+  /// the C interface.
+  #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+  pub enum Ty<u8> {
+    /// 64-bit signed integer
+    Integer = (1),
+    /// 64-bit IEEE floating point number
+    Float = (2),
+    /// UTF-8 string
+    Text = (3),
+    /// Binary data
+    Blob = (4),
+    /// `NULL`
+    Null = (5),
+  }
+}
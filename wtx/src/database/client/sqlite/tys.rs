@@ -0,0 +1,527 @@
+macro_rules! test {
+  ($name:ident, $ty:ty, $instance:expr) => {
+    #[cfg(test)]
+    #[test]
+    fn $name() {
+      let vec = &mut crate::misc::filled_buffer::FilledBuffer::_new();
+      let mut fbw = crate::misc::FilledBufferWriter::new(0, vec);
+      let mut ev = EncodeValue::new(&mut fbw);
+      let instance: $ty = $instance;
+      Encode::<Sqlite<crate::Error>>::encode(&instance, &mut ev).unwrap();
+      let ty = ev.take_ty();
+      let decoded: $ty =
+        Decode::<Sqlite<crate::Error>>::decode(&DecodeValue::new(ev.fbw()._curr_bytes(), ty))
+          .unwrap();
+      assert_eq!(instance, decoded);
+    }
+  };
+}
+
+mod array {
+  use crate::{
+    database::{
+      client::sqlite::{DecodeValue, EncodeValue, Sqlite, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::{from_utf8_basic, ArrayString},
+  };
+
+  impl<E, const N: usize> Decode<'_, Sqlite<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(from_utf8_basic(dv.bytes()).map_err(Into::into)?.try_into()?)
+    }
+  }
+  impl<E, const N: usize> Encode<Sqlite<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(Ty::Text);
+      ev.fbw().extend_from_slice(self.as_str().as_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E, const N: usize> Typed<Sqlite<E>> for ArrayString<N>
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Text;
+  }
+
+  test!(array_string, ArrayString<4>, ArrayString::try_from("123").unwrap());
+}
+
+// SQLite doesn't have dedicated date and time types, as such, values are stored as ISO-8601
+// strings, which is the same format used by the built-in date and time functions.
+#[cfg(feature = "chrono")]
+mod chrono {
+  use crate::{
+    database::{
+      client::sqlite::{DecodeValue, EncodeValue, Sqlite, SqliteError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::{from_utf8_basic, ArrayString},
+  };
+  use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+  use core::fmt::Write;
+
+  const DATE_FMT: &str = "%Y-%m-%d";
+  const DATE_TIME_FMT: &str = "%Y-%m-%d %H:%M:%S%.f";
+  const DATE_TIME_T_FMT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+  impl<E> Decode<'_, Sqlite<E>> for DateTime<Utc>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let naive = <NaiveDateTime as Decode<Sqlite<E>>>::decode(dv)?;
+      Ok(Utc.from_utc_datetime(&naive))
+    }
+  }
+  impl<E, TZ> Encode<Sqlite<E>> for DateTime<TZ>
+  where
+    E: From<crate::Error>,
+    TZ: TimeZone,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Sqlite<E>>::encode(&self.naive_utc(), ev)
+    }
+  }
+  impl<E, TZ> Typed<Sqlite<E>> for DateTime<TZ>
+  where
+    E: From<crate::Error>,
+    TZ: TimeZone,
+  {
+    const TY: Ty = Ty::Text;
+  }
+
+  test!(datetime_utc, DateTime<Utc>, Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap());
+
+  impl<E> Decode<'_, Sqlite<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      NaiveDate::parse_from_str(text(dv)?, DATE_FMT).map_err(|_err| invalid())
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      let mut buffer = ArrayString::<16>::new();
+      buffer.write_fmt(format_args!("{}", self.format(DATE_FMT))).map_err(crate::Error::from)?;
+      ev.set_ty(Ty::Text);
+      ev.fbw().extend_from_slice(buffer.as_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for NaiveDate
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Text;
+  }
+
+  test!(naive_date, NaiveDate, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+
+  impl<E> Decode<'_, Sqlite<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      let str = text(dv)?;
+      NaiveDateTime::parse_from_str(str, DATE_TIME_FMT)
+        .or_else(|_| NaiveDateTime::parse_from_str(str, DATE_TIME_T_FMT))
+        .map_err(|_err| invalid())
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      let mut buffer = ArrayString::<40>::new();
+      buffer
+        .write_fmt(format_args!("{}", self.format(DATE_TIME_FMT)))
+        .map_err(crate::Error::from)?;
+      ev.set_ty(Ty::Text);
+      ev.fbw().extend_from_slice(buffer.as_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for NaiveDateTime
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Text;
+  }
+
+  test!(
+    naive_date_time,
+    NaiveDateTime,
+    NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_micro_opt(3, 4, 5, 6).unwrap()
+  );
+
+  #[inline]
+  fn invalid<E>() -> E
+  where
+    E: From<crate::Error>,
+  {
+    E::from(SqliteError::UnexpectedValueFromBytes { expected: Ty::Text }.into())
+  }
+
+  #[inline]
+  fn text<'any, E>(dv: &DecodeValue<'any>) -> Result<&'any str, E>
+  where
+    E: From<crate::Error>,
+  {
+    if *dv.ty() != Ty::Text {
+      return Err(E::from(
+        SqliteError::UnexpectedValueTy { expected: Ty::Text, received: *dv.ty() }.into(),
+      ));
+    }
+    Ok(from_utf8_basic(dv.bytes()).map_err(crate::Error::from)?)
+  }
+}
+
+mod collections {
+  use crate::{
+    database::{
+      client::sqlite::{DecodeValue, EncodeValue, Sqlite, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::from_utf8_basic,
+  };
+  use alloc::{string::String, vec::Vec};
+
+  // &[u8]
+
+  impl<'exec, E> Decode<'exec, Sqlite<E>> for &'exec [u8]
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'exec>) -> Result<Self, E> {
+      Ok(dv.bytes())
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for &[u8]
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(Ty::Blob);
+      ev.fbw().extend_from_slice(self)?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for &[u8]
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Blob;
+  }
+  test!(bytes, &[u8], &[1, 2, 3, 4]);
+
+  // Vec<u8>
+
+  impl<E> Decode<'_, Sqlite<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(dv.bytes().into())
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Sqlite<E>>::encode(&self.as_slice(), ev)
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for Vec<u8>
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Blob;
+  }
+  test!(vec_bytes, Vec<u8>, alloc::vec![1, 2, 3, 4]);
+
+  // str
+
+  impl<E> Encode<Sqlite<E>> for str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(Ty::Text);
+      ev.fbw().extend_from_slice(self.as_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for str
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Text;
+  }
+
+  // &str
+
+  impl<'exec, E> Decode<'exec, Sqlite<E>> for &'exec str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'exec>) -> Result<Self, E> {
+      Ok(from_utf8_basic(dv.bytes()).map_err(crate::Error::from)?)
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for &str
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Sqlite<E>>::encode(*self, ev)
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for &str
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Text;
+  }
+  test!(str, &str, "1234");
+
+  // String
+
+  impl<E> Decode<'_, Sqlite<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      match from_utf8_basic(dv.bytes()).map_err(crate::Error::from) {
+        Ok(elem) => Ok(elem.into()),
+        Err(err) => Err(err.into()),
+      }
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Sqlite<E>>::encode(self.as_str(), ev)
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for String
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Text;
+  }
+  test!(string, String, String::from("1234"));
+}
+
+mod primitives {
+  use crate::{
+    database::{
+      client::sqlite::{DecodeValue, EncodeValue, Sqlite, SqliteError, Ty},
+      Decode, Encode, Typed,
+    },
+    misc::Usize,
+  };
+
+  // Integers and booleans are always transferred as 64-bit signed integers, as such, `u64`
+  // values greater than `i64::MAX` can't be represented.
+  macro_rules! impl_integer {
+    ($ty:ty) => {
+      impl<E> Decode<'_, Sqlite<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        #[inline]
+        fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+          Ok(<$ty>::try_from(i128::from(decode_i64(dv)?)).map_err(crate::Error::from)?)
+        }
+      }
+
+      impl<E> Encode<Sqlite<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        #[inline]
+        fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+          let n = i64::try_from(i128::from(*self)).map_err(crate::Error::from)?;
+          ev.set_ty(Ty::Integer);
+          ev.fbw().extend_from_slice(&n.to_le_bytes())?;
+          Ok(())
+        }
+      }
+
+      impl<E> Typed<Sqlite<E>> for $ty
+      where
+        E: From<crate::Error>,
+      {
+        const TY: Ty = Ty::Integer;
+      }
+    };
+  }
+
+  impl_integer!(i8);
+  impl_integer!(u8);
+  impl_integer!(i16);
+  impl_integer!(u16);
+  impl_integer!(i32);
+  impl_integer!(u32);
+  impl_integer!(i64);
+  impl_integer!(u64);
+
+  test!(i8, i8, 37);
+  test!(i64, i64, -37);
+  test!(u64, u64, 37);
+
+  // bool
+
+  impl<E> Decode<'_, Sqlite<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      Ok(decode_i64(dv)? != 0)
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Sqlite<E>>::encode(&i64::from(*self), ev)
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for bool
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Integer;
+  }
+
+  test!(bool_true, bool, true);
+  test!(bool_false, bool, false);
+
+  // f32
+
+  impl<E> Decode<'_, Sqlite<E>> for f32
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      #[expect(clippy::cast_possible_truncation, reason = "SQLite only has double precision")]
+      Ok(<f64 as Decode<Sqlite<E>>>::decode(dv)? as f32)
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for f32
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      Encode::<Sqlite<E>>::encode(&f64::from(*self), ev)
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for f32
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Float;
+  }
+
+  test!(f32, f32, 37.5);
+
+  // f64
+
+  impl<E> Decode<'_, Sqlite<E>> for f64
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn decode(dv: &DecodeValue<'_>) -> Result<Self, E> {
+      match dv.ty() {
+        Ty::Float => Ok(f64::from_le_bytes(le_bytes(dv)?)),
+        #[expect(clippy::cast_precision_loss, reason = "same behavior of `CAST(x AS REAL)`")]
+        Ty::Integer => Ok(i64::from_le_bytes(le_bytes(dv)?) as f64),
+        _ => Err(E::from(
+          SqliteError::UnexpectedValueTy { expected: Ty::Float, received: *dv.ty() }.into(),
+        )),
+      }
+    }
+  }
+  impl<E> Encode<Sqlite<E>> for f64
+  where
+    E: From<crate::Error>,
+  {
+    #[inline]
+    fn encode(&self, ev: &mut EncodeValue<'_, '_>) -> Result<(), E> {
+      ev.set_ty(Ty::Float);
+      ev.fbw().extend_from_slice(&self.to_le_bytes())?;
+      Ok(())
+    }
+  }
+  impl<E> Typed<Sqlite<E>> for f64
+  where
+    E: From<crate::Error>,
+  {
+    const TY: Ty = Ty::Float;
+  }
+
+  test!(f64, f64, 37.5);
+
+  #[inline]
+  fn decode_i64(dv: &DecodeValue<'_>) -> crate::Result<i64> {
+    if *dv.ty() != Ty::Integer {
+      return Err(
+        SqliteError::UnexpectedValueTy { expected: Ty::Integer, received: *dv.ty() }.into(),
+      );
+    }
+    Ok(i64::from_le_bytes(le_bytes(dv)?))
+  }
+
+  #[inline]
+  fn le_bytes(dv: &DecodeValue<'_>) -> crate::Result<[u8; 8]> {
+    if let &[a, b, c, d, e, f, g, h] = dv.bytes() {
+      return Ok([a, b, c, d, e, f, g, h]);
+    }
+    Err(
+      SqliteError::UnexpectedBufferSize {
+        expected: 8,
+        received: Usize::from(dv.bytes().len()).into_u64().try_into().unwrap_or(u32::MAX),
+      }
+      .into(),
+    )
+  }
+}
@@ -1,6 +1,9 @@
 /// Database Error
 #[derive(Debug)]
 pub enum DatabaseError {
+  /// Savepoint names must start with an ASCII letter or an underscore and only contain ASCII
+  /// alphanumeric characters or underscores.
+  InvalidSavepointName,
   /// A "null" field received from the database was decoded as a non-nullable type or value.
   MissingFieldDataInDecoding(&'static str),
 }
@@ -1,8 +1,8 @@
 //! Database
 
 use crate::{
-  database::{Database, FromRecord, RecordValues, StmtCmd},
-  misc::ConnectionState,
+  database::{Database, DatabaseError, FromRecord, RecordValues, StmtCmd, TransactionOptions},
+  misc::{ArrayString, ConnectionState},
 };
 use core::future::Future;
 
@@ -100,19 +100,113 @@ pub trait Executor {
     }
   }
 
-  /// Makes internal calls to "BEGIN" and "COMMIT".
+  /// Starts a transaction according to the given options.
+  ///
+  /// The default implementation uses a syntax that is accepted by PostgreSQL and should be
+  /// overridden by other databases.
+  #[inline]
+  fn begin(&mut self, to: TransactionOptions) -> impl Future<Output = crate::Result<()>> {
+    async move {
+      let mut cmd = ArrayString::<64>::new();
+      cmd.push_str("BEGIN")?;
+      let mut separator = " ";
+      if let Some(elem) = to.isolation_level() {
+        cmd.push_str(" ISOLATION LEVEL ")?;
+        cmd.push_str(elem.as_str())?;
+        separator = ", ";
+      }
+      if to.is_read_only() {
+        cmd.push_str(separator)?;
+        cmd.push_str("READ ONLY")?;
+        separator = ", ";
+      }
+      if to.is_deferrable() {
+        cmd.push_str(separator)?;
+        cmd.push_str("DEFERRABLE")?;
+      }
+      self.execute(&cmd, |_| {}).await
+    }
+  }
+
+  /// Executes `fun` inside a savepoint called `name`, which should only be used within an ongoing
+  /// transaction.
+  ///
+  /// Makes internal calls to "SAVEPOINT" and "RELEASE SAVEPOINT". If `fun` returns an error, then
+  /// the changes made after the savepoint are discarded through "ROLLBACK TO SAVEPOINT" and the
+  /// error is returned. The outer transaction remains usable in both cases.
+  ///
+  /// `name` must start with an ASCII letter or an underscore and only contain ASCII alphanumeric
+  /// characters or underscores.
+  #[inline]
+  fn savepoint<'this, F, R>(
+    &'this mut self,
+    name: &str,
+    fun: impl FnOnce(&'this mut Self) -> F,
+  ) -> impl Future<Output = Result<R, <Self::Database as Database>::Error>>
+  where
+    F: Future<Output = (Result<R, <Self::Database as Database>::Error>, &'this mut Self)>,
+  {
+    async move {
+      let is_valid = name.as_bytes().first().is_some_and(|el| !el.is_ascii_digit())
+        && name.bytes().all(|el| el.is_ascii_alphanumeric() || el == b'_');
+      if !is_valid {
+        return Err(crate::Error::from(DatabaseError::InvalidSavepointName).into());
+      }
+      let mut cmd = ArrayString::<96>::new();
+      cmd.push_str("SAVEPOINT ")?;
+      cmd.push_str(name)?;
+      self.execute(&cmd, |_| {}).await?;
+      let (rslt, this) = fun(self).await;
+      cmd.clear();
+      if rslt.is_err() {
+        cmd.push_str("ROLLBACK TO SAVEPOINT ")?;
+        cmd.push_str(name)?;
+        cmd.push_str("; ")?;
+      }
+      cmd.push_str("RELEASE SAVEPOINT ")?;
+      cmd.push_str(name)?;
+      let local_rslt = this.execute(&cmd, |_| {}).await;
+      let elem = rslt?;
+      local_rslt?;
+      Ok(elem)
+    }
+  }
+
+  /// Makes internal calls to "BEGIN" and "COMMIT". If `fun` returns an error, then "ROLLBACK" is
+  /// issued instead and the error is returned.
+  ///
+  /// `fun` must give the executor back along side its result.
+  #[inline]
   fn transaction<'this, F, R>(
     &'this mut self,
     fun: impl FnOnce(&'this mut Self) -> F,
   ) -> impl Future<Output = Result<R, <Self::Database as Database>::Error>>
   where
-    F: Future<Output = Result<(R, &'this mut Self), <Self::Database as Database>::Error>>,
+    F: Future<Output = (Result<R, <Self::Database as Database>::Error>, &'this mut Self)>,
+  {
+    self.transaction_with_options(TransactionOptions::new(), fun)
+  }
+
+  /// Similar to [`Self::transaction`] but starts the transaction with the given options.
+  #[inline]
+  fn transaction_with_options<'this, F, R>(
+    &'this mut self,
+    to: TransactionOptions,
+    fun: impl FnOnce(&'this mut Self) -> F,
+  ) -> impl Future<Output = Result<R, <Self::Database as Database>::Error>>
+  where
+    F: Future<Output = (Result<R, <Self::Database as Database>::Error>, &'this mut Self)>,
   {
     async move {
-      self.execute("BEGIN", |_| {}).await?;
-      let (rslt, this) = fun(self).await?;
-      this.execute("COMMIT", |_| {}).await?;
-      Ok(rslt)
+      self.begin(to).await?;
+      let (rslt, this) = fun(self).await;
+      if rslt.is_ok() {
+        this.execute("COMMIT", |_| {}).await?;
+      } else {
+        // The original error is more relevant than a possible rollback error.
+        let _rslt = this.execute("ROLLBACK", |_| {}).await;
+      }
+      rslt
     }
   }
 }
@@ -0,0 +1,26 @@
+/// Determines how a transaction sees the changes made by other concurrent transactions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IsolationLevel {
+  /// Only sees data committed before each statement began.
+  ReadCommitted,
+  /// Can see data that other transactions haven't committed yet. Some databases treat this level
+  /// as [`IsolationLevel::ReadCommitted`].
+  ReadUncommitted,
+  /// Only sees data committed before the transaction began.
+  RepeatableRead,
+  /// Behaves as if all concurrent transactions were executed one after another.
+  Serializable,
+}
+
+impl IsolationLevel {
+  /// SQL representation
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::ReadCommitted => "READ COMMITTED",
+      Self::ReadUncommitted => "READ UNCOMMITTED",
+      Self::RepeatableRead => "REPEATABLE READ",
+      Self::Serializable => "SERIALIZABLE",
+    }
+  }
+}
@@ -28,15 +28,17 @@ use crate::{
 };
 use alloc::string::String;
 pub use commands::*;
-use core::future::Future;
+use core::{future::Future, time::Duration};
 pub use migration::*;
 pub use repeatability::Repeatability;
-pub use schema_manager_error::SchemaManagerError;
+pub use schema_manager_error::{SchemaManagerError, TomlErrorKind};
 
 /// Default batch size
 pub const DEFAULT_BATCH_SIZE: usize = 128;
 /// Default configuration file name.
 pub const DEFAULT_CFG_FILE_NAME: &str = "wtx.toml";
+/// Default amount of time spent waiting for the lock held by other instances.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
 pub(crate) const _WTX: &str = "wtx";
 pub(crate) const _WTX_SCHEMA_PREFIX: &str = "_wtx.";
 
@@ -83,6 +85,17 @@ pub trait SchemaManagement: Executor {
     I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
     S: Lease<str> + 'migration;
 
+  /// Acquires a database-level lock that prevents concurrent instances from modifying the schema
+  /// at the same time. Returns [`SchemaManagerError::LockTimeout`] if the lock isn't acquired
+  /// within `timeout`.
+  ///
+  /// Databases that don't support such a feature don't do anything.
+  fn lock(
+    &mut self,
+    buffer_cmd: &mut String,
+    timeout: Duration,
+  ) -> impl Future<Output = crate::Result<()>>;
+
   /// Retrieves all migrations of the given `mg` group.
   fn migrations<S>(
     &mut self,
@@ -93,6 +106,17 @@ pub trait SchemaManagement: Executor {
   where
     S: Lease<str>;
 
+  /// Inserts into `results` all the objects of the database schema, excluding the internal
+  /// tables of the schema manager.
+  ///
+  /// Only PostgreSQL is supported, other databases return
+  /// [`SchemaManagerError::SnapshotIsOnlySupportedByPostgres`].
+  fn snapshot(
+    &mut self,
+    buffer: (&mut String, &mut Vector<Identifier>),
+    results: &mut SchemaSnapshot,
+  ) -> impl Future<Output = crate::Result<()>>;
+
   /// Retrieves all tables contained in a schema. If the implementation does not supports schemas,
   /// the parameter is ignored.
   fn table_names(
@@ -101,6 +125,9 @@ pub trait SchemaManagement: Executor {
     results: &mut Vector<Identifier>,
     schema: &str,
   ) -> impl Future<Output = crate::Result<()>>;
+
+  /// Releases the lock acquired by [`Self::lock`].
+  fn unlock(&mut self, buffer_cmd: &mut String) -> impl Future<Output = crate::Result<()>>;
 }
 
 impl SchemaManagement for () {
@@ -142,6 +169,11 @@ impl SchemaManagement for () {
     Ok(())
   }
 
+  #[inline]
+  async fn lock(&mut self, _: &mut String, _: Duration) -> crate::Result<()> {
+    Ok(())
+  }
+
   #[inline]
   async fn migrations<S>(
     &mut self,
@@ -155,6 +187,15 @@ impl SchemaManagement for () {
     Ok(())
   }
 
+  #[inline]
+  async fn snapshot(
+    &mut self,
+    _: (&mut String, &mut Vector<Identifier>),
+    _: &mut SchemaSnapshot,
+  ) -> crate::Result<()> {
+    Ok(())
+  }
+
   #[inline]
   async fn table_names(
     &mut self,
@@ -164,11 +205,126 @@ impl SchemaManagement for () {
   ) -> crate::Result<()> {
     Ok(())
   }
+
+  #[inline]
+  async fn unlock(&mut self, _: &mut String) -> crate::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql {
+  use alloc::string::String;
+  use core::time::Duration;
+
+  use crate::{
+    database::{
+      client::mysql::{Executor, ExecutorBuffer},
+      schema_manager::{
+        fixed_sql_commands::{
+          _delete_migrations, _insert_migrations, _migrations_by_mg_version_query,
+          mysql::{_clear, _lock, _table_names, _unlock, _CREATE_MIGRATION_TABLES},
+        },
+        DbMigration, MigrationGroup, SchemaManagement, SchemaManagerError, SchemaSnapshot,
+        UserMigration,
+      },
+      DatabaseTy, Executor as _, Identifier,
+    },
+    misc::{Lease, LeaseMut, Stream, Vector},
+  };
+
+  impl<EB, STREAM> SchemaManagement for Executor<crate::Error, EB, STREAM>
+  where
+    EB: LeaseMut<ExecutorBuffer>,
+    STREAM: Stream,
+  {
+    #[inline]
+    async fn clear(&mut self, buffer: (&mut String, &mut Vector<Identifier>)) -> crate::Result<()> {
+      _clear(buffer, self).await
+    }
+
+    #[inline]
+    async fn create_wtx_tables(&mut self) -> crate::Result<()> {
+      self.execute(_CREATE_MIGRATION_TABLES, |_| {}).await?;
+      Ok(())
+    }
+
+    #[inline]
+    async fn delete_migrations<S>(
+      &mut self,
+      buffer_cmd: &mut String,
+      mg: &MigrationGroup<S>,
+      version: i32,
+    ) -> crate::Result<()>
+    where
+      S: Lease<str>,
+    {
+      _delete_migrations(buffer_cmd, self, mg, "", version).await
+    }
+
+    #[inline]
+    async fn insert_migrations<'migration, DBS, I, S>(
+      &mut self,
+      buffer_cmd: &mut String,
+      mg: &MigrationGroup<S>,
+      migrations: I,
+    ) -> crate::Result<()>
+    where
+      DBS: Lease<[DatabaseTy]> + 'migration,
+      I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+      S: Lease<str> + 'migration,
+    {
+      _insert_migrations(buffer_cmd, self, mg, migrations, "").await
+    }
+
+    #[inline]
+    async fn lock(&mut self, buffer_cmd: &mut String, timeout: Duration) -> crate::Result<()> {
+      _lock(buffer_cmd, self, timeout).await
+    }
+
+    #[inline]
+    async fn migrations<S>(
+      &mut self,
+      buffer_cmd: &mut String,
+      mg: &MigrationGroup<S>,
+      results: &mut Vector<DbMigration>,
+    ) -> crate::Result<()>
+    where
+      S: Lease<str>,
+    {
+      _migrations_by_mg_version_query(buffer_cmd, self, mg.version(), results, "").await
+    }
+
+    #[inline]
+    async fn snapshot(
+      &mut self,
+      _: (&mut String, &mut Vector<Identifier>),
+      _: &mut SchemaSnapshot,
+    ) -> crate::Result<()> {
+      Err(SchemaManagerError::SnapshotIsOnlySupportedByPostgres.into())
+    }
+
+    #[inline]
+    async fn table_names(
+      &mut self,
+      buffer_cmd: &mut String,
+      results: &mut Vector<Identifier>,
+      schema: &str,
+    ) -> crate::Result<()> {
+      _table_names(buffer_cmd, self, results, schema).await
+    }
+
+    #[inline]
+    async fn unlock(&mut self, _: &mut String) -> crate::Result<()> {
+      _unlock(self).await
+    }
+  }
 }
 
 #[cfg(feature = "postgres")]
 mod postgres {
   use alloc::string::String;
+  use core::time::Duration;
 
   use crate::{
     database::{
@@ -176,9 +332,10 @@ mod postgres {
       schema_manager::{
         fixed_sql_commands::{
           _delete_migrations, _insert_migrations, _migrations_by_mg_version_query,
-          postgres::{_clear, _table_names, _CREATE_MIGRATION_TABLES},
+          postgres::{_clear, _lock, _snapshot, _table_names, _unlock, _CREATE_MIGRATION_TABLES},
         },
-        DbMigration, MigrationGroup, SchemaManagement, UserMigration, _WTX_SCHEMA_PREFIX,
+        DbMigration, MigrationGroup, SchemaManagement, SchemaSnapshot, UserMigration,
+        _WTX_SCHEMA_PREFIX,
       },
       DatabaseTy, Executor as _, Identifier,
     },
@@ -229,6 +386,11 @@ mod postgres {
       _insert_migrations(buffer_cmd, self, mg, migrations, _WTX_SCHEMA_PREFIX).await
     }
 
+    #[inline]
+    async fn lock(&mut self, buffer_cmd: &mut String, timeout: Duration) -> crate::Result<()> {
+      _lock(buffer_cmd, self, timeout).await
+    }
+
     #[inline]
     async fn migrations<S>(
       &mut self,
@@ -243,6 +405,15 @@ mod postgres {
         .await
     }
 
+    #[inline]
+    async fn snapshot(
+      &mut self,
+      buffer: (&mut String, &mut Vector<Identifier>),
+      results: &mut SchemaSnapshot,
+    ) -> crate::Result<()> {
+      _snapshot(buffer, self, results).await
+    }
+
     #[inline]
     async fn table_names(
       &mut self,
@@ -252,5 +423,119 @@ mod postgres {
     ) -> crate::Result<()> {
       _table_names(buffer_cmd, self, results, schema).await
     }
+
+    #[inline]
+    async fn unlock(&mut self, buffer_cmd: &mut String) -> crate::Result<()> {
+      _unlock(buffer_cmd, self).await
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+  use alloc::string::String;
+  use core::time::Duration;
+
+  use crate::{
+    database::{
+      client::sqlite::{Executor, ExecutorBuffer},
+      schema_manager::{
+        fixed_sql_commands::{
+          _delete_migrations, _insert_migrations, _migrations_by_mg_version_query,
+          sqlite::{_clear, _table_names, _CREATE_MIGRATION_TABLES},
+        },
+        DbMigration, MigrationGroup, SchemaManagement, SchemaManagerError, SchemaSnapshot,
+        UserMigration,
+      },
+      DatabaseTy, Executor as _, Identifier,
+    },
+    misc::{Lease, LeaseMut, Vector},
+  };
+
+  impl<EB> SchemaManagement for Executor<crate::Error, EB>
+  where
+    EB: LeaseMut<ExecutorBuffer>,
+  {
+    #[inline]
+    async fn clear(&mut self, buffer: (&mut String, &mut Vector<Identifier>)) -> crate::Result<()> {
+      _clear(buffer, self).await
+    }
+
+    #[inline]
+    async fn create_wtx_tables(&mut self) -> crate::Result<()> {
+      self.execute(_CREATE_MIGRATION_TABLES, |_| {}).await?;
+      Ok(())
+    }
+
+    #[inline]
+    async fn delete_migrations<S>(
+      &mut self,
+      buffer_cmd: &mut String,
+      mg: &MigrationGroup<S>,
+      version: i32,
+    ) -> crate::Result<()>
+    where
+      S: Lease<str>,
+    {
+      _delete_migrations(buffer_cmd, self, mg, "", version).await
+    }
+
+    #[inline]
+    async fn insert_migrations<'migration, DBS, I, S>(
+      &mut self,
+      buffer_cmd: &mut String,
+      mg: &MigrationGroup<S>,
+      migrations: I,
+    ) -> crate::Result<()>
+    where
+      DBS: Lease<[DatabaseTy]> + 'migration,
+      I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+      S: Lease<str> + 'migration,
+    {
+      _insert_migrations(buffer_cmd, self, mg, migrations, "").await
+    }
+
+    // Writers are already serialized by the database file.
+    #[inline]
+    async fn lock(&mut self, _: &mut String, _: Duration) -> crate::Result<()> {
+      Ok(())
+    }
+
+    #[inline]
+    async fn migrations<S>(
+      &mut self,
+      buffer_cmd: &mut String,
+      mg: &MigrationGroup<S>,
+      results: &mut Vector<DbMigration>,
+    ) -> crate::Result<()>
+    where
+      S: Lease<str>,
+    {
+      _migrations_by_mg_version_query(buffer_cmd, self, mg.version(), results, "").await
+    }
+
+    #[inline]
+    async fn snapshot(
+      &mut self,
+      _: (&mut String, &mut Vector<Identifier>),
+      _: &mut SchemaSnapshot,
+    ) -> crate::Result<()> {
+      Err(SchemaManagerError::SnapshotIsOnlySupportedByPostgres.into())
+    }
+
+    #[inline]
+    async fn table_names(
+      &mut self,
+      buffer_cmd: &mut String,
+      results: &mut Vector<Identifier>,
+      schema: &str,
+    ) -> crate::Result<()> {
+      _table_names(buffer_cmd, self, results, schema).await
+    }
+
+    #[inline]
+    async fn unlock(&mut self, _: &mut String) -> crate::Result<()> {
+      Ok(())
+    }
   }
 }
@@ -1,25 +1,35 @@
 #[cfg(feature = "schema-manager-dev")]
 mod clear;
+mod diff;
 mod migrate;
 mod rollback;
 #[cfg(feature = "schema-manager-dev")]
 mod seed;
+mod status;
 mod validate;
 
 use crate::{
   database::{
     executor::Executor,
-    schema_manager::{UserMigration, DEFAULT_BATCH_SIZE},
+    schema_manager::{
+      CodeMigration, DbMigration, MigrationGroup, SchemaManagement, SchemaManagerError,
+      UserMigration, UserMigrationRef, DEFAULT_BATCH_SIZE, DEFAULT_LOCK_TIMEOUT,
+    },
     Database, DatabaseTy,
   },
-  misc::Lease,
+  misc::{Lease, Vector},
 };
+use alloc::string::String;
+use core::time::Duration;
+pub use diff::{SchemaDiff, SchemaDrift, SchemaObject, SchemaObjectKind, SchemaSnapshot};
+pub use status::{MigrationGroupStatus, MigrationStatus};
 
 /// SQL commands facade
 #[derive(Debug)]
 pub struct Commands<E> {
   batch_size: usize,
   pub(crate) executor: E,
+  lock_timeout: Duration,
 }
 
 impl<E> Commands<E>
@@ -29,7 +39,7 @@ where
   /// Creates a new instance from a given Backend and batch size.
   #[inline]
   pub fn new(batch_size: usize, executor: E) -> Self {
-    Self { batch_size, executor }
+    Self { batch_size, executor, lock_timeout: DEFAULT_LOCK_TIMEOUT }
   }
 
   /// Creates a new instance from a given Backend.
@@ -37,7 +47,7 @@ where
   /// Batch size will default to 128.
   #[inline]
   pub fn with_executor(database: E) -> Self {
-    Self { batch_size: DEFAULT_BATCH_SIZE, executor: database }
+    Self { batch_size: DEFAULT_BATCH_SIZE, executor: database, lock_timeout: DEFAULT_LOCK_TIMEOUT }
   }
 
   /// Batch size
@@ -46,6 +56,23 @@ where
     self.batch_size
   }
 
+  /// Maximum amount of time spent waiting for the database-level lock held by other instances
+  /// before migrating, rolling back or clearing.
+  ///
+  /// Defaults to 60 seconds.
+  #[inline]
+  pub fn lock_timeout(&self) -> Duration {
+    self.lock_timeout
+  }
+
+  /// Mutable version of [`Self::lock_timeout`].
+  #[inline]
+  #[must_use]
+  pub fn set_lock_timeout(mut self, value: Duration) -> Self {
+    self.lock_timeout = value;
+    self
+  }
+
   #[inline]
   fn filter_by_db<'migration, DBS, I, S>(
     migrations: I,
@@ -63,4 +90,80 @@ where
       }
     })
   }
+
+  // Merges SQL and code migrations by version. Code migrations are represented by migrations
+  // without SQL commands.
+  #[inline]
+  fn merge_with_code<'any, CS, DBS, I, S>(
+    code_migrations: &'any [CodeMigration<E, CS>],
+    user_migrations: I,
+  ) -> crate::Result<Vector<(UserMigrationRef<'any, 'any>, Option<&'any CodeMigration<E, CS>>)>>
+  where
+    CS: Lease<str>,
+    DBS: Lease<[DatabaseTy]> + 'any,
+    I: Clone + Iterator<Item = &'any UserMigration<DBS, S>>,
+    S: Lease<str> + 'any,
+  {
+    let mut rslt = Vector::new();
+    for elem in Self::filter_by_db(user_migrations) {
+      let migration = UserMigrationRef::from_all_parts(
+        elem.checksum(),
+        elem.dbs(),
+        elem.is_transactional(),
+        elem.name(),
+        elem.repeatability(),
+        elem.sql_down(),
+        elem.sql_up(),
+        elem.version(),
+      );
+      rslt.push((migration, None))?;
+    }
+    for elem in code_migrations {
+      rslt.push((elem.user_migration(), Some(elem)))?;
+    }
+    rslt.sort_by_key(|el| el.0.version());
+    for window in rslt.windows(2) {
+      if let [(a, _), (b, _)] = window {
+        if a.version() == b.version() {
+          return Err(SchemaManagerError::DuplicatedMigrationVersion(a.version()).into());
+        }
+      }
+    }
+    Ok(rslt)
+  }
+}
+
+impl<E> Commands<E>
+where
+  E: SchemaManagement,
+{
+  // Internal tables are only created by commands that modify the database, as such, read-only
+  // commands treat their absence as a database without applied migrations.
+  #[inline]
+  pub(crate) async fn has_wtx_tables(&mut self, buffer_cmd: &mut String) -> crate::Result<bool> {
+    let schema = match <E::Database as Database>::TY {
+      DatabaseTy::Postgres => "_wtx",
+      _ => "",
+    };
+    let mut table_names = Vector::new();
+    self.executor.table_names(buffer_cmd, &mut table_names, schema).await?;
+    Ok(table_names.iter().any(|el| el.as_str() == "_wtx_migration"))
+  }
+
+  // Code migrations are only known by the `*_with_code` commands, as such, their records are
+  // ignored by commands that only deal with SQL migrations.
+  #[inline]
+  pub(crate) async fn sql_migrations<S>(
+    &mut self,
+    buffer_cmd: &mut String,
+    mg: &MigrationGroup<S>,
+    buffer_db_migrations: &mut Vector<DbMigration>,
+  ) -> crate::Result<()>
+  where
+    S: Lease<str>,
+  {
+    self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+    buffer_db_migrations.retain(|el| !el.is_code());
+    Ok(())
+  }
 }
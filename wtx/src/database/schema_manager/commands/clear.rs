@@ -15,8 +15,10 @@ where
   #[inline]
   pub async fn clear(
     &mut self,
-    buffer: (&mut String, &mut Vector<Identifier>),
+    (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
   ) -> crate::Result<()> {
-    self.executor.clear(buffer).await
+    with_lock!(self, buffer_cmd, crate::Result<()>, {
+      self.executor.clear((buffer_cmd, buffer_idents)).await
+    })
   }
 }
@@ -0,0 +1,388 @@
+use crate::{
+  database::{
+    schema_manager::{Commands, SchemaManagement},
+    Identifier,
+  },
+  misc::Vector,
+};
+use alloc::string::String;
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use {
+  crate::database::{schema_manager::DbMigration, Database},
+  std::path::Path,
+};
+
+/// Kind of a database object. The declaration order is also the order of creation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SchemaObjectKind {
+  /// Schema
+  Schema,
+  /// Sequence
+  Sequence,
+  /// Enum, composite or base type
+  Type,
+  /// Domain
+  Domain,
+  /// Table
+  Table,
+  /// Column of a table, named as `table.column`.
+  Column,
+  /// Constraint of a table, named as `table.constraint`.
+  Constraint,
+  /// Index that isn't managed by a constraint
+  Index,
+  /// Function, named alongside its arguments.
+  Function,
+  /// View
+  View,
+  /// Procedure, named alongside its arguments.
+  Procedure,
+}
+
+impl fmt::Display for SchemaObjectKind {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Column => "column",
+      Self::Constraint => "constraint",
+      Self::Domain => "domain",
+      Self::Function => "function",
+      Self::Index => "index",
+      Self::Procedure => "procedure",
+      Self::Schema => "schema",
+      Self::Sequence => "sequence",
+      Self::Table => "table",
+      Self::Type => "type",
+      Self::View => "view",
+    })
+  }
+}
+
+/// Introspected database object.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SchemaObject {
+  kind: SchemaObjectKind,
+  name: String,
+  definition: String,
+}
+
+impl SchemaObject {
+  /// Creates a new instance from its parts.
+  #[inline]
+  pub fn new(kind: SchemaObjectKind, name: String, definition: String) -> Self {
+    Self { kind, name, definition }
+  }
+
+  /// Textual representation provided by the database, if any.
+  #[inline]
+  pub fn definition(&self) -> &str {
+    &self.definition
+  }
+
+  /// Kind
+  #[inline]
+  pub fn kind(&self) -> SchemaObjectKind {
+    self.kind
+  }
+
+  /// Name
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn push_create(&self, buffer: &mut String) -> fmt::Result {
+    let Self { definition, kind, name } = self;
+    let definition = definition.trim().trim_end_matches(';');
+    match kind {
+      SchemaObjectKind::Column => {
+        let (table, column) = name.split_once('.').unwrap_or_default();
+        buffer.write_fmt(format_args!(
+          "ALTER TABLE \"{table}\" ADD COLUMN \"{column}\" {definition};\n"
+        ))
+      }
+      SchemaObjectKind::Constraint => {
+        let (table, constraint) = name.split_once('.').unwrap_or_default();
+        buffer.write_fmt(format_args!(
+          "ALTER TABLE \"{table}\" ADD CONSTRAINT \"{constraint}\" {definition};\n"
+        ))
+      }
+      SchemaObjectKind::Domain => {
+        buffer.write_fmt(format_args!("CREATE DOMAIN \"{name}\" AS {definition};\n"))
+      }
+      SchemaObjectKind::Function | SchemaObjectKind::Index | SchemaObjectKind::Procedure => {
+        buffer.write_fmt(format_args!("{definition};\n"))
+      }
+      SchemaObjectKind::Schema => buffer.write_fmt(format_args!("CREATE SCHEMA \"{name}\";\n")),
+      SchemaObjectKind::Sequence => buffer.write_fmt(format_args!("CREATE SEQUENCE \"{name}\";\n")),
+      SchemaObjectKind::Table => buffer.write_fmt(format_args!("CREATE TABLE \"{name}\" ();\n")),
+      SchemaObjectKind::Type => {
+        buffer.write_fmt(format_args!("CREATE TYPE \"{name}\" {definition};\n"))
+      }
+      SchemaObjectKind::View => {
+        buffer.write_fmt(format_args!("CREATE VIEW \"{name}\" AS {definition};\n"))
+      }
+    }
+  }
+
+  fn push_drop(&self, buffer: &mut String) -> fmt::Result {
+    let Self { kind, name, .. } = self;
+    match kind {
+      SchemaObjectKind::Column => {
+        let (table, column) = name.split_once('.').unwrap_or_default();
+        buffer.write_fmt(format_args!("ALTER TABLE \"{table}\" DROP COLUMN \"{column}\";\n"))
+      }
+      SchemaObjectKind::Constraint => {
+        let (table, constraint) = name.split_once('.').unwrap_or_default();
+        buffer
+          .write_fmt(format_args!("ALTER TABLE \"{table}\" DROP CONSTRAINT \"{constraint}\";\n"))
+      }
+      SchemaObjectKind::Function | SchemaObjectKind::Procedure => {
+        let (ident, args) = name.split_once('(').unwrap_or((name, ")"));
+        buffer.write_fmt(format_args!("DROP {} \"{ident}\"({args};\n", kind_upper(*kind)))
+      }
+      _ => buffer.write_fmt(format_args!("DROP {} \"{name}\";\n", kind_upper(*kind))),
+    }
+  }
+}
+
+/// Difference between an object of the expected schema and an object of the actual schema.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaDrift {
+  /// Object that only exists in the actual schema.
+  Added(SchemaObject),
+  /// Object that exists in both schemas with different definitions. The first element is the
+  /// expected object and the second element is the actual object.
+  Changed(SchemaObject, SchemaObject),
+  /// Object that only exists in the expected schema.
+  Removed(SchemaObject),
+}
+
+/// All the objects of a database schema, sorted by kind and name.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SchemaSnapshot {
+  objects: Vector<SchemaObject>,
+}
+
+impl SchemaSnapshot {
+  /// Empty instance
+  #[inline]
+  pub const fn new() -> Self {
+    Self { objects: Vector::new() }
+  }
+
+  /// Removes all objects.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.objects.clear();
+  }
+
+  /// Compares `self`, the expected schema, with `actual`.
+  #[inline]
+  pub fn diff(&self, actual: &SchemaSnapshot) -> crate::Result<SchemaDiff> {
+    let mut drifts = Vector::new();
+    let mut actual_iter = actual.objects.iter().peekable();
+    let mut expected_iter = self.objects.iter().peekable();
+    loop {
+      let drift = match (expected_iter.peek(), actual_iter.peek()) {
+        (None, None) => break,
+        (None, Some(a)) => {
+          let drift = SchemaDrift::Added((*a).clone());
+          let _ = actual_iter.next();
+          drift
+        }
+        (Some(e), None) => {
+          let drift = SchemaDrift::Removed((*e).clone());
+          let _ = expected_iter.next();
+          drift
+        }
+        (Some(e), Some(a)) => match (e.kind, &e.name).cmp(&(a.kind, &a.name)) {
+          core::cmp::Ordering::Equal => {
+            let drift = (e.definition != a.definition)
+              .then(|| SchemaDrift::Changed((*e).clone(), (*a).clone()));
+            let _ = actual_iter.next();
+            let _ = expected_iter.next();
+            let Some(elem) = drift else {
+              continue;
+            };
+            elem
+          }
+          core::cmp::Ordering::Greater => {
+            let drift = SchemaDrift::Added((*a).clone());
+            let _ = actual_iter.next();
+            drift
+          }
+          core::cmp::Ordering::Less => {
+            let drift = SchemaDrift::Removed((*e).clone());
+            let _ = expected_iter.next();
+            drift
+          }
+        },
+      };
+      drifts.push(drift)?;
+    }
+    Ok(SchemaDiff { drifts })
+  }
+
+  /// Objects sorted by kind and name.
+  #[inline]
+  pub fn objects(&self) -> &[SchemaObject] {
+    &self.objects
+  }
+
+  /// Inserts a new object. Objects are sorted by [`Self::sort`].
+  #[inline]
+  pub fn push(&mut self, object: SchemaObject) -> crate::Result<()> {
+    self.objects.push(object)?;
+    Ok(())
+  }
+
+  /// Sorts the inserted objects by kind and name.
+  #[inline]
+  pub fn sort(&mut self) {
+    self.objects.sort_unstable();
+  }
+}
+
+/// Set of [`SchemaDrift`]s sorted by kind and name.
+///
+/// The [`fmt::Display`] implementation outputs a drift report.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaDiff {
+  drifts: Vector<SchemaDrift>,
+}
+
+impl SchemaDiff {
+  /// Drifts
+  #[inline]
+  pub fn drifts(&self) -> &[SchemaDrift] {
+    &self.drifts
+  }
+
+  /// If the actual schema is different than the expected schema.
+  #[inline]
+  pub fn has_drifted(&self) -> bool {
+    !self.drifts.is_empty()
+  }
+
+  /// Writes into `up` the commands that transform the expected schema into the actual schema and
+  /// into `down` the commands that revert `up`.
+  ///
+  /// The output is only a suggestion. Changed objects are dropped and then recreated, which can
+  /// lead to data loss, as such, they are preceded by a warning comment.
+  #[inline]
+  pub fn suggested_migration(&self, up: &mut String, down: &mut String) -> crate::Result<()> {
+    for drift in self.drifts.iter().rev() {
+      match drift {
+        SchemaDrift::Added(actual) => actual.push_drop(down)?,
+        SchemaDrift::Changed(expected, actual) => {
+          push_review_comment(up, expected)?;
+          expected.push_drop(up)?;
+          push_review_comment(down, actual)?;
+          actual.push_drop(down)?;
+        }
+        SchemaDrift::Removed(expected) => expected.push_drop(up)?,
+      }
+    }
+    for drift in self.drifts.iter() {
+      match drift {
+        SchemaDrift::Added(actual) => actual.push_create(up)?,
+        SchemaDrift::Changed(expected, actual) => {
+          actual.push_create(up)?;
+          expected.push_create(down)?;
+        }
+        SchemaDrift::Removed(expected) => expected.push_create(down)?,
+      }
+    }
+    Ok(())
+  }
+}
+
+impl fmt::Display for SchemaDiff {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for drift in self.drifts.iter() {
+      match drift {
+        SchemaDrift::Added(actual) => {
+          f.write_fmt(format_args!("added   {:<10} {}\n", actual.kind, actual.name))?;
+        }
+        SchemaDrift::Changed(expected, actual) => {
+          f.write_fmt(format_args!("changed {:<10} {}\n", actual.kind, actual.name))?;
+          f.write_fmt(format_args!("  expected: {}\n", expected.definition.trim()))?;
+          f.write_fmt(format_args!("  actual:   {}\n", actual.definition.trim()))?;
+        }
+        SchemaDrift::Removed(expected) => {
+          f.write_fmt(format_args!("removed {:<10} {}\n", expected.kind, expected.name))?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+impl<E> Commands<E>
+where
+  E: SchemaManagement,
+{
+  /// Compares the schema of the database pointed by `expected` with the schema of the current
+  /// database.
+  #[inline]
+  pub async fn diff(
+    &mut self,
+    (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
+    expected: &mut Commands<E>,
+  ) -> crate::Result<SchemaDiff> {
+    let mut expected_snapshot = SchemaSnapshot::new();
+    expected.executor.snapshot((buffer_cmd, buffer_idents), &mut expected_snapshot).await?;
+    let mut actual_snapshot = SchemaSnapshot::new();
+    self.executor.snapshot((buffer_cmd, buffer_idents), &mut actual_snapshot).await?;
+    expected_snapshot.diff(&actual_snapshot)
+  }
+
+  /// Clears the scratch database pointed by `scratch`, applies all migrations of the configuration
+  /// file and then compares the resulting schema with the schema of the current database.
+  ///
+  /// Useful to detect changes that were made without migrations.
+  #[cfg(feature = "std")]
+  #[inline]
+  pub async fn diff_from_toml(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations, buffer_idents): (
+      &mut String,
+      &mut Vector<DbMigration>,
+      &mut Vector<Identifier>,
+    ),
+    path: &Path,
+    scratch: &mut Commands<E>,
+  ) -> Result<SchemaDiff, <E::Database as Database>::Error> {
+    with_lock!(scratch, buffer_cmd, crate::Result<()>, {
+      scratch.executor.clear((buffer_cmd, buffer_idents)).await
+    })?;
+    scratch.migrate_from_toml_path((buffer_cmd, buffer_db_migrations), path).await?;
+    Ok(self.diff((buffer_cmd, buffer_idents), scratch).await?)
+  }
+}
+
+fn kind_upper(kind: SchemaObjectKind) -> &'static str {
+  match kind {
+    SchemaObjectKind::Column => "COLUMN",
+    SchemaObjectKind::Constraint => "CONSTRAINT",
+    SchemaObjectKind::Domain => "DOMAIN",
+    SchemaObjectKind::Function => "FUNCTION",
+    SchemaObjectKind::Index => "INDEX",
+    SchemaObjectKind::Procedure => "PROCEDURE",
+    SchemaObjectKind::Schema => "SCHEMA",
+    SchemaObjectKind::Sequence => "SEQUENCE",
+    SchemaObjectKind::Table => "TABLE",
+    SchemaObjectKind::Type => "TYPE",
+    SchemaObjectKind::View => "VIEW",
+  }
+}
+
+fn push_review_comment(buffer: &mut String, object: &SchemaObject) -> fmt::Result {
+  buffer.write_fmt(format_args!(
+    "-- {} \"{}\" was modified, review the following commands to avoid data loss\n",
+    object.kind, object.name
+  ))
+}
@@ -1,11 +1,16 @@
 use crate::{
   database::{
-    schema_manager::{Commands, DbMigration, MigrationGroup, SchemaManagement, UserMigration},
-    DatabaseTy,
+    schema_manager::{
+      fixed_sql_commands::{_insert_migrations_plan, _push_insert_migrations, _schema_prefix},
+      CodeMigration, Commands, DbMigration, MigrationGroup, SchemaManagement, UserMigration,
+      UserMigrationRef,
+    },
+    Database, DatabaseTy,
   },
   misc::{Lease, Vector},
 };
 use alloc::string::String;
+use core::iter;
 #[cfg(feature = "std")]
 use {
   crate::database::schema_manager::misc::{group_and_migrations_from_path, parse_root_toml},
@@ -28,15 +33,138 @@ where
     mg: &MigrationGroup<S>,
     user_migrations: I,
   ) -> crate::Result<()>
+  where
+    DBS: Lease<[DatabaseTy]> + 'migration,
+    I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+    S: Lease<str> + 'migration,
+  {
+    with_lock!(self, buffer_cmd, crate::Result<()>, {
+      buffer_db_migrations.clear();
+      self.executor.create_wtx_tables().await?;
+      self.sql_migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+      self.do_migrate((buffer_cmd, buffer_db_migrations), None, mg, user_migrations).await
+    })
+  }
+
+  /// Writes into `buffer_plan` the statements that [`Self::migrate`] would execute without
+  /// executing them.
+  ///
+  /// Read-only command that treats missing internal tables as a database without applied
+  /// migrations.
+  #[inline]
+  pub async fn migrate_dry_run<'migration, DBS, I, S>(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    buffer_plan: &mut String,
+    mg: &MigrationGroup<S>,
+    user_migrations: I,
+  ) -> crate::Result<()>
   where
     DBS: Lease<[DatabaseTy]> + 'migration,
     I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
     S: Lease<str> + 'migration,
   {
     buffer_db_migrations.clear();
-    self.executor.create_wtx_tables().await?;
-    self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
-    self.do_migrate((buffer_cmd, buffer_db_migrations), mg, user_migrations).await
+    if self.has_wtx_tables(buffer_cmd).await? {
+      self.sql_migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+    }
+    self
+      .do_migrate((buffer_cmd, buffer_db_migrations), Some(buffer_plan), mg, user_migrations)
+      .await
+  }
+
+  /// Similar to [`Self::migrate`] but also applies migrations expressed as Rust code. Both sets
+  /// are merged by version and recorded in the same history.
+  ///
+  /// Each code migration is executed alongside its record within a dedicated transaction.
+  #[inline]
+  pub async fn migrate_with_code<'any, CS, DBS, I, S>(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    mg: &MigrationGroup<S>,
+    user_migrations: I,
+    code_migrations: &'any [CodeMigration<E, CS>],
+  ) -> Result<(), <E::Database as Database>::Error>
+  where
+    CS: Lease<str>,
+    DBS: Lease<[DatabaseTy]> + 'any,
+    I: Clone + Iterator<Item = &'any UserMigration<DBS, S>>,
+    S: Lease<str> + 'any,
+  {
+    with_lock!(self, buffer_cmd, Result<(), <E::Database as Database>::Error>, {
+      buffer_db_migrations.clear();
+      self.executor.create_wtx_tables().await?;
+      self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+      let merged = Self::merge_with_code(code_migrations, user_migrations)?;
+      Self::do_validate(buffer_db_migrations, merged.iter().map(|el| &el.0))?;
+      let last_db_mig_version = buffer_db_migrations.last().map(DbMigration::version);
+      buffer_db_migrations.clear();
+      let mg_ref = MigrationGroup::new(mg.name(), mg.version());
+      let schema_prefix = _schema_prefix(<E::Database as Database>::TY);
+      let no_migrations = iter::empty::<&UserMigrationRef<'_, '_>>();
+      self.executor.insert_migrations(buffer_cmd, &mg_ref, no_migrations).await?;
+      let idx = merged
+        .iter()
+        .position(|el| last_db_mig_version.is_none_or(|version| el.0.version() > version))
+        .unwrap_or(merged.len());
+      let mut to_apply = merged.get(idx..).unwrap_or_default();
+      while let Some(((migration, code_opt), rest)) = to_apply.split_first() {
+        if let Some(code) = code_opt {
+          _push_insert_migrations(
+            buffer_cmd,
+            true,
+            &mg_ref,
+            iter::once(migration),
+            schema_prefix,
+          )?;
+          self
+            .executor
+            .transaction(|this| async {
+              let rslt = async {
+                code.up(this).await?;
+                this.execute(buffer_cmd.as_str(), |_| {}).await
+              }
+              .await;
+              (rslt.map_err(Into::into), this)
+            })
+            .await?;
+          buffer_cmd.clear();
+          to_apply = rest;
+        } else {
+          let len = to_apply.iter().take_while(|el| el.1.is_none()).count();
+          let (sql, local_rest) = to_apply.split_at_checked(len).unwrap_or_default();
+          self.executor.insert_migrations(buffer_cmd, &mg_ref, sql.iter().map(|el| &el.0)).await?;
+          to_apply = local_rest;
+        }
+      }
+      Ok(())
+    })
+  }
+
+  /// Applies `migrate_dry_run` to a set of migration groups according to the configuration file.
+  #[cfg(feature = "std")]
+  #[inline]
+  pub async fn migrate_dry_run_from_toml_path(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    buffer_plan: &mut String,
+    path: &Path,
+  ) -> crate::Result<()> {
+    let (mut migration_groups, _) = parse_root_toml(path)?;
+    migration_groups.sort_unstable();
+    crate::database::schema_manager::misc::is_sorted_and_unique(migration_groups.as_slice())?;
+    let has_wtx_tables = self.has_wtx_tables(buffer_cmd).await?;
+    for mg in migration_groups.iter() {
+      self
+        .do_migrate_from_dir(
+          (buffer_cmd, buffer_db_migrations),
+          Some(&mut *buffer_plan),
+          has_wtx_tables,
+          mg,
+        )
+        .await?;
+    }
+    Ok(())
   }
 
   /// Applies `migrate` to a set of migrations according to a given directory
@@ -44,11 +172,13 @@ where
   #[inline]
   pub async fn migrate_from_dir(
     &mut self,
-    buffer: (&mut String, &mut Vector<DbMigration>),
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
     path: &Path,
   ) -> crate::Result<()> {
-    self.executor.create_wtx_tables().await?;
-    self.do_migrate_from_dir(buffer, path).await
+    with_lock!(self, buffer_cmd, crate::Result<()>, {
+      self.executor.create_wtx_tables().await?;
+      self.do_migrate_from_dir((buffer_cmd, buffer_db_migrations), None, true, path).await
+    })
   }
 
   /// Applies `migrate` to a set of migration groups according to the configuration file.
@@ -61,8 +191,7 @@ where
   ) -> crate::Result<()> {
     let (mut migration_groups, _) = parse_root_toml(path)?;
     migration_groups.sort_unstable();
-    self.migrate_from_groups_paths(buffer, &migration_groups).await?;
-    Ok(())
+    self.migrate_from_groups_paths(buffer, &migration_groups).await
   }
 
   /// Applies `migrate` to a set of migrations according to a given set of groups
@@ -76,12 +205,14 @@ where
     DBS: Lease<[DatabaseTy]>,
     S: Lease<str>,
   {
-    self.executor.create_wtx_tables().await?;
-    for (mg, m) in groups {
-      self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
-      self.do_migrate((buffer_cmd, buffer_db_migrations), mg, m.iter()).await?;
-    }
-    Ok(())
+    with_lock!(self, buffer_cmd, crate::Result<()>, {
+      self.executor.create_wtx_tables().await?;
+      for (mg, m) in groups {
+        self.sql_migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+        self.do_migrate((buffer_cmd, buffer_db_migrations), None, mg, m.iter()).await?;
+      }
+      Ok(())
+    })
   }
 
   /// Applies `migrate` to the set of provided migration groups paths.
@@ -92,18 +223,22 @@ where
     (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
     migration_groups: &[PathBuf],
   ) -> crate::Result<()> {
-    self.executor.create_wtx_tables().await?;
     crate::database::schema_manager::misc::is_sorted_and_unique(migration_groups)?;
-    for mg in migration_groups {
-      self.do_migrate_from_dir((buffer_cmd, buffer_db_migrations), mg).await?;
-    }
-    Ok(())
+    with_lock!(self, buffer_cmd, crate::Result<()>, {
+      self.executor.create_wtx_tables().await?;
+      for mg in migration_groups {
+        self.do_migrate_from_dir((buffer_cmd, buffer_db_migrations), None, true, mg).await?;
+      }
+      Ok(())
+    })
   }
 
+  // If `buffer_plan` is provided, statements are written instead of executed.
   #[inline]
   async fn do_migrate<'migration, DBS, I, S>(
     &mut self,
     (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    buffer_plan: Option<&mut String>,
     mg: &MigrationGroup<S>,
     user_migrations: I,
   ) -> crate::Result<()>
@@ -114,11 +249,14 @@ where
   {
     let filtered_by_db = Self::filter_by_db(user_migrations);
     Self::do_validate(buffer_db_migrations, filtered_by_db.clone())?;
-    if let Some(last_db_mig_version) = buffer_db_migrations.last().map(DbMigration::version) {
-      let to_apply = filtered_by_db.filter(move |e| e.version() > last_db_mig_version);
-      self.executor.insert_migrations(buffer_cmd, mg, to_apply).await?;
+    let last_db_mig_version = buffer_db_migrations.last().map(DbMigration::version);
+    let to_apply = filtered_by_db
+      .filter(move |el| last_db_mig_version.is_none_or(|version| el.version() > version));
+    if let Some(elem) = buffer_plan {
+      let schema_prefix = _schema_prefix(<E::Database as Database>::TY);
+      _insert_migrations_plan(elem, mg, to_apply, schema_prefix)?;
     } else {
-      self.executor.insert_migrations(buffer_cmd, mg, filtered_by_db).await?;
+      self.executor.insert_migrations(buffer_cmd, mg, to_apply).await?;
     }
     buffer_db_migrations.clear();
     Ok(())
@@ -129,16 +267,27 @@ where
   async fn do_migrate_from_dir(
     &mut self,
     (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    mut buffer_plan: Option<&mut String>,
+    has_wtx_tables: bool,
     path: &Path,
   ) -> crate::Result<()> {
     let (mg, mut migrations) = group_and_migrations_from_path(path, Ord::cmp)?;
-    self.executor.migrations(buffer_cmd, &mg, buffer_db_migrations).await?;
+    if has_wtx_tables {
+      self.sql_migrations(buffer_cmd, &mg, buffer_db_migrations).await?;
+    }
     let mut tmp_migrations = Vector::new();
     loop_files!(
       tmp_migrations,
       migrations,
       self.batch_size(),
-      self.do_migrate((buffer_cmd, buffer_db_migrations), &mg, tmp_migrations.iter()).await?
+      self
+        .do_migrate(
+          (buffer_cmd, buffer_db_migrations),
+          buffer_plan.as_deref_mut(),
+          &mg,
+          tmp_migrations.iter()
+        )
+        .await?
     );
     Ok(())
   }
@@ -1,6 +1,9 @@
 use crate::{
   database::{
-    schema_manager::{Commands, DbMigration, MigrationGroup, SchemaManagement, UserMigration},
+    schema_manager::{
+      fixed_sql_commands::_next_chunk, CodeMigration, Commands, DbMigration, MigrationGroup,
+      SchemaManagement, SchemaManagerError, UserMigration,
+    },
     Database, DatabaseTy,
   },
   misc::{Lease, Vector},
@@ -9,7 +12,6 @@ use alloc::string::String;
 #[cfg(feature = "std")]
 use {
   crate::database::schema_manager::misc::{group_and_migrations_from_path, parse_root_toml},
-  crate::database::schema_manager::SchemaManagerError,
   std::path::Path,
 };
 
@@ -19,7 +21,10 @@ where
 {
   /// Rollbacks the migrations of a group to a given `version`.
   ///
-  /// Before issuing a rollback, all migrations are validated.
+  /// Before issuing a rollback, all migrations are validated. Migrations that aren't
+  /// transactional are reverted standalone.
+  ///
+  /// Code migrations greater than `version` must be reverted by [`Self::rollback_with_code`].
   #[inline]
   pub async fn rollback<'migration, DBS, I, S>(
     &mut self,
@@ -33,23 +38,9 @@ where
     I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
     S: Lease<str> + 'migration,
   {
-    self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
-    let filtered_by_db = Self::filter_by_db(migrations);
-    Self::do_validate(buffer_db_migrations, filtered_by_db.clone())?;
-    for elem in filtered_by_db.map(UserMigration::sql_down) {
-      buffer_cmd.push_str(elem);
-    }
-    self
-      .executor
-      .transaction(|this| async {
-        this.execute(buffer_cmd.as_str(), |_| {}).await?;
-        Ok(((), this))
-      })
-      .await?;
-    buffer_cmd.clear();
-    self.executor.delete_migrations(buffer_cmd, mg, version).await?;
-    buffer_db_migrations.clear();
-    Ok(())
+    with_lock!(self, buffer_cmd, Result<(), <E::Database as Database>::Error>, {
+      self.do_rollback((buffer_cmd, buffer_db_migrations), mg, migrations, version).await
+    })
   }
 
   /// Applies `rollback` to a set of groups according to the configuration file
@@ -66,10 +57,12 @@ where
       return Err(crate::Error::from(SchemaManagerError::DifferentRollbackVersions).into());
     }
     migration_groups.sort_by(|a, b| b.cmp(a));
-    for (mg, &version) in migration_groups.into_iter().zip(versions) {
-      self.do_rollback_from_dir((buffer_cmd, buffer_db_migrations), &mg, version).await?;
-    }
-    Ok(())
+    with_lock!(self, buffer_cmd, Result<(), <E::Database as Database>::Error>, {
+      for (mg, &version) in migration_groups.into_iter().zip(versions) {
+        self.do_rollback_from_dir((buffer_cmd, buffer_db_migrations), &mg, version).await?;
+      }
+      Ok(())
+    })
   }
 
   /// Applies `rollback` to a set of migrations according to a given directory
@@ -77,11 +70,92 @@ where
   #[cfg(feature = "std")]
   pub async fn rollback_from_dir(
     &mut self,
-    buffer: (&mut String, &mut Vector<DbMigration>),
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
     path: &Path,
     version: i32,
   ) -> Result<(), <E::Database as Database>::Error> {
-    self.do_rollback_from_dir(buffer, path, version).await
+    with_lock!(self, buffer_cmd, Result<(), <E::Database as Database>::Error>, {
+      self.do_rollback_from_dir((buffer_cmd, buffer_db_migrations), path, version).await
+    })
+  }
+
+  /// Similar to [`Self::rollback`] but also reverts migrations expressed as Rust code. Only
+  /// migrations greater than `version` are reverted, in descending order.
+  ///
+  /// Each code migration is reverted within a dedicated transaction.
+  #[inline]
+  pub async fn rollback_with_code<'any, CS, DBS, I, S>(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    mg: &MigrationGroup<S>,
+    user_migrations: I,
+    code_migrations: &'any [CodeMigration<E, CS>],
+    version: i32,
+  ) -> Result<(), <E::Database as Database>::Error>
+  where
+    CS: Lease<str>,
+    DBS: Lease<[DatabaseTy]> + 'any,
+    I: Clone + Iterator<Item = &'any UserMigration<DBS, S>>,
+    S: Lease<str> + 'any,
+  {
+    with_lock!(self, buffer_cmd, Result<(), <E::Database as Database>::Error>, {
+      self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+      let merged = Self::merge_with_code(code_migrations, user_migrations)?;
+      Self::do_validate(buffer_db_migrations, merged.iter().map(|el| &el.0))?;
+      buffer_db_migrations.clear();
+      let idx = merged.iter().position(|el| el.0.version() > version).unwrap_or(merged.len());
+      let mut to_revert = merged.get(idx..).unwrap_or_default();
+      while let Some(((_, code_opt), rest)) = to_revert.split_last() {
+        if let Some(code) = code_opt {
+          self
+            .executor
+            .transaction(|this| async {
+              let rslt = code.down(this).await;
+              (rslt.map_err(Into::into), this)
+            })
+            .await?;
+          to_revert = rest;
+        } else {
+          let len = to_revert.iter().rev().take_while(|el| el.1.is_none()).count();
+          let at = to_revert.len().wrapping_sub(len);
+          let (local_rest, sql) = to_revert.split_at_checked(at).unwrap_or_default();
+          self.revert_sql(buffer_cmd, sql.iter().rev().map(|el| &el.0)).await?;
+          to_revert = local_rest;
+        }
+      }
+      self.executor.delete_migrations(buffer_cmd, mg, version).await?;
+      Ok(())
+    })
+  }
+
+  #[inline]
+  async fn do_rollback<'migration, DBS, I, S>(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    mg: &MigrationGroup<S>,
+    migrations: I,
+    version: i32,
+  ) -> Result<(), <E::Database as Database>::Error>
+  where
+    DBS: Lease<[DatabaseTy]> + 'migration,
+    I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+    S: Lease<str> + 'migration,
+  {
+    self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+    if let Some(elem) =
+      buffer_db_migrations.iter().find(|el| el.is_code() && el.version() > version)
+    {
+      let err = SchemaManagerError::UnrevertibleCodeMigration(elem.version());
+      buffer_db_migrations.clear();
+      return Err(crate::Error::from(err).into());
+    }
+    buffer_db_migrations.retain(|el| !el.is_code());
+    let filtered_by_db = Self::filter_by_db(migrations);
+    Self::do_validate(buffer_db_migrations, filtered_by_db.clone())?;
+    self.revert_sql(buffer_cmd, filtered_by_db.filter(|el| el.version() > version)).await?;
+    self.executor.delete_migrations(buffer_cmd, mg, version).await?;
+    buffer_db_migrations.clear();
+    Ok(())
   }
 
   #[inline]
@@ -100,9 +174,41 @@ where
       migrations,
       self.batch_size(),
       self
-        .rollback((buffer_cmd, buffer_db_migrations), &mg, tmp_migrations.iter(), version)
+        .do_rollback((buffer_cmd, buffer_db_migrations), &mg, tmp_migrations.iter(), version)
         .await?
     );
     Ok(())
   }
+
+  // Consecutive transactional migrations are reverted within the same transaction.
+  #[inline]
+  async fn revert_sql<'migration, DBS, I, S>(
+    &mut self,
+    buffer_cmd: &mut String,
+    mut migrations: I,
+  ) -> Result<(), <E::Database as Database>::Error>
+  where
+    DBS: Lease<[DatabaseTy]> + 'migration,
+    I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+    S: Lease<str> + 'migration,
+  {
+    while let Some((is_transactional, chunk)) = _next_chunk(&mut migrations) {
+      for migration in chunk {
+        buffer_cmd.push_str(migration.sql_down());
+      }
+      if is_transactional {
+        self
+          .executor
+          .transaction(|this| async {
+            let rslt = this.execute(buffer_cmd.as_str(), |_| {}).await.map_err(Into::into);
+            (rslt, this)
+          })
+          .await?;
+      } else {
+        self.executor.execute(buffer_cmd.as_str(), |_| {}).await?;
+      }
+      buffer_cmd.clear();
+    }
+    Ok(())
+  }
 }
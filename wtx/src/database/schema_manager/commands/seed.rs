@@ -29,8 +29,8 @@ where
     self
       .executor
       .transaction(|this| async {
-        this.execute(buffer_cmd.as_str(), |_| {}).await?;
-        Ok(((), this))
+        let rslt = this.execute(buffer_cmd.as_str(), |_| {}).await.map_err(Into::into);
+        (rslt, this)
       })
       .await?;
     buffer_cmd.clear();
@@ -0,0 +1,184 @@
+use crate::{
+  database::{
+    schema_manager::{
+      misc::is_migration_divergent, Commands, DbMigration, MigrationGroup, Repeatability,
+      SchemaManagement, UserMigration,
+    },
+    DatabaseTy,
+  },
+  misc::{Lease, Vector},
+};
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use {
+  crate::database::schema_manager::misc::{group_and_migrations_from_path, parse_root_toml},
+  std::path::Path,
+};
+
+/// State of a migration in relation to the database.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MigrationStatus {
+  /// Local migration that is equal to the one inserted in the database.
+  Applied,
+  /// Local migration that has a different name or checksum than the one inserted in the
+  /// database.
+  Divergent,
+  /// Migration inserted in the database that doesn't exist locally.
+  Missing,
+  /// Local migration that wasn't inserted in the database.
+  Pending,
+}
+
+impl fmt::Display for MigrationStatus {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Applied => "applied",
+      Self::Divergent => "divergent",
+      Self::Missing => "missing",
+      Self::Pending => "pending",
+    })
+  }
+}
+
+/// Status of all the migrations of a group, local or remote, sorted by version.
+#[derive(Debug, Eq, PartialEq)]
+pub struct MigrationGroupStatus {
+  mg: MigrationGroup<String>,
+  migrations: Vector<(i32, String, MigrationStatus)>,
+}
+
+impl MigrationGroupStatus {
+  /// Group
+  #[inline]
+  pub fn group(&self) -> &MigrationGroup<String> {
+    &self.mg
+  }
+
+  /// If there are migrations that can't be applied without manual intervention.
+  #[inline]
+  pub fn has_drifted(&self) -> bool {
+    self
+      .migrations
+      .iter()
+      .any(|el| matches!(el.2, MigrationStatus::Divergent | MigrationStatus::Missing))
+  }
+
+  /// Version, name and status of each migration.
+  #[inline]
+  pub fn migrations(&self) -> &[(i32, String, MigrationStatus)] {
+    &self.migrations
+  }
+}
+
+impl<E> Commands<E>
+where
+  E: SchemaManagement,
+{
+  /// Compares the migrations of a group with the migrations inserted in the database.
+  ///
+  /// Read-only command that treats missing internal tables as a database without applied
+  /// migrations. Records of code migrations are ignored.
+  #[inline]
+  pub async fn status<'migration, DBS, I, S>(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    mg: &MigrationGroup<S>,
+    user_migrations: I,
+  ) -> crate::Result<MigrationGroupStatus>
+  where
+    DBS: Lease<[DatabaseTy]> + 'migration,
+    I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+    S: Lease<str> + 'migration,
+  {
+    buffer_db_migrations.clear();
+    if self.has_wtx_tables(buffer_cmd).await? {
+      self.sql_migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+    }
+    let mut mgs = MigrationGroupStatus {
+      mg: MigrationGroup::new(mg.name().into(), mg.version()),
+      migrations: Vector::new(),
+    };
+    Self::do_status(buffer_db_migrations, &mut mgs, user_migrations)?;
+    Self::push_missing(buffer_db_migrations, &mut mgs)?;
+    buffer_db_migrations.clear();
+    Ok(mgs)
+  }
+
+  /// Applies `status` to a set of groups according to the configuration file
+  #[cfg(feature = "std")]
+  #[inline]
+  pub async fn status_from_toml(
+    &mut self,
+    (buffer_cmd, buffer_db_migrations): (&mut String, &mut Vector<DbMigration>),
+    path: &Path,
+    results: &mut Vector<MigrationGroupStatus>,
+  ) -> crate::Result<()> {
+    let (mut migration_groups, _) = parse_root_toml(path)?;
+    migration_groups.sort_unstable();
+    let has_wtx_tables = self.has_wtx_tables(buffer_cmd).await?;
+    for mg_path in migration_groups {
+      let (mg, mut migrations) = group_and_migrations_from_path(&mg_path, Ord::cmp)?;
+      if has_wtx_tables {
+        self.sql_migrations(buffer_cmd, &mg, buffer_db_migrations).await?;
+      }
+      let mut mgs = MigrationGroupStatus { mg, migrations: Vector::new() };
+      let mut tmp_migrations = Vector::new();
+      loop_files!(
+        tmp_migrations,
+        migrations,
+        self.batch_size(),
+        Self::do_status(buffer_db_migrations, &mut mgs, tmp_migrations.iter())?
+      );
+      Self::push_missing(buffer_db_migrations, &mut mgs)?;
+      buffer_db_migrations.clear();
+      results.push(mgs)?;
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn do_status<'migration, DBS, I, S>(
+    db_migrations: &[DbMigration],
+    mgs: &mut MigrationGroupStatus,
+    user_migrations: I,
+  ) -> crate::Result<()>
+  where
+    DBS: Lease<[DatabaseTy]> + 'migration,
+    I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+    S: Lease<str> + 'migration,
+  {
+    for migration in Self::filter_by_db(user_migrations) {
+      let version = migration.version();
+      let status = if !db_migrations.iter().any(|el| el.version() == version) {
+        MigrationStatus::Pending
+      } else if migration.repeatability() != Some(Repeatability::Always)
+        && is_migration_divergent(db_migrations, migration)
+      {
+        MigrationStatus::Divergent
+      } else {
+        MigrationStatus::Applied
+      };
+      mgs.migrations.push((version, migration.name().into(), status))?;
+    }
+    Ok(())
+  }
+
+  // Migrations that only exist in the database are appended and everything is then sorted by
+  // version.
+  #[inline]
+  fn push_missing(
+    db_migrations: &[DbMigration],
+    mgs: &mut MigrationGroupStatus,
+  ) -> crate::Result<()> {
+    for db_migration in db_migrations {
+      let version = db_migration.version();
+      if !mgs.migrations.iter().any(|el| el.0 == version) {
+        mgs.migrations.push((version, db_migration.name().into(), MigrationStatus::Missing))?;
+      }
+    }
+    mgs.migrations.sort_by_key(|el| el.0);
+    Ok(())
+  }
+}
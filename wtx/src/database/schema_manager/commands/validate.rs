@@ -21,6 +21,8 @@ where
 {
   /// Verifies if the provided migrations are a superset of the migrations within the database
   /// by verification their checksums.
+  ///
+  /// Records of code migrations are ignored.
   #[inline]
   pub async fn validate<'migration, DBS, I, S>(
     &mut self,
@@ -33,7 +35,7 @@ where
     I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
     S: Lease<str> + 'migration,
   {
-    self.executor.migrations(buffer_cmd, mg, buffer_db_migrations).await?;
+    self.sql_migrations(buffer_cmd, mg, buffer_db_migrations).await?;
     Self::do_validate(buffer_db_migrations, Self::filter_by_db(migrations))?;
     buffer_db_migrations.clear();
     Ok(())
@@ -109,7 +111,7 @@ where
   ) -> crate::Result<()> {
     let opt = group_and_migrations_from_path(path, Ord::cmp);
     let Ok((mg, mut migrations)) = opt else { return Ok(()) };
-    self.executor.migrations(buffer_cmd, &mg, buffer_db_migrations).await?;
+    self.sql_migrations(buffer_cmd, &mg, buffer_db_migrations).await?;
     let mut tmp_migrations = Vector::new();
     loop_files!(
       tmp_migrations,
@@ -8,6 +8,7 @@ use crate::database::schema_manager::{MigrationGroup, UserMigrationRef};
 /// ```rust
 /// let _ = wtx::database::schema_manager::UserMigrationRef::from_user_parts(
 ///   &[],
+///   true,
 ///   "create_author",
 ///   None,
 ///   [
@@ -21,6 +22,7 @@ use crate::database::schema_manager::{MigrationGroup, UserMigrationRef};
 pub fn migration() -> UserMigrationRef<'static, 'static> {
   UserMigrationRef::from_user_parts(
     &[],
+    true,
     "create_author",
     None,
     [
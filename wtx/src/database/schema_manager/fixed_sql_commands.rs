@@ -25,19 +25,32 @@ macro_rules! _serial_id {
   };
 }
 
+// Value of the `repeatability` column that identifies code migrations, which are ignored by
+// commands that only deal with SQL migrations.
+pub(crate) const _CODE_REPEATABILITY: u32 = 255;
+
+// Arbitrary identifier shared by all instances that manage the same database. Represents `wtx_lock`
+// in ASCII.
+#[cfg(feature = "postgres")]
+pub(crate) const _LOCK_ID: i64 = 0x7774_785f_6c6f_636b;
+
+#[cfg(feature = "mysql")]
+pub(crate) mod mysql;
 #[cfg(feature = "postgres")]
 pub(crate) mod postgres;
+#[cfg(feature = "sqlite")]
+pub(crate) mod sqlite;
 
 use crate::{
   database::{
     executor::Executor,
-    schema_manager::{DbMigration, MigrationGroup, UserMigration},
+    schema_manager::{DbMigration, MigrationGroup, UserMigration, _WTX_SCHEMA_PREFIX},
     Database, DatabaseTy, FromRecord,
   },
   misc::{Lease, Vector},
 };
 use alloc::string::String;
-use core::fmt::Write;
+use core::{fmt::Write, iter::Take};
 
 #[inline]
 pub(crate) async fn _delete_migrations<E, S>(
@@ -74,58 +87,86 @@ where
   I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
   S: Lease<str> + 'migration,
 {
-  buffer_cmd
-    .write_fmt(format_args!(
-      "INSERT INTO {schema_prefix}_wtx_migration_group (version, name)
-    SELECT * FROM (SELECT {mg_version} AS version, '{mg_name}' AS name) AS tmp
-    WHERE NOT EXISTS (
-      SELECT 1 FROM {schema_prefix}_wtx_migration_group WHERE version = {mg_version}
-    );",
-      mg_name = mg.name(),
-      mg_version = mg.version(),
-    ))
-    .map_err(Into::into)?;
+  _push_insert_migration_group(buffer_cmd, mg, schema_prefix)?;
   executor.execute(buffer_cmd.as_str(), |_| {}).await?;
   buffer_cmd.clear();
 
-  for migration in migrations.clone() {
-    buffer_cmd.push_str(migration.sql_up());
-  }
-  executor
-    .transaction(|this| async {
-      this.execute(buffer_cmd.as_str(), |_| {}).await?;
-      Ok(((), this))
-    })
-    .await?;
-  buffer_cmd.clear();
+  let mut remaining = migrations;
+  while let Some((is_transactional, chunk)) = _next_chunk(&mut remaining) {
+    for migration in chunk.clone() {
+      buffer_cmd.push_str(migration.sql_up());
+    }
+    if is_transactional {
+      executor
+        .transaction(|this| async {
+          let rslt = this.execute(buffer_cmd.as_str(), |_| {}).await.map_err(Into::into);
+          (rslt, this)
+        })
+        .await?;
+    } else {
+      executor.execute(buffer_cmd.as_str(), |_| {}).await?;
+    }
+    buffer_cmd.clear();
 
-  for migration in migrations {
-    buffer_cmd
-      .write_fmt(format_args!(
-        "INSERT INTO {schema_prefix}_wtx_migration (
-        version, _wtx_migration_omg_version, checksum, name
-      ) VALUES (
-        {m_version}, {mg_version}, '{m_checksum}', '{m_name}'
-      );",
-        m_checksum = migration.checksum(),
-        m_name = migration.name(),
-        m_version = migration.version(),
-        mg_version = mg.version(),
-        schema_prefix = schema_prefix,
-      ))
-      .map_err(Into::into)?;
+    _push_insert_migrations(buffer_cmd, false, mg, chunk, schema_prefix)?;
+    executor
+      .transaction(|this| async {
+        let rslt = this.execute(buffer_cmd.as_str(), |_| {}).await.map_err(Into::into);
+        (rslt, this)
+      })
+      .await?;
+    buffer_cmd.clear();
   }
-  executor
-    .transaction(|this| async {
-      this.execute(buffer_cmd.as_str(), |_| {}).await?;
-      Ok(((), this))
-    })
-    .await?;
-  buffer_cmd.clear();
 
   Ok(())
 }
 
+/// Writes the statements that [`_insert_migrations`] would execute, in the same order.
+#[inline]
+pub(crate) fn _insert_migrations_plan<'migration, DBS, I, S>(
+  buffer_plan: &mut String,
+  mg: &MigrationGroup<S>,
+  migrations: I,
+  schema_prefix: &str,
+) -> crate::Result<()>
+where
+  DBS: Lease<[DatabaseTy]> + 'migration,
+  I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+  S: Lease<str> + 'migration,
+{
+  buffer_plan.write_fmt(format_args!("-- {} ({})\n", mg.name(), mg.version()))?;
+  _push_insert_migration_group(buffer_plan, mg, schema_prefix)?;
+  buffer_plan.push('\n');
+  let mut remaining = migrations;
+  while let Some((is_transactional, chunk)) = _next_chunk(&mut remaining) {
+    if is_transactional {
+      buffer_plan.push_str("BEGIN;\n");
+    }
+    for migration in chunk.clone() {
+      buffer_plan.push_str(migration.sql_up());
+      if !migration.sql_up().ends_with('\n') {
+        buffer_plan.push('\n');
+      }
+    }
+    if is_transactional {
+      buffer_plan.push_str("COMMIT;\n");
+    }
+    buffer_plan.push_str("BEGIN;\n");
+    _push_insert_migrations(buffer_plan, false, mg, chunk, schema_prefix)?;
+    buffer_plan.push_str("\nCOMMIT;\n");
+  }
+  Ok(())
+}
+
+/// Prefix used by the internal tables of a database.
+#[inline]
+pub(crate) fn _schema_prefix(ty: DatabaseTy) -> &'static str {
+  match ty {
+    DatabaseTy::Postgres => _WTX_SCHEMA_PREFIX,
+    _ => "",
+  }
+}
+
 #[inline]
 pub(crate) async fn _migrations_by_mg_version_query<E, D>(
   buffer_cmd: &mut String,
@@ -166,3 +207,77 @@ where
   buffer_cmd.clear();
   Ok(())
 }
+
+// Returns consecutive transactional migrations or a single non-transactional migration while
+// advancing `migrations` accordingly.
+#[inline]
+pub(crate) fn _next_chunk<'migration, DBS, I, S>(migrations: &mut I) -> Option<(bool, Take<I>)>
+where
+  DBS: Lease<[DatabaseTy]> + 'migration,
+  I: Clone + Iterator<Item = &'migration UserMigration<DBS, S>>,
+  S: Lease<str> + 'migration,
+{
+  let is_transactional = migrations.clone().next()?.is_transactional();
+  let len = if is_transactional {
+    migrations.clone().take_while(|el| el.is_transactional()).count()
+  } else {
+    1
+  };
+  let chunk = migrations.clone().take(len);
+  let _elem = migrations.nth(len.wrapping_sub(1));
+  Some((is_transactional, chunk))
+}
+
+#[inline]
+fn _push_insert_migration_group<S>(
+  buffer: &mut String,
+  mg: &MigrationGroup<S>,
+  schema_prefix: &str,
+) -> crate::Result<()>
+where
+  S: Lease<str>,
+{
+  buffer.write_fmt(format_args!(
+    "INSERT INTO {schema_prefix}_wtx_migration_group (version, name)
+    SELECT * FROM (SELECT {mg_version} AS version, '{mg_name}' AS name) AS tmp
+    WHERE NOT EXISTS (
+      SELECT 1 FROM {schema_prefix}_wtx_migration_group WHERE version = {mg_version}
+    );",
+    mg_name = mg.name(),
+    mg_version = mg.version(),
+  ))?;
+  Ok(())
+}
+
+#[inline]
+pub(crate) fn _push_insert_migrations<'migration, DBS, I, S>(
+  buffer: &mut String,
+  is_code: bool,
+  mg: &MigrationGroup<S>,
+  migrations: I,
+  schema_prefix: &str,
+) -> crate::Result<()>
+where
+  DBS: Lease<[DatabaseTy]> + 'migration,
+  I: Iterator<Item = &'migration UserMigration<DBS, S>>,
+  S: Lease<str> + 'migration,
+{
+  for migration in migrations {
+    buffer.write_fmt(format_args!(
+      "INSERT INTO {schema_prefix}_wtx_migration (
+        version, _wtx_migration_omg_version, checksum, name, repeatability
+      ) VALUES (
+        {m_version}, {mg_version}, '{m_checksum}', '{m_name}', ",
+      m_checksum = migration.checksum(),
+      m_name = migration.name(),
+      m_version = migration.version(),
+      mg_version = mg.version(),
+    ))?;
+    if is_code {
+      buffer.write_fmt(format_args!("{_CODE_REPEATABILITY}\n      );"))?;
+    } else {
+      buffer.push_str("NULL\n      );");
+    }
+  }
+  Ok(())
+}
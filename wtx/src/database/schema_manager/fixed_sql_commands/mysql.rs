@@ -0,0 +1,150 @@
+use crate::{
+  database::{
+    client::mysql::Mysql, executor::Executor, schema_manager::SchemaManagerError, Identifier,
+    Record as _,
+  },
+  misc::Vector,
+};
+use alloc::string::String;
+use core::{fmt::Write, time::Duration};
+
+pub(crate) static _CREATE_MIGRATION_TABLES: &str = concat!(
+  "CREATE TABLE IF NOT EXISTS _wtx_migration_group (",
+  _wtx_migration_group_columns!(),
+  ");
+  CREATE TABLE IF NOT EXISTS _wtx_migration (",
+  _serial_id!(),
+  "created_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,",
+  _wtx_migration_columns!(),
+  ");"
+);
+
+// MySQL doesn't support transactional DDL, as such, the commands are executed as a single batch
+// with foreign key checks temporarily disabled.
+#[inline]
+pub(crate) async fn _clear<E>(
+  (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
+  executor: &mut E,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Mysql<crate::Error>>,
+{
+  buffer_cmd.push_str("SET FOREIGN_KEY_CHECKS = 0;");
+
+  _views(executor, buffer_idents).await?;
+  _push_drop((buffer_cmd, buffer_idents), "VIEW")?;
+
+  _table_names(buffer_cmd, executor, buffer_idents, "").await?;
+  _push_drop((buffer_cmd, buffer_idents), "TABLE")?;
+
+  buffer_cmd.push_str("SET FOREIGN_KEY_CHECKS = 1;");
+  executor.execute(buffer_cmd.as_str(), |_| {}).await?;
+  buffer_cmd.clear();
+
+  Ok(())
+}
+
+// `GET_LOCK` only accepts seconds, as such, fractions are rounded up.
+#[inline]
+pub(crate) async fn _lock<E>(
+  buffer_cmd: &mut String,
+  executor: &mut E,
+  timeout: Duration,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Mysql<crate::Error>>,
+{
+  let before = buffer_cmd.len();
+  let secs = timeout.as_secs().saturating_add(u64::from(timeout.subsec_nanos() > 0));
+  buffer_cmd
+    .write_fmt(format_args!("SELECT CAST(COALESCE(GET_LOCK('_wtx', {secs}), 0) AS SIGNED)"))?;
+  let is_locked = executor
+    .fetch_with_stmt(buffer_cmd.get(before..).unwrap_or_default(), ())
+    .await?
+    .decode::<_, i64>(0)?;
+  buffer_cmd.truncate(before);
+  if is_locked != 1 {
+    return Err(SchemaManagerError::LockTimeout.into());
+  }
+  Ok(())
+}
+
+/// An empty `schema` refers to the database of the current connection.
+#[inline]
+pub(crate) async fn _table_names<E>(
+  buffer_cmd: &mut String,
+  executor: &mut E,
+  results: &mut Vector<Identifier>,
+  schema: &str,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Mysql<crate::Error>>,
+{
+  let before = buffer_cmd.len();
+  buffer_cmd.push_str(
+    "SELECT
+      table_name AS generic_column
+    FROM
+      information_schema.tables
+    WHERE
+      table_type = 'BASE TABLE' AND table_schema = ",
+  );
+  if schema.is_empty() {
+    buffer_cmd.push_str("DATABASE()");
+  } else {
+    buffer_cmd.write_fmt(format_args!("'{schema}'"))?;
+  }
+  executor
+    .simple_entities(buffer_cmd.get(before..).unwrap_or_default(), (), |result| {
+      results.push(result)?;
+      Ok(())
+    })
+    .await?;
+  buffer_cmd.truncate(before);
+  Ok(())
+}
+
+#[inline]
+pub(crate) async fn _unlock<E>(executor: &mut E) -> crate::Result<()>
+where
+  E: Executor<Database = Mysql<crate::Error>>,
+{
+  executor.execute("DO RELEASE_LOCK('_wtx')", |_| {}).await
+}
+
+#[inline]
+pub(crate) async fn _views<E>(
+  executor: &mut E,
+  results: &mut Vector<Identifier>,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Mysql<crate::Error>>,
+{
+  executor
+    .simple_entities(
+      "SELECT
+      table_name AS generic_column
+    FROM
+      information_schema.views
+    WHERE
+      table_schema = DATABASE()",
+      (),
+      |result| {
+        results.push(result)?;
+        Ok(())
+      },
+    )
+    .await
+}
+
+#[inline]
+fn _push_drop(
+  (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
+  structure: &str,
+) -> crate::Result<()> {
+  for identifier in buffer_idents.iter() {
+    buffer_cmd.write_fmt(format_args!("DROP {structure} `{identifier}`;"))?;
+  }
+  buffer_idents.clear();
+  Ok(())
+}
@@ -1,9 +1,17 @@
 use crate::{
-  database::{client::postgres::Postgres, executor::Executor, Identifier},
+  database::{
+    client::postgres::{Postgres, SqlState},
+    executor::Executor,
+    schema_manager::{
+      fixed_sql_commands::_LOCK_ID, SchemaManagerError, SchemaObject, SchemaObjectKind,
+      SchemaSnapshot,
+    },
+    Identifier, Record as _,
+  },
   misc::Vector,
 };
-use alloc::string::String;
-use core::fmt::Write;
+use alloc::string::{String, ToString};
+use core::{fmt::Write, time::Duration};
 
 pub(crate) static _CREATE_MIGRATION_TABLES: &str = concat!(
   "CREATE SCHEMA IF NOT EXISTS _wtx; \
@@ -51,8 +59,8 @@ where
 
   executor
     .transaction(|this| async {
-      this.execute(buffer_cmd.as_str(), |_| {}).await?;
-      Ok(((), this))
+      let rslt = this.execute(buffer_cmd.as_str(), |_| {}).await.map_err(Into::into);
+      (rslt, this)
     })
     .await?;
   buffer_cmd.clear();
@@ -60,6 +68,28 @@ where
   Ok(())
 }
 
+// Each returned row is composed by a name and a definition.
+#[inline]
+pub(crate) async fn _definitions<E>(
+  cmd: &str,
+  executor: &mut E,
+  kind: SchemaObjectKind,
+  results: &mut SchemaSnapshot,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Postgres<crate::Error>>,
+{
+  let _records = executor
+    .fetch_many_with_stmt(cmd, (), |record| {
+      let name = record.decode::<_, &str>(0)?.to_string();
+      let definition = record.decode::<_, &str>(1)?.to_string();
+      results.push(SchemaObject::new(kind, name, definition))?;
+      Ok(())
+    })
+    .await?;
+  Ok(())
+}
+
 #[inline]
 pub(crate) async fn _domains<E>(
   executor: &mut E,
@@ -117,6 +147,35 @@ where
     .await
 }
 
+// `lock_timeout` also applies to advisory locks. A zero value would disable the timeout, as such,
+// at least one millisecond is always used.
+#[inline]
+pub(crate) async fn _lock<E>(
+  buffer_cmd: &mut String,
+  executor: &mut E,
+  timeout: Duration,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Postgres<crate::Error>>,
+{
+  let before = buffer_cmd.len();
+  let ms = timeout.as_millis().max(1);
+  buffer_cmd.write_fmt(format_args!("SET lock_timeout = {ms}"))?;
+  executor.execute(buffer_cmd.get(before..).unwrap_or_default(), |_| {}).await?;
+  buffer_cmd.truncate(before);
+  buffer_cmd.write_fmt(format_args!("SELECT pg_advisory_lock({_LOCK_ID})"))?;
+  let rslt = executor.fetch_with_stmt(buffer_cmd.get(before..).unwrap_or_default(), ()).await;
+  let rslt = rslt.map(|_| ());
+  buffer_cmd.truncate(before);
+  executor.execute("RESET lock_timeout", |_| {}).await?;
+  match rslt {
+    Err(crate::Error::PostgresDbError(err)) if *err.code() == SqlState::E55P03 => {
+      Err(SchemaManagerError::LockTimeout.into())
+    }
+    _ => rslt,
+  }
+}
+
 #[inline]
 pub(crate) async fn _pg_proc<E>(
   (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
@@ -246,6 +305,191 @@ where
   Ok(())
 }
 
+// The same objects handled by `_clear` alongside columns, constraints and indexes. Everything
+// that has a textual representation is compared by its definition.
+//
+// Objects are retrieved from the current schema, i.e., the first existing schema of the
+// `search_path`.
+#[inline]
+pub(crate) async fn _snapshot<E>(
+  (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
+  executor: &mut E,
+  results: &mut SchemaSnapshot,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Postgres<crate::Error>>,
+{
+  let record = executor.fetch_with_stmt("SELECT current_schema()::TEXT", ()).await?;
+  let schema = Identifier::try_from(record.decode::<_, &str>(0)?)?;
+
+  _schemas(executor, buffer_idents).await?;
+  buffer_idents.retain(|el| el.as_str() != "_wtx" && el != &schema);
+  _push_objects(buffer_idents, SchemaObjectKind::Schema, results)?;
+
+  _definitions(
+    "SELECT
+      sequence_name,
+      ''
+    FROM information_schema.sequences
+    WHERE sequence_schema = current_schema()",
+    executor,
+    SchemaObjectKind::Sequence,
+    results,
+  )
+  .await?;
+
+  _table_names(buffer_cmd, executor, buffer_idents, &schema).await?;
+  _push_objects(buffer_idents, SchemaObjectKind::Table, results)?;
+
+  _definitions(
+    "SELECT
+      c.relname || '.' || a.attname,
+      format_type(a.atttypid, a.atttypmod)
+        || CASE WHEN a.attnotnull THEN ' NOT NULL' ELSE '' END
+        || COALESCE(' DEFAULT ' || pg_get_expr(d.adbin, d.adrelid), '')
+    FROM pg_catalog.pg_attribute a
+      JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+      JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+      LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+      LEFT JOIN pg_depend dep ON dep.objid = c.oid AND dep.deptype = 'e'
+    WHERE n.nspname = current_schema()
+      AND c.relkind IN ('p', 'r')
+      AND a.attnum > 0
+      AND NOT a.attisdropped
+      AND dep.objid IS NULL",
+    executor,
+    SchemaObjectKind::Column,
+    results,
+  )
+  .await?;
+
+  _definitions(
+    "SELECT
+      c.relname || '.' || con.conname,
+      pg_get_constraintdef(con.oid)
+    FROM pg_catalog.pg_constraint con
+      JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+      JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+      LEFT JOIN pg_depend dep ON dep.objid = c.oid AND dep.deptype = 'e'
+    WHERE n.nspname = current_schema()
+      AND con.contype IN ('c', 'f', 'p', 'u', 'x')
+      AND dep.objid IS NULL",
+    executor,
+    SchemaObjectKind::Constraint,
+    results,
+  )
+  .await?;
+
+  _definitions(
+    "SELECT
+      t.typname,
+      format_type(t.typbasetype, t.typtypmod)
+        || CASE WHEN t.typnotnull THEN ' NOT NULL' ELSE '' END
+        || COALESCE(' DEFAULT ' || t.typdefault, '')
+        || COALESCE((
+          SELECT string_agg(
+            ' CONSTRAINT ' || quote_ident(con.conname) || ' ' || pg_get_constraintdef(con.oid),
+            '' ORDER BY con.conname
+          )
+          FROM pg_catalog.pg_constraint con
+          WHERE con.contypid = t.oid
+        ), '')
+    FROM pg_catalog.pg_type t
+      JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+      LEFT JOIN pg_depend dep ON dep.objid = t.oid AND dep.deptype = 'e'
+    WHERE t.typtype = 'd'
+      AND n.nspname = current_schema()
+      AND dep.objid IS NULL",
+    executor,
+    SchemaObjectKind::Domain,
+    results,
+  )
+  .await?;
+
+  _definitions(
+    "SELECT
+      i.relname,
+      pg_get_indexdef(i.oid)
+    FROM pg_catalog.pg_index x
+      JOIN pg_catalog.pg_class i ON i.oid = x.indexrelid
+      JOIN pg_catalog.pg_namespace n ON n.oid = i.relnamespace
+      LEFT JOIN pg_depend dep ON dep.objid = x.indrelid AND dep.deptype = 'e'
+    WHERE n.nspname = current_schema()
+      AND dep.objid IS NULL
+      -- that aren't managed by constraints
+      AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_constraint con
+        WHERE con.conindid = i.oid AND con.contype IN ('p', 'u', 'x')
+      )",
+    executor,
+    SchemaObjectKind::Index,
+    results,
+  )
+  .await?;
+
+  _proc_definitions(buffer_cmd, executor, SchemaObjectKind::Function, 'f', results).await?;
+  _proc_definitions(buffer_cmd, executor, SchemaObjectKind::Procedure, 'p', results).await?;
+
+  _definitions(
+    "SELECT
+      t.typname,
+      CASE t.typtype
+        WHEN 'c' THEN 'AS (' || COALESCE((
+          SELECT string_agg(
+            quote_ident(a.attname) || ' ' || format_type(a.atttypid, a.atttypmod),
+            ', ' ORDER BY a.attnum
+          )
+          FROM pg_catalog.pg_attribute a
+          WHERE a.attrelid = t.typrelid AND a.attnum > 0 AND NOT a.attisdropped
+        ), '') || ')'
+        WHEN 'e' THEN 'AS ENUM (' || COALESCE((
+          SELECT string_agg(quote_literal(e.enumlabel), ', ' ORDER BY e.enumsortorder)
+          FROM pg_catalog.pg_enum e
+          WHERE e.enumtypid = t.oid
+        ), '') || ')'
+        ELSE ''
+      END
+    FROM
+      pg_catalog.pg_type t
+      LEFT JOIN pg_depend dep ON dep.objid = t.oid and dep.deptype = 'e'
+    WHERE
+      (t.typrelid = 0 OR (
+        SELECT c.relkind = 'c' FROM pg_catalog.pg_class c WHERE c.oid = t.typrelid)
+      )
+      AND NOT EXISTS(
+        SELECT 1 FROM pg_catalog.pg_type el WHERE el.oid = t.typelem AND el.typarray = t.oid
+      )
+      AND t.typnamespace in (
+        select oid from pg_catalog.pg_namespace where nspname = current_schema()
+      )
+      AND dep.objid is null
+      AND t.typtype != 'd'",
+    executor,
+    SchemaObjectKind::Type,
+    results,
+  )
+  .await?;
+
+  _definitions(
+    "SELECT
+      c.relname,
+      pg_get_viewdef(c.oid)
+    FROM pg_catalog.pg_class c
+      JOIN pg_namespace n ON n.oid = c.relnamespace
+      LEFT JOIN pg_depend dep ON dep.objid = c.oid AND dep.deptype = 'e'
+    WHERE c.relkind = 'v'
+      AND n.nspname = current_schema()
+      AND dep.objid IS NULL",
+    executor,
+    SchemaObjectKind::View,
+    results,
+  )
+  .await?;
+
+  results.sort();
+  Ok(())
+}
+
 #[inline]
 pub(crate) async fn _types<E>(
   executor: &mut E,
@@ -282,6 +526,18 @@ where
     .await
 }
 
+#[inline]
+pub(crate) async fn _unlock<E>(buffer_cmd: &mut String, executor: &mut E) -> crate::Result<()>
+where
+  E: Executor<Database = Postgres<crate::Error>>,
+{
+  let before = buffer_cmd.len();
+  buffer_cmd.write_fmt(format_args!("SELECT pg_advisory_unlock({_LOCK_ID})"))?;
+  let _record = executor.fetch_with_stmt(buffer_cmd.get(before..).unwrap_or_default(), ()).await?;
+  buffer_cmd.truncate(before);
+  Ok(())
+}
+
 #[inline]
 pub(crate) async fn _views<E>(
   executor: &mut E,
@@ -311,6 +567,39 @@ where
     .await
 }
 
+#[inline]
+async fn _proc_definitions<E>(
+  buffer_cmd: &mut String,
+  executor: &mut E,
+  kind: SchemaObjectKind,
+  prokind: char,
+  results: &mut SchemaSnapshot,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Postgres<crate::Error>>,
+{
+  let before = buffer_cmd.len();
+  buffer_cmd.write_fmt(format_args!(
+    "
+    SELECT
+      proname || '(' || pg_get_function_identity_arguments(pg_proc.oid) || ')',
+      pg_get_functiondef(pg_proc.oid)
+    FROM
+      pg_proc
+      INNER JOIN pg_namespace ns ON (pg_proc.pronamespace = ns.oid)
+      -- that don't depend on an extension
+      LEFT JOIN pg_depend dep ON dep.objid = pg_proc.oid AND dep.deptype = 'e'
+    WHERE
+      ns.nspname = current_schema()
+      AND dep.objid IS NULL
+      AND pg_proc.prokind = '{prokind}'
+    ",
+  ))?;
+  _definitions(buffer_cmd.get(before..).unwrap_or_default(), executor, kind, results).await?;
+  buffer_cmd.truncate(before);
+  Ok(())
+}
+
 #[inline]
 fn _push_drop(
   (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
@@ -322,3 +611,16 @@ fn _push_drop(
   buffer_idents.clear();
   Ok(())
 }
+
+#[inline]
+fn _push_objects(
+  buffer_idents: &mut Vector<Identifier>,
+  kind: SchemaObjectKind,
+  results: &mut SchemaSnapshot,
+) -> crate::Result<()> {
+  for identifier in buffer_idents.iter() {
+    results.push(SchemaObject::new(kind, identifier.as_str().to_string(), String::new()))?;
+  }
+  buffer_idents.clear();
+  Ok(())
+}
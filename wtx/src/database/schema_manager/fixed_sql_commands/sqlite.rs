@@ -0,0 +1,105 @@
+use crate::{
+  database::{client::sqlite::Sqlite, executor::Executor, Identifier},
+  misc::Vector,
+};
+use alloc::string::String;
+use core::fmt::Write;
+
+// `INTEGER PRIMARY KEY` columns are aliases of the automatically generated `ROWID`.
+pub(crate) static _CREATE_MIGRATION_TABLES: &str = concat!(
+  "CREATE TABLE IF NOT EXISTS _wtx_migration_group (",
+  _wtx_migration_group_columns!(),
+  ");
+  CREATE TABLE IF NOT EXISTS _wtx_migration (
+  id INTEGER PRIMARY KEY,
+  created_on TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,",
+  _wtx_migration_columns!(),
+  ");"
+);
+
+// Foreign keys must be disabled outside of transactions.
+#[inline]
+pub(crate) async fn _clear<E>(
+  (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
+  executor: &mut E,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Sqlite<crate::Error>>,
+{
+  buffer_cmd.push_str("PRAGMA foreign_keys = OFF;");
+
+  _views(executor, buffer_idents).await?;
+  _push_drop((buffer_cmd, buffer_idents), "VIEW")?;
+
+  _table_names(buffer_cmd, executor, buffer_idents, "").await?;
+  _push_drop((buffer_cmd, buffer_idents), "TABLE")?;
+
+  buffer_cmd.push_str("PRAGMA foreign_keys = ON;");
+  executor.execute(buffer_cmd.as_str(), |_| {}).await?;
+  buffer_cmd.clear();
+
+  Ok(())
+}
+
+/// An empty `schema` refers to the main database. Other values refer to attached databases.
+#[inline]
+pub(crate) async fn _table_names<E>(
+  buffer_cmd: &mut String,
+  executor: &mut E,
+  results: &mut Vector<Identifier>,
+  schema: &str,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Sqlite<crate::Error>>,
+{
+  let before = buffer_cmd.len();
+  buffer_cmd.write_fmt(format_args!(
+    "SELECT
+      name AS generic_column
+    FROM
+      \"{schema}\".sqlite_master
+    WHERE
+      type = 'table' AND name NOT LIKE 'sqlite_%'",
+    schema = if schema.is_empty() { "main" } else { schema }
+  ))?;
+  executor
+    .simple_entities(buffer_cmd.get(before..).unwrap_or_default(), (), |result| {
+      results.push(result)?;
+      Ok(())
+    })
+    .await?;
+  buffer_cmd.truncate(before);
+  Ok(())
+}
+
+#[inline]
+pub(crate) async fn _views<E>(
+  executor: &mut E,
+  results: &mut Vector<Identifier>,
+) -> crate::Result<()>
+where
+  E: Executor<Database = Sqlite<crate::Error>>,
+{
+  executor
+    .simple_entities(
+      "SELECT name AS generic_column FROM sqlite_master WHERE type = 'view'",
+      (),
+      |result| {
+        results.push(result)?;
+        Ok(())
+      },
+    )
+    .await
+}
+
+#[inline]
+fn _push_drop(
+  (buffer_cmd, buffer_idents): (&mut String, &mut Vector<Identifier>),
+  structure: &str,
+) -> crate::Result<()> {
+  for identifier in buffer_idents.iter() {
+    buffer_cmd.write_fmt(format_args!("DROP {structure} \"{identifier}\";"))?;
+  }
+  buffer_idents.clear();
+  Ok(())
+}
@@ -97,13 +97,18 @@ create_all_integration_tests!(
   // Database
 
   postgres:
-    db::postgres::_clean_drops_all_objs;
+    db::postgres::_clean_drops_all_objs,
+    db::postgres::_code_migrations_share_the_history_of_sql_migrations,
+    db::postgres::_diff_detects_changes_made_without_migrations,
+    db::postgres::_lock_is_exclusive,
+    db::postgres::_non_transactional_migrations_are_executed_standalone;
 
   // Generic
 
   generic:
     generic::all_tables_returns_the_number_of_tables_of_the_default_schema,
-    generic::rollback_works;
+    generic::rollback_works,
+    generic::status_and_migrate_dry_run_work;
 
   // Schema
 
@@ -1,12 +1,22 @@
 #[cfg(feature = "schema-manager-dev")]
 use {
   crate::database::{
-    client::postgres::Postgres, schema_manager::fixed_sql_commands::postgres,
-    schema_manager::integration_tests, schema_manager::Commands, schema_manager::DbMigration,
-    schema_manager::SchemaManagement, FromRecord, Identifier,
+    client::postgres::{Config, Executor, ExecutorBuffer, Postgres},
+    schema_manager::fixed_sql_commands::postgres,
+    schema_manager::integration_tests,
+    schema_manager::CodeMigration,
+    schema_manager::Commands,
+    schema_manager::DbMigration,
+    schema_manager::SchemaManagement,
+    schema_manager::SchemaManagerError,
+    schema_manager::{MigrationGroup, UserMigrationRef},
+    schema_manager::{SchemaDrift, SchemaObjectKind, SchemaSnapshot},
+    FromRecord, Identifier, Record as _, DEFAULT_URI_VAR,
   },
-  crate::misc::Vector,
-  alloc::string::String,
+  crate::misc::{UriRef, Vector, Xorshift64},
+  alloc::{boxed::Box, string::String},
+  core::time::Duration,
+  tokio::net::TcpStream,
 };
 
 #[cfg(feature = "schema-manager-dev")]
@@ -111,3 +121,292 @@ pub(crate) async fn _clean_drops_all_objs<E>(
   assert_eq!(buffer_idents.len(), 0);
   buffer_idents.clear();
 }
+
+#[cfg(feature = "schema-manager-dev")]
+pub(crate) async fn _code_migrations_share_the_history_of_sql_migrations<E>(
+  (buffer_cmd, buffer_db_migrations, _): (
+    &mut String,
+    &mut Vector<DbMigration>,
+    &mut Vector<Identifier>,
+  ),
+  c: &mut Commands<E>,
+  _: integration_tests::AuxTestParams,
+) where
+  E: SchemaManagement<Database = Postgres<crate::Error>>,
+{
+  let mg = MigrationGroup::new("code", 1);
+  let code_migrations = [CodeMigration::new(
+    "insert_code_foo",
+    "1",
+    [
+      |executor: &mut E| {
+        Box::pin(async move { executor.execute("INSERT INTO foo VALUES (2);", |_| {}).await })
+      },
+      |executor: &mut E| {
+        Box::pin(async move { executor.execute("DELETE FROM foo WHERE id = 2;", |_| {}).await })
+      },
+    ],
+    2,
+  )];
+  let migrations = [
+    UserMigrationRef::from_user_parts(
+      &[],
+      true,
+      "create_foo",
+      None,
+      ["CREATE TABLE foo (id INT NOT NULL);", "DROP TABLE foo;"],
+      1,
+    )
+    .unwrap(),
+    UserMigrationRef::from_user_parts(
+      &[],
+      true,
+      "insert_sql_foo",
+      None,
+      ["INSERT INTO foo VALUES (3);", "DELETE FROM foo WHERE id = 3;"],
+      3,
+    )
+    .unwrap(),
+  ];
+  c.migrate_with_code((buffer_cmd, buffer_db_migrations), &mg, migrations.iter(), &code_migrations)
+    .await
+    .unwrap();
+  c.executor.migrations(buffer_cmd, &mg, buffer_db_migrations).await.unwrap();
+  assert_eq!(buffer_db_migrations.len(), 3);
+  assert_eq!(buffer_db_migrations[1].name(), "insert_code_foo");
+  assert_eq!(buffer_db_migrations[1].checksum(), code_migrations[0].checksum());
+  buffer_db_migrations.clear();
+  let record = c.executor.fetch_with_stmt("SELECT SUM(id)::int8 FROM foo", ()).await.unwrap();
+  assert_eq!(record.decode::<_, i64>(0).unwrap(), 5);
+
+  c.validate((buffer_cmd, buffer_db_migrations), &mg, migrations.iter()).await.unwrap();
+  let mgs = c.status((buffer_cmd, buffer_db_migrations), &mg, migrations.iter()).await.unwrap();
+  assert!(!mgs.has_drifted());
+  assert_eq!(mgs.migrations().len(), 2);
+  assert!(matches!(
+    c.rollback((buffer_cmd, buffer_db_migrations), &mg, migrations.iter(), 1).await,
+    Err(crate::Error::SchemaManagerError(SchemaManagerError::UnrevertibleCodeMigration(2)))
+  ));
+
+  c.migrate_with_code((buffer_cmd, buffer_db_migrations), &mg, migrations.iter(), &code_migrations)
+    .await
+    .unwrap();
+  c.rollback_with_code(
+    (buffer_cmd, buffer_db_migrations),
+    &mg,
+    migrations.iter(),
+    &code_migrations,
+    1,
+  )
+  .await
+  .unwrap();
+  c.executor.migrations(buffer_cmd, &mg, buffer_db_migrations).await.unwrap();
+  assert_eq!(buffer_db_migrations.len(), 1);
+  buffer_db_migrations.clear();
+  let record = c.executor.fetch_with_stmt("SELECT COUNT(*) FROM foo", ()).await.unwrap();
+  assert_eq!(record.decode::<_, i64>(0).unwrap(), 0);
+}
+
+#[cfg(feature = "schema-manager-dev")]
+pub(crate) async fn _diff_detects_changes_made_without_migrations<E>(
+  (buffer_cmd, _, buffer_idents): (&mut String, &mut Vector<DbMigration>, &mut Vector<Identifier>),
+  c: &mut Commands<E>,
+  _: integration_tests::AuxTestParams,
+) where
+  E: SchemaManagement<Database = Postgres<crate::Error>>,
+{
+  c.executor
+    .execute(
+      "CREATE TYPE mood AS ENUM ('happy', 'ok');
+      CREATE TABLE foo (id INT PRIMARY KEY, name TEXT);
+      CREATE VIEW foo_names AS SELECT name FROM foo;",
+      |_| {},
+    )
+    .await
+    .unwrap();
+  let mut expected = SchemaSnapshot::new();
+  c.executor.snapshot((buffer_cmd, buffer_idents), &mut expected).await.unwrap();
+
+  c.executor
+    .execute(
+      "ALTER TABLE foo ADD COLUMN age INT NOT NULL DEFAULT 0;
+      CREATE INDEX foo_name_idx ON foo (name);
+      DROP VIEW foo_names;",
+      |_| {},
+    )
+    .await
+    .unwrap();
+  c.executor.execute("ALTER TYPE mood ADD VALUE 'sad'", |_| {}).await.unwrap();
+  let mut actual = SchemaSnapshot::new();
+  c.executor.snapshot((buffer_cmd, buffer_idents), &mut actual).await.unwrap();
+
+  let diff = expected.diff(&actual).unwrap();
+  let summary: alloc::vec::Vec<_> = diff
+    .drifts()
+    .iter()
+    .map(|drift| match drift {
+      SchemaDrift::Added(elem) => ('+', elem.kind(), elem.name()),
+      SchemaDrift::Changed(_, elem) => ('~', elem.kind(), elem.name()),
+      SchemaDrift::Removed(elem) => ('-', elem.kind(), elem.name()),
+    })
+    .collect();
+  assert_eq!(
+    summary,
+    [
+      ('~', SchemaObjectKind::Type, "mood"),
+      ('+', SchemaObjectKind::Column, "foo.age"),
+      ('+', SchemaObjectKind::Index, "foo_name_idx"),
+      ('-', SchemaObjectKind::View, "foo_names"),
+    ]
+  );
+
+  let (mut up, mut down) = (String::new(), String::new());
+  diff.suggested_migration(&mut up, &mut down).unwrap();
+  let mut snapshot = SchemaSnapshot::new();
+  c.executor.execute(&down, |_| {}).await.unwrap();
+  c.executor.snapshot((buffer_cmd, buffer_idents), &mut snapshot).await.unwrap();
+  assert!(!expected.diff(&snapshot).unwrap().has_drifted());
+  snapshot.clear();
+  c.executor.execute(&up, |_| {}).await.unwrap();
+  c.executor.snapshot((buffer_cmd, buffer_idents), &mut snapshot).await.unwrap();
+  assert!(!actual.diff(&snapshot).unwrap().has_drifted());
+
+  // Objects are retrieved from the current schema
+  c.executor
+    .execute(
+      "CREATE SCHEMA diff_schema; SET search_path TO diff_schema; CREATE TABLE bar (id INT);",
+      |_| {},
+    )
+    .await
+    .unwrap();
+  snapshot.clear();
+  c.executor.snapshot((buffer_cmd, buffer_idents), &mut snapshot).await.unwrap();
+  c.executor.execute("RESET search_path", |_| {}).await.unwrap();
+  let diff = SchemaSnapshot::new().diff(&snapshot).unwrap();
+  let mut names: alloc::vec::Vec<_> = diff
+    .drifts()
+    .iter()
+    .map(|drift| match drift {
+      SchemaDrift::Added(elem) | SchemaDrift::Changed(_, elem) | SchemaDrift::Removed(elem) => {
+        (elem.kind(), elem.name())
+      }
+    })
+    .collect();
+  names.sort();
+  assert_eq!(names, [(SchemaObjectKind::Table, "bar"), (SchemaObjectKind::Column, "bar.id")]);
+}
+
+#[cfg(feature = "schema-manager-dev")]
+pub(crate) async fn _lock_is_exclusive<E>(
+  (buffer_cmd, _, buffer_idents): (&mut String, &mut Vector<DbMigration>, &mut Vector<Identifier>),
+  c: &mut Commands<E>,
+  _: integration_tests::AuxTestParams,
+) where
+  E: SchemaManagement<Database = Postgres<crate::Error>>,
+{
+  let uri = std::env::var(DEFAULT_URI_VAR).unwrap();
+  let uri = UriRef::new(&uri);
+  let mut rng = Xorshift64::from(crate::misc::simple_seed());
+  let mut other = Commands::with_executor(
+    Executor::connect(
+      &Config::from_uri(&uri).unwrap(),
+      ExecutorBuffer::new(usize::MAX, &mut rng),
+      &mut rng,
+      TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap(),
+    )
+    .await
+    .unwrap(),
+  )
+  .set_lock_timeout(Duration::from_millis(100));
+
+  c.executor.lock(buffer_cmd, Duration::from_secs(1)).await.unwrap();
+  assert!(matches!(
+    other.clear((buffer_cmd, buffer_idents)).await,
+    Err(crate::Error::SchemaManagerError(SchemaManagerError::LockTimeout))
+  ));
+  c.executor.unlock(buffer_cmd).await.unwrap();
+  other.clear((buffer_cmd, buffer_idents)).await.unwrap();
+}
+
+#[cfg(feature = "schema-manager-dev")]
+pub(crate) async fn _non_transactional_migrations_are_executed_standalone<E>(
+  (buffer_cmd, buffer_db_migrations, _): (
+    &mut String,
+    &mut Vector<DbMigration>,
+    &mut Vector<Identifier>,
+  ),
+  c: &mut Commands<E>,
+  _: integration_tests::AuxTestParams,
+) where
+  E: SchemaManagement<Database = Postgres<crate::Error>>,
+{
+  let mg = MigrationGroup::new("concurrently", 1);
+  let migrations = [
+    UserMigrationRef::from_user_parts(
+      &[],
+      true,
+      "create_foo",
+      None,
+      ["CREATE TABLE foo (id INT NOT NULL);", "DROP TABLE foo;"],
+      1,
+    )
+    .unwrap(),
+    UserMigrationRef::from_user_parts(
+      &[],
+      false,
+      "index_foo",
+      None,
+      ["CREATE INDEX CONCURRENTLY foo_idx ON foo (id)", "DROP INDEX CONCURRENTLY foo_idx"],
+      2,
+    )
+    .unwrap(),
+    UserMigrationRef::from_user_parts(
+      &[],
+      true,
+      "insert_foo",
+      None,
+      ["INSERT INTO foo VALUES (1);", "DELETE FROM foo;"],
+      3,
+    )
+    .unwrap(),
+  ];
+  c.migrate((buffer_cmd, buffer_db_migrations), &mg, migrations.iter()).await.unwrap();
+  c.executor.migrations(buffer_cmd, &mg, buffer_db_migrations).await.unwrap();
+  assert_eq!(buffer_db_migrations.len(), 3);
+  buffer_db_migrations.clear();
+  let record = c
+    .executor
+    .fetch_with_stmt("SELECT COUNT(*) FROM pg_indexes WHERE indexname = 'foo_idx'", ())
+    .await
+    .unwrap();
+  assert_eq!(record.decode::<_, i64>(0).unwrap(), 1);
+
+  c.rollback((buffer_cmd, buffer_db_migrations), &mg, migrations.iter().rev(), 1).await.unwrap();
+  c.executor.migrations(buffer_cmd, &mg, buffer_db_migrations).await.unwrap();
+  assert_eq!(buffer_db_migrations.len(), 1);
+  buffer_db_migrations.clear();
+  let record = c
+    .executor
+    .fetch_with_stmt("SELECT COUNT(*) FROM pg_indexes WHERE indexname = 'foo_idx'", ())
+    .await
+    .unwrap();
+  assert_eq!(record.decode::<_, i64>(0).unwrap(), 0);
+
+  // Several statements sent at once are executed within an implicit transaction.
+  let statements = UserMigrationRef::from_user_parts(
+    &[],
+    false,
+    "statements",
+    None,
+    [
+      "CREATE INDEX CONCURRENTLY a_idx ON foo (id); CREATE INDEX CONCURRENTLY b_idx ON foo (id);",
+      "",
+    ],
+    2,
+  )
+  .unwrap();
+  let rslt = c
+    .migrate((buffer_cmd, buffer_db_migrations), &mg, [&migrations[0], &statements].into_iter())
+    .await;
+  assert!(rslt.is_err());
+}
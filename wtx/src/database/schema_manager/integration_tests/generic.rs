@@ -1,7 +1,8 @@
 use crate::{
   database::{
     schema_manager::{
-      integration_tests::AuxTestParams, Commands, DbMigration, MigrationGroup, SchemaManagement,
+      integration_tests::AuxTestParams, Commands, DbMigration, MigrationGroup, MigrationStatus,
+      SchemaManagement,
     },
     Database, Identifier,
   },
@@ -56,3 +57,50 @@ pub(crate) async fn rollback_works<E>(
   assert_eq!(buffer_idents.len(), 2);
   buffer_idents.clear();
 }
+
+pub(crate) async fn status_and_migrate_dry_run_work<E>(
+  (buffer_cmd, buffer_db_migrations, buffer_idents): (
+    &mut String,
+    &mut Vector<DbMigration>,
+    &mut Vector<Identifier>,
+  ),
+  c: &mut Commands<E>,
+  aux: AuxTestParams,
+) where
+  E: SchemaManagement,
+  <E::Database as Database>::Error: Debug,
+{
+  let path = Path::new("../.test-utils/migrations.toml");
+  let mut buffer_plan = String::new();
+  let mut results = Vector::new();
+
+  c.migrate_dry_run_from_toml_path((buffer_cmd, buffer_db_migrations), &mut buffer_plan, path)
+    .await
+    .unwrap();
+  assert!(buffer_plan.starts_with("-- initial (1)\n"));
+  assert!(buffer_plan.contains("CREATE TABLE author"));
+  assert!(buffer_plan.contains("-- more_stuff (2)\n"));
+  c.executor.table_names(buffer_cmd, buffer_idents, aux.default_schema).await.unwrap();
+  assert_eq!(buffer_idents.len(), aux.schema_regulator);
+  buffer_idents.clear();
+
+  c.status_from_toml((buffer_cmd, buffer_db_migrations), path, &mut results).await.unwrap();
+  assert_eq!(results.len(), 2);
+  assert!(results.iter().all(|el| !el.has_drifted()));
+  assert!(results.iter().flat_map(|el| el.migrations()).all(|el| el.2 == MigrationStatus::Pending));
+  results.clear();
+  c.executor.table_names(buffer_cmd, buffer_idents, aux.wtx_schema).await.unwrap();
+  assert_eq!(buffer_idents.len(), 0);
+
+  c.migrate_from_toml_path((buffer_cmd, buffer_db_migrations), path).await.unwrap();
+  c.status_from_toml((buffer_cmd, buffer_db_migrations), path, &mut results).await.unwrap();
+  assert_eq!(results.len(), 2);
+  assert_eq!(results[0].migrations().len(), 4);
+  assert!(results.iter().flat_map(|el| el.migrations()).all(|el| el.2 == MigrationStatus::Applied));
+
+  buffer_plan.clear();
+  c.migrate_dry_run_from_toml_path((buffer_cmd, buffer_db_migrations), &mut buffer_plan, path)
+    .await
+    .unwrap();
+  assert!(!buffer_plan.contains("CREATE TABLE author"));
+}
@@ -13,3 +13,17 @@ macro_rules! loop_files {
     }
   }};
 }
+
+// Evaluates `$cb` while holding the database-level lock. The lock is released even if `$cb` fails.
+macro_rules! with_lock {
+  ($this:expr, $buffer_cmd:expr, $rslt:ty, $cb:block) => {{
+    $this.executor.lock($buffer_cmd, $this.lock_timeout).await?;
+    let rslt: $rslt = async $cb.await;
+    let unlock_rslt = $this.executor.unlock($buffer_cmd).await;
+    match (rslt, unlock_rslt) {
+      (Ok(elem), Ok(())) => Ok(elem),
+      (Err(err), _) => Err(err),
+      (Ok(_), Err(err)) => Err(err.into()),
+    }
+  }};
+}
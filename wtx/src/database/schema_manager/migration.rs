@@ -1,8 +1,10 @@
+mod code_migration;
 mod db_migration;
 mod migration_common;
 mod migration_group;
 mod user_migration;
 
+pub use code_migration::*;
 pub use db_migration::*;
 pub use migration_group::*;
 pub use user_migration::*;
@@ -0,0 +1,114 @@
+use crate::{
+  database::schema_manager::{
+    migration::migration_common::MigrationCommon, misc::calc_checksum, UserMigrationRef,
+  },
+  misc::Lease,
+};
+use alloc::boxed::Box;
+use core::{fmt, future::Future, pin::Pin};
+
+/// Function of a [`CodeMigration`] that receives the executor of the schema manager.
+pub type CodeMigrationFn<E> = for<'any> fn(&'any mut E) -> CodeMigrationFuture<'any>;
+/// Future returned by a [`CodeMigrationFn`].
+pub type CodeMigrationFuture<'any> = Pin<Box<dyn Future<Output = crate::Result<()>> + 'any>>;
+
+/// A migration expressed as Rust code, useful for data transformations that can't be written in
+/// SQL.
+///
+/// Code migrations share the versions of the SQL migrations of the same group and are recorded
+/// in the same history.
+///
+/// ```rust
+/// use wtx::database::{schema_manager::CodeMigration, Executor};
+///
+/// let _ = CodeMigration::<(), _>::new(
+///   "backfill_author",
+///   "1",
+///   [
+///     |executor| Box::pin(async move { executor.execute("UPDATE author SET ...", |_| {}).await }),
+///     |_| Box::pin(async move { Ok(()) }),
+///   ],
+///   2,
+/// );
+/// ```
+///
+/// * Types
+///
+/// E: Executor
+/// S: String
+pub struct CodeMigration<E, S> {
+  common: MigrationCommon<S>,
+  down: CodeMigrationFn<E>,
+  up: CodeMigrationFn<E>,
+}
+
+impl<E, S> CodeMigration<E, S>
+where
+  S: Lease<str>,
+{
+  /// Creates a new instance from all necessary input parameters that a user should provide.
+  ///
+  /// Functions can't be hashed, as such, `revision` is an arbitrary content that should be
+  /// modified whenever the logic changes. For example, a number or the `include_str!` of the file
+  /// that contains the migration.
+  #[inline]
+  pub fn new(name: S, revision: &str, [up, down]: [CodeMigrationFn<E>; 2], version: i32) -> Self {
+    let checksum = calc_checksum(name.lease(), revision, "", version);
+    Self { common: MigrationCommon { checksum, name, repeatability: None, version }, down, up }
+  }
+
+  /// Checksum
+  #[inline]
+  pub fn checksum(&self) -> u64 {
+    self.common.checksum
+  }
+
+  /// Reverts the changes made by [`Self::up`].
+  #[inline]
+  pub fn down<'any>(&self, executor: &'any mut E) -> CodeMigrationFuture<'any> {
+    (self.down)(executor)
+  }
+
+  /// Name
+  #[inline]
+  pub fn name(&self) -> &str {
+    self.common.name.lease()
+  }
+
+  /// Applies the changes.
+  #[inline]
+  pub fn up<'any>(&self, executor: &'any mut E) -> CodeMigrationFuture<'any> {
+    (self.up)(executor)
+  }
+
+  /// Version
+  #[inline]
+  pub fn version(&self) -> i32 {
+    self.common.version
+  }
+
+  // Representation used to validate and record the migration.
+  #[inline]
+  pub(crate) fn user_migration(&self) -> UserMigrationRef<'static, '_> {
+    UserMigrationRef::from_all_parts(
+      self.checksum(),
+      &[],
+      true,
+      self.name(),
+      None,
+      "",
+      "",
+      self.version(),
+    )
+  }
+}
+
+impl<E, S> fmt::Debug for CodeMigration<E, S>
+where
+  S: fmt::Debug,
+{
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CodeMigration").field("common", &self.common).finish()
+  }
+}
@@ -1,8 +1,8 @@
 use crate::{
   database::{
     schema_manager::{
-      migration::migration_common::MigrationCommon, MigrationGroup, Repeatability,
-      SchemaManagerError,
+      fixed_sql_commands::_CODE_REPEATABILITY, migration::migration_common::MigrationCommon,
+      MigrationGroup, Repeatability, SchemaManagerError,
     },
     DatabaseTy, Identifier,
   },
@@ -18,6 +18,7 @@ pub struct DbMigration {
   created_on: DateTime<Utc>,
   db_ty: DatabaseTy,
   group: MigrationGroup<Identifier>,
+  is_code: bool,
 }
 
 impl DbMigration {
@@ -45,6 +46,12 @@ impl DbMigration {
     &self.group
   }
 
+  /// If the migration was applied as a [`crate::database::schema_manager::CodeMigration`].
+  #[inline]
+  pub fn is_code(&self) -> bool {
+    self.is_code
+  }
+
   /// Name
   #[inline]
   pub fn name(&self) -> &str {
@@ -58,6 +65,33 @@ impl DbMigration {
   }
 }
 
+#[cfg(feature = "mysql")]
+impl<E> crate::database::FromRecord<crate::database::client::mysql::Mysql<E>> for DbMigration
+where
+  E: From<crate::Error>,
+{
+  #[inline]
+  fn from_record(from: &crate::database::client::mysql::Record<'_, E>) -> Result<Self, E> {
+    use crate::database::Record as _;
+    let repeatability = from.decode_opt("repeatability")?;
+    Ok(Self {
+      common: MigrationCommon {
+        checksum: _checksum_from_str(from.decode("checksum")?)?,
+        name: from.decode::<_, &str>("name")?.try_into()?,
+        repeatability: _from_u32(repeatability),
+        version: from.decode("version")?,
+      },
+      created_on: from.decode("created_on")?,
+      db_ty: DatabaseTy::Mysql,
+      group: MigrationGroup::new(
+        from.decode::<_, &str>("omg_name")?.try_into()?,
+        from.decode("omg_version")?,
+      ),
+      is_code: repeatability == Some(_CODE_REPEATABILITY),
+    })
+  }
+}
+
 #[cfg(feature = "postgres")]
 impl<E> crate::database::FromRecord<crate::database::client::postgres::Postgres<E>> for DbMigration
 where
@@ -66,11 +100,12 @@ where
   #[inline]
   fn from_record(from: &crate::database::client::postgres::Record<'_, E>) -> Result<Self, E> {
     use crate::database::Record as _;
+    let repeatability = from.decode_opt("repeatability")?;
     Ok(Self {
       common: MigrationCommon {
         checksum: _checksum_from_str(from.decode("checksum")?)?,
         name: from.decode::<_, &str>("name")?.try_into()?,
-        repeatability: _from_u32(from.decode_opt("repeatability")?),
+        repeatability: _from_u32(repeatability),
         version: from.decode("version")?,
       },
       created_on: from.decode("created_on")?,
@@ -79,6 +114,34 @@ where
         from.decode::<_, &str>("omg_name")?.try_into()?,
         from.decode("omg_version")?,
       ),
+      is_code: repeatability == Some(_CODE_REPEATABILITY),
+    })
+  }
+}
+
+#[cfg(feature = "sqlite")]
+impl<E> crate::database::FromRecord<crate::database::client::sqlite::Sqlite<E>> for DbMigration
+where
+  E: From<crate::Error>,
+{
+  #[inline]
+  fn from_record(from: &crate::database::client::sqlite::Record<'_, E>) -> Result<Self, E> {
+    use crate::database::Record as _;
+    let repeatability = from.decode_opt("repeatability")?;
+    Ok(Self {
+      common: MigrationCommon {
+        checksum: _checksum_from_str(from.decode("checksum")?)?,
+        name: from.decode::<_, &str>("name")?.try_into()?,
+        repeatability: _from_u32(repeatability),
+        version: from.decode("version")?,
+      },
+      created_on: from.decode("created_on")?,
+      db_ty: DatabaseTy::Sqlite,
+      group: MigrationGroup::new(
+        from.decode::<_, &str>("omg_name")?.try_into()?,
+        from.decode("omg_version")?,
+      ),
+      is_code: repeatability == Some(_CODE_REPEATABILITY),
     })
   }
 }
@@ -104,6 +167,7 @@ fn _fixed_from_naive_utc(naive: NaiveDateTime) -> DateTime<Utc> {
 fn _from_u32(n: Option<u32>) -> Option<Repeatability> {
   match n? {
     0 => Some(Repeatability::Always),
+    _CODE_REPEATABILITY => None,
     _ => Some(Repeatability::OnChecksumChange),
   }
 }
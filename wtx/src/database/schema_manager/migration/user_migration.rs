@@ -26,6 +26,7 @@ pub type UserMigrationRef<'dbs, 'str> = UserMigration<&'dbs [DatabaseTy], &'str
 pub struct UserMigration<DBS, S> {
   common: MigrationCommon<S>,
   dbs: DBS,
+  is_transactional: bool,
   sql_down: S,
   sql_up: S,
 }
@@ -40,6 +41,7 @@ where
   pub const fn from_all_parts(
     checksum: u64,
     dbs: DBS,
+    is_transactional: bool,
     name: S,
     repeatability: Option<Repeatability>,
     sql_down: S,
@@ -49,6 +51,7 @@ where
     Self {
       dbs,
       common: MigrationCommon { checksum, name, repeatability, version },
+      is_transactional,
       sql_down,
       sql_up,
     }
@@ -58,6 +61,7 @@ where
   #[inline]
   pub fn from_user_parts(
     dbs: DBS,
+    is_transactional: bool,
     name: S,
     repeatability: Option<Repeatability>,
     [sql_up, sql_down]: [S; 2],
@@ -68,6 +72,7 @@ where
     Ok(Self {
       dbs,
       common: MigrationCommon { checksum, name, repeatability, version },
+      is_transactional,
       sql_down,
       sql_up,
     })
@@ -101,6 +106,23 @@ where
     self.dbs.lease()
   }
 
+  /// If `false`, the migration is executed or reverted outside of a transaction, which is
+  /// necessary for commands like `CREATE INDEX CONCURRENTLY` or `VACUUM`.
+  ///
+  /// Such migrations should contain a single statement because PostgreSQL executes several
+  /// statements sent at once within an implicit transaction.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use wtx::database::schema_manager::doc_tests::migration;
+  /// assert_eq!(migration().is_transactional(), true)
+  /// ```
+  #[inline]
+  pub fn is_transactional(&self) -> bool {
+    self.is_transactional
+  }
+
   /// Name
   ///
   /// # Example
@@ -14,14 +14,23 @@ use alloc::string::String;
 use std::io::{BufRead, BufReader, Read};
 
 /// Auxiliary parameters of a migration file
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct MigrationCfg {
   /// All unique declared databases
   pub dbs: ArrayVector<DatabaseTy, { DatabaseTy::len() }>,
+  /// Declared transaction mode. Defaults to `true`.
+  pub is_transactional: bool,
   /// Declared repeatability
   pub repeatability: Option<Repeatability>,
 }
 
+impl Default for MigrationCfg {
+  #[inline]
+  fn default() -> Self {
+    Self { dbs: ArrayVector::new(), is_transactional: true, repeatability: None }
+  }
+}
+
 /// In-memory representation of a parsed migration file
 #[derive(Debug, Default)]
 pub struct ParsedMigration {
@@ -64,13 +73,20 @@ where
     iterations(&mut overall_buffer, &mut br, |_| false)?;
   }
 
+  if let Some(rslt) = overall_buffer.split("-- wtx transaction").nth(1) {
+    if let Ok(is_transactional) = rslt.trim().parse() {
+      parsed_migration.cfg.is_transactional = is_transactional;
+    }
+    iterations(&mut overall_buffer, &mut br, |_| false)?;
+  }
+
   if !overall_buffer.contains("-- wtx IN") {
     return Err(SchemaManagerError::IncompleteSqlFile.into());
   }
 
   iterations(&mut overall_buffer, &mut br, |str_read| !str_read.contains("-- wtx OUT"))?;
 
-  if let Some(rslt) = overall_buffer.rsplit("-- wtx OUT").nth(1) {
+  if let Some((rslt, _)) = overall_buffer.rsplit_once("-- wtx OUT") {
     parsed_migration.sql_in = rslt.trim().into();
   } else {
     parsed_migration.sql_in = overall_buffer.trim().into();
@@ -94,12 +110,15 @@ pub(crate) fn parse_migration_toml<R>(read: R) -> crate::Result<MigrationCfg>
 where
   R: Read,
 {
-  let mut migration_toml = MigrationCfg { dbs: ArrayVector::new(), repeatability: None };
+  let mut migration_toml = MigrationCfg::default();
 
-  for (ident, toml_expr) in toml(read)? {
+  for (ident, toml_expr, _) in toml(read)? {
     match (ident.as_str(), toml_expr) {
       ("dbs", Expr::Array(array)) => {
-        for str in array.into_iter() {
+        for expr in array.into_iter() {
+          let Expr::String(str) = expr else {
+            continue;
+          };
           let Ok(elem) = str.as_str().try_into() else {
             continue;
           };
@@ -112,6 +131,9 @@ where
         };
         migration_toml.repeatability = Some(elem);
       }
+      ("transaction", Expr::Bool(elem)) => {
+        migration_toml.is_transactional = elem;
+      }
       _ => {}
     }
   }
@@ -226,6 +248,23 @@ mod tests {
     assert_eq!(on_checksum_change.cfg.repeatability, Some(Repeatability::OnChecksumChange));
   }
 
+  #[test]
+  fn parses_transaction() {
+    let s = "-- wtx IN\nSOMETHING";
+    let no_declaration = parse_unified_migration(s.as_bytes()).unwrap();
+    assert!(no_declaration.cfg.is_transactional);
+
+    let s = "-- wtx transaction FOO\n-- wtx IN\nSOMETHING";
+    let with_incorrect_declaration = parse_unified_migration(s.as_bytes()).unwrap();
+    assert!(with_incorrect_declaration.cfg.is_transactional);
+
+    let s = "-- wtx dbs postgres\n-- wtx transaction false\n-- wtx IN\nSOMETHING";
+    let non_transactional = parse_unified_migration(s.as_bytes()).unwrap();
+    assert_eq!(non_transactional.cfg.dbs[0], DatabaseTy::Postgres);
+    assert!(!non_transactional.cfg.is_transactional);
+    assert_eq!("SOMETHING", non_transactional.sql_in);
+  }
+
   #[test]
   fn parses_mandatory_params() {
     let s = "-- wtx IN\n\nSOMETHING\nFOO";
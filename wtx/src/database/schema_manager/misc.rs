@@ -22,8 +22,8 @@ use core::hash::{Hash, Hasher};
 use {
   crate::{
     database::schema_manager::{
-      toml_parser::{toml, Expr},
-      MigrationGroup, Repeatability, UserMigrationOwned,
+      toml_parser::{toml, Expr, Table},
+      MigrationGroup, Repeatability, TomlErrorKind, UserMigrationOwned,
     },
     misc::{ArrayVector, FromRadix10, Vector},
   },
@@ -41,6 +41,7 @@ type MigrationGroupParts = (String, i32);
 #[cfg(feature = "std")]
 type MigrationParts = (
   ArrayVector<DatabaseTy, { DatabaseTy::len() }>,
+  bool,
   String,
   Option<Repeatability>,
   String,
@@ -90,6 +91,7 @@ where
       let name;
       let version;
       let mut dbs = ArrayVector::new();
+      let mut is_transactional = true;
       let mut repeatability = None;
       let mut sql_down = String::default();
       let mut sql_up = String::default();
@@ -116,6 +118,7 @@ where
           if file_name == cfg_file_name.as_str() {
             let mc = parse_migration_toml(File::open(file_path)?)?;
             dbs = mc.dbs;
+            is_transactional = mc.is_transactional;
             repeatability = mc.repeatability;
           } else if file_name == down_file_name.as_str() {
             sql_down = read_to_string(file_path)?;
@@ -131,13 +134,14 @@ where
         version = parts.1;
         let pm = parse_unified_migration(File::open(local_path)?)?;
         dbs = pm.cfg.dbs;
+        is_transactional = pm.cfg.is_transactional;
         repeatability = pm.cfg.repeatability;
         sql_up = pm.sql_in;
         sql_down = pm.sql_out;
       } else {
         return Err(SchemaManagerError::InvalidMigration.into());
       }
-      Ok((dbs, name, repeatability, sql_down, sql_up, version))
+      Ok((dbs, is_transactional, name, repeatability, sql_down, sql_up, version))
     });
 
     Ok((mg, migrations))
@@ -146,18 +150,80 @@ where
   let ((mg_name, mg_version), ms) = group_and_migrations_from_path(path, cb)?;
   let mg = MigrationGroup::new(mg_name, mg_version);
   let mapped = ms.map(|rslt| {
-    let (dbs, name, repeatability, sql_down, sql_up, version) = rslt?;
-    UserMigrationOwned::from_user_parts(dbs, name, repeatability, [sql_up, sql_down], version)
+    let (dbs, is_transactional, name, repeatability, sql_down, sql_up, version) = rslt?;
+    UserMigrationOwned::from_user_parts(
+      dbs,
+      is_transactional,
+      name,
+      repeatability,
+      [sql_up, sql_down],
+      version,
+    )
   });
   Ok((mg, mapped))
 }
 
+/// Parameters of the root configuration file.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct RootCfg {
+  /// Number of files (migrations or seeds) that is going to be sent to the database in a single
+  /// transaction.
+  pub batch_size: Option<usize>,
+  /// Paths to directories that contain migrations.
+  pub migration_groups: Vector<PathBuf>,
+  /// Database schema that should be used by default.
+  pub schema: Option<String>,
+  /// Path to the directory that contains seeds.
+  pub seeds: Option<PathBuf>,
+}
+
+/// Parses the root configuration file. If `env` is provided, the parameters of the
+/// `[environments.{env}]` section override the root parameters.
+#[cfg(feature = "std")]
+#[inline]
+pub fn parse_root_cfg(cfg_path: &Path, env: Option<&str>) -> crate::Result<RootCfg> {
+  let cfg_dir = cfg_path.parent().unwrap_or_else(|| Path::new("."));
+  parse_root_cfg_raw(File::open(cfg_path)?, cfg_dir, env)
+}
+
+/// Similar to `parse_root_cfg`, takes a stream of bytes and a base path as arguments.
+#[cfg(feature = "std")]
+#[inline]
+pub fn parse_root_cfg_raw<R>(read: R, root: &Path, env: Option<&str>) -> crate::Result<RootCfg>
+where
+  R: Read,
+{
+  let table = toml(read)?;
+  let mut cfg = RootCfg::default();
+  apply_root_params(&mut cfg, &table, true, root)?;
+  if let Some(env) = env {
+    let env_table = table
+      .iter()
+      .find_map(|el| match el {
+        (key, Expr::Table(elem), _) if key == "environments" => Some(elem),
+        _ => None,
+      })
+      .and_then(|envs| {
+        envs.iter().find_map(|el| match el {
+          (key, Expr::Table(elem), _) if key == env => Some(elem),
+          _ => None,
+        })
+      });
+    let Some(elem) = env_table else {
+      return Err(SchemaManagerError::UnknownTomlEnvironment.into());
+    };
+    apply_root_params(&mut cfg, elem, false, root)?;
+  }
+  Ok(cfg)
+}
+
 /// All paths to directories that contain migrations and optional seeds
 #[cfg(feature = "std")]
 #[inline]
 pub fn parse_root_toml(cfg_path: &Path) -> crate::Result<(Vector<PathBuf>, Option<PathBuf>)> {
-  let cfg_dir = cfg_path.parent().unwrap_or_else(|| Path::new("."));
-  parse_root_toml_raw(File::open(cfg_path)?, cfg_dir)
+  let cfg = parse_root_cfg(cfg_path, None)?;
+  Ok((cfg.migration_groups, cfg.seeds))
 }
 
 /// Similar to `parse_root_toml`, takes a stream of bytes and a base path as arguments.
@@ -170,36 +236,8 @@ pub fn parse_root_toml_raw<R>(
 where
   R: Read,
 {
-  let mut migration_groups = Vector::new();
-  let mut seeds = None;
-
-  for (ident, toml_expr) in toml(read)? {
-    match (ident.as_str(), toml_expr) {
-      ("migration_groups", Expr::Array(array)) => {
-        for elem in array.into_iter() {
-          let path = root.join(elem.as_str());
-          let name_opt = || path.file_name()?.to_str();
-          let Some(name) = name_opt() else {
-            continue;
-          };
-          if elem.is_empty() || !path.is_dir() || dir_name_parts(name).is_err() {
-            continue;
-          }
-          migration_groups.push(path)?;
-        }
-      }
-      ("seeds", Expr::String(elem)) => {
-        let path = root.join(elem.as_str());
-        if !path.is_dir() {
-          continue;
-        }
-        seeds = Some(path);
-      }
-      _ => {}
-    }
-  }
-
-  Ok((migration_groups, seeds))
+  let cfg = parse_root_cfg_raw(read, root, None)?;
+  Ok((cfg.migration_groups, cfg.seeds))
 }
 
 #[inline]
@@ -256,6 +294,70 @@ fn binary_search_migration_by_version(
   }
 }
 
+// Migration groups and environments can only be declared at the root level. Sections of all
+// environments are validated, regardless of the selected one.
+#[cfg(feature = "std")]
+#[inline]
+fn apply_root_params(
+  cfg: &mut RootCfg,
+  table: &Table,
+  is_root: bool,
+  root: &Path,
+) -> crate::Result<()> {
+  for (ident, toml_expr, position) in table.iter() {
+    match (ident.as_str(), toml_expr) {
+      ("batch_size", Expr::Integer(elem)) => {
+        let Ok(batch_size @ 1..) = usize::try_from(*elem) else {
+          return Err(position.err(TomlErrorKind::InvalidValue));
+        };
+        cfg.batch_size = Some(batch_size);
+      }
+      ("environments", Expr::Table(envs)) if is_root => {
+        for (_, env, env_position) in envs.iter() {
+          let Expr::Table(elem) = env else {
+            return Err(env_position.err(TomlErrorKind::InvalidValue));
+          };
+          apply_root_params(&mut RootCfg::default(), elem, false, root)?;
+        }
+      }
+      ("migration_groups", Expr::Array(array)) if is_root => {
+        for elem in array.iter() {
+          let Expr::String(elem) = elem else {
+            return Err(position.err(TomlErrorKind::InvalidValue));
+          };
+          let path = root.join(elem.as_str());
+          let name_opt = || path.file_name()?.to_str();
+          let Some(name) = name_opt() else {
+            continue;
+          };
+          if elem.is_empty() || !path.is_dir() || dir_name_parts(name).is_err() {
+            continue;
+          }
+          cfg.migration_groups.push(path)?;
+        }
+      }
+      ("schema", Expr::String(elem)) => {
+        cfg.schema = Some(elem.clone());
+      }
+      ("seeds", Expr::String(elem)) => {
+        let path = root.join(elem.as_str());
+        if !path.is_dir() {
+          continue;
+        }
+        cfg.seeds = Some(path);
+      }
+      ("batch_size" | "schema" | "seeds", _) => {
+        return Err(position.err(TomlErrorKind::InvalidValue));
+      }
+      ("environments" | "migration_groups", _) if is_root => {
+        return Err(position.err(TomlErrorKind::InvalidValue));
+      }
+      _ => return Err(position.err(TomlErrorKind::UnknownKey)),
+    }
+  }
+  Ok(())
+}
+
 #[cfg(feature = "std")]
 #[inline]
 fn dir_name_parts(s: &str) -> crate::Result<(String, i32)> {
@@ -303,3 +405,64 @@ fn migrations_from_dir(path: &Path) -> crate::Result<(MigrationGroupParts, Vecto
 fn read_dir(dir: &Path) -> crate::Result<impl Iterator<Item = crate::Result<DirEntry>>> {
   Ok(std::fs::read_dir(dir)?.map(|entry_rslt| entry_rslt.map_err(Into::into)))
 }
+
+#[cfg(all(feature = "std", test))]
+mod tests {
+  use crate::database::schema_manager::{
+    misc::parse_root_cfg_raw, SchemaManagerError, TomlErrorKind,
+  };
+  use std::path::Path;
+
+  #[test]
+  fn parse_root_cfg_raw_overrides_root_parameters_with_environment_parameters() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../.test-utils");
+    let content = br#"
+      batch_size = 32
+      migration_groups = ["migrations/1__initial"] # Initial
+      schema = "app"
+      seeds = "migrations"
+
+      [environments.dev]
+      batch_size = 4
+      schema = "dev"
+    "#;
+    let cfg = parse_root_cfg_raw(&content[..], &root, None).unwrap();
+    assert_eq!(cfg.batch_size, Some(32));
+    assert_eq!(cfg.migration_groups.len(), 1);
+    assert_eq!(cfg.schema.as_deref(), Some("app"));
+    assert_eq!(cfg.seeds, Some(root.join("migrations")));
+    let cfg = parse_root_cfg_raw(&content[..], &root, Some("dev")).unwrap();
+    assert_eq!(cfg.batch_size, Some(4));
+    assert_eq!(cfg.migration_groups.len(), 1);
+    assert_eq!(cfg.schema.as_deref(), Some("dev"));
+    assert_eq!(cfg.seeds, Some(root.join("migrations")));
+    assert!(matches!(
+      parse_root_cfg_raw(&content[..], &root, Some("prod")),
+      Err(crate::Error::SchemaManagerError(SchemaManagerError::UnknownTomlEnvironment))
+    ));
+  }
+
+  #[test]
+  fn parse_root_cfg_raw_reports_invalid_parameters() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../.test-utils");
+    let err = |content: &str| match parse_root_cfg_raw(content.as_bytes(), &root, None) {
+      Err(crate::Error::SchemaManagerError(SchemaManagerError::InvalidToml {
+        column,
+        kind,
+        line,
+      })) => (line, column, kind),
+      rslt => panic!("{rslt:?}"),
+    };
+    assert_eq!(err("schema = \"app\"\nbatch_size = 0"), (2, 1, TomlErrorKind::InvalidValue));
+    assert_eq!(err("batch_size = -1"), (1, 1, TomlErrorKind::InvalidValue));
+    assert_eq!(err("seeds = 1"), (1, 1, TomlErrorKind::InvalidValue));
+    assert_eq!(err("migration_groups = [1]"), (1, 1, TomlErrorKind::InvalidValue));
+    assert_eq!(err("batch-size = 1"), (1, 1, TomlErrorKind::UnknownKey));
+    assert_eq!(err("environments.dev = 1"), (1, 1, TomlErrorKind::InvalidValue));
+    assert_eq!(
+      err("[environments.dev]\n  migration_groups = []"),
+      (2, 3, TomlErrorKind::UnknownKey)
+    );
+    assert_eq!(err("[environments.prod]\nscheme = \"prod\""), (2, 1, TomlErrorKind::UnknownKey));
+  }
+}
@@ -16,12 +16,78 @@ pub enum SchemaManagerError {
     /// Received
     received: u32,
   },
+  /// A SQL migration and a code migration of the same group have the same version
+  DuplicatedMigrationVersion(i32),
   /// Migration file has invalid syntax,
   InvalidMigration,
-  /// TOML parser only supports a subset of the official TOML specification
-  TomlParserOnlySupportsStringsAndArraysOfStrings,
-  /// TOML parser only supports a subset of the official TOML specification
-  TomlValueIsTooLarge,
+  /// Configuration file isn't a valid TOML document
+  InvalidToml {
+    /// Column of the invalid content, starting at 1
+    column: u32,
+    /// Line of the invalid content, starting at 1
+    line: u32,
+    /// Kind
+    kind: TomlErrorKind,
+  },
+  /// The database-level lock held by another instance wasn't released within the configured
+  /// timeout
+  LockTimeout,
+  /// Commands that compare schemas must be provided with a scratch database
+  MissingScratchDatabase,
+  /// The schema of the database differs from the schema produced by the migrations
+  SchemaHasDrifted,
+  /// Schema snapshots are only supported by PostgreSQL
+  SnapshotIsOnlySupportedByPostgres,
+  /// Code migrations recorded in the database can only be reverted by
+  /// [`crate::database::schema_manager::Commands::rollback_with_code`]
+  UnrevertibleCodeMigration(i32),
+  /// The requested section doesn't exist in the `environments` table of the configuration file
+  UnknownTomlEnvironment,
   /// Migration file has an empty attribute
   IncompleteSqlFile,
 }
+
+/// Cause of a [`SchemaManagerError::InvalidToml`] error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TomlErrorKind {
+  /// Comments can't contain control characters
+  ControlCharacter,
+  /// Key defined more than once
+  DuplicatedKey,
+  /// Table defined more than once
+  DuplicatedTable,
+  /// Keys must be followed by `=`
+  ExpectedEquals,
+  /// Expected a bare or quoted key
+  ExpectedKey,
+  /// Key/value pairs and table headers must be followed by a new line
+  ExpectedNewLine,
+  /// Keys must be followed by values
+  ExpectedValue,
+  /// Inline tables can't be extended by dotted keys or table headers
+  ExtendedInlineTable,
+  /// Invalid date-time
+  InvalidDateTime,
+  /// Invalid escape sequence or unicode scalar value
+  InvalidEscape,
+  /// Invalid number, unknown value or value not accepted by a configuration parameter
+  InvalidValue,
+  /// Dotted key or table header extends a value that isn't a table
+  NotATable,
+  /// Arrays declared through values can't be extended by arrays of tables
+  StaticArray,
+  /// Arrays or inline tables nested beyond the supported depth
+  TooDeep,
+  /// Multi-line strings can't end with more than five quotes
+  TooManyQuotes,
+  /// Key isn't a parameter of the configuration file
+  UnknownKey,
+  /// Array elements must be separated by `,` and arrays must end with `]`
+  UnterminatedArray,
+  /// Inline table elements must be separated by `,` and inline tables must end with `}`
+  UnterminatedInlineTable,
+  /// String without a closing delimiter
+  UnterminatedString,
+  /// Table header without a closing `]`
+  UnterminatedTableHeader,
+}
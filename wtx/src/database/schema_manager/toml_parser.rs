@@ -1,222 +1,962 @@
 //! Migration TOML parser
 
 use crate::{
-  database::schema_manager::SchemaManagerError,
-  misc::{str_split1, ArrayString, ArrayVector},
+  database::schema_manager::{SchemaManagerError, TomlErrorKind},
+  misc::Vector,
 };
 use alloc::string::String;
-use std::io::{BufRead, BufReader, Read};
+use core::iter;
+use std::io::Read;
 
-pub(crate) const EXPR_ARRAY_MAX_LEN: usize = 8;
+// Prevents stack overflows caused by deeply nested arrays or inline tables.
+const MAX_DEPTH: u8 = 32;
 
-pub(crate) type ExprArrayTy = ArrayVector<ExprStringTy, EXPR_ARRAY_MAX_LEN>;
-pub(crate) type ExprStringTy = ArrayString<128>;
-pub(crate) type IdentTy = ArrayString<64>;
-pub(crate) type RootParamsTy = ArrayVector<(IdentTy, Expr), 2>;
+pub(crate) type Table = Vector<(String, Expr, Position)>;
 
-#[expect(clippy::large_enum_variant, reason = "work in progress")]
 #[derive(Debug, PartialEq)]
 pub(crate) enum Expr {
-  Array(ExprArrayTy),
-  String(ExprStringTy),
+  Array(Vector<Expr>),
+  Bool(bool),
+  /// Offset date-time, local date-time, local date or local time in its original form.
+  DateTime(String),
+  Float(f64),
+  Integer(i64),
+  String(String),
+  Table(Table),
 }
 
+/// Line and column of the key that declared an entry, starting at 1.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Position {
+  pub(crate) column: u32,
+  pub(crate) line: u32,
+}
+
+impl Position {
+  #[inline]
+  pub(crate) fn err(self, kind: TomlErrorKind) -> crate::Error {
+    SchemaManagerError::InvalidToml { column: self.column, line: self.line, kind }.into()
+  }
+}
+
+/// Parses the whole content of `read` according to the TOML v1.0.0 specification. Entries are
+/// returned in their declaration order.
 #[inline]
-pub(crate) fn toml<R>(read: R) -> crate::Result<RootParamsTy>
+pub(crate) fn toml<R>(mut read: R) -> crate::Result<Table>
 where
   R: Read,
 {
-  let mut br = BufReader::new(read);
-  let mut is_in_array_context = None;
-  let mut buffer = String::new();
-  let mut root_params = ArrayVector::new();
-
-  macro_rules! clear_and_continue {
-    () => {
-      buffer.clear();
-      continue;
+  let mut input = String::new();
+  let _ = read.read_to_string(&mut input)?;
+  Parser {
+    bytes: input.as_bytes(),
+    depth: 0,
+    idx: 0,
+    input: &input,
+    line: 1,
+    line_start: 0,
+    scanned: 0,
+  }
+  .parse()
+}
+
+struct Parser<'input> {
+  bytes: &'input [u8],
+  depth: u8,
+  idx: usize,
+  input: &'input str,
+  // Lines of entries are counted incrementally up to `scanned`.
+  line: u32,
+  line_start: usize,
+  scanned: usize,
+}
+
+impl<'input> Parser<'input> {
+  fn parse(mut self) -> crate::Result<Table> {
+    let mut current = Vector::<String>::new();
+    let mut defined = Vector::<Vector<String>>::new();
+    let mut inline = Vector::<Vector<String>>::new();
+    let mut root = Table::new();
+    loop {
+      self.skip_trivia()?;
+      let Some(byte) = self.peek() else {
+        break;
+      };
+      if byte == b'[' {
+        self.advance(1);
+        let is_array = self.eat(b'[');
+        self.skip_ws();
+        let position = self.position();
+        let keys = self.parse_key()?;
+        self.skip_ws();
+        if !self.eat(b']') || (is_array && !self.eat(b']')) {
+          return Err(self.err(TomlErrorKind::UnterminatedTableHeader));
+        }
+        self.check_inline(&inline, keys.iter())?;
+        if is_array {
+          self.push_array_table(&mut root, &keys, position)?;
+          defined.retain(|el| !el.starts_with(keys.as_slice()));
+          inline.retain(|el| !el.starts_with(keys.as_slice()));
+        } else {
+          if defined.iter().any(|el| el.as_slice() == keys.as_slice()) {
+            return Err(self.err(TomlErrorKind::DuplicatedTable));
+          }
+          let _ = self.table_mut(&mut root, &keys, true, position)?;
+          defined.push(keys.clone())?;
+        }
+        self.expect_eol()?;
+        current = keys;
+      } else {
+        let position = self.position();
+        let keys = self.parse_key()?;
+        self.skip_ws();
+        if !self.eat(b'=') {
+          return Err(self.err(TomlErrorKind::ExpectedEquals));
+        }
+        self.skip_ws();
+        let expr = self.parse_expr()?;
+        let parents = keys.get(..keys.len().wrapping_sub(1)).unwrap_or_default();
+        self.check_inline(&inline, current.iter().chain(parents))?;
+        if matches!(expr, Expr::Table(_)) {
+          let mut path = current.clone();
+          for key in keys.iter() {
+            path.push(key.clone())?;
+          }
+          inline.push(path)?;
+        }
+        let table = self.table_mut(&mut root, &current, false, position)?;
+        self.insert(table, &keys, expr, position)?;
+        self.expect_eol()?;
+      }
+    }
+    Ok(root)
+  }
+
+  fn advance(&mut self, n: usize) {
+    self.idx = self.idx.wrapping_add(n);
+  }
+
+  // Inline tables are self-contained, as such, dotted keys and table headers can't navigate
+  // through them.
+  fn check_inline<'path>(
+    &self,
+    inline: &[Vector<String>],
+    path: impl Clone + Iterator<Item = &'path String>,
+  ) -> crate::Result<()> {
+    let is_extended = inline.iter().any(|el| {
+      let mut iter = path.clone();
+      el.iter().all(|key| iter.next() == Some(key))
+    });
+    if is_extended {
+      return Err(self.err(TomlErrorKind::ExtendedInlineTable));
+    }
+    Ok(())
+  }
+
+  fn eat(&mut self, byte: u8) -> bool {
+    let is_eq = self.peek() == Some(byte);
+    if is_eq {
+      self.advance(1);
+    }
+    is_eq
+  }
+
+  fn eat_newline(&mut self) -> bool {
+    if self.eat(b'\n') {
+      return true;
+    }
+    let is_crlf = self.rest().starts_with(b"\r\n");
+    if is_crlf {
+      self.advance(2);
+    }
+    is_crlf
+  }
+
+  fn eat_str(&mut self, value: &str) -> bool {
+    let is_eq = self.rest().starts_with(value.as_bytes());
+    if is_eq {
+      self.advance(value.len());
+    }
+    is_eq
+  }
+
+  // Line and column are calculated on demand because errors are rare.
+  fn err(&self, kind: TomlErrorKind) -> crate::Error {
+    let before = self.input.get(..self.idx).unwrap_or(self.input);
+    let line = before.bytes().filter(|el| *el == b'\n').count().wrapping_add(1);
+    let line_start = before.rfind('\n').map_or(0, |el| el.wrapping_add(1));
+    Position { column: self.column(line_start), line: u32::try_from(line).unwrap_or(u32::MAX) }
+      .err(kind)
+  }
+
+  fn column(&self, line_start: usize) -> u32 {
+    let column = self.input.get(line_start..self.idx).unwrap_or_default().chars().count();
+    u32::try_from(column.wrapping_add(1)).unwrap_or(u32::MAX)
+  }
+
+  fn expect_eol(&mut self) -> crate::Result<()> {
+    self.skip_ws();
+    self.skip_comment()?;
+    if self.peek().is_some() && !self.eat_newline() {
+      return Err(self.err(TomlErrorKind::ExpectedNewLine));
+    }
+    Ok(())
+  }
+
+  fn insert(
+    &self,
+    mut table: &mut Table,
+    keys: &[String],
+    expr: Expr,
+    position: Position,
+  ) -> crate::Result<()> {
+    let [parents @ .., last] = keys else {
+      return Err(self.err(TomlErrorKind::ExpectedKey));
     };
+    for key in parents {
+      let idx = match table.iter().position(|el| &el.0 == key) {
+        Some(elem) => elem,
+        None => {
+          table.push((key.clone(), Expr::Table(Table::new()), position))?;
+          table.len().wrapping_sub(1)
+        }
+      };
+      let Some((_, Expr::Table(elem), _)) = table.get_mut(idx) else {
+        return Err(self.err(TomlErrorKind::NotATable));
+      };
+      table = elem;
+    }
+    if table.iter().any(|el| &el.0 == last) {
+      return Err(self.err(TomlErrorKind::DuplicatedKey));
+    }
+    table.push((last.clone(), expr, position))?;
+    Ok(())
   }
 
-  loop {
-    if br.read_line(&mut buffer)? == 0 {
-      break;
+  fn nest(&mut self, cb: fn(&mut Self) -> crate::Result<Expr>) -> crate::Result<Expr> {
+    if self.depth >= MAX_DEPTH {
+      return Err(self.err(TomlErrorKind::TooDeep));
     }
+    self.depth = self.depth.wrapping_add(1);
+    let rslt = cb(self);
+    self.depth = self.depth.wrapping_sub(1);
+    rslt
+  }
 
-    let buffer_ref = buffer.trim();
+  fn parse_array(&mut self) -> crate::Result<Expr> {
+    let mut array = Vector::new();
+    loop {
+      self.skip_trivia()?;
+      if self.eat(b']') {
+        break;
+      }
+      array.push(self.parse_expr()?)?;
+      self.skip_trivia()?;
+      if self.eat(b',') {
+        continue;
+      }
+      if self.eat(b']') {
+        break;
+      }
+      return Err(self.err(TomlErrorKind::UnterminatedArray));
+    }
+    Ok(Expr::Array(array))
+  }
 
-    if buffer_ref.starts_with('#') {
-      clear_and_continue!();
+  fn parse_basic_string(&mut self, is_multiline: bool) -> crate::Result<String> {
+    let mut rslt = String::new();
+    if is_multiline {
+      let _ = self.eat_newline();
     }
+    loop {
+      let start = self.idx;
+      while let Some(byte) = self.peek() {
+        if matches!(byte, b'"' | b'\\' | b'\n') || (byte == b'\r' && !is_multiline) {
+          break;
+        }
+        self.advance(1);
+      }
+      rslt.push_str(self.input.get(start..self.idx).unwrap_or_default());
+      match self.peek() {
+        None | Some(b'\n' | b'\r') if !is_multiline => {
+          return Err(self.err(TomlErrorKind::UnterminatedString));
+        }
+        None => return Err(self.err(TomlErrorKind::UnterminatedString)),
+        Some(b'\n') => {
+          rslt.push('\n');
+          self.advance(1);
+        }
+        Some(b'\\') => {
+          self.advance(1);
+          self.parse_escape(is_multiline, &mut rslt)?;
+        }
+        Some(_) => {
+          if !is_multiline {
+            self.advance(1);
+            return Ok(rslt);
+          }
+          let quotes = self.quotes_run(b'"');
+          self.advance(quotes);
+          if let Some(extra) = quotes.checked_sub(3) {
+            // Up to two additional quotes are part of the content of multi-line strings.
+            if extra > 2 {
+              return Err(self.err(TomlErrorKind::TooManyQuotes));
+            }
+            rslt.extend(iter::repeat('"').take(extra));
+            return Ok(rslt);
+          }
+          rslt.extend(iter::repeat('"').take(quotes));
+        }
+      }
+    }
+  }
 
-    if let Some(ident) = is_in_array_context.as_ref() {
-      if buffer_ref.ends_with(']') {
-        let inner = buffer_ref.get(0..buffer_ref.len().saturating_sub(1)).unwrap_or_default();
-        parse_and_push_toml_expr_array(inner, *ident, &mut root_params)?;
-        is_in_array_context = None;
-        buffer.clear();
+  fn parse_escape(&mut self, is_multiline: bool, rslt: &mut String) -> crate::Result<()> {
+    let Some(byte) = self.peek() else {
+      return Err(self.err(TomlErrorKind::UnterminatedString));
+    };
+    match byte {
+      b'"' => rslt.push('"'),
+      b'\\' => rslt.push('\\'),
+      b'b' => rslt.push('\u{8}'),
+      b'f' => rslt.push('\u{c}'),
+      b'n' => rslt.push('\n'),
+      b'r' => rslt.push('\r'),
+      b't' => rslt.push('\t'),
+      b'u' | b'U' => {
+        let len = if byte == b'u' { 4 } else { 8 };
+        let hex =
+          self.input.get(self.idx.wrapping_add(1)..self.idx.wrapping_add(len).wrapping_add(1));
+        let Some(elem) =
+          hex.and_then(|el| u32::from_str_radix(el, 16).ok()).and_then(char::from_u32)
+        else {
+          return Err(self.err(TomlErrorKind::InvalidEscape));
+        };
+        rslt.push(elem);
+        self.advance(len);
       }
-      continue;
+      b' ' | b'\t' | b'\r' | b'\n' if is_multiline => {
+        // Line ending backslashes trim all whitespaces and new lines.
+        self.skip_ws();
+        if !self.eat_newline() {
+          return Err(self.err(TomlErrorKind::InvalidEscape));
+        }
+        while self.peek().is_some_and(|el| matches!(el, b' ' | b'\t' | b'\n' | b'\r')) {
+          self.advance(1);
+        }
+        return Ok(());
+      }
+      _ => return Err(self.err(TomlErrorKind::InvalidEscape)),
     }
+    self.advance(1);
+    Ok(())
+  }
 
-    let mut root_param_iter = buffer_ref.split('=');
+  fn parse_expr(&mut self) -> crate::Result<Expr> {
+    match self.peek() {
+      Some(b'"') => {
+        let is_multiline = self.eat_str(r#"""""#);
+        if !is_multiline {
+          self.advance(1);
+        }
+        Ok(Expr::String(self.parse_basic_string(is_multiline)?))
+      }
+      Some(b'\'') => {
+        let is_multiline = self.eat_str("'''");
+        if !is_multiline {
+          self.advance(1);
+        }
+        Ok(Expr::String(self.parse_literal_string(is_multiline)?))
+      }
+      Some(b'[') => {
+        self.advance(1);
+        self.nest(Self::parse_array)
+      }
+      Some(b'{') => {
+        self.advance(1);
+        self.nest(Self::parse_inline_table)
+      }
+      Some(_) => {
+        if self.eat_str("true") {
+          return Ok(Expr::Bool(true));
+        }
+        if self.eat_str("false") {
+          return Ok(Expr::Bool(false));
+        }
+        self.parse_number_or_date_time()
+      }
+      None => Err(self.err(TomlErrorKind::ExpectedValue)),
+    }
+  }
+
+  fn parse_inline_table(&mut self) -> crate::Result<Expr> {
+    let mut inline = Vector::<Vector<String>>::new();
+    let mut table = Table::new();
+    self.skip_ws();
+    if self.eat(b'}') {
+      return Ok(Expr::Table(table));
+    }
+    loop {
+      self.skip_ws();
+      let position = self.position();
+      let keys = self.parse_key()?;
+      self.skip_ws();
+      if !self.eat(b'=') {
+        return Err(self.err(TomlErrorKind::ExpectedEquals));
+      }
+      self.skip_ws();
+      let expr = self.parse_expr()?;
+      self
+        .check_inline(&inline, keys.get(..keys.len().wrapping_sub(1)).unwrap_or_default().iter())?;
+      if matches!(expr, Expr::Table(_)) {
+        inline.push(keys.clone())?;
+      }
+      self.insert(&mut table, &keys, expr, position)?;
+      self.skip_ws();
+      if self.eat(b',') {
+        continue;
+      }
+      if self.eat(b'}') {
+        break;
+      }
+      return Err(self.err(TomlErrorKind::UnterminatedInlineTable));
+    }
+    Ok(Expr::Table(table))
+  }
+
+  fn parse_key(&mut self) -> crate::Result<Vector<String>> {
+    let mut keys = Vector::new();
+    loop {
+      let key = match self.peek() {
+        Some(b'"') => {
+          self.advance(1);
+          self.parse_basic_string(false)?
+        }
+        Some(b'\'') => {
+          self.advance(1);
+          self.parse_literal_string(false)?
+        }
+        _ => {
+          let start = self.idx;
+          while self
+            .peek()
+            .is_some_and(|el| el.is_ascii_alphanumeric() || matches!(el, b'_' | b'-'))
+          {
+            self.advance(1);
+          }
+          if start == self.idx {
+            return Err(self.err(TomlErrorKind::ExpectedKey));
+          }
+          self.input.get(start..self.idx).unwrap_or_default().into()
+        }
+      };
+      keys.push(key)?;
+      self.skip_ws();
+      if !self.eat(b'.') {
+        return Ok(keys);
+      }
+      self.skip_ws();
+    }
+  }
 
-    let ident = if let Some(el) = root_param_iter.next() {
-      el.trim().try_into().map_err(|_err| SchemaManagerError::TomlValueIsTooLarge)?
-    } else {
-      clear_and_continue!();
+  fn parse_literal_string(&mut self, is_multiline: bool) -> crate::Result<String> {
+    if is_multiline {
+      let _ = self.eat_newline();
+    }
+    let (delimiter, delimiter_len) = if is_multiline { ("'''", 3) } else { ("'", 1) };
+    let start = self.idx;
+    let rest = self.input.get(start..).unwrap_or_default();
+    let Some(len) = rest.find(delimiter) else {
+      return Err(self.err(TomlErrorKind::UnterminatedString));
     };
+    let mut content = rest.get(..len).unwrap_or_default();
+    if let (false, Some(newline)) = (is_multiline, content.find(['\n', '\r'])) {
+      self.advance(newline);
+      return Err(self.err(TomlErrorKind::UnterminatedString));
+    }
+    self.advance(len);
+    // Up to two additional quotes are part of the content of multi-line strings.
+    let quotes = self.quotes_run(b'\'');
+    let extra = if is_multiline { quotes.wrapping_sub(delimiter_len) } else { 0 };
+    if extra > 2 {
+      return Err(self.err(TomlErrorKind::TooManyQuotes));
+    }
+    content = rest.get(..len.wrapping_add(extra)).unwrap_or_default();
+    self.advance(extra.wrapping_add(delimiter_len));
+    Ok(content.into())
+  }
 
-    let expr_raw = if let Some(el) = root_param_iter.next() {
-      el.trim()
-    } else {
-      clear_and_continue!();
+  fn parse_number_or_date_time(&mut self) -> crate::Result<Expr> {
+    let start = self.idx;
+    self.skip_token();
+    let token = self.input.get(start..self.idx).unwrap_or_default();
+    let bytes = token.as_bytes();
+    let is_date = bytes.get(4) == Some(&b'-') && bytes.get(7) == Some(&b'-');
+    let is_time = bytes.get(2) == Some(&b':');
+    if is_date || is_time {
+      // A space can also separate the date from the time.
+      if is_date
+        && token.len() == 10
+        && self.peek() == Some(b' ')
+        && self.bytes.get(self.idx.wrapping_add(1)).is_some_and(u8::is_ascii_digit)
+      {
+        self.advance(1);
+        self.skip_token();
+      }
+      let value = self.input.get(start..self.idx).unwrap_or_default();
+      if !value.bytes().all(|el| el.is_ascii_digit() || b"-:.+TtZz ".contains(&el)) {
+        self.idx = start;
+        return Err(self.err(TomlErrorKind::InvalidDateTime));
+      }
+      return Ok(Expr::DateTime(value.into()));
+    }
+    let (is_negative, unsigned) = match bytes.first() {
+      Some(b'+') => (false, token.get(1..).unwrap_or_default()),
+      Some(b'-') => (true, token.get(1..).unwrap_or_default()),
+      _ => (false, token),
+    };
+    let Some(expr) = number(is_negative, token.len() != unsigned.len(), unsigned) else {
+      self.idx = start;
+      return Err(self.err(TomlErrorKind::InvalidValue));
     };
+    Ok(expr)
+  }
 
-    if expr_raw.starts_with('[') {
-      if expr_raw.ends_with(']') {
-        let inner = expr_raw.get(1..expr_raw.len().saturating_sub(1)).unwrap_or_default();
-        parse_and_push_toml_expr_array(inner, ident, &mut root_params)?;
-      } else {
-        is_in_array_context = Some(ident);
+  fn peek(&self) -> Option<u8> {
+    self.bytes.get(self.idx).copied()
+  }
+
+  // Entries are counted from the last scanned index because positions only move forward.
+  fn position(&mut self) -> Position {
+    let chunk = self.bytes.get(self.scanned..self.idx).unwrap_or_default();
+    for (offset, byte) in chunk.iter().enumerate() {
+      if *byte == b'\n' {
+        self.line = self.line.wrapping_add(1);
+        self.line_start = self.scanned.wrapping_add(offset).wrapping_add(1);
       }
-    } else {
-      parse_and_push_toml_expr_string(expr_raw, ident, &mut root_params)?;
     }
+    self.scanned = self.idx;
+    Position { column: self.column(self.line_start), line: self.line }
+  }
 
-    buffer.clear();
+  fn push_array_table(
+    &self,
+    root: &mut Table,
+    keys: &[String],
+    position: Position,
+  ) -> crate::Result<()> {
+    let [parents @ .., last] = keys else {
+      return Err(self.err(TomlErrorKind::ExpectedKey));
+    };
+    let table = self.table_mut(root, parents, false, position)?;
+    match table.iter_mut().find(|el| &el.0 == last) {
+      Some((_, Expr::Array(array), _)) => {
+        if array.iter().any(|el| !matches!(el, Expr::Table(_))) {
+          return Err(self.err(TomlErrorKind::StaticArray));
+        }
+        array.push(Expr::Table(Table::new()))?;
+      }
+      Some(_) => return Err(self.err(TomlErrorKind::DuplicatedKey)),
+      None => {
+        let mut array = Vector::new();
+        array.push(Expr::Table(Table::new()))?;
+        table.push((last.clone(), Expr::Array(array), position))?;
+      }
+    }
+    Ok(())
   }
 
-  Ok(root_params)
-}
+  // Number of consecutive `byte`s starting at the current position.
+  fn quotes_run(&self, byte: u8) -> usize {
+    self.rest().iter().take_while(|el| **el == byte).count()
+  }
 
-#[inline]
-fn parse_and_push_toml_expr_array(
-  s: &str,
-  ident: IdentTy,
-  root_params: &mut RootParamsTy,
-) -> crate::Result<()> {
-  let expr_array = parse_expr_array(s)?;
-  root_params
-    .push((ident, Expr::Array(expr_array)))
-    .map_err(|_err| SchemaManagerError::TomlValueIsTooLarge)?;
-  Ok(())
-}
+  fn rest(&self) -> &'input [u8] {
+    self.bytes.get(self.idx..).unwrap_or_default()
+  }
 
-#[inline]
-fn parse_and_push_toml_expr_string(
-  s: &str,
-  ident: IdentTy,
-  root_params: &mut RootParamsTy,
-) -> crate::Result<()> {
-  let expr_string = parse_expr_string(s)?;
-  root_params
-    .push((ident, Expr::String(expr_string)))
-    .map_err(|_err| SchemaManagerError::TomlValueIsTooLarge)?;
-  Ok(())
-}
+  fn skip_comment(&mut self) -> crate::Result<()> {
+    if self.peek() != Some(b'#') {
+      return Ok(());
+    }
+    while let Some(byte) = self.peek() {
+      if byte == b'\n' || self.rest().starts_with(b"\r\n") {
+        break;
+      }
+      if (byte < 0x20 && byte != b'\t') || byte == 0x7f {
+        return Err(self.err(TomlErrorKind::ControlCharacter));
+      }
+      self.advance(1);
+    }
+    Ok(())
+  }
 
-#[inline]
-fn parse_expr_array(s: &str) -> crate::Result<ExprArrayTy> {
-  let mut array = ArrayVector::new();
-  if s.is_empty() {
-    return Ok(array);
+  fn skip_token(&mut self) {
+    while self.peek().is_some_and(|el| {
+      el.is_ascii_alphanumeric() || matches!(el, b'_' | b'+' | b'-' | b'.' | b':')
+    }) {
+      self.advance(1);
+    }
   }
-  for elem in str_split1(s, b',') {
-    let expr_string = parse_expr_string(elem.trim())?;
-    array.push(expr_string).map_err(|_err| SchemaManagerError::TomlValueIsTooLarge)?;
+
+  // Whitespaces, comments and new lines
+  fn skip_trivia(&mut self) -> crate::Result<()> {
+    loop {
+      self.skip_ws();
+      self.skip_comment()?;
+      if !self.eat_newline() {
+        return Ok(());
+      }
+    }
+  }
+
+  fn skip_ws(&mut self) {
+    while self.peek().is_some_and(|el| el == b' ' || el == b'\t') {
+      self.advance(1);
+    }
+  }
+
+  // Navigates through `keys`, creating missing tables. The last element is used when an array of
+  // tables is found in the middle of the path.
+  fn table_mut<'table>(
+    &self,
+    mut table: &'table mut Table,
+    keys: &[String],
+    is_header: bool,
+    position: Position,
+  ) -> crate::Result<&'table mut Table> {
+    for (idx, key) in keys.iter().enumerate() {
+      let entry_idx = match table.iter().position(|el| &el.0 == key) {
+        Some(elem) => elem,
+        None => {
+          table.push((key.clone(), Expr::Table(Table::new()), position))?;
+          table.len().wrapping_sub(1)
+        }
+      };
+      let is_last = idx.wrapping_add(1) == keys.len();
+      table = match table.get_mut(entry_idx) {
+        Some((_, Expr::Table(elem), _)) => elem,
+        Some((_, Expr::Array(array), _)) if !(is_header && is_last) => match array.last_mut() {
+          Some(Expr::Table(elem)) => elem,
+          _ => return Err(self.err(TomlErrorKind::DuplicatedKey)),
+        },
+        _ => return Err(self.err(TomlErrorKind::DuplicatedKey)),
+      };
+    }
+    Ok(table)
   }
-  Ok(array)
 }
 
-#[inline]
-fn parse_expr_string(s: &str) -> crate::Result<ExprStringTy> {
-  let mut iter = str_split1(s, b'"');
-  let _ = iter.next().ok_or(SchemaManagerError::TomlParserOnlySupportsStringsAndArraysOfStrings)?;
-  let value =
-    iter.next().ok_or(SchemaManagerError::TomlParserOnlySupportsStringsAndArraysOfStrings)?;
-  let _ = iter.next().ok_or(SchemaManagerError::TomlParserOnlySupportsStringsAndArraysOfStrings)?;
-  if iter.next().is_some() {
-    return Err(SchemaManagerError::TomlParserOnlySupportsStringsAndArraysOfStrings.into());
-  }
-  Ok(value.trim().try_into().map_err(|_err| SchemaManagerError::TomlValueIsTooLarge)?)
+// Underscores must be surrounded by digits.
+fn are_underscores_valid(value: &str, is_digit: fn(&u8) -> bool) -> bool {
+  let bytes = value.as_bytes();
+  bytes.iter().enumerate().all(|(idx, byte)| {
+    *byte != b'_'
+      || (idx.checked_sub(1).and_then(|el| bytes.get(el)).is_some_and(is_digit)
+        && bytes.get(idx.wrapping_add(1)).is_some_and(is_digit))
+  })
+}
+
+fn number(is_negative: bool, has_sign: bool, unsigned: &str) -> Option<Expr> {
+  match unsigned {
+    "inf" => return Some(Expr::Float(if is_negative { f64::NEG_INFINITY } else { f64::INFINITY })),
+    "nan" => return Some(Expr::Float(f64::NAN)),
+    _ => {}
+  }
+  for (prefix, radix) in [("0b", 2), ("0o", 8), ("0x", 16)] {
+    if let Some(digits) = unsigned.strip_prefix(prefix) {
+      if has_sign || !are_underscores_valid(digits, u8::is_ascii_hexdigit) {
+        return None;
+      }
+      return i64::from_str_radix(&digits.replace('_', ""), radix).ok().map(Expr::Integer);
+    }
+  }
+  if !are_underscores_valid(unsigned, u8::is_ascii_digit)
+    || !unsigned.bytes().all(|el| el.is_ascii_digit() || b"_.eE+-".contains(&el))
+  {
+    return None;
+  }
+  let clean = unsigned.replace('_', "");
+  let integer_part = clean.split(['.', 'e', 'E']).next().unwrap_or_default();
+  if integer_part.is_empty() || (integer_part.len() > 1 && integer_part.starts_with('0')) {
+    return None;
+  }
+  let is_float = clean.contains(['.', 'e', 'E']);
+  if let Some((_, fraction)) = clean.split_once('.') {
+    if !fraction.starts_with(|el: char| el.is_ascii_digit()) {
+      return None;
+    }
+  }
+  let signed = if is_negative { alloc::format!("-{clean}") } else { clean };
+  if is_float {
+    signed.parse().ok().map(Expr::Float)
+  } else {
+    signed.parse().ok().map(Expr::Integer)
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use crate::{
-    database::schema_manager::toml_parser::{toml, Expr, ExprArrayTy},
-    misc::ArrayVector,
+    database::schema_manager::{
+      toml_parser::{toml, Expr, Position, Table},
+      SchemaManagerError, TomlErrorKind,
+    },
+    misc::Vector,
   };
+  use alloc::{string::String, vec::Vec};
+
+  fn array<const N: usize>(elems: [Expr; N]) -> Expr {
+    Expr::Array(Vector::from(Vec::from(elems)))
+  }
+
+  fn entry(key: &str, expr: Expr) -> (String, Expr, Position) {
+    (key.into(), expr, Position::default())
+  }
+
+  fn string(value: &str) -> Expr {
+    Expr::String(value.into())
+  }
+
+  fn table<const N: usize>(elems: [(&str, Expr); N]) -> Expr {
+    Expr::Table(Vector::from(
+      elems.into_iter().map(|(key, expr)| entry(key, expr)).collect::<Vec<_>>(),
+    ))
+  }
+
+  fn toml_err(input: &str) -> (u32, u32, TomlErrorKind) {
+    match toml(input.as_bytes()) {
+      Err(crate::Error::SchemaManagerError(SchemaManagerError::InvalidToml {
+        column,
+        kind,
+        line,
+      })) => (line, column, kind),
+      rslt => panic!("{rslt:?}"),
+    }
+  }
+
+  fn toml_ok(input: &str) -> Table {
+    toml(input.as_bytes()).unwrap()
+  }
+
+  // Entries built by `entry` have default positions.
+  fn without_positions(mut table: Table) -> Table {
+    fn strip(expr: &mut Expr) {
+      match expr {
+        Expr::Array(elems) => elems.iter_mut().for_each(strip),
+        Expr::Table(table) => {
+          for (_, local_expr, position) in table.iter_mut() {
+            *position = Position::default();
+            strip(local_expr);
+          }
+        }
+        _ => {}
+      }
+    }
+    for (_, expr, position) in table.iter_mut() {
+      *position = Position::default();
+      strip(expr);
+    }
+    table
+  }
 
   #[test]
   fn toml_parses_root_parameter_array_in_a_single_line() {
-    let array = toml(
-      &br#"
+    let array = without_positions(
+      toml(
+        &br#"
     foo = ["1", "2"]
 
     bar=[]
     "#[..],
-    )
-    .unwrap();
-    assert_eq!(
-      array[0],
-      (
-        "foo".try_into().unwrap(),
-        Expr::Array({
-          let mut elems = ArrayVector::new();
-          elems.push("1".try_into().unwrap()).unwrap();
-          elems.push("2".try_into().unwrap()).unwrap();
-          elems
-        })
       )
+      .unwrap(),
     );
-    assert_eq!(array[1], ("bar".try_into().unwrap(), Expr::Array(ExprArrayTy::default())));
+    assert_eq!(array[0], entry("foo", self::array([string("1"), string("2")])));
+    assert_eq!(array[1], entry("bar", self::array([])));
   }
 
   #[test]
   fn toml_parses_root_parameter_array_in_multiple_lines() {
-    let array = toml(
-      &br#"
+    let array = without_positions(
+      toml(
+        &br#"
     foo=[
       "1",
       "2",
       "3"
     ]
     "#[..],
-    )
-    .unwrap();
-    assert_eq!(
-      array[0],
-      (
-        "foo".try_into().unwrap(),
-        Expr::Array({
-          let mut elems = ArrayVector::new();
-          elems.push("1".try_into().unwrap()).unwrap();
-          elems.push("2".try_into().unwrap()).unwrap();
-          elems.push("3".try_into().unwrap()).unwrap();
-          elems
-        })
       )
+      .unwrap(),
     );
+    assert_eq!(array[0], entry("foo", self::array([string("1"), string("2"), string("3")])));
+  }
+
+  #[test]
+  fn toml_parses_root_parameter_bool() {
+    let array = without_positions(toml(&b"foo = false\nbar=true"[..]).unwrap());
+    assert_eq!(array[0], entry("foo", Expr::Bool(false)));
+    assert_eq!(array[1], entry("bar", Expr::Bool(true)));
   }
 
   #[test]
   fn toml_parses_root_parameter_string() {
-    let array = toml(&br#"foo="bar""#[..]).unwrap();
-    assert_eq!(array[0], ("foo".try_into().unwrap(), Expr::String("bar".try_into().unwrap())));
+    let array = without_positions(toml(&br#"foo="bar""#[..]).unwrap());
+    assert_eq!(array[0], entry("foo", string("bar")));
   }
 
   #[test]
   fn toml_ignores_comments() {
-    let array = toml(
-      &br#"
+    let array = without_positions(
+      toml(
+        &br#"
     # Foo
 
-    foo="bar"
+    foo="bar" # Bar
+    baz = [ # Baz
+      "1", # One
+      # Two
+    ]
     "#[..],
-    )
-    .unwrap();
-    assert_eq!(array[0], ("foo".try_into().unwrap(), Expr::String("bar".try_into().unwrap())));
+      )
+      .unwrap(),
+    );
+    assert_eq!(array[0], entry("foo", string("bar")));
+    assert_eq!(array[1], entry("baz", self::array([string("1")])));
+  }
+
+  #[test]
+  fn toml_parses_arrays_of_tables() {
+    let root = without_positions(toml_ok(
+      "[[foo]]\na = 1\n[foo.bar]\nb = 2\n[[foo]]\na = 3\n[foo.bar]\nb = 4",
+    ));
+    assert_eq!(
+      root[0],
+      entry(
+        "foo",
+        array([
+          table([("a", Expr::Integer(1)), ("bar", table([("b", Expr::Integer(2))]))]),
+          table([("a", Expr::Integer(3)), ("bar", table([("b", Expr::Integer(4))]))]),
+        ])
+      )
+    );
+  }
+
+  #[test]
+  fn toml_parses_numbers_and_date_times() {
+    let root = toml_ok(
+      "a = +1_000\nb = -17\nc = 0xdead_beef\nd = 0o755\ne = 0b11\nf = 2.5\ng = -1e-2\n\
+      h = -inf\ni = 1979-05-27T07:32:00Z\nj = 1979-05-27 07:32:00\nk = 07:32:00",
+    );
+    assert_eq!(root[0].1, Expr::Integer(1000));
+    assert_eq!(root[1].1, Expr::Integer(-17));
+    assert_eq!(root[2].1, Expr::Integer(0xdead_beef));
+    assert_eq!(root[3].1, Expr::Integer(0o755));
+    assert_eq!(root[4].1, Expr::Integer(3));
+    assert_eq!(root[5].1, Expr::Float(2.5));
+    assert_eq!(root[6].1, Expr::Float(-0.01));
+    assert_eq!(root[7].1, Expr::Float(f64::NEG_INFINITY));
+    assert_eq!(root[8].1, Expr::DateTime("1979-05-27T07:32:00Z".into()));
+    assert_eq!(root[9].1, Expr::DateTime("1979-05-27 07:32:00".into()));
+    assert_eq!(root[10].1, Expr::DateTime("07:32:00".into()));
+  }
+
+  #[test]
+  fn toml_parses_strings() {
+    let root = toml_ok(
+      "a = \"tab\\there \\u00e9\"\nb = 'C:\\Users'\nc = \"\"\"\nline\n\\\n   joined\"\"\"\"\n\
+      d = '''\n'raw' \\n'''\n\"quoted key\" = 1",
+    );
+    assert_eq!(root[0].1, string("tab\there é"));
+    assert_eq!(root[1].1, string("C:\\Users"));
+    assert_eq!(root[2].1, string("line\njoined\""));
+    assert_eq!(root[3].1, string("'raw' \\n"));
+    assert_eq!(root[4].0, "quoted key");
+  }
+
+  #[test]
+  fn toml_parses_tables_and_dotted_keys() {
+    let root = without_positions(toml_ok(
+      "a.b = 1\n[env.dev]\nseeds = \"dev\"\n[env.prod]\npoint = { x = 1, y.z = 2 }\n[env]\nc = true",
+    ));
+    assert_eq!(root[0], entry("a", table([("b", Expr::Integer(1))])));
+    assert_eq!(
+      root[1],
+      entry(
+        "env",
+        table([
+          ("dev", table([("seeds", string("dev"))])),
+          (
+            "prod",
+            table([(
+              "point",
+              table([("x", Expr::Integer(1)), ("y", table([("z", Expr::Integer(2))]))])
+            )])
+          ),
+          ("c", Expr::Bool(true)),
+        ])
+      )
+    );
+  }
+
+  #[test]
+  fn toml_returns_line_and_column_of_errors() {
+    assert_eq!(toml_err("a = 1\nb = \"foo"), (2, 9, TomlErrorKind::UnterminatedString));
+    assert_eq!(toml_err("a = 1\na = 2"), (2, 6, TomlErrorKind::DuplicatedKey));
+    assert_eq!(toml_err("[a]\n[a]"), (2, 4, TomlErrorKind::DuplicatedTable));
+    assert_eq!(toml_err("a = [1 2]"), (1, 8, TomlErrorKind::UnterminatedArray));
+    assert_eq!(toml_err("a = 01"), (1, 5, TomlErrorKind::InvalidValue));
+    assert_eq!(toml_err("a = 1 b = 2"), (1, 7, TomlErrorKind::ExpectedNewLine));
+    assert_eq!(toml_err("a = \"\\q\""), (1, 7, TomlErrorKind::InvalidEscape));
+    assert_eq!(toml_err("[[a]]\n[a]"), (2, 4, TomlErrorKind::DuplicatedKey));
+  }
+
+  #[test]
+  fn toml_rejects_extensions_of_inline_tables() {
+    let kind = TomlErrorKind::ExtendedInlineTable;
+    assert_eq!(toml_err("a = { b = 1 }\n[a]"), (2, 4, kind));
+    assert_eq!(toml_err("a = { b = 1 }\n[a.c]"), (2, 6, kind));
+    assert_eq!(toml_err("a = { b = 1 }\n[[a.c]]"), (2, 8, kind));
+    assert_eq!(toml_err("a = { b = 1 }\na.c = 2"), (2, 8, kind));
+    assert_eq!(toml_err("[x]\na = {}\n[x.a]"), (3, 6, kind));
+    assert_eq!(toml_err("a = { b = { c = 1 }, b.d = 2 }"), (1, 29, kind));
+    let root = without_positions(toml_ok("[[x]]\na = { b = 1 }\n[[x]]\n[x.a]\nb = 2"));
+    assert_eq!(
+      root[0],
+      entry(
+        "x",
+        array([
+          table([("a", table([("b", Expr::Integer(1))]))]),
+          table([("a", table([("b", Expr::Integer(2))]))]),
+        ])
+      )
+    );
+  }
+
+  #[test]
+  fn toml_rejects_deeply_nested_values() {
+    let nested = |open: &str, close: &str, n: usize| {
+      alloc::format!("a = {}1{}", open.repeat(n), close.repeat(n))
+    };
+    let _ = toml_ok(&nested("[", "]", 32));
+    assert_eq!(toml_err(&nested("[", "]", 33)), (1, 38, TomlErrorKind::TooDeep));
+    let _ = toml_ok(&nested("{ b = ", " }", 32));
+    assert_eq!(toml_err(&nested("{ b = ", " }", 33)).2, TomlErrorKind::TooDeep);
+  }
+
+  #[test]
+  fn toml_stores_positions_of_entries() {
+    let root = toml_ok("a = 1\n\n[b]\n  c.d = 2\n[[e]]\nf = { g = 3 }");
+    let position = |entry: &(String, Expr, Position)| (entry.2.line, entry.2.column);
+    assert_eq!(position(&root[0]), (1, 1));
+    assert_eq!(position(&root[1]), (3, 2));
+    let Expr::Table(b) = &root[1].1 else { panic!() };
+    assert_eq!(position(&b[0]), (4, 3));
+    let Expr::Array(e) = &root[2].1 else { panic!() };
+    let Some(Expr::Table(e_elem)) = e.first() else { panic!() };
+    assert_eq!(position(&e_elem[0]), (6, 1));
+    let Expr::Table(f) = &e_elem[0].1 else { panic!() };
+    assert_eq!(position(&f[0]), (6, 7));
   }
 }
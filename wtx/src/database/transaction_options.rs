@@ -0,0 +1,68 @@
+use crate::database::IsolationLevel;
+
+/// Characteristics of a transaction started by [`crate::database::Executor::transaction_with_options`].
+///
+/// Unset options use the defaults of the database.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TransactionOptions {
+  is_deferrable: bool,
+  is_read_only: bool,
+  isolation_level: Option<IsolationLevel>,
+}
+
+impl TransactionOptions {
+  /// Creates an instance with default values.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { is_deferrable: false, is_read_only: false, isolation_level: None }
+  }
+
+  /// If a serializable and read-only transaction should wait until it can run without the
+  /// possibility of being cancelled by serialization failures.
+  ///
+  /// Defaults to `false`.
+  #[inline]
+  pub const fn is_deferrable(&self) -> bool {
+    self.is_deferrable
+  }
+
+  /// If the transaction is forbidden to modify data.
+  ///
+  /// Defaults to `false`.
+  #[inline]
+  pub const fn is_read_only(&self) -> bool {
+    self.is_read_only
+  }
+
+  /// See [`IsolationLevel`].
+  ///
+  /// Defaults to `None`.
+  #[inline]
+  pub const fn isolation_level(&self) -> Option<IsolationLevel> {
+    self.isolation_level
+  }
+
+  /// Mutable version of [`Self::is_deferrable`].
+  #[inline]
+  #[must_use]
+  pub fn set_is_deferrable(mut self, value: bool) -> Self {
+    self.is_deferrable = value;
+    self
+  }
+
+  /// Mutable version of [`Self::is_read_only`].
+  #[inline]
+  #[must_use]
+  pub fn set_is_read_only(mut self, value: bool) -> Self {
+    self.is_read_only = value;
+    self
+  }
+
+  /// Mutable version of [`Self::isolation_level`].
+  #[inline]
+  #[must_use]
+  pub fn set_isolation_level(mut self, value: Option<IsolationLevel>) -> Self {
+    self.isolation_level = value;
+    self
+  }
+}
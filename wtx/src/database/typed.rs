@@ -16,3 +16,11 @@ where
 {
   const TY: D::Ty = T::TY;
 }
+
+impl<D, T> Typed<D> for Option<T>
+where
+  D: Database,
+  T: Typed<D>,
+{
+  const TY: D::Ty = T::TY;
+}
@@ -56,16 +56,24 @@ pub enum Error {
   MatchitInsertError(Box<matchit::InsertError>),
   #[cfg(feature = "digest")]
   MacError(digest::MacError),
+  #[cfg(feature = "mssql")]
+  MssqlDbError(Box<crate::database::client::mssql::DbError>),
+  #[cfg(feature = "mysql")]
+  MysqlDbError(Box<crate::database::client::mysql::DbError>),
   #[cfg(feature = "postgres")]
   PostgresDbError(Box<crate::database::client::postgres::DbError>),
   #[cfg(feature = "quick-protobuf")]
   QuickProtobuf(Box<quick_protobuf::Error>),
+  #[cfg(feature = "redis")]
+  RedisDbError(Box<crate::database::client::redis::DbError>),
   #[cfg(feature = "rustls")]
   RustlsError(Box<rustls::Error>),
   #[cfg(feature = "serde_json")]
   SerdeJson(serde_json::Error),
   #[cfg(feature = "http-session")]
   SessionError(crate::http::SessionError),
+  #[cfg(feature = "sqlite")]
+  SqliteDbError(Box<crate::database::client::sqlite::DbError>),
   #[cfg(feature = "tokio")]
   TokioJoinError(Box<tokio::task::JoinError>),
   #[cfg(feature = "tracing-subscriber")]
@@ -153,17 +161,27 @@ pub enum Error {
   FromRadix10Error(FromRadix10Error),
   #[cfg(feature = "http")]
   HttpError(crate::http::HttpError),
+  #[cfg(feature = "http1")]
+  Http1Error(crate::http1::Http1Error),
   #[cfg(feature = "http2")]
   Http2ErrorGoAway(crate::http2::Http2ErrorCode, Option<crate::http2::Http2Error>),
   #[cfg(feature = "http2")]
   Http2ErrorReset(crate::http2::Http2ErrorCode, Option<crate::http2::Http2Error>, u32),
+  #[cfg(feature = "mssql")]
+  MssqlError(crate::database::client::mssql::MssqlError),
+  #[cfg(feature = "mysql")]
+  MysqlError(crate::database::client::mysql::MysqlError),
   #[cfg(feature = "postgres")]
   PostgresError(crate::database::client::postgres::PostgresError),
   QueueError(DequeueError),
+  #[cfg(feature = "redis")]
+  RedisError(crate::database::client::redis::RedisError),
   #[cfg(feature = "schema-manager")]
   SchemaManagerError(crate::database::schema_manager::SchemaManagerError),
   #[cfg(feature = "http-server-framework")]
   ServerFrameworkError(crate::http::server_framework::ServerFrameworkError),
+  #[cfg(feature = "sqlite")]
+  SqliteError(crate::database::client::sqlite::SqliteError),
   VectorError(VectorError),
   #[cfg(feature = "web-socket")]
   WebSocketError(crate::web_socket::WebSocketError),
@@ -374,6 +392,22 @@ impl From<core::num::ParseIntError> for Error {
   }
 }
 
+#[cfg(feature = "mssql")]
+impl From<crate::database::client::mssql::DbError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::mssql::DbError) -> Self {
+    Self::MssqlDbError(from.into())
+  }
+}
+
+#[cfg(feature = "mysql")]
+impl From<crate::database::client::mysql::DbError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::mysql::DbError) -> Self {
+    Self::MysqlDbError(from.into())
+  }
+}
+
 #[cfg(feature = "postgres")]
 impl From<crate::database::client::postgres::DbError> for Error {
   #[inline]
@@ -390,6 +424,14 @@ impl From<quick_protobuf::Error> for Error {
   }
 }
 
+#[cfg(feature = "redis")]
+impl From<crate::database::client::redis::DbError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::redis::DbError) -> Self {
+    Self::RedisDbError(from.into())
+  }
+}
+
 #[cfg(feature = "rustls")]
 impl From<rustls::Error> for Error {
   #[inline]
@@ -414,6 +456,14 @@ impl From<crate::http::SessionError> for Error {
   }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<crate::database::client::sqlite::DbError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::sqlite::DbError) -> Self {
+    Self::SqliteDbError(from.into())
+  }
+}
+
 #[cfg(feature = "tokio")]
 impl From<tokio::task::JoinError> for Error {
   #[inline]
@@ -504,6 +554,14 @@ impl From<crate::http::HttpError> for Error {
   }
 }
 
+#[cfg(feature = "http1")]
+impl From<crate::http1::Http1Error> for Error {
+  #[inline]
+  fn from(from: crate::http1::Http1Error) -> Self {
+    Self::Http1Error(from)
+  }
+}
+
 #[cfg(feature = "client-api-framework")]
 impl From<crate::client_api_framework::ClientApiFrameworkError> for Error {
   #[inline]
@@ -535,6 +593,22 @@ impl From<FromRadix10Error> for Error {
   }
 }
 
+#[cfg(feature = "mssql")]
+impl From<crate::database::client::mssql::MssqlError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::mssql::MssqlError) -> Self {
+    Self::MssqlError(from)
+  }
+}
+
+#[cfg(feature = "mysql")]
+impl From<crate::database::client::mysql::MysqlError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::mysql::MysqlError) -> Self {
+    Self::MysqlError(from)
+  }
+}
+
 #[cfg(feature = "postgres")]
 impl From<crate::database::client::postgres::PostgresError> for Error {
   #[inline]
@@ -550,6 +624,14 @@ impl From<DequeueError> for Error {
   }
 }
 
+#[cfg(feature = "redis")]
+impl From<crate::database::client::redis::RedisError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::redis::RedisError) -> Self {
+    Self::RedisError(from)
+  }
+}
+
 #[cfg(feature = "schema-manager")]
 impl From<crate::database::schema_manager::SchemaManagerError> for Error {
   #[inline]
@@ -566,6 +648,14 @@ impl From<crate::http::server_framework::ServerFrameworkError> for Error {
   }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<crate::database::client::sqlite::SqliteError> for Error {
+  #[inline]
+  fn from(from: crate::database::client::sqlite::SqliteError) -> Self {
+    Self::SqliteError(from)
+  }
+}
+
 impl From<VectorError> for Error {
   #[inline]
   fn from(from: VectorError) -> Self {
@@ -20,7 +20,7 @@ mod operation_mode;
 mod optioned_server;
 mod percent_encoding;
 mod protocol;
-#[cfg(feature = "http2")]
+#[cfg(any(feature = "http1", feature = "http2"))]
 mod req_builder;
 mod req_res_buffer;
 mod req_res_data;
@@ -50,7 +50,7 @@ pub use operation_mode::*;
 pub use optioned_server::OptionedServer;
 pub use percent_encoding::{AsciiSet, PercentDecode, PercentEncode};
 pub use protocol::Protocol;
-#[cfg(feature = "http2")]
+#[cfg(any(feature = "http1", feature = "http2"))]
 pub use req_builder::ReqBuilder;
 pub use req_res_buffer::ReqResBuffer;
 pub use req_res_data::{ReqResData, ReqResDataMut};
@@ -23,6 +23,8 @@ pub use client_pool_resource::ClientPoolResource;
 pub use client_pool_rm::ClientPoolRM;
 #[cfg(feature = "tokio")]
 pub use tokio::ClientPoolTokio;
+#[cfg(feature = "tokio")]
+pub use tokio_http1::ClientPoolTokioHttp1;
 #[cfg(feature = "tokio-rustls")]
 pub use tokio_rustls::ClientPoolTokioRustls;
 
@@ -61,7 +63,15 @@ where
       })
       .await;
   }
+}
 
+impl<RL, RM> ClientPool<RL, RM>
+where
+  RL: Lock<Resource = SimplePoolResource<RM::Resource>>,
+  RM: ResourceManager<CreateAux = str, Error = crate::Error, RecycleAux = str>,
+  for<'any> RL: 'any,
+  for<'any> RM: 'any,
+{
   /// Returns a guard that contains the internal elements.
   #[inline]
   pub async fn lock(
@@ -151,6 +161,77 @@ mod tokio {
   }
 }
 
+#[cfg(feature = "tokio")]
+mod tokio_http1 {
+  use crate::{
+    http::client_pool::{ClientPool, ClientPoolBuilder, ClientPoolRM, ClientPoolResource, NoAuxFn},
+    http1::{Http1, Http1Buffer},
+    misc::{Fun, UriRef},
+    pool::{ResourceManager, SimplePoolResource},
+  };
+  use tokio::{net::TcpStream, sync::Mutex};
+
+  /// A [`ClientPool`] of HTTP/1.1 connections using the elements of `tokio`.
+  pub type ClientPoolTokioHttp1<F> = ClientPool<
+    Mutex<SimplePoolResource<Resource<<F as Fun<()>>::Output>>>,
+    ClientPoolRM<F, Client>,
+  >;
+  type Client = Http1<TcpStream, true>;
+  type Resource<AUX> = ClientPoolResource<AUX, Client>;
+
+  impl<AUX> ClientPoolBuilder<NoAuxFn, Mutex<SimplePoolResource<Resource<AUX>>>, Client> {
+    /// Creates a new builder with the maximum number of HTTP/1.1 connections delimited by `len`.
+    ///
+    /// Connection is established using the elements provided by the `tokio` project.
+    #[inline]
+    pub fn tokio_http1(len: usize) -> Self {
+      Self::_no_aux_fun(len)
+    }
+  }
+
+  impl<AUX, F> ResourceManager for ClientPoolRM<F, Client>
+  where
+    F: Fn() -> AUX,
+  {
+    type CreateAux = str;
+    type Error = crate::Error;
+    type RecycleAux = str;
+    type Resource = Resource<AUX>;
+
+    #[inline]
+    async fn create(&self, ca: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
+      Ok(ClientPoolResource { aux: (self._fun)(), client: self.connect(ca).await? })
+    }
+
+    #[inline]
+    async fn is_invalid(&self, resource: &Self::Resource) -> bool {
+      resource.client.connection_state().is_closed()
+    }
+
+    #[inline]
+    async fn recycle(
+      &self,
+      ra: &Self::RecycleAux,
+      resource: &mut Self::Resource,
+    ) -> Result<(), Self::Error> {
+      resource.client = self.connect(ra).await?;
+      Ok(())
+    }
+  }
+
+  impl<F> ClientPoolRM<F, Client> {
+    #[inline]
+    async fn connect(&self, uri: &str) -> crate::Result<Client> {
+      let uri = UriRef::new(uri);
+      Ok(Http1::connect(
+        Http1Buffer::default(),
+        self._cp._to_h1p(),
+        TcpStream::connect(uri.hostname_with_implied_port()).await?,
+      ))
+    }
+  }
+}
+
 #[cfg(feature = "tokio-rustls")]
 mod tokio_rustls {
   use crate::{
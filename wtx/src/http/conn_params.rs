@@ -10,8 +10,16 @@ pub(crate) struct ConnParams {
   pub(crate) _max_recv_streams_num: u32,
 }
 
-#[cfg(feature = "http2")]
 impl ConnParams {
+  #[cfg(feature = "http1")]
+  #[inline]
+  pub(crate) fn _to_h1p(self) -> crate::http1::Http1Params {
+    crate::http1::Http1Params::default()
+      .set_max_body_len(self._max_body_len)
+      .set_max_headers_len(self._max_headers_len)
+  }
+
+  #[cfg(feature = "http2")]
   #[inline]
   pub(crate) fn _to_hp(self) -> crate::http2::Http2Params {
     crate::http2::Http2Params::default()
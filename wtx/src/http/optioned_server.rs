@@ -3,8 +3,12 @@
 //
 // FIXME(STABLE): Return type notation
 
+#[cfg(all(feature = "http1", feature = "tokio"))]
+mod http1_tokio;
 #[cfg(all(feature = "http2", feature = "tokio"))]
 mod http2_tokio;
+#[cfg(all(feature = "http1", feature = "http2", feature = "tokio"))]
+mod http_tokio;
 #[cfg(all(feature = "pool", feature = "tokio", feature = "web-socket-handshake"))]
 mod web_socket_tokio;
 
@@ -0,0 +1,124 @@
+use crate::{
+  http::{optioned_server::OptionedServer, AutoStream, ReqResBuffer, Request, Response},
+  http1::{Http1, Http1Buffer, Http1Params},
+  misc::{FnFut, StreamReader, StreamWriter},
+};
+use core::{future::Future, net::IpAddr};
+use tokio::net::{TcpListener, TcpStream};
+
+impl OptionedServer {
+  /// Optioned HTTP/1.1 server using tokio.
+  ///
+  /// Requests of a connection are processed sequentially and each connection is kept alive as
+  /// long as both parties allow it.
+  #[inline]
+  pub async fn http1_tokio<A, ACPT, CA, E, HA, N, S, SA>(
+    addr: &str,
+    auto_cb: A,
+    conn_cb: impl Clone + Fn() -> crate::Result<(CA, Http1Buffer, Http1Params)> + Send + 'static,
+    err_cb: impl Clone + Fn(E) + Send + 'static,
+    headers_cb: impl Clone + Fn(&CA, Request<&mut ReqResBuffer>, &SA) -> Result<HA, E> + Send + 'static,
+    stream_cb: impl Clone + Fn() -> crate::Result<(SA, ReqResBuffer)> + Send + 'static,
+    (acceptor_cb, conn_acceptor_cb, net_cb): (
+      impl FnOnce() -> crate::Result<ACPT> + Send + 'static,
+      impl Clone + Fn(&ACPT) -> ACPT + Send + 'static,
+      impl Clone + Fn(ACPT, TcpStream) -> N + Send + 'static,
+    ),
+  ) -> crate::Result<()>
+  where
+    A: Clone
+      + FnFut<(HA, AutoStream<CA, SA>), Result = Result<Response<ReqResBuffer>, E>>
+      + Send
+      + 'static,
+    A::Future: Send,
+    ACPT: Send + 'static,
+    CA: Clone + Send + 'static,
+    E: From<crate::Error> + Send + 'static,
+    HA: Send + 'static,
+    N: Future<Output = crate::Result<S>> + Send,
+    S: Send
+      + StreamReader<read(..): Send>
+      + StreamWriter<write_all(..): Send, write_all_vectored(..): Send>
+      + 'static,
+    SA: Send + 'static,
+    for<'any> &'any A: Send,
+    for<'any> &'any CA: Send,
+    for<'any> &'any SA: Send,
+  {
+    let listener = TcpListener::bind(addr).await?;
+    let acceptor = acceptor_cb()?;
+    loop {
+      let tcp_stream = listener.accept().await?.0;
+      let peer = tcp_stream.peer_addr()?.ip();
+      let conn_acceptor = conn_acceptor_cb(&acceptor);
+      let conn_auto_cb = auto_cb.clone();
+      let conn_conn_cb = conn_cb.clone();
+      let conn_err_cb = err_cb.clone();
+      let conn_headers_cb = headers_cb.clone();
+      let conn_net_cb = net_cb.clone();
+      let conn_stream_cb = stream_cb.clone();
+      let _conn_jh = tokio::spawn(async move {
+        let initial = async move {
+          let (conn_ca, hb, hp) = conn_conn_cb()?;
+          Ok::<_, E>((
+            conn_ca,
+            Http1::accept(hb, hp, conn_net_cb(conn_acceptor, tcp_stream).await?),
+          ))
+        };
+        match initial.await {
+          Err(err) => conn_err_cb(err),
+          Ok((conn_ca, http1)) => {
+            Self::_http1_conn(
+              conn_auto_cb,
+              conn_ca,
+              conn_err_cb,
+              conn_headers_cb,
+              http1,
+              peer,
+              conn_stream_cb,
+            )
+            .await;
+          }
+        }
+      });
+    }
+  }
+
+  /// Drives an HTTP/1.1 connection whose transport was already established.
+  #[inline]
+  pub(crate) async fn _http1_conn<A, CA, E, HA, S, SA>(
+    conn_auto_cb: A,
+    conn_ca: CA,
+    conn_err_cb: impl Fn(E),
+    conn_headers_cb: impl Fn(&CA, Request<&mut ReqResBuffer>, &SA) -> Result<HA, E>,
+    mut http1: Http1<S, false>,
+    peer: IpAddr,
+    conn_stream_cb: impl Fn() -> crate::Result<(SA, ReqResBuffer)>,
+  ) where
+    A: FnFut<(HA, AutoStream<CA, SA>), Result = Result<Response<ReqResBuffer>, E>>,
+    CA: Clone,
+    E: From<crate::Error>,
+    S: StreamReader + StreamWriter,
+  {
+    let fun = async move {
+      loop {
+        let (stream_aux, rrb) = conn_stream_cb()?;
+        let Some(mut req) = http1.recv_req(rrb).await? else {
+          return Ok(());
+        };
+        let headers_aux = conn_headers_cb(
+          &conn_ca,
+          Request { method: req.method, rrd: &mut req.rrd, version: req.version },
+          &stream_aux,
+        )?;
+        let auto_stream =
+          AutoStream { conn_aux: conn_ca.clone(), peer, protocol: None, req, stream_aux };
+        let res = conn_auto_cb.call((headers_aux, auto_stream)).await?;
+        http1.send_res(res).await?;
+      }
+    };
+    if let Err(err) = fun.await {
+      conn_err_cb(err);
+    }
+  }
+}
@@ -0,0 +1,208 @@
+use crate::{
+  http::{
+    optioned_server::OptionedServer, AutoStream, ManualServerStreamTokio, OperationMode, Protocol,
+    ReqResBuffer, Request, Response,
+  },
+  http1::{Http1, Http1Buffer, Http1Params},
+  http2::{Http2Buffer, Http2Params},
+  misc::{Either, FnFut, StreamReader, StreamWriter},
+};
+use core::future::Future;
+use tokio::net::{TcpListener, TcpStream};
+
+impl OptionedServer {
+  /// Optioned HTTP/1.1 and HTTP/2 server using tokio.
+  ///
+  /// The protocol of each connection is decided by `net_cb`, which returns [`Either::Left`] for
+  /// HTTP/1.1 connections and [`Either::Right`] for HTTP/2 connections. Bytes that were read
+  /// while detecting HTTP/1.1 connections should be placed in the returned [`Http1Buffer`].
+  ///
+  /// `headers_cb` is only called for HTTP/1.1 requests while `operation_mode` is only called for
+  /// HTTP/2 requests.
+  #[inline]
+  pub async fn http_tokio<A, ACPT, CA, E, HA, M, N, OM, S, SA, SR, SW>(
+    addr: &str,
+    auto_cb: A,
+    conn_cb: impl Clone
+      + Fn() -> crate::Result<(CA, Http1Params, Http2Buffer, Http2Params)>
+      + Send
+      + 'static,
+    err_cb: impl Clone + Fn(E) + Send + 'static,
+    headers_cb: impl Clone + Fn(&CA, Request<&mut ReqResBuffer>, &SA) -> Result<HA, E> + Send + 'static,
+    manual_cb: M,
+    operation_mode: OM,
+    stream_cb: impl Clone + Fn() -> crate::Result<(SA, ReqResBuffer)> + Send + 'static,
+    (acceptor_cb, conn_acceptor_cb, net_cb): (
+      impl FnOnce() -> crate::Result<ACPT> + Send + 'static,
+      impl Clone + Fn(&ACPT) -> ACPT + Send + 'static,
+      impl Clone + Fn(ACPT, TcpStream) -> N + Send + 'static,
+    ),
+  ) -> crate::Result<()>
+  where
+    A: Clone
+      + FnFut<(HA, AutoStream<CA, SA>), Result = Result<Response<ReqResBuffer>, E>>
+      + Send
+      + 'static,
+    A::Future: Send,
+    ACPT: Send + 'static,
+    CA: Clone + Send + 'static,
+    E: From<crate::Error> + Send + 'static,
+    HA: Send + 'static,
+    M: Clone
+      + FnFut<(HA, ManualServerStreamTokio<CA, Http2Buffer, SA, SW>), Result = Result<(), E>>
+      + Send
+      + 'static,
+    M::Future: Send,
+    N: Future<Output = crate::Result<Either<(Http1Buffer, S), (SR, SW)>>> + Send,
+    OM: Clone
+      + Fn(&CA, Option<Protocol>, Request<&mut ReqResBuffer>, &SA) -> Result<(HA, OperationMode), E>
+      + Send
+      + 'static,
+    S: Send
+      + StreamReader<read(..): Send>
+      + StreamWriter<write_all(..): Send, write_all_vectored(..): Send>
+      + 'static,
+    SA: Send + 'static,
+    SR: Send + StreamReader<read(..): Send, read_skip(..): Send> + Unpin + 'static,
+    SW: Send + StreamWriter<write_all(..): Send, write_all_vectored(..): Send> + Unpin + 'static,
+    for<'any> &'any A: Send,
+    for<'any> &'any CA: Send,
+    for<'any> &'any M: Send,
+    for<'any> &'any OM: Send,
+    for<'any> &'any SA: Send,
+  {
+    let listener = TcpListener::bind(addr).await?;
+    let acceptor = acceptor_cb()?;
+    loop {
+      let tcp_stream = listener.accept().await?.0;
+      let peer = tcp_stream.peer_addr()?.ip();
+      let conn_acceptor = conn_acceptor_cb(&acceptor);
+      let conn_auto_cb = auto_cb.clone();
+      let conn_conn_cb = conn_cb.clone();
+      let conn_err_cb = err_cb.clone();
+      let conn_headers_cb = headers_cb.clone();
+      let conn_manual_cb = manual_cb.clone();
+      let conn_net_cb = net_cb.clone();
+      let conn_stream_cb = stream_cb.clone();
+      let conn_op_cb = operation_mode.clone();
+      let _conn_jh = tokio::spawn(async move {
+        let initial = async move {
+          let (conn_ca, h1p, h2b, h2p) = conn_conn_cb()?;
+          Ok::<_, E>((conn_ca, h1p, h2b, h2p, conn_net_cb(conn_acceptor, tcp_stream).await?))
+        };
+        match initial.await {
+          Err(err) => conn_err_cb(err),
+          Ok((conn_ca, h1p, _, _, Either::Left((h1b, stream)))) => {
+            Self::_http1_conn(
+              conn_auto_cb,
+              conn_ca,
+              conn_err_cb,
+              conn_headers_cb,
+              Http1::accept(h1b, h1p, stream),
+              peer,
+              conn_stream_cb,
+            )
+            .await;
+          }
+          Ok((conn_ca, _, h2b, h2p, Either::Right(parts))) => {
+            Self::_http2_conn(
+              conn_auto_cb,
+              conn_ca,
+              conn_err_cb,
+              h2b,
+              h2p,
+              conn_manual_cb,
+              conn_op_cb,
+              parts,
+              peer,
+              conn_stream_cb,
+            )
+            .await;
+          }
+        }
+      });
+    }
+  }
+
+  /// Detects the protocol of a cleartext connection.
+  ///
+  /// Connections that start with the HTTP/2 connection preface (prior knowledge) are HTTP/2
+  /// connections, everything else is handled as HTTP/1.1. Requests that try to upgrade to `h2c`
+  /// are served as HTTP/1.1 because such mechanism is deprecated by RFC9113.
+  #[cfg(feature = "http-server-framework")]
+  #[inline]
+  pub(crate) async fn _cleartext_parts(
+    mut tcp_stream: TcpStream,
+  ) -> crate::Result<
+    Either<
+      (Http1Buffer, TcpStream),
+      (PrefacedReader<tokio::net::tcp::OwnedReadHalf>, tokio::net::tcp::OwnedWriteHalf),
+    >,
+  > {
+    let mut buffer = [0; 24];
+    let mut len: usize = 0;
+    while len < buffer.len() {
+      let read = tcp_stream.read(buffer.get_mut(len..).unwrap_or_default()).await?;
+      len = len.wrapping_add(read);
+      let received = buffer.get(..len).unwrap_or_default();
+      if read == 0 || !crate::http2::PREFACE.starts_with(received) {
+        return Ok(Either::Left((Http1Buffer::with_initial_bytes(received)?, tcp_stream)));
+      }
+    }
+    let (stream_reader, stream_writer) = tcp_stream.into_split();
+    Ok(Either::Right((PrefacedReader { idx: 0, stream_reader }, stream_writer)))
+  }
+
+  /// Uses the ALPN value of a TLS connection to detect its protocol. Connections without ALPN are
+  /// handled as HTTP/1.1.
+  #[cfg(all(feature = "http-server-framework", feature = "tokio-rustls"))]
+  #[inline]
+  pub(crate) fn _tls_parts<T>(
+    tls_stream: tokio_rustls::server::TlsStream<T>,
+  ) -> Either<
+    (Http1Buffer, tokio_rustls::server::TlsStream<T>),
+    (
+      tokio::io::ReadHalf<tokio_rustls::server::TlsStream<T>>,
+      tokio::io::WriteHalf<tokio_rustls::server::TlsStream<T>>,
+    ),
+  >
+  where
+    T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+  {
+    if tls_stream.get_ref().1.alpn_protocol() == Some(b"h2") {
+      Either::Right(tokio::io::split(tls_stream))
+    } else {
+      Either::Left((Http1Buffer::new(), tls_stream))
+    }
+  }
+}
+
+/// Returns the HTTP/2 connection preface that was already consumed by the protocol detection
+/// before reading from the underlying stream.
+#[cfg(feature = "http-server-framework")]
+#[derive(Debug)]
+pub(crate) struct PrefacedReader<SR> {
+  idx: usize,
+  stream_reader: SR,
+}
+
+#[cfg(feature = "http-server-framework")]
+impl<SR> StreamReader for PrefacedReader<SR>
+where
+  SR: StreamReader,
+{
+  #[inline]
+  async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+    let preface = crate::http2::PREFACE.get(self.idx..).unwrap_or_default();
+    if preface.is_empty() {
+      return self.stream_reader.read(bytes).await;
+    }
+    let len = preface.len().min(bytes.len());
+    bytes
+      .get_mut(..len)
+      .unwrap_or_default()
+      .copy_from_slice(preface.get(..len).unwrap_or_default());
+    self.idx = self.idx.wrapping_add(len);
+    Ok(len)
+  }
+}
@@ -1,7 +1,6 @@
 use crate::{
   http::{Header, KnownHeaderName, Method, Mime, ReqResBuffer, ReqUri, Request, Response},
-  http2::{Http2, Http2Buffer, Http2Data, Http2RecvStatus},
-  misc::{LeaseMut, Lock, RefCounter, StreamWriter},
+  misc::LeaseMut,
 };
 
 /// Request builder
@@ -33,16 +32,18 @@ impl ReqBuilder {
 
 impl ReqBuilder {
   /// Sends a request with inner parameters.
+  #[cfg(feature = "http2")]
   #[inline]
   pub async fn send<HD, SW>(
     self,
-    client: &mut Http2<HD, true>,
+    client: &mut crate::http2::Http2<HD, true>,
     req_uri: impl Into<ReqUri<'_>>,
   ) -> crate::Result<Response<ReqResBuffer>>
   where
-    HD: RefCounter,
-    HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
-    SW: StreamWriter,
+    HD: crate::misc::RefCounter,
+    HD::Item:
+      crate::misc::Lock<Resource = crate::http2::Http2Data<crate::http2::Http2Buffer, SW, true>>,
+    SW: crate::misc::StreamWriter,
   {
     let mut stream = client.stream().await?;
     if stream.send_req(Request::http2(self.method, &self.rrb), req_uri).await?.is_closed() {
@@ -50,13 +51,28 @@ impl ReqBuilder {
     }
     let (hrs, res_rrb) = stream.recv_res(self.rrb).await?;
     let status_code = match hrs {
-      Http2RecvStatus::Eos(elem) => elem,
+      crate::http2::Http2RecvStatus::Eos(elem) => elem,
       _ => return Err(crate::Error::ClosedConnection),
     };
     stream.common().clear(false).await?;
     Ok(Response::http2(res_rrb, status_code))
   }
 
+  /// Sends a request with inner parameters through an HTTP/1.1 connection.
+  #[cfg(feature = "http1")]
+  #[inline]
+  pub async fn send_http1<S>(
+    self,
+    client: &mut crate::http1::Http1<S, true>,
+    req_uri: impl Into<ReqUri<'_>>,
+  ) -> crate::Result<Response<ReqResBuffer>>
+  where
+    S: crate::misc::StreamReader + crate::misc::StreamWriter,
+  {
+    client.send_req(Request::http2(self.method, &self.rrb), req_uri).await?;
+    client.recv_res(self.rrb).await
+  }
+
   /// Media type of the resource.
   #[inline]
   pub fn content_type(mut self, mime: Mime) -> crate::Result<Self> {
@@ -87,6 +87,20 @@ where
     })
   }
 
+  /// Same as [`Self::_route_params`] but only accepts automatic endpoints, which are the only
+  /// ones available to HTTP/1.1 connections.
+  #[inline]
+  fn _http1_route_params(
+    path: &str,
+    router: &Arc<Router<CA, E, EN, M, S, SA>>,
+  ) -> Result<ArrayVector<RouteMatch, 4>, E> {
+    let (headers_aux, om) = Self::_route_params(path, router)?;
+    if om == OperationMode::Manual {
+      return Err(crate::Error::from(ServerFrameworkError::OperationModeMismatch).into());
+    }
+    Ok(headers_aux)
+  }
+
   #[inline]
   fn _route_params(
     path: &str,
@@ -156,3 +156,89 @@ async fn nested_middlewares() {
   // 3 + 3 + 11 + 7 + 7
   assert_eq!(router_auto_stream.stream_aux.0, 31);
 }
+
+#[cfg(all(feature = "http-client-pool", feature = "nightly", feature = "tokio"))]
+#[tokio::test]
+async fn http1_connections() {
+  use crate::{
+    http::{client_pool::ClientPoolBuilder, ReqBuilder},
+    misc::UriString,
+  };
+
+  async fn auto(state: StateClean<'_, (), (), ReqResBuffer>) -> crate::Result<StatusCode> {
+    state.req.rrd.body.extend_from_copyable_slice(b"auto")?;
+    Ok(StatusCode::Ok)
+  }
+
+  async fn manual<S>(_: ManualStream<(), S, ()>) -> crate::Result<()> {
+    Ok(())
+  }
+
+  let uri = crate::tests::_uri();
+  let router = Router::paths(paths!(("/auto", get(auto)), ("/manual", get(manual)))).unwrap();
+  let sf = ServerFrameworkBuilder::new(router).without_aux();
+  let host = alloc::format!("{}:{}", uri.hostname(), uri.port().unwrap_or_default());
+  let _server_jh = tokio::spawn(async move {
+    sf.tokio_http1(&host, |_| {}, |_| Ok(())).await.unwrap();
+  });
+  tokio::time::sleep(core::time::Duration::from_millis(100)).await;
+
+  let pool = ClientPoolBuilder::tokio_http1(1).build();
+  let mut rrb = ReqResBuffer::empty();
+  let auto_uri = UriString::new(alloc::format!("{}/auto", uri.as_str()));
+  for _ in 0..2 {
+    let mut guard = pool.lock(&auto_uri.to_ref()).await.unwrap();
+    let res = ReqBuilder::get(rrb).send_http1(&mut guard.client, &auto_uri.to_ref()).await.unwrap();
+    assert_eq!(res.status_code, StatusCode::Ok);
+    assert_eq!(res.rrd.body.as_slice(), b"auto");
+    rrb = res.rrd;
+  }
+  let manual_uri = UriString::new(alloc::format!("{}/manual", uri.as_str()));
+  let mut guard = pool.lock(&manual_uri.to_ref()).await.unwrap();
+  assert!(ReqBuilder::get(rrb).send_http1(&mut guard.client, &manual_uri.to_ref()).await.is_err());
+}
+
+#[cfg(all(feature = "http-client-pool", feature = "nightly", feature = "tokio"))]
+#[tokio::test]
+async fn http1_and_http2_connections() {
+  use crate::{
+    http::{client_pool::ClientPoolBuilder, ReqBuilder, Version},
+    misc::{simple_seed, UriString, Xorshift64},
+  };
+
+  async fn auto(state: StateClean<'_, (), (), ReqResBuffer>) -> crate::Result<StatusCode> {
+    state.req.rrd.body.extend_from_copyable_slice(b"auto")?;
+    Ok(StatusCode::Ok)
+  }
+
+  let uri = crate::tests::_uri();
+  let router = Router::paths(paths!(("/auto", get(auto)))).unwrap();
+  let sf = ServerFrameworkBuilder::new(router).without_aux();
+  let host = alloc::format!("{}:{}", uri.hostname(), uri.port().unwrap_or_default());
+  let _server_jh = tokio::spawn(async move {
+    sf.tokio(&host, Xorshift64::from(simple_seed()), |_| {}, |_| Ok(())).await.unwrap();
+  });
+  tokio::time::sleep(core::time::Duration::from_millis(100)).await;
+
+  let auto_uri = UriString::new(alloc::format!("{}/auto", uri.as_str()));
+
+  let http1_pool = ClientPoolBuilder::tokio_http1(1).build();
+  let mut http1_guard = http1_pool.lock(&auto_uri.to_ref()).await.unwrap();
+  let res = ReqBuilder::get(ReqResBuffer::empty())
+    .send_http1(&mut http1_guard.client, &auto_uri.to_ref())
+    .await
+    .unwrap();
+  assert_eq!((res.status_code, res.version), (StatusCode::Ok, Version::Http1_1));
+  assert_eq!(res.rrd.body.as_slice(), b"auto");
+
+  let http2_pool = ClientPoolBuilder::tokio(1).build();
+  let mut http2_guard = http2_pool.lock(&auto_uri.to_ref()).await.unwrap();
+  let res = ReqBuilder::get(ReqResBuffer::empty())
+    .send(&mut http2_guard.client, &auto_uri.to_ref())
+    .await
+    .unwrap();
+  assert_eq!((res.status_code, res.version), (StatusCode::Ok, Version::Http2));
+  assert_eq!(res.rrd.body.as_slice(), b"auto");
+  drop(http2_guard);
+  http2_pool.close_all().await;
+}
@@ -38,6 +38,10 @@ where
   for<'any> &'any Router<CA, E, EN, M, Stream, SA>: Send,
 {
   /// Starts listening to incoming requests based on the given `host`.
+  ///
+  /// Connections that start with the HTTP/2 connection preface are served as HTTP/2 and all other
+  /// connections are served as HTTP/1.1. Endpoints that manually manage streams are only available
+  /// to HTTP/2 connections.
   #[inline]
   pub async fn tokio<RNG>(
     self,
@@ -50,11 +54,20 @@ where
     RNG: Clone + Rng + Send + 'static,
   {
     let Self { _ca_cb, _cp, _sa_cb, _router } = self;
-    OptionedServer::http2_tokio(
+    let http1_headers_cb = headers_cb.clone();
+    OptionedServer::http_tokio(
       host,
       Self::_auto,
-      move || Ok((CA::conn_aux(_ca_cb())?, Http2Buffer::new(rng.clone()), _cp._to_hp())),
+      move || {
+        let ca = CA::conn_aux(_ca_cb())?;
+        Ok((ca, _cp._to_h1p(), Http2Buffer::new(rng.clone()), _cp._to_hp()))
+      },
       err_cb,
+      move |_, req, sa| {
+        let rslt = Self::_http1_route_params(req.rrd.uri.path(), &sa.1)?;
+        http1_headers_cb(req)?;
+        Ok(rslt)
+      },
       Self::tokio_manual,
       move |_, _, req, sa| {
         let rslt = Self::_route_params(req.rrd.uri.path(), &sa.1)?;
@@ -62,7 +75,35 @@ where
         Ok(rslt)
       },
       move || Ok(((_sa_cb.clone(), Arc::clone(&_router)), ReqResBuffer::empty())),
-      (|| Ok(()), |_| {}, |_, stream| async move { Ok(stream.into_split()) }),
+      (|| Ok(()), |_| {}, |_, stream| OptionedServer::_cleartext_parts(stream)),
+    )
+    .await
+  }
+
+  /// Starts listening to incoming HTTP/1.1 requests based on the given `host`.
+  ///
+  /// Endpoints that manually manage streams are only available to HTTP/2 connections, as such,
+  /// their requests will return [`super::ServerFrameworkError::OperationModeMismatch`].
+  #[inline]
+  pub async fn tokio_http1(
+    self,
+    host: &str,
+    err_cb: impl Clone + Fn(E) + Send + 'static,
+    headers_cb: impl Clone + Fn(Request<&mut ReqResBuffer>) -> Result<(), E> + Send + Sync + 'static,
+  ) -> crate::Result<()> {
+    let Self { _ca_cb, _cp, _sa_cb, _router } = self;
+    OptionedServer::http1_tokio(
+      host,
+      Self::_auto,
+      move || Ok((CA::conn_aux(_ca_cb())?, crate::http1::Http1Buffer::new(), _cp._to_h1p())),
+      err_cb,
+      move |_, req, sa| {
+        let rslt = Self::_http1_route_params(req.rrd.uri.path(), &sa.1)?;
+        headers_cb(req)?;
+        Ok(rslt)
+      },
+      move || Ok(((_sa_cb.clone(), Arc::clone(&_router)), ReqResBuffer::empty())),
+      (|| Ok(()), |_| {}, |_, stream| async move { Ok(stream) }),
     )
     .await
   }
@@ -109,6 +150,9 @@ where
   for<'any> &'any Router<CA, E, EN, M, StreamRustls, SA>: Send,
 {
   /// Starts listening to incoming encrypted requests based on the given `host`.
+  ///
+  /// The protocol of each connection is negotiated through ALPN, where HTTP/2 is preferred over
+  /// HTTP/1.1. Endpoints that manually manage streams are only available to HTTP/2 connections.
   #[inline]
   pub async fn tokio_rustls<RNG>(
     self,
@@ -122,11 +166,20 @@ where
     RNG: Clone + Rng + Send + 'static,
   {
     let Self { _ca_cb, _cp, _sa_cb, _router } = self;
-    OptionedServer::http2_tokio(
+    let http1_headers_cb = headers_cb.clone();
+    OptionedServer::http_tokio(
       host,
       Self::_auto,
-      move || Ok((CA::conn_aux(_ca_cb())?, Http2Buffer::new(rng.clone()), _cp._to_hp())),
+      move || {
+        let ca = CA::conn_aux(_ca_cb())?;
+        Ok((ca, _cp._to_h1p(), Http2Buffer::new(rng.clone()), _cp._to_hp()))
+      },
       err_cb,
+      move |_, req, sa| {
+        let rslt = Self::_http1_route_params(req.rrd.uri.path(), &sa.1)?;
+        http1_headers_cb(req)?;
+        Ok(rslt)
+      },
       Self::tokio_rustls_manual,
       move |_, _, req, sa| {
         let rslt = Self::_route_params(req.rrd.uri.path(), &sa.1)?;
@@ -137,11 +190,14 @@ where
       (
         || {
           crate::misc::TokioRustlsAcceptor::without_client_auth()
+            .http1()
             .http2()
             .build_with_cert_chain_and_priv_key(cert_chain, priv_key)
         },
         |acceptor| acceptor.clone(),
-        |acceptor, stream| async move { Ok(tokio::io::split(acceptor.accept(stream).await?)) },
+        |acceptor, stream| async move {
+          Ok(OptionedServer::_tls_parts(acceptor.accept(stream).await?))
+        },
       ),
     )
     .await
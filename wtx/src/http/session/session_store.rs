@@ -87,7 +87,6 @@ where
 #[cfg(feature = "pool")]
 mod pool {
   use crate::{
-    database::{client::postgres::Postgres, Decode, Encode},
     http::session::{SessionId, SessionState, SessionStore},
     misc::Lock,
     pool::{ResourceManager, SimplePool, SimplePoolResource},
@@ -96,7 +95,6 @@ mod pool {
 
   impl<CS, E, R, RL, RM> SessionStore<CS, E> for SimplePool<RL, RM>
   where
-    CS: for<'de> Decode<'de, Postgres<E>> + Encode<Postgres<E>>,
     E: From<Error>,
     R: SessionStore<CS, E>,
     RL: Lock<Resource = SimplePoolResource<R>>,
@@ -208,3 +206,78 @@ mod postgres {
     }
   }
 }
+
+#[cfg(feature = "redis")]
+mod redis {
+  use crate::{
+    database::client::redis::{Client, ClientBuffer},
+    http::session::{SessionId, SessionState, SessionStore},
+    misc::{LeaseMut, Stream},
+    Error,
+  };
+  use serde::{de::DeserializeOwned, Serialize};
+
+  const PREFIX: &[u8; 12] = b"wtx:session:";
+
+  /// Each state is stored as JSON in a key composed by the `wtx:session:` prefix followed by the
+  /// binary identifier.
+  ///
+  /// Expirations are delegated to the server through `PXAT`, which requires Redis 6.2 or newer,
+  /// as such, [`SessionStore::delete_expired`] does nothing.
+  impl<CB, CS, E, S> SessionStore<CS, E> for Client<CB, S>
+  where
+    CB: LeaseMut<ClientBuffer>,
+    CS: DeserializeOwned + Serialize,
+    E: From<Error>,
+    S: Stream,
+  {
+    #[inline]
+    async fn create(&mut self, state: &SessionState<CS>) -> Result<(), E> {
+      let value = serde_json::to_vec(state).map_err(Error::from)?;
+      if let Some(expires) = state.expires {
+        self
+          .execute::<_, ()>(("SET", key(&state.id), value, "PXAT", expires.timestamp_millis()))
+          .await?;
+      } else {
+        self.set(key(&state.id), value).await?;
+      }
+      Ok(())
+    }
+
+    #[inline]
+    async fn delete(&mut self, id: &SessionId) -> Result<(), E> {
+      let _ = self.del(&[key(id)]).await?;
+      Ok(())
+    }
+
+    #[inline]
+    async fn delete_expired(&mut self) -> Result<(), E> {
+      Ok(())
+    }
+
+    #[inline]
+    async fn read(&mut self, id: &SessionId) -> Result<Option<SessionState<CS>>, E> {
+      let Some(value) = self.get::<_, Option<&[u8]>>(key(id)).await? else {
+        return Ok(None);
+      };
+      Ok(Some(serde_json::from_slice(value).map_err(Error::from)?))
+    }
+
+    #[inline]
+    async fn update(&mut self, id: &SessionId, state: &SessionState<CS>) -> Result<(), E> {
+      if *id != state.id {
+        SessionStore::<CS, E>::delete(self, id).await?;
+      }
+      self.create(state).await
+    }
+  }
+
+  #[inline]
+  fn key(id: &SessionId) -> [u8; 28] {
+    let mut rslt = [0; 28];
+    for (to, from) in rslt.iter_mut().zip(PREFIX.iter().chain(id)) {
+      *to = *from;
+    }
+    rslt
+  }
+}
@@ -0,0 +1,335 @@
+//! HTTP/1.1
+//!
+//! 1. Bodies are entirely stored in memory before being delivered.
+//! 2. Clients don't support pipelining, in other words, a request is only sent after the
+//!    response of the previous request is received.
+//! 3. Received URIs always have the `http` scheme.
+//! 4. Servers reply with `400 Bad Request` and close the connection when a request has more
+//!    than one `Host` header or when a HTTP/1.1 request doesn't have a `Host` header.
+
+mod http1_buffer;
+mod http1_error;
+mod http1_params;
+mod misc;
+#[cfg(all(feature = "_async-tests", test))]
+mod tests;
+
+use crate::{
+  http::{
+    KnownHeaderName, Method, ReqResBuffer, ReqResData, ReqUri, Request, Response, StatusCode,
+    Version,
+  },
+  http1::misc::{
+    push_headers, read_body, read_more, version, write_framing, write_headers, BodyLen, MsgInfo,
+    MAX_HEADERS_NUM,
+  },
+  misc::{from_utf8_basic, ConnectionState, Lease, StreamReader, StreamWriter, Usize},
+};
+pub use http1_buffer::Http1Buffer;
+pub use http1_error::Http1Error;
+pub use http1_params::Http1Params;
+use httparse::{Status, EMPTY_HEADER};
+
+const BAD_REQUEST: &[u8] = b"HTTP/1.1 400 \r\nconnection: close\r\ncontent-length: 0\r\n\r\n";
+
+pub(crate) const MAX_BODY_LEN: u32 = 131_070;
+pub(crate) const MAX_HEADERS_LEN: u32 = 8_192;
+pub(crate) const READ_BUFFER_LEN: u32 = 4_096;
+
+/// Sends and receives messages of a single persistent connection.
+#[derive(Debug)]
+pub struct Http1<S, const IS_CLIENT: bool> {
+  hb: Http1Buffer,
+  hp: Http1Params,
+  is_conn_open: bool,
+  is_persistent: bool,
+  method: Method,
+  stream: S,
+  version: Version,
+}
+
+impl<S, const IS_CLIENT: bool> Http1<S, IS_CLIENT>
+where
+  S: StreamReader + StreamWriter,
+{
+  /// See [`ConnectionState`].
+  #[inline]
+  pub fn connection_state(&self) -> ConnectionState {
+    ConnectionState::from(self.is_conn_open)
+  }
+
+  /// Splits the instance into its buffer and its stream. Useful when the connection is upgraded
+  /// to another protocol.
+  #[inline]
+  pub fn into_parts(self) -> (Http1Buffer, S) {
+    (self.hb, self.stream)
+  }
+
+  #[inline]
+  fn new(hb: Http1Buffer, hp: Http1Params, stream: S) -> Self {
+    Self {
+      hb,
+      hp,
+      is_conn_open: true,
+      is_persistent: true,
+      method: Method::Get,
+      stream,
+      version: Version::Http1_1,
+    }
+  }
+}
+
+impl<S> Http1<S, false>
+where
+  S: StreamReader + StreamWriter,
+{
+  /// Creates a server connection. Bytes that were already read from `stream` can be provided
+  /// through [`Http1Buffer::with_initial_bytes`].
+  #[inline]
+  pub fn accept(hb: Http1Buffer, hp: Http1Params, stream: S) -> Self {
+    Self::new(hb, hp, stream)
+  }
+
+  /// Awaits for a request.
+  ///
+  /// Returns [`None`] if the connection has been closed by the remote peer or if the previous
+  /// response closed the connection.
+  #[inline]
+  pub async fn recv_req(
+    &mut self,
+    mut rrb: ReqResBuffer,
+  ) -> crate::Result<Option<Request<ReqResBuffer>>> {
+    if !self.is_conn_open {
+      return Ok(None);
+    }
+    self.is_conn_open = false;
+    rrb.clear();
+    let Self { hb, hp, method, stream, version: conn_version, .. } = self;
+    let (head_len, local_method, local_version) = loop {
+      if !hb.rb.is_empty() {
+        let mut headers = [EMPTY_HEADER; MAX_HEADERS_NUM];
+        let mut req = httparse::Request::new(&mut headers);
+        if let Status::Complete(len) = req.parse(&hb.rb)? {
+          let local_method = Method::try_from(req.method.unwrap_or_default())?;
+          push_headers(&mut rrb.headers, false, req.headers)?;
+          let local_version = version(req.version);
+          let host_name: &[u8] = KnownHeaderName::Host.into();
+          let mut hosts = rrb.headers.iter().filter(|el| el.name.as_bytes() == host_name);
+          let host = match (hosts.next(), hosts.next()) {
+            (Some(host_header), None) => from_utf8_basic(host_header.value)?,
+            (None, _) if local_version == Version::Http1 => "",
+            _ => {
+              stream.write_all(BAD_REQUEST).await?;
+              return Err(Http1Error::InvalidHost.into());
+            }
+          };
+          let target = req.path.unwrap_or_default();
+          rrb.uri.reset(|buffer| {
+            if target.contains("://") {
+              buffer.push_str(target);
+              return Ok(());
+            }
+            buffer.push_str("http://");
+            if target.starts_with('/') {
+              buffer.push_str(host);
+              buffer.push_str(target);
+            } else if target == "*" {
+              buffer.push_str(host);
+            } else {
+              buffer.push_str(target);
+            }
+            Ok(())
+          })?;
+          break (len, local_method, local_version);
+        }
+      }
+      if hb.rb.len() > *Usize::from(hp.max_headers_len()) {
+        return Err(Http1Error::VeryLargeHeadersLen.into());
+      }
+      if read_more(hp, &mut hb.rb, stream).await? == 0 {
+        if hb.rb.is_empty() {
+          return Ok(None);
+        }
+        return Err(crate::Error::UnexpectedStreamReadEOF);
+      }
+    };
+    let mi = MsgInfo::new(&rrb.headers)?;
+    let body_len = match mi.body_len {
+      None => BodyLen::Fixed(0),
+      Some(BodyLen::Eof) => return Err(Http1Error::UnsupportedTransferEncoding.into()),
+      Some(elem) => elem,
+    };
+    if mi.has_continue && local_version == Version::Http1_1 && body_len != BodyLen::Fixed(0) {
+      stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").await?;
+    }
+    let idx =
+      read_body(&mut rrb.body, body_len, &mut rrb.headers, hp, head_len, &mut hb.rb, stream)
+        .await?;
+    drop(hb.rb.drain(..idx));
+    *method = local_method;
+    *conn_version = local_version;
+    self.is_conn_open = true;
+    self.is_persistent = mi.is_persistent(local_version);
+    Ok(Some(Request { method: local_method, rrd: rrb, version: local_version }))
+  }
+
+  /// Sends the response of the last received request.
+  ///
+  /// The connection is closed afterwards if the request or the response didn't allow
+  /// subsequent messages.
+  #[inline]
+  pub async fn send_res<RRD>(&mut self, res: Response<RRD>) -> crate::Result<()>
+  where
+    RRD: ReqResData,
+    RRD::Body: Lease<[u8]>,
+  {
+    let Self { hb, is_conn_open, is_persistent, method, stream, version, .. } = self;
+    if !*is_conn_open {
+      return Err(crate::Error::ClosedConnection);
+    }
+    *is_conn_open = false;
+    let status_code = res.status_code;
+    // HTTP/1.0 clients don't support chunked encoding, as such, trailers are dropped and the
+    // connection isn't kept alive.
+    let is_http1_0 = *version == Version::Http1;
+    let is_close = !*is_persistent
+      || MsgInfo::new(res.rrd.headers())?.has_close
+      || (is_http1_0 && res.rrd.headers().trailers().has_any());
+    let has_body = {
+      let n = u16::from(status_code);
+      !(100..200).contains(&n) && status_code != StatusCode::NoContent && n != 304
+    };
+    hb.wb.clear();
+    hb.wb.extend_from_copyable_slices([
+      b"HTTP/1.1 ".as_slice(),
+      status_code.strings().number.as_bytes(),
+      b" \r\n",
+    ])?;
+    write_headers(res.rrd.headers(), &mut hb.wb)?;
+    if !is_close && is_http1_0 {
+      // HTTP/1.0 clients only reuse connections that are explicitly kept alive.
+      hb.wb.extend_from_copyable_slice(b"connection: keep-alive\r\n")?;
+    }
+    let body = write_framing(
+      !is_http1_0,
+      res.rrd.body().lease(),
+      has_body,
+      res.rrd.headers(),
+      is_close,
+      &mut hb.wb,
+    )?;
+    if *method == Method::Head || body.is_empty() {
+      stream.write_all(&hb.wb).await?;
+    } else {
+      stream.write_all_vectored(&[&hb.wb, body]).await?;
+    }
+    *is_conn_open = !is_close;
+    Ok(())
+  }
+}
+
+impl<S> Http1<S, true>
+where
+  S: StreamReader + StreamWriter,
+{
+  /// Creates a client connection.
+  #[inline]
+  pub fn connect(hb: Http1Buffer, hp: Http1Params, stream: S) -> Self {
+    Self::new(hb, hp, stream)
+  }
+
+  /// Receives the response of the last sent request.
+  #[inline]
+  pub async fn recv_res(&mut self, mut rrb: ReqResBuffer) -> crate::Result<Response<ReqResBuffer>> {
+    let Self { hb, hp, is_conn_open, is_persistent, method, stream, .. } = self;
+    if !*is_conn_open {
+      return Err(crate::Error::ClosedConnection);
+    }
+    *is_conn_open = false;
+    rrb.clear();
+    let (head_len, status_code, local_version) = loop {
+      if !hb.rb.is_empty() {
+        let mut headers = [EMPTY_HEADER; MAX_HEADERS_NUM];
+        let mut res = httparse::Response::new(&mut headers);
+        if let Status::Complete(len) = res.parse(&hb.rb)? {
+          let code = res.code.unwrap_or_default();
+          if (100..200).contains(&code) && code != 101 {
+            drop(hb.rb.drain(..len));
+            continue;
+          }
+          push_headers(&mut rrb.headers, false, res.headers)?;
+          break (len, StatusCode::try_from(code)?, version(res.version));
+        }
+      }
+      if hb.rb.len() > *Usize::from(hp.max_headers_len()) {
+        return Err(Http1Error::VeryLargeHeadersLen.into());
+      }
+      if read_more(hp, &mut hb.rb, stream).await? == 0 {
+        return Err(crate::Error::UnexpectedStreamReadEOF);
+      }
+    };
+    let mi = MsgInfo::new(&rrb.headers)?;
+    let n = u16::from(status_code);
+    let has_body = *method != Method::Head
+      && !(100..200).contains(&n)
+      && status_code != StatusCode::NoContent
+      && n != 304;
+    let body_len = match mi.body_len {
+      _ if !has_body => BodyLen::Fixed(0),
+      None => BodyLen::Eof,
+      Some(elem) => elem,
+    };
+    let idx =
+      read_body(&mut rrb.body, body_len, &mut rrb.headers, hp, head_len, &mut hb.rb, stream)
+        .await?;
+    drop(hb.rb.drain(..idx));
+    *is_conn_open = *is_persistent && body_len != BodyLen::Eof && mi.is_persistent(local_version);
+    Ok(Response { rrd: rrb, status_code, version: local_version })
+  }
+
+  /// Sends a request to the server.
+  #[inline]
+  pub async fn send_req<RRD>(
+    &mut self,
+    req: Request<RRD>,
+    req_uri: impl Into<ReqUri<'_>>,
+  ) -> crate::Result<()>
+  where
+    RRD: ReqResData,
+    RRD::Body: Lease<[u8]>,
+  {
+    let Self { hb, is_conn_open, is_persistent, method, stream, .. } = self;
+    if !*is_conn_open {
+      return Err(crate::Error::ClosedConnection);
+    }
+    *is_conn_open = false;
+    let uri = match req_uri.into() {
+      ReqUri::Data => &req.rrd.uri().to_ref(),
+      ReqUri::Param(elem) => elem,
+    };
+    let is_close = MsgInfo::new(req.rrd.headers())?.has_close;
+    let body = req.rrd.body().lease();
+    let has_body =
+      !body.is_empty() || matches!(req.method, Method::Patch | Method::Post | Method::Put);
+    hb.wb.clear();
+    hb.wb.extend_from_copyable_slices([
+      req.method.strings().custom[0].as_bytes(),
+      b" ",
+      uri.relative_reference_slash().as_bytes(),
+      b" HTTP/1.1\r\nhost: ",
+      if matches!(uri.port(), Some(80 | 443)) { uri.hostname() } else { uri.host() }.as_bytes(),
+      b"\r\n",
+    ])?;
+    write_headers(req.rrd.headers(), &mut hb.wb)?;
+    let local_body = write_framing(true, body, has_body, req.rrd.headers(), is_close, &mut hb.wb)?;
+    if local_body.is_empty() {
+      stream.write_all(&hb.wb).await?;
+    } else {
+      stream.write_all_vectored(&[&hb.wb, local_body]).await?;
+    }
+    *method = req.method;
+    *is_conn_open = true;
+    *is_persistent = !is_close;
+    Ok(())
+  }
+}
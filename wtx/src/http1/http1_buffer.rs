@@ -0,0 +1,25 @@
+use crate::misc::Vector;
+
+/// Groups all intermediate structures necessary to perform HTTP/1.1 connections.
+#[derive(Debug, Default)]
+pub struct Http1Buffer {
+  /// Received bytes that weren't processed yet.
+  pub(crate) rb: Vector<u8>,
+  /// Start line and headers of outgoing messages.
+  pub(crate) wb: Vector<u8>,
+}
+
+impl Http1Buffer {
+  /// Creates a new instance without pre-allocated resources.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { rb: Vector::new(), wb: Vector::new() }
+  }
+
+  /// Creates a new instance with bytes that were already read from the network, which will be
+  /// processed before anything else.
+  #[inline]
+  pub fn with_initial_bytes(bytes: &[u8]) -> crate::Result<Self> {
+    Ok(Self { rb: Vector::from_slice(bytes)?, wb: Vector::new() })
+  }
+}
@@ -0,0 +1,18 @@
+/// Errors for `Http1`.
+#[derive(Debug)]
+pub enum Http1Error {
+  /// A message can't contain both `Content-Length` and `Transfer-Encoding` headers.
+  ContentLengthWithTransferEncoding,
+  /// A chunk of a body encoded with `chunked` doesn't have the expected format.
+  InvalidChunk,
+  /// The value of a `Content-Length` header is not a valid number.
+  InvalidContentLength,
+  /// Received request doesn't have exactly one `Host` header.
+  InvalidHost,
+  /// Received request has a transfer encoding that isn't `chunked`.
+  UnsupportedTransferEncoding,
+  /// Received body has a length greater than the specified threshold.
+  VeryLargeBodyLen,
+  /// Received start line and headers have a length greater than the specified threshold.
+  VeryLargeHeadersLen,
+}
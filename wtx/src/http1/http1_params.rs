@@ -0,0 +1,76 @@
+use crate::http1::{MAX_BODY_LEN, MAX_HEADERS_LEN, READ_BUFFER_LEN};
+
+/// Configurations for the local structures of a connection.
+#[derive(Clone, Copy, Debug)]
+pub struct Http1Params {
+  max_body_len: u32,
+  max_headers_len: u32,
+  read_buffer_len: u32,
+}
+
+impl Http1Params {
+  /// Maximum request/response body length
+  ///
+  /// Applies to bodies delimited by `Content-Length`, by chunks or by the end of the connection.
+  ///
+  /// Defaults to 131070 bytes.
+  #[inline]
+  pub const fn max_body_len(&self) -> u32 {
+    self.max_body_len
+  }
+
+  /// Maximum headers length
+  ///
+  /// The sum of the lengths of the start line, of the headers and of the trailers.
+  ///
+  /// Defaults to 8192 bytes.
+  #[inline]
+  pub const fn max_headers_len(&self) -> u32 {
+    self.max_headers_len
+  }
+
+  /// Read Buffer Length.
+  ///
+  /// Additional space allocated every time bytes sent by external actors are read.
+  ///
+  /// Defaults to 4096 bytes.
+  #[inline]
+  pub const fn read_buffer_len(&self) -> u32 {
+    self.read_buffer_len
+  }
+
+  /// Mutable version of [`Self::max_body_len`].
+  #[inline]
+  #[must_use]
+  pub fn set_max_body_len(mut self, value: u32) -> Self {
+    self.max_body_len = value;
+    self
+  }
+
+  /// Mutable version of [`Self::max_headers_len`].
+  #[inline]
+  #[must_use]
+  pub fn set_max_headers_len(mut self, value: u32) -> Self {
+    self.max_headers_len = value;
+    self
+  }
+
+  /// Mutable version of [`Self::read_buffer_len`].
+  #[inline]
+  #[must_use]
+  pub fn set_read_buffer_len(mut self, value: u32) -> Self {
+    self.read_buffer_len = value.max(1);
+    self
+  }
+}
+
+impl Default for Http1Params {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      max_body_len: MAX_BODY_LEN,
+      max_headers_len: MAX_HEADERS_LEN,
+      read_buffer_len: READ_BUFFER_LEN,
+    }
+  }
+}
@@ -0,0 +1,301 @@
+use crate::{
+  http::{Header, Headers, HttpError, Version, MAX_HEADER_NAME_LEN},
+  http1::{Http1Error, Http1Params},
+  misc::{bytes_split1, from_utf8_basic, BufferMode, FromRadix10, StreamReader, Usize, Vector},
+};
+use core::fmt::Write as _;
+use httparse::{Status, EMPTY_HEADER};
+
+pub(crate) const MAX_HEADERS_NUM: usize = 64;
+
+/// How the end of a body is delimited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum BodyLen {
+  Chunked,
+  Eof,
+  Fixed(usize),
+}
+
+/// Framing and connection information extracted from the headers of a message.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MsgInfo {
+  pub(crate) body_len: Option<BodyLen>,
+  pub(crate) has_close: bool,
+  pub(crate) has_continue: bool,
+  pub(crate) has_keep_alive: bool,
+}
+
+impl MsgInfo {
+  #[inline]
+  pub(crate) fn new(headers: &Headers) -> crate::Result<Self> {
+    let mut content_length = None;
+    let mut has_close = false;
+    let mut has_continue = false;
+    let mut has_keep_alive = false;
+    let mut transfer_encoding = None;
+    for header in headers.iter() {
+      match header.name.as_bytes() {
+        b"connection" => {
+          for token in bytes_split1(header.value, b',') {
+            let trimmed = token.trim_ascii();
+            has_close |= trimmed.eq_ignore_ascii_case(b"close");
+            has_keep_alive |= trimmed.eq_ignore_ascii_case(b"keep-alive");
+          }
+        }
+        b"content-length" => {
+          let len = usize::from_radix_10(header.value.trim_ascii())
+            .map_err(|_err| Http1Error::InvalidContentLength)?;
+          if content_length.is_some_and(|el| el != len) {
+            return Err(Http1Error::InvalidContentLength.into());
+          }
+          content_length = Some(len);
+        }
+        b"expect" => {
+          has_continue = header.value.trim_ascii().eq_ignore_ascii_case(b"100-continue");
+        }
+        b"transfer-encoding" => {
+          let last = bytes_split1(header.value, b',').last().unwrap_or_default();
+          transfer_encoding = Some(last.trim_ascii().eq_ignore_ascii_case(b"chunked"));
+        }
+        _ => {}
+      }
+    }
+    let body_len = match (content_length, transfer_encoding) {
+      (None, None) => None,
+      (None, Some(is_chunked)) => Some(if is_chunked { BodyLen::Chunked } else { BodyLen::Eof }),
+      (Some(len), None) => Some(BodyLen::Fixed(len)),
+      (Some(_), Some(_)) => return Err(Http1Error::ContentLengthWithTransferEncoding.into()),
+    };
+    Ok(Self { body_len, has_close, has_continue, has_keep_alive })
+  }
+
+  /// If the connection can be used by subsequent messages.
+  #[inline]
+  pub(crate) fn is_persistent(&self, version: Version) -> bool {
+    match version {
+      Version::Http1 => self.has_keep_alive && !self.has_close,
+      _ => !self.has_close,
+    }
+  }
+}
+
+/// Pushes received headers using lowercase names, which is the representation used by all
+/// HTTP versions of this project.
+#[inline]
+pub(crate) fn push_headers(
+  headers: &mut Headers,
+  is_trailer: bool,
+  received: &[httparse::Header<'_>],
+) -> crate::Result<()> {
+  for header in received {
+    if header.name.len() > MAX_HEADER_NAME_LEN {
+      return Err(HttpError::HeaderFieldIsTooLarge.into());
+    }
+    let mut buffer = [0; MAX_HEADER_NAME_LEN];
+    let name = buffer.get_mut(..header.name.len()).unwrap_or_default();
+    name.copy_from_slice(header.name.as_bytes());
+    name.make_ascii_lowercase();
+    headers.push_from_iter(Header {
+      is_sensitive: false,
+      is_trailer,
+      name: from_utf8_basic(name)?,
+      value: [header.value],
+    })?;
+  }
+  Ok(())
+}
+
+/// Reads more bytes into `rb`, returning the number of read bytes.
+#[inline]
+pub(crate) async fn read_more<SR>(
+  hp: &Http1Params,
+  rb: &mut Vector<u8>,
+  stream: &mut SR,
+) -> crate::Result<usize>
+where
+  SR: StreamReader,
+{
+  let len = rb.len();
+  rb.expand(BufferMode::Additional(*Usize::from(hp.read_buffer_len())), 0)?;
+  let rslt = stream.read(rb.get_mut(len..).unwrap_or_default()).await;
+  rb.truncate(len.wrapping_add(*rslt.as_ref().unwrap_or(&0)));
+  rslt
+}
+
+/// Reads the body that starts at `idx`, returning the index of the first byte that doesn't
+/// belong to the message.
+#[inline]
+pub(crate) async fn read_body<SR>(
+  body: &mut Vector<u8>,
+  body_len: BodyLen,
+  headers: &mut Headers,
+  hp: &Http1Params,
+  mut idx: usize,
+  rb: &mut Vector<u8>,
+  stream: &mut SR,
+) -> crate::Result<usize>
+where
+  SR: StreamReader,
+{
+  let max_body_len = *Usize::from(hp.max_body_len());
+  match body_len {
+    BodyLen::Chunked => {
+      loop {
+        let (chunk_begin, chunk_len) = loop {
+          match httparse::parse_chunk_size(rb.get(idx..).unwrap_or_default())
+            .map_err(|_err| Http1Error::InvalidChunk)?
+          {
+            Status::Complete((local_idx, len)) => {
+              break (
+                idx.wrapping_add(local_idx),
+                usize::try_from(len).map_err(|_err| Http1Error::VeryLargeBodyLen)?,
+              );
+            }
+            Status::Partial => {
+              if rb.len().wrapping_sub(idx) > *Usize::from(hp.max_headers_len()) {
+                return Err(Http1Error::InvalidChunk.into());
+              }
+              read_more_or_eof(hp, rb, stream).await?;
+            }
+          }
+        };
+        idx = chunk_begin;
+        if chunk_len == 0 {
+          break;
+        }
+        if body.len().saturating_add(chunk_len) > max_body_len {
+          return Err(Http1Error::VeryLargeBodyLen.into());
+        }
+        let chunk_end = chunk_begin.wrapping_add(chunk_len);
+        while rb.len() < chunk_end.wrapping_add(2) {
+          read_more_or_eof(hp, rb, stream).await?;
+        }
+        body.extend_from_copyable_slice(rb.get(chunk_begin..chunk_end).unwrap_or_default())?;
+        if rb.get(chunk_end..chunk_end.wrapping_add(2)) != Some(b"\r\n") {
+          return Err(Http1Error::InvalidChunk.into());
+        }
+        idx = chunk_end.wrapping_add(2);
+      }
+      loop {
+        if rb.len().wrapping_sub(idx) > *Usize::from(hp.max_headers_len()) {
+          return Err(Http1Error::VeryLargeHeadersLen.into());
+        }
+        let mut trailers = [EMPTY_HEADER; MAX_HEADERS_NUM];
+        if let Status::Complete((len, local_trailers)) =
+          httparse::parse_headers(rb.get(idx..).unwrap_or_default(), &mut trailers)?
+        {
+          push_headers(headers, true, local_trailers)?;
+          idx = idx.wrapping_add(len);
+          break;
+        }
+        read_more_or_eof(hp, rb, stream).await?;
+      }
+    }
+    BodyLen::Eof => {
+      loop {
+        if rb.len().wrapping_sub(idx) > max_body_len {
+          return Err(Http1Error::VeryLargeBodyLen.into());
+        }
+        if read_more(hp, rb, stream).await? == 0 {
+          break;
+        }
+      }
+      body.extend_from_copyable_slice(rb.get(idx..).unwrap_or_default())?;
+      idx = rb.len();
+    }
+    BodyLen::Fixed(len) => {
+      if len > max_body_len {
+        return Err(Http1Error::VeryLargeBodyLen.into());
+      }
+      let end = idx.wrapping_add(len);
+      while rb.len() < end {
+        read_more_or_eof(hp, rb, stream).await?;
+      }
+      body.extend_from_copyable_slice(rb.get(idx..end).unwrap_or_default())?;
+      idx = end;
+    }
+  }
+  Ok(idx)
+}
+
+/// Version of a parsed message.
+#[inline]
+pub(crate) fn version(n: Option<u8>) -> Version {
+  if n == Some(0) {
+    Version::Http1
+  } else {
+    Version::Http1_1
+  }
+}
+
+/// Writes all headers that aren't trailers or that aren't managed by the connection.
+#[inline]
+pub(crate) fn write_headers(headers: &Headers, wb: &mut Vector<u8>) -> crate::Result<()> {
+  for header in headers.iter() {
+    if header.is_trailer || is_managed_header(header.name.as_bytes()) {
+      continue;
+    }
+    wb.extend_from_copyable_slices([header.name.as_bytes(), b": ", header.value, b"\r\n"])?;
+  }
+  Ok(())
+}
+
+/// Finishes the header section of a message, which was partially written in `wb`, and returns
+/// the bytes of the body. Bodies of messages with trailers are sent as a single chunk, unless
+/// the peer doesn't support chunked encoding, in which case the trailers are dropped.
+#[inline]
+pub(crate) fn write_framing<'body>(
+  allows_chunked: bool,
+  body: &'body [u8],
+  has_body: bool,
+  headers: &Headers,
+  is_close: bool,
+  wb: &mut Vector<u8>,
+) -> crate::Result<&'body [u8]> {
+  if is_close {
+    wb.extend_from_copyable_slice(b"connection: close\r\n")?;
+  }
+  if !has_body {
+    wb.extend_from_copyable_slice(b"\r\n")?;
+    return Ok(&[]);
+  }
+  if !allows_chunked || !headers.trailers().has_any() {
+    wb.write_fmt(format_args!("content-length: {}\r\n\r\n", body.len()))?;
+    return Ok(body);
+  }
+  wb.extend_from_copyable_slice(b"transfer-encoding: chunked\r\n\r\n")?;
+  if !body.is_empty() {
+    wb.write_fmt(format_args!("{:x}\r\n", body.len()))?;
+    wb.extend_from_copyable_slices([body, b"\r\n"])?;
+  }
+  wb.extend_from_copyable_slice(b"0\r\n")?;
+  for header in headers.iter() {
+    if header.is_trailer && !is_managed_header(header.name.as_bytes()) {
+      wb.extend_from_copyable_slices([header.name.as_bytes(), b": ", header.value, b"\r\n"])?;
+    }
+  }
+  wb.extend_from_copyable_slice(b"\r\n")?;
+  Ok(&[])
+}
+
+#[inline]
+fn is_managed_header(name: &[u8]) -> bool {
+  [b"connection".as_slice(), b"content-length", b"host", b"transfer-encoding"]
+    .iter()
+    .any(|el| name.eq_ignore_ascii_case(el))
+}
+
+#[inline]
+async fn read_more_or_eof<SR>(
+  hp: &Http1Params,
+  rb: &mut Vector<u8>,
+  stream: &mut SR,
+) -> crate::Result<()>
+where
+  SR: StreamReader,
+{
+  if read_more(hp, rb, stream).await? == 0 {
+    return Err(crate::Error::UnexpectedStreamReadEOF);
+  }
+  Ok(())
+}
@@ -0,0 +1,178 @@
+use crate::{
+  http::{Header, Method, ReqResBuffer, Request, StatusCode, Version},
+  http1::{Http1, Http1Buffer, Http1Error, Http1Params},
+  misc::{BytesStream, StreamReader, StreamWriter, UriString},
+  tests::_uri,
+};
+use tokio::net::{TcpListener, TcpStream};
+
+#[tokio::test]
+async fn chunked_and_pipelined_requests() {
+  let mut stream = BytesStream::default();
+  stream
+    .write_all(
+      b"POST /a HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n\
+      3\r\nabc\r\n2;ext=1\r\nde\r\n0\r\nX-Trailer: 1\r\n\r\n\
+      GET /b?c=d HTTP/1.1\r\nHOST: localhost\r\nConnection: close\r\n\r\n",
+    )
+    .await
+    .unwrap();
+  let mut http1 = Http1::accept(Http1Buffer::new(), Http1Params::default(), stream);
+
+  let req = http1.recv_req(ReqResBuffer::empty()).await.unwrap().unwrap();
+  assert_eq!((req.method, req.version), (Method::Post, Version::Http1_1));
+  assert_eq!(req.rrd.body.as_slice(), b"abcde");
+  assert_eq!(req.rrd.uri.as_str(), "http://localhost/a");
+  let trailer = req.rrd.headers.get_by_name(b"x-trailer").unwrap();
+  assert_eq!((trailer.is_trailer, trailer.value), (true, "1".as_bytes()));
+  http1.send_res(req.into_response(StatusCode::Ok)).await.unwrap();
+
+  let mut req = http1.recv_req(ReqResBuffer::empty()).await.unwrap().unwrap();
+  assert_eq!(req.method, Method::Get);
+  assert_eq!(req.rrd.uri.path(), "/b");
+  assert_eq!(req.rrd.uri.query_and_fragment(), "?c=d");
+  req.rrd.clear();
+  req.rrd.body.extend_from_copyable_slice(b"fgh").unwrap();
+  http1.send_res(req.into_response(StatusCode::NotFound)).await.unwrap();
+  assert!(http1.connection_state().is_closed());
+  assert!(http1.recv_req(ReqResBuffer::empty()).await.unwrap().is_none());
+
+  let (_, mut stream) = http1.into_parts();
+  let mut buffer = [0; 256];
+  let len = stream.read(&mut buffer).await.unwrap();
+  assert_eq!(
+    buffer.get(..len).unwrap(),
+    b"HTTP/1.1 200 \r\ntransfer-encoding: chunked\r\n\r\n5\r\nabcde\r\n0\r\n\
+    x-trailer: 1\r\n\r\nHTTP/1.1 404 \r\nconnection: close\r\ncontent-length: 3\r\n\r\nfgh"
+  );
+}
+
+#[tokio::test]
+async fn http1_0_keep_alive_is_echoed() {
+  let mut stream = BytesStream::default();
+  stream
+    .write_all(
+      b"GET /a HTTP/1.0\r\nHost: localhost\r\nConnection: keep-alive\r\n\r\n\
+      GET /b HTTP/1.0\r\nHost: localhost\r\n\r\n",
+    )
+    .await
+    .unwrap();
+  let mut http1 = Http1::accept(Http1Buffer::new(), Http1Params::default(), stream);
+  let req = http1.recv_req(ReqResBuffer::empty()).await.unwrap().unwrap();
+  assert_eq!(req.version, Version::Http1);
+  http1.send_res(req.into_response(StatusCode::Ok)).await.unwrap();
+  assert!(http1.connection_state().is_open());
+  let req = http1.recv_req(ReqResBuffer::empty()).await.unwrap().unwrap();
+  http1.send_res(req.into_response(StatusCode::Ok)).await.unwrap();
+  assert!(http1.connection_state().is_closed());
+  let (_, mut stream) = http1.into_parts();
+  let mut buffer = [0; 256];
+  let len = stream.read(&mut buffer).await.unwrap();
+  assert_eq!(
+    buffer.get(..len).unwrap(),
+    b"HTTP/1.1 200 \r\nconnection: keep-alive\r\ncontent-length: 0\r\n\r\n\
+    HTTP/1.1 200 \r\nconnection: close\r\ncontent-length: 0\r\n\r\n"
+  );
+}
+
+#[tokio::test]
+async fn http1_0_requests_without_host_are_accepted_and_responses_are_not_chunked() {
+  let mut stream = BytesStream::default();
+  stream.write_all(b"GET /a HTTP/1.0\r\nConnection: keep-alive\r\n\r\n").await.unwrap();
+  let mut http1 = Http1::accept(Http1Buffer::new(), Http1Params::default(), stream);
+  let req = http1.recv_req(ReqResBuffer::empty()).await.unwrap().unwrap();
+  assert_eq!((req.version, req.rrd.uri.path()), (Version::Http1, "/a"));
+  let mut res = req.into_response(StatusCode::Ok);
+  res.rrd.body.extend_from_copyable_slice(b"abc").unwrap();
+  res
+    .rrd
+    .headers
+    .push_from_iter(Header {
+      is_sensitive: false,
+      is_trailer: true,
+      name: "x-trailer",
+      value: [&b"1"[..]],
+    })
+    .unwrap();
+  http1.send_res(res).await.unwrap();
+  assert!(http1.connection_state().is_closed());
+  let (_, mut stream) = http1.into_parts();
+  let mut buffer = [0; 128];
+  let len = stream.read(&mut buffer).await.unwrap();
+  assert_eq!(
+    buffer.get(..len).unwrap(),
+    b"HTTP/1.1 200 \r\nconnection: close\r\ncontent-length: 3\r\n\r\nabc"
+  );
+}
+
+#[tokio::test]
+async fn requests_without_a_single_host_are_rejected() {
+  for bytes in [
+    &b"GET /a HTTP/1.1\r\n\r\n"[..],
+    b"GET /a HTTP/1.1\r\nHost: a\r\nHost: b\r\n\r\n",
+    b"GET /a HTTP/1.0\r\nHost: a\r\nHost: b\r\n\r\n",
+  ] {
+    let mut stream = BytesStream::default();
+    stream.write_all(bytes).await.unwrap();
+    let mut http1 = Http1::accept(Http1Buffer::new(), Http1Params::default(), stream);
+    assert!(matches!(
+      http1.recv_req(ReqResBuffer::empty()).await,
+      Err(crate::Error::Http1Error(Http1Error::InvalidHost))
+    ));
+    assert!(http1.connection_state().is_closed());
+    let (_, mut stream) = http1.into_parts();
+    let mut buffer = [0; 128];
+    let len = stream.read(&mut buffer).await.unwrap();
+    assert_eq!(
+      buffer.get(..len).unwrap(),
+      b"HTTP/1.1 400 \r\nconnection: close\r\ncontent-length: 0\r\n\r\n"
+    );
+  }
+}
+
+#[tokio::test]
+async fn connections() {
+  let uri = _uri();
+  server(&uri).await;
+  client(&uri).await;
+}
+
+async fn client(uri: &UriString) {
+  let mut http1 = Http1::connect(
+    Http1Buffer::new(),
+    Http1Params::default(),
+    TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap(),
+  );
+  let uri_ref = uri.to_ref();
+  let mut rrb = ReqResBuffer::empty();
+  for idx in 0..3 {
+    rrb.clear();
+    rrb.body.extend_from_copyable_slice(b"123").unwrap();
+    rrb.headers.push_from_iter(Header::from_name_and_value("Idx", [&[idx + b'0'][..]])).unwrap();
+    http1.send_req(Request::http2(Method::Post, &rrb), &uri_ref).await.unwrap();
+    let res = http1.recv_res(rrb).await.unwrap();
+    assert_eq!((res.status_code, res.version), (StatusCode::Ok, Version::Http1_1));
+    assert_eq!(res.rrd.body.as_slice(), b"123");
+    assert_eq!(res.rrd.headers.get_by_name(b"idx").unwrap().value, &[idx + b'0']);
+    assert!(http1.connection_state().is_open());
+    rrb = res.rrd;
+  }
+  rrb.clear();
+  http1.send_req(Request::http2(Method::Head, &rrb), &uri_ref).await.unwrap();
+  let res = http1.recv_res(rrb).await.unwrap();
+  assert_eq!(res.rrd.body.as_slice(), b"");
+  assert!(http1.connection_state().is_open());
+}
+
+async fn server(uri: &UriString) {
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut http1 = Http1::accept(Http1Buffer::new(), Http1Params::default(), stream);
+    let mut rrb = ReqResBuffer::empty();
+    while let Some(req) = http1.recv_req(rrb).await.unwrap() {
+      http1.send_res(req.rrd.as_http2_response(StatusCode::Ok)).await.unwrap();
+      rrb = req.rrd;
+    }
+  });
+}
@@ -27,6 +27,8 @@ mod error;
 pub mod grpc;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "http1")]
+pub mod http1;
 #[cfg(feature = "http2")]
 pub mod http2;
 pub mod misc;
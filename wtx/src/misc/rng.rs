@@ -97,11 +97,8 @@ where
     if slice.len() <= 1 {
       return;
     }
-    for from_idx in 0..slice.len() {
-      let range = 0..from_idx.wrapping_add(1);
-      let Some(to_idx) = self.elem_from_range(&range) else {
-        continue;
-      };
+    for from_idx in (1..slice.len()).rev() {
+      let to_idx = usize::from_rng(self).wrapping_rem(from_idx.wrapping_add(1));
       slice.swap(from_idx, to_idx);
     }
   }
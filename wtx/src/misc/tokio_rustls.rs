@@ -104,6 +104,7 @@ impl Default for TokioRustlsConnector {
 #[derive(Debug)]
 pub struct TokioRustlsAcceptor {
   builder: ConfigBuilder<ServerConfig, WantsServerCert>,
+  is_http1: bool,
   is_http2: bool,
 }
 
@@ -111,7 +112,11 @@ impl TokioRustlsAcceptor {
   /// New instance without client authentication.
   #[inline]
   pub fn without_client_auth() -> Self {
-    Self { builder: ServerConfig::builder().with_no_client_auth(), is_http2: false }
+    Self {
+      builder: ServerConfig::builder().with_no_client_auth(),
+      is_http1: false,
+      is_http2: false,
+    }
   }
 
   /// Creates a [`tokio_rustls::TlsAcceptor`] with a single certificate chain and matching private
@@ -126,13 +131,28 @@ impl TokioRustlsAcceptor {
       rustls_pemfile::certs(&mut &*cert_chain).collect::<Result<_, _>>()?,
       rustls_pemfile::private_key(&mut &*priv_key)?.ok_or_else(|| invalid_input_err("No key"))?,
     )?;
-    if self.is_http2 {
+    if self.is_http1 || self.is_http2 {
       config.alpn_protocols.clear();
+    }
+    if self.is_http2 {
       config.alpn_protocols.push("h2".into());
     }
+    if self.is_http1 {
+      config.alpn_protocols.push("http/1.1".into());
+    }
     Ok(TlsAcceptor::from(Arc::new(config)))
   }
 
+  /// Erases the set of ALPN protocols when building and then pushes the expected ALPN value for an
+  /// HTTP/1.1 connection.
+  ///
+  /// Can be combined with [`Self::http2`], in which case HTTP/2 is preferred.
+  #[inline]
+  pub fn http1(mut self) -> Self {
+    self.is_http1 = true;
+    self
+  }
+
   /// Erases the set of ALPN protocols when building and then pushes the expected ALPN value for an
   /// HTTP2 connection.
   #[inline]
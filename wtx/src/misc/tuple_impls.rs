@@ -293,6 +293,32 @@ macro_rules! impl_tuples {
         }
       )+
     }
+
+    #[cfg(feature = "redis")]
+    mod redis {
+      use crate::{
+        database::client::redis::{encode_arg, Arg, Command},
+        misc::Vector
+      };
+
+      $(
+        impl<$($T,)*> Command for ($( $T, )*)
+        where
+          $($T: Arg,)*
+        {
+          #[inline]
+          fn encode_args(&self, _wb: &mut Vector<u8>) -> crate::Result<()> {
+            $( encode_arg(&self.$N, _wb)?; )*
+            Ok(())
+          }
+
+          #[inline]
+          fn len(&self) -> usize {
+            const { 0 $(+ { const $T: usize = 1; $T })* }
+          }
+        }
+      )+
+    }
   }
 }
 
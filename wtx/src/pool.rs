@@ -7,6 +7,8 @@ mod simple_pool;
 use core::future::Future;
 #[cfg(feature = "postgres")]
 pub use resource_manager::database::PostgresRM;
+#[cfg(feature = "redis")]
+pub use resource_manager::redis::RedisRM;
 pub use resource_manager::{ResourceManager, SimpleRM};
 #[cfg(feature = "std")]
 pub use simple_pool::*;
@@ -109,9 +109,9 @@ pub(crate) mod database {
   }
 
   macro_rules! _executor {
-    ($uri_str:expr, |$config:ident, $uri:ident| $cb:expr) => {{
-      let $uri = crate::misc::UriRef::new($uri_str);
-      let config_rslt = crate::database::client::postgres::Config::from_uri(&$uri);
+    ($uri_str:expr, |$config:ident| $cb:expr) => {{
+      let uri = crate::misc::UriRef::new($uri_str);
+      let config_rslt = crate::database::client::postgres::Config::from_uri(&uri);
       let $config = config_rslt?;
       Ok($cb.await?)
     }};
@@ -158,12 +158,12 @@ pub(crate) mod database {
       #[inline]
       async fn create(&self, _: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
         let mut rng = &self._rng;
-        _executor!(&self._uri, |config, uri| {
-          Executor::connect(
+        _executor!(&self._uri, |config| {
+          Executor::connect_hosts(
             &config,
             ExecutorBuffer::new(self._max_stmts, &mut rng),
             &mut rng,
-            TcpStream::connect(uri.hostname_with_implied_port()).await.map_err(Into::into)?,
+            |host, port| async move { Ok(TcpStream::connect((host, port)).await?) },
           )
         })
       }
@@ -182,13 +182,10 @@ pub(crate) mod database {
         let mut rng = &self._rng;
         let mut buffer = ExecutorBuffer::new(self._max_stmts, &mut rng);
         mem::swap(&mut buffer, &mut resource.eb);
-        *resource = _executor!(&self._uri, |config, uri| {
-          Executor::connect(
-            &config,
-            buffer,
-            &mut rng,
-            TcpStream::connect(uri.hostname_with_implied_port()).await.map_err(Into::into)?,
-          )
+        *resource = _executor!(&self._uri, |config| {
+          Executor::connect_hosts(&config, buffer, &mut rng, |host, port| async move {
+            Ok(TcpStream::connect((host, port)).await?)
+          })
         })?;
         Ok(())
       }
@@ -237,18 +234,18 @@ pub(crate) mod database {
       #[inline]
       async fn create(&self, _: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
         let mut rng = &self._rng;
-        _executor!(&self._uri, |config, uri| {
-          Executor::connect_encrypted(
+        _executor!(&self._uri, |config| {
+          Executor::connect_hosts_encrypted(
             &config,
             ExecutorBuffer::new(self._max_stmts, &mut rng),
             &mut rng,
-            TcpStream::connect(uri.hostname_with_implied_port()).await.map_err(Into::into)?,
-            |stream| async {
+            |host, port| async move { Ok(TcpStream::connect((host, port)).await?) },
+            |host, stream| async move {
               let mut rslt = TokioRustlsConnector::from_auto()?;
               if let Some(elem) = self._certs {
                 rslt = rslt.push_certs(elem)?;
               }
-              rslt.connect_without_client_auth(uri.hostname(), stream).await
+              rslt.connect_without_client_auth(host, stream).await
             },
           )
         })
@@ -268,18 +265,18 @@ pub(crate) mod database {
         let mut rng = &self._rng;
         let mut buffer = ExecutorBuffer::new(self._max_stmts, &mut rng);
         mem::swap(&mut buffer, &mut resource.eb);
-        *resource = _executor!(&self._uri, |config, uri| {
-          Executor::connect_encrypted(
+        *resource = _executor!(&self._uri, |config| {
+          Executor::connect_hosts_encrypted(
             &config,
             buffer,
             &mut rng,
-            TcpStream::connect(uri.hostname_with_implied_port()).await.map_err(Into::into)?,
-            |stream| async {
+            |host, port| async move { Ok(TcpStream::connect((host, port)).await?) },
+            |host, stream| async move {
               let mut rslt = TokioRustlsConnector::from_auto()?;
               if let Some(elem) = self._certs {
                 rslt = rslt.push_certs(elem)?;
               }
-              rslt.connect_without_client_auth(uri.hostname(), stream).await
+              rslt.connect_without_client_auth(host, stream).await
             },
           )
         })?;
@@ -288,3 +285,144 @@ pub(crate) mod database {
     }
   }
 }
+
+#[cfg(feature = "redis")]
+pub(crate) mod redis {
+  use alloc::string::String;
+  use core::marker::PhantomData;
+
+  const DEFAULT_PORT: u16 = 6379;
+
+  /// Manages Redis clients.
+  #[derive(Debug)]
+  pub struct RedisRM<S> {
+    _certs: Option<&'static [u8]>,
+    _stream: PhantomData<S>,
+    _uri: String,
+  }
+
+  macro_rules! _client {
+    ($uri_str:expr, |$config:ident, $host:ident, $port:ident| $cb:expr) => {{
+      let uri = crate::misc::UriRef::new($uri_str);
+      let $config = crate::database::client::redis::Config::from_uri(&uri)?;
+      let ($host, $port) = (uri.hostname(), uri.port().unwrap_or(DEFAULT_PORT));
+      $cb
+    }};
+  }
+
+  #[cfg(feature = "tokio")]
+  mod tokio {
+    use crate::{
+      database::client::redis::{Client, ClientBuffer},
+      pool::{resource_manager::redis::DEFAULT_PORT, RedisRM, ResourceManager},
+    };
+    use alloc::string::String;
+    use core::{marker::PhantomData, mem};
+    use tokio::net::TcpStream;
+
+    impl RedisRM<TcpStream> {
+      /// Resource manager using the `tokio` project.
+      #[inline]
+      pub fn tokio(uri: String) -> Self {
+        Self { _certs: None, _stream: PhantomData, _uri: uri }
+      }
+    }
+
+    impl ResourceManager for RedisRM<TcpStream> {
+      type CreateAux = ();
+      type Error = crate::Error;
+      type RecycleAux = ();
+      type Resource = Client<ClientBuffer, TcpStream>;
+
+      #[inline]
+      async fn create(&self, _: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
+        _client!(&self._uri, |config, host, port| {
+          Client::connect(&config, ClientBuffer::new(), TcpStream::connect((host, port)).await?)
+            .await
+        })
+      }
+
+      #[inline]
+      async fn is_invalid(&self, resource: &Self::Resource) -> bool {
+        resource.connection_state().is_closed()
+      }
+
+      #[inline]
+      async fn recycle(
+        &self,
+        _: &Self::RecycleAux,
+        resource: &mut Self::Resource,
+      ) -> Result<(), Self::Error> {
+        let mut buffer = ClientBuffer::new();
+        mem::swap(&mut buffer, &mut resource.cb);
+        *resource = _client!(&self._uri, |config, host, port| {
+          Client::connect(&config, buffer, TcpStream::connect((host, port)).await?).await
+        })?;
+        Ok(())
+      }
+    }
+  }
+
+  #[cfg(feature = "tokio-rustls")]
+  mod tokio_rustls {
+    use crate::{
+      database::client::redis::{Client, ClientBuffer},
+      misc::TokioRustlsConnector,
+      pool::{resource_manager::redis::DEFAULT_PORT, RedisRM, ResourceManager},
+    };
+    use alloc::string::String;
+    use core::{marker::PhantomData, mem};
+    use tokio::net::TcpStream;
+    use tokio_rustls::client::TlsStream;
+
+    impl RedisRM<TlsStream<TcpStream>> {
+      /// Resource manager using the `tokio-rustls` project.
+      #[inline]
+      pub fn tokio_rustls(certs: Option<&'static [u8]>, uri: String) -> Self {
+        Self { _certs: certs, _stream: PhantomData, _uri: uri }
+      }
+
+      #[inline]
+      async fn stream(&self, host: &str, port: u16) -> crate::Result<TlsStream<TcpStream>> {
+        let mut connector = TokioRustlsConnector::from_auto()?;
+        if let Some(elem) = self._certs {
+          connector = connector.push_certs(elem)?;
+        }
+        connector.connect_without_client_auth(host, TcpStream::connect((host, port)).await?).await
+      }
+    }
+
+    impl ResourceManager for RedisRM<TlsStream<TcpStream>> {
+      type CreateAux = ();
+      type Error = crate::Error;
+      type RecycleAux = ();
+      type Resource = Client<ClientBuffer, TlsStream<TcpStream>>;
+
+      #[inline]
+      async fn create(&self, _: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
+        _client!(&self._uri, |config, host, port| {
+          Client::connect(&config, ClientBuffer::new(), self.stream(host, port).await?).await
+        })
+      }
+
+      #[inline]
+      async fn is_invalid(&self, resource: &Self::Resource) -> bool {
+        resource.connection_state().is_closed()
+      }
+
+      #[inline]
+      async fn recycle(
+        &self,
+        _: &Self::RecycleAux,
+        resource: &mut Self::Resource,
+      ) -> Result<(), Self::Error> {
+        let mut buffer = ClientBuffer::new();
+        mem::swap(&mut buffer, &mut resource.cb);
+        *resource = _client!(&self._uri, |config, host, port| {
+          Client::connect(&config, buffer, self.stream(host, port).await?).await
+        })?;
+        Ok(())
+      }
+    }
+  }
+}